@@ -28,6 +28,9 @@ bind_enum!(
     ShiftGrid,
     ToggleCursorSnap,
     ToggleCollision,
+    ToggleSprites,
+    RotateClockwise,
+    RotateCounterClockwise,
     TextureEditor,
     PropertiesEditor,
     Settings
@@ -55,6 +58,9 @@ impl Bind
             Self::ShiftGrid => KeyCode::Slash,
             Self::ToggleCursorSnap => KeyCode::Backslash,
             Self::ToggleCollision => KeyCode::Equal,
+            Self::ToggleSprites => KeyCode::Digit0,
+            Self::RotateClockwise => KeyCode::PageDown,
+            Self::RotateCounterClockwise => KeyCode::PageUp,
             Self::Square => KeyCode::KeyQ,
             Self::Triangle => KeyCode::KeyT,
             Self::Circle => KeyCode::KeyR,
@@ -134,7 +140,7 @@ impl Bind
         -> bool
     {
         assert!(
-            matches!(self, Self::TextureEditor | Self::Snap | Self::Zoom),
+            matches!(self, Self::TextureEditor | Self::Snap | Self::Zoom | Self::Intersection),
             "Bind {self:?} has no alternative function."
         );
 
@@ -0,0 +1,399 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::{ButtonInput, KeyCode};
+use configparser::ini::Ini;
+use hill_vacuum_proc_macros::{EnumIter, EnumSize};
+use hill_vacuum_shared::continue_if_none;
+
+use super::{bind::Bind, BindsKeyCodes};
+use crate::{config::IniConfig, error_message, utils::misc::FromToStr, HardcodedActions};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The name of the section of the config file containing the subtools chords.
+const CHORDS_SECTION: &str = "SUBTOOL_CONTROLS";
+/// The separator of the keys of a chord in the config file.
+const CHORD_SEPARATOR: &str = " + ";
+/// The hardcoded chord of the quick prop creation of the paint tool.
+const PAINT_QUICK_CHORD: Chord = Chord::new(false, true, false, KeyCode::Enter);
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The subtools activated by a key chord that can be rebound.
+#[derive(Clone, Copy, Debug, EnumIter, EnumSize, PartialEq, Eq)]
+pub enum SubToolChord
+{
+    /// Vertex tool split.
+    VertexSplit,
+    /// Vertex tool polygon to path.
+    VertexPolygonToPath,
+    /// Path tool movement simulation.
+    PathSimulation,
+    /// Paint tool prop creation.
+    PaintCreation
+}
+
+impl SubToolChord
+{
+    /// The key of the chord in the config file.
+    #[inline]
+    #[must_use]
+    const fn config_file_key(self) -> &'static str
+    {
+        match self
+        {
+            Self::VertexSplit => "vertex_split",
+            Self::VertexPolygonToPath => "vertex_polygon_to_path",
+            Self::PathSimulation => "path_simulation",
+            Self::PaintCreation => "paint_creation"
+        }
+    }
+
+    /// The name of the tool the subtool belongs to. The chords of the subtools of the same tool
+    /// must be different.
+    #[inline]
+    #[must_use]
+    const fn tool(self) -> &'static str
+    {
+        match self
+        {
+            Self::VertexSplit | Self::VertexPolygonToPath => "Vertex",
+            Self::PathSimulation => "Path",
+            Self::PaintCreation => "Paint"
+        }
+    }
+
+    /// The default [`Chord`] associated with the subtool.
+    #[inline]
+    #[must_use]
+    const fn default_chord(self) -> Chord
+    {
+        match self
+        {
+            Self::VertexSplit | Self::PathSimulation | Self::PaintCreation =>
+            {
+                Chord::new(false, false, false, KeyCode::Enter)
+            },
+            Self::VertexPolygonToPath => Chord::new(false, true, false, KeyCode::Enter)
+        }
+    }
+
+    /// Returns the default subtools chords config file section.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_chords() -> String
+    {
+        let mut config = format!("[{CHORDS_SECTION}]\n");
+
+        for subtool in Self::iter()
+        {
+            config.push_str(&format!(
+                "{} = {}\n",
+                subtool.config_file_key(),
+                subtool.default_chord()
+            ));
+        }
+
+        config
+    }
+
+    /// Returns the [`Chord`] associated with the subtool.
+    #[inline]
+    #[must_use]
+    pub const fn chord(self, binds: &BindsKeyCodes) -> Chord { binds.chord(self) }
+
+    /// Whether the [`Chord`] associated with the subtool has just been pressed.
+    #[inline]
+    #[must_use]
+    pub fn just_pressed(self, key_inputs: &ButtonInput<KeyCode>, binds: &BindsKeyCodes) -> bool
+    {
+        binds.chord(self).just_pressed(key_inputs)
+    }
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A key pressed while holding down an exact set of modifiers.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chord
+{
+    /// Whether Ctrl must be pressed.
+    ctrl:  bool,
+    /// Whether Shift must be pressed.
+    shift: bool,
+    /// Whether Alt must be pressed.
+    alt:   bool,
+    /// The key.
+    key:   KeyCode
+}
+
+impl std::fmt::Display for Chord
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for (pressed, modifier) in [(self.ctrl, "Ctrl"), (self.shift, "Shift"), (self.alt, "Alt")]
+        {
+            if pressed
+            {
+                write!(f, "{modifier}{CHORD_SEPARATOR}")?;
+            }
+        }
+
+        write!(f, "{}", self.key.to_str())
+    }
+}
+
+impl Chord
+{
+    /// Returns a new [`Chord`].
+    #[inline]
+    const fn new(ctrl: bool, shift: bool, alt: bool, key: KeyCode) -> Self
+    {
+        Self {
+            ctrl,
+            shift,
+            alt,
+            key
+        }
+    }
+
+    /// Returns the [`Chord`] described by `value`, such as "Ctrl + Shift + Enter", if it is
+    /// valid.
+    #[inline]
+    fn from_config_value(value: &str) -> Option<Self>
+    {
+        let mut chord = Self::new(false, false, false, KeyCode::Enter);
+        let mut key = None;
+
+        for token in value.split('+').map(str::trim)
+        {
+            let modifier = match token
+            {
+                "Ctrl" => &mut chord.ctrl,
+                "Shift" => &mut chord.shift,
+                "Alt" => &mut chord.alt,
+                _ =>
+                {
+                    if key.is_some()
+                    {
+                        return None;
+                    }
+
+                    key = KeyCode::from_str(token)?.into();
+                    continue;
+                }
+            };
+
+            if *modifier
+            {
+                return None;
+            }
+
+            *modifier = true;
+        }
+
+        chord.key = key?;
+        Self::is_key_legal(chord.key).then_some(chord)
+    }
+
+    /// Whether `key` can be the key of a [`Chord`].
+    #[inline]
+    #[must_use]
+    fn is_key_legal(key: KeyCode) -> bool
+    {
+        const ILLEGAL_KEYS: [KeyCode; 10] = [
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::AltLeft,
+            KeyCode::AltRight,
+            KeyCode::ShiftLeft,
+            KeyCode::ShiftRight,
+            KeyCode::Escape,
+            KeyCode::Space,
+            KeyCode::Tab,
+            KeyCode::Backspace
+        ];
+
+        ILLEGAL_KEYS.into_iter().all(|k| k != key)
+    }
+
+    /// Whether the key of the [`Chord`] has just been pressed while exactly its modifiers are held
+    /// down.
+    #[inline]
+    #[must_use]
+    pub fn just_pressed(&self, key_inputs: &ButtonInput<KeyCode>) -> bool
+    {
+        let held = |keys: [KeyCode; 2]| key_inputs.any_pressed(keys);
+
+        key_inputs.just_pressed(self.key) &&
+            held([KeyCode::ControlLeft, KeyCode::ControlRight]) == self.ctrl &&
+            held([KeyCode::ShiftLeft, KeyCode::ShiftRight]) == self.shift &&
+            held([KeyCode::AltLeft, KeyCode::AltRight]) == self.alt
+    }
+
+    /// Whether the [`Chord`] is triggered by the same keys as the unmodified press of `key`.
+    #[inline]
+    #[must_use]
+    fn overlaps_key(&self, key: KeyCode) -> bool
+    {
+        !(self.ctrl || self.shift || self.alt) && self.key == key
+    }
+
+    /// Whether the [`Chord`] also triggers the [`HardcodedActions`] `action`.
+    #[inline]
+    #[must_use]
+    fn overlaps_hardcoded_action(&self, action: HardcodedActions) -> bool
+    {
+        if self.key != action.key()
+        {
+            return false;
+        }
+
+        match action
+        {
+            HardcodedActions::Fullscreen => self.alt,
+            HardcodedActions::ToggleManual => true,
+            _ => self.ctrl
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The [`Chord`]s associated with the [`SubToolChord`]s.
+#[derive(Clone, Copy)]
+pub(in crate::config::controls) struct SubToolChords([Chord; SubToolChord::SIZE]);
+
+impl Default for SubToolChords
+{
+    #[inline]
+    fn default() -> Self
+    {
+        let mut chords = [SubToolChord::VertexSplit.default_chord(); SubToolChord::SIZE];
+
+        for (chord, subtool) in chords.iter_mut().zip(SubToolChord::iter())
+        {
+            *chord = subtool.default_chord();
+        }
+
+        Self(chords)
+    }
+}
+
+impl SubToolChords
+{
+    /// Returns the [`Chord`] associated with `subtool`.
+    #[inline]
+    pub const fn get(&self, subtool: SubToolChord) -> Chord { self.0[subtool as usize] }
+
+    /// Loads the chords stored in `config`. Invalid chords, chords shared by subtools of the same
+    /// tool, unmodified chords using the key of a [`Bind`], and chords triggering hardcoded
+    /// shortcuts are reported and replaced by the default ones.
+    #[inline]
+    pub fn load(&mut self, config: &Ini, binds: &BindsKeyCodes)
+    {
+        let mut errors = Vec::new();
+
+        for subtool in SubToolChord::iter()
+        {
+            let value = continue_if_none!(config.get(CHORDS_SECTION, subtool.config_file_key()));
+
+            match Chord::from_config_value(&value)
+            {
+                Some(chord) => self.0[subtool as usize] = chord,
+                None => errors.push(format!("Invalid chord \"{value}\" for {subtool:?}"))
+            };
+        }
+
+        for subtool in SubToolChord::iter()
+        {
+            let chord = self.get(subtool);
+
+            if let Some(bind) = Bind::iter().find(|bind| {
+                binds.get(*bind).is_some_and(|key| chord.overlaps_key(key))
+            })
+            {
+                errors.push(format!(
+                    "{subtool:?} chord \"{chord}\" conflicts with the {bind:?} bind"
+                ));
+            }
+            else if let Some(action) =
+                HardcodedActions::iter().find(|action| chord.overlaps_hardcoded_action(*action))
+            {
+                errors.push(format!(
+                    "{subtool:?} chord \"{chord}\" conflicts with the {} shortcut",
+                    action.key_combo()
+                ));
+            }
+            else if subtool.tool() == "Paint" && chord == PAINT_QUICK_CHORD
+            {
+                errors.push(format!(
+                    "{subtool:?} chord \"{chord}\" conflicts with the quick prop creation"
+                ));
+            }
+            else
+            {
+                continue;
+            }
+
+            self.0[subtool as usize] = subtool.default_chord();
+        }
+
+        for (i, a) in SubToolChord::iter().enumerate()
+        {
+            for b in SubToolChord::iter().skip(i + 1)
+            {
+                if a.tool() != b.tool() || self.get(a) != self.get(b)
+                {
+                    continue;
+                }
+
+                errors.push(format!(
+                    "{a:?} and {b:?} share the chord \"{}\" in the {} tool",
+                    self.get(a),
+                    a.tool()
+                ));
+                self.0[a as usize] = a.default_chord();
+                self.0[b as usize] = b.default_chord();
+            }
+        }
+
+        if errors.is_empty()
+        {
+            return;
+        }
+
+        error_message(&format!(
+            "Subtool chords conflicts, the default chords were restored:\n{}",
+            errors.join("\n")
+        ));
+    }
+
+    /// Stores the chords in `config`.
+    #[inline]
+    pub fn save(&self, config: &mut IniConfig)
+    {
+        for subtool in SubToolChord::iter()
+        {
+            config.0.set(
+                CHORDS_SECTION,
+                subtool.config_file_key(),
+                self.get(subtool).to_string().into()
+            );
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod bind;
+pub mod chord;
 
 //=======================================================================//
 // IMPORTS
@@ -9,7 +10,10 @@ use bevy::input::keyboard::KeyCode;
 use configparser::ini::Ini;
 use hill_vacuum_shared::{continue_if_none, return_if_none};
 
-use self::bind::Bind;
+use self::{
+    bind::Bind,
+    chord::{Chord, SubToolChord, SubToolChords}
+};
 use super::IniConfig;
 use crate::utils::{iterators::SkipIndexIterator, misc::FromToStr};
 
@@ -20,19 +24,26 @@ use crate::utils::{iterators::SkipIndexIterator, misc::FromToStr};
 
 /// The name of the sections of the config file containing the controls binds.
 const INI_SECTION: &str = "EDITOR_CONTROLS";
+/// The name of the section of the config file containing the key repeat timing.
+const KEY_REPEAT_SECTION: &str = "KEY_REPEAT";
+/// The key repeat delay ini key.
+const KEY_REPEAT_DELAY_FIELD: &str = "delay";
+/// The key repeat interval ini key.
+const KEY_REPEAT_INTERVAL_FIELD: &str = "interval";
 
 //=======================================================================//
 // TYPES
 //
 //=======================================================================//
 
-/// `Keycode` values associated with the `Bind`s.
-pub struct BindsKeyCodes([Option<KeyCode>; Bind::SIZE]);
+/// `Keycode` values associated with the `Bind`s, and [`Chord`]s associated with the
+/// [`SubToolChord`]s.
+pub struct BindsKeyCodes([Option<KeyCode>; Bind::SIZE], SubToolChords);
 
 impl Default for BindsKeyCodes
 {
     #[inline]
-    fn default() -> Self { Self([None; Bind::SIZE]) }
+    fn default() -> Self { Self([None; Bind::SIZE], SubToolChords::default()) }
 }
 
 impl BindsKeyCodes
@@ -45,6 +56,10 @@ impl BindsKeyCodes
         {
             bind.set_from_config(config, self);
         }
+
+        let mut chords = self.1;
+        chords.load(config, self);
+        self.1 = chords;
     }
 
     /// Stores the `Keycode` values of the binds in `config`.
@@ -61,6 +76,8 @@ impl BindsKeyCodes
 
             config.0.set(INI_SECTION, bind.config_file_key(), Some(value));
         }
+
+        self.1.save(config);
     }
 
     /// Returns the `KeyCode` value associated with `bind`.
@@ -68,6 +85,10 @@ impl BindsKeyCodes
     #[must_use]
     pub const fn get(&self, bind: Bind) -> Option<KeyCode> { self.0[bind as usize] }
 
+    /// Returns the [`Chord`] associated with `subtool`.
+    #[inline]
+    pub const fn chord(&self, subtool: SubToolChord) -> Chord { self.1.get(subtool) }
+
     /// Sets the `KeyCode` associated with `bind`.
     #[inline]
     fn set(&mut self, bind: Bind, value: KeyCode) { self.0[bind as usize] = value.into(); }
@@ -122,3 +143,130 @@ impl BindsKeyCodes
         }
     }
 }
+
+//=======================================================================//
+
+/// The timing of the repetition of the nudge actions while their keys are held down.
+#[derive(Clone, Copy)]
+pub struct KeyRepeat
+{
+    /// The time, in seconds, a key must be held down before its press starts being repeated.
+    delay:    f32,
+    /// The time, in seconds, between two repetitions.
+    interval: f32
+}
+
+impl Default for KeyRepeat
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            delay:    Self::DEFAULT_DELAY,
+            interval: Self::DEFAULT_INTERVAL
+        }
+    }
+}
+
+impl KeyRepeat
+{
+    /// The default delay.
+    const DEFAULT_DELAY: f32 = 0.4;
+    /// The default interval.
+    const DEFAULT_INTERVAL: f32 = 0.05;
+    /// The range of the delay.
+    pub const DELAY_RANGE: std::ops::RangeInclusive<f32> = 0.1..=2f32;
+    /// The range of the interval.
+    pub const INTERVAL_RANGE: std::ops::RangeInclusive<f32> = 0.01..=1f32;
+
+    /// Returns the default key repeat config file section.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_key_repeat() -> String
+    {
+        format!(
+            "[{KEY_REPEAT_SECTION}]\n{KEY_REPEAT_DELAY_FIELD} = {}\n{KEY_REPEAT_INTERVAL_FIELD} = \
+             {}\n",
+            Self::DEFAULT_DELAY,
+            Self::DEFAULT_INTERVAL
+        )
+    }
+
+    /// Loads the key repeat timing stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        /// Reads the value associated with `key`, if any.
+        #[inline]
+        fn value(config: &Ini, key: &str, range: &std::ops::RangeInclusive<f32>) -> Option<f32>
+        {
+            config
+                .get(KEY_REPEAT_SECTION, key)?
+                .parse::<f32>()
+                .ok()
+                .map(|value| value.clamp(*range.start(), *range.end()))
+        }
+
+        if let Some(delay) = value(config, KEY_REPEAT_DELAY_FIELD, &Self::DELAY_RANGE)
+        {
+            self.delay = delay;
+        }
+
+        if let Some(interval) = value(config, KEY_REPEAT_INTERVAL_FIELD, &Self::INTERVAL_RANGE)
+        {
+            self.interval = interval;
+        }
+    }
+
+    /// Stores the key repeat timing in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(KEY_REPEAT_SECTION, KEY_REPEAT_DELAY_FIELD, self.delay.to_string().into());
+        config.0.set(
+            KEY_REPEAT_SECTION,
+            KEY_REPEAT_INTERVAL_FIELD,
+            self.interval.to_string().into()
+        );
+    }
+
+    /// Returns a mutable reference to the delay.
+    #[inline]
+    #[must_use]
+    pub fn delay_mut(&mut self) -> &mut f32 { &mut self.delay }
+
+    /// Returns a mutable reference to the interval.
+    #[inline]
+    #[must_use]
+    pub fn interval_mut(&mut self) -> &mut f32 { &mut self.interval }
+
+    /// Resets the timing to the default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+
+    /// Whether a key that was held down for `prev` seconds in the previous frame and for `held`
+    /// seconds in the current one should repeat its press.
+    #[inline]
+    #[must_use]
+    pub fn repeats(&self, prev: f32, held: f32) -> bool
+    {
+        /// Returns the amount of repetitions that occurred after the key was held down for `time`
+        /// seconds.
+        #[inline]
+        #[must_use]
+        fn repetitions(key_repeat: &KeyRepeat, time: f32) -> Option<u32>
+        {
+            if time < key_repeat.delay
+            {
+                return None;
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Some(((time - key_repeat.delay) / key_repeat.interval) as u32)
+        }
+
+        repetitions(self, held) > repetitions(self, prev)
+    }
+}
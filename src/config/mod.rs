@@ -20,16 +20,18 @@ use bevy::{
         system::{Res, ResMut, Resource},
         world::{FromWorld, Mut, World}
     },
+    math::Vec2,
     sprite::ColorMaterial
 };
 use configparser::ini::Ini;
-use hill_vacuum_shared::FILE_EXTENSION;
+use hill_vacuum_shared::{return_if_none, FILE_EXTENSION};
 use is_executable::IsExecutable;
 
-use self::controls::{bind::Bind, BindsKeyCodes};
+use self::controls::{bind::Bind, chord::SubToolChord, BindsKeyCodes, KeyRepeat};
 use crate::{
     error_message,
     map::drawer::color::{Color, ColorResources},
+    utils::hull::Hull,
     EditorState
 };
 
@@ -48,6 +50,76 @@ const OPEN_FILE_FIELD: &str = "file";
 const EXPORTER_SECTION: &str = "EXPORTER";
 /// The exporter executable ini key.
 const EXPORTER_FIELD: &str = "exporter";
+/// The ini section of the new map template key.
+const TEMPLATE_SECTION: &str = "TEMPLATE";
+/// The new map template file ini key.
+const TEMPLATE_FIELD: &str = "template";
+/// The ini section of the detail culling.
+const DETAIL_CULLING_SECTION: &str = "DETAIL_CULLING";
+/// The detail culling minimum size ini key.
+const DETAIL_CULLING_MIN_SIZE_FIELD: &str = "min_size";
+/// The ini section of the map complexity limits.
+const COMPLEXITY_LIMITS_SECTION: &str = "COMPLEXITY_LIMITS";
+/// The maximum amount of brushes ini key.
+const COMPLEXITY_LIMITS_BRUSHES_FIELD: &str = "brushes";
+/// The maximum amount of vertexes ini key.
+const COMPLEXITY_LIMITS_VERTEXES_FIELD: &str = "vertexes";
+/// The maximum amount of things ini key.
+const COMPLEXITY_LIMITS_THINGS_FIELD: &str = "things";
+/// The ini section of the selection of the spawned entities.
+const SPAWN_SELECTION_SECTION: &str = "SPAWN_SELECTION";
+/// The spawned entities selection ini key.
+const SPAWN_SELECTION_FIELD: &str = "select";
+/// The ini section of the play area.
+const PLAY_AREA_SECTION: &str = "PLAY_AREA";
+/// The play area visibility ini key.
+const PLAY_AREA_VISIBLE_FIELD: &str = "visible";
+/// The play area left bound ini key.
+const PLAY_AREA_LEFT_FIELD: &str = "left";
+/// The play area right bound ini key.
+const PLAY_AREA_RIGHT_FIELD: &str = "right";
+/// The play area bottom bound ini key.
+const PLAY_AREA_BOTTOM_FIELD: &str = "bottom";
+/// The play area top bound ini key.
+const PLAY_AREA_TOP_FIELD: &str = "top";
+/// The ini section of the canonical save.
+const CANONICAL_SAVE_SECTION: &str = "CANONICAL_SAVE";
+/// The canonical save toggle ini key.
+const CANONICAL_SAVE_ENABLED_FIELD: &str = "enabled";
+/// The canonical save decimal places ini key.
+const CANONICAL_SAVE_DECIMALS_FIELD: &str = "decimals";
+/// The ini section of the export written alongside the map file.
+const SAVE_EXPORT_SECTION: &str = "SAVE_EXPORT";
+/// The save export format ini key.
+const SAVE_EXPORT_FORMAT_FIELD: &str = "format";
+/// The ini section of the recently used textures.
+const RECENT_TEXTURES_SECTION: &str = "RECENT_TEXTURES";
+/// The amount of recently used textures ini key.
+const RECENT_TEXTURES_AMOUNT_FIELD: &str = "amount";
+/// The ini section of the operations log key.
+const OPERATIONS_LOG_SECTION: &str = "OPERATIONS_LOG";
+/// The operations log target ini key.
+const OPERATIONS_LOG_FIELD: &str = "log";
+/// The operations log target value to write the log to the standard output.
+const OPERATIONS_LOG_STDOUT: &str = "stdout";
+/// The ini section of the tools hidden from the toolbar.
+const HIDDEN_TOOLS_SECTION: &str = "HIDDEN_TOOLS";
+/// The hidden tools ini key.
+const HIDDEN_TOOLS_FIELD: &str = "tools";
+/// The separator of the names of the hidden tools in the config file.
+const HIDDEN_TOOLS_SEPARATOR: char = ',';
+/// The ini section of the confirmation of the bulk destructive operations.
+const BULK_CONFIRMATION_SECTION: &str = "BULK_CONFIRMATION";
+/// The bulk confirmation entities threshold ini key.
+const BULK_CONFIRMATION_THRESHOLD_FIELD: &str = "threshold";
+/// The ini section of the snap threshold.
+const SNAP_SECTION: &str = "SNAP";
+/// The snap threshold distance in pixels ini key.
+const SNAP_THRESHOLD_FIELD: &str = "threshold";
+/// The ini section of the tolerance of the overlapping things check.
+const OVERLAPPING_THINGS_SECTION: &str = "OVERLAPPING_THINGS";
+/// The overlapping things tolerance ini key.
+const OVERLAPPING_THINGS_TOLERANCE_FIELD: &str = "tolerance";
 
 //=======================================================================//
 // TYPES
@@ -111,16 +183,951 @@ impl OpenFile
 
 //=======================================================================//
 
+/// The settings of the culling of the entities that are too small on screen to be worth drawing.
+#[derive(Clone, Copy)]
+pub struct DetailCulling
+{
+    /// The minimum size, in pixels, the longest side of the [`Hull`](crate::Hull) of an entity
+    /// must have on screen for it to be drawn.
+    min_size: f32
+}
+
+impl Default for DetailCulling
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            min_size: Self::DEFAULT_MIN_SIZE
+        }
+    }
+}
+
+impl DetailCulling
+{
+    /// The default minimum size.
+    const DEFAULT_MIN_SIZE: f32 = 4f32;
+    /// The range of the minimum size.
+    pub const MIN_SIZE_RANGE: std::ops::RangeInclusive<f32> = 1f32..=64f32;
+
+    /// Returns the default detail culling config file section.
+    #[inline]
+    #[must_use]
+    fn default_detail_culling() -> String
+    {
+        format!(
+            "[{DETAIL_CULLING_SECTION}]\n{DETAIL_CULLING_MIN_SIZE_FIELD} = {}\n",
+            Self::DEFAULT_MIN_SIZE
+        )
+    }
+
+    /// Loads the detail culling settings stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(min_size) = config
+            .get(DETAIL_CULLING_SECTION, DETAIL_CULLING_MIN_SIZE_FIELD)
+            .and_then(|value| value.parse::<f32>().ok())
+        {
+            self.min_size =
+                min_size.clamp(*Self::MIN_SIZE_RANGE.start(), *Self::MIN_SIZE_RANGE.end());
+        }
+    }
+
+    /// Stores the detail culling settings in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            DETAIL_CULLING_SECTION,
+            DETAIL_CULLING_MIN_SIZE_FIELD,
+            self.min_size.to_string().into()
+        );
+    }
+
+    /// Returns the minimum on screen size.
+    #[inline]
+    #[must_use]
+    pub const fn min_size(&self) -> f32 { self.min_size }
+
+    /// Returns a mutable reference to the minimum on screen size.
+    #[inline]
+    #[must_use]
+    pub fn min_size_mut(&mut self) -> &mut f32 { &mut self.min_size }
+
+    /// Resets the settings to the default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+}
+
+//=======================================================================//
+
+/// Whether the entities spawned by drawing, pasting, and painting props are left selected.
+#[derive(Clone, Copy)]
+pub struct SpawnSelection(bool);
+
+impl Default for SpawnSelection
+{
+    #[inline]
+    fn default() -> Self { Self(true) }
+}
+
+impl SpawnSelection
+{
+    /// Returns the default spawn selection config file section.
+    #[inline]
+    #[must_use]
+    fn default_spawn_selection() -> String
+    {
+        format!("[{SPAWN_SELECTION_SECTION}]\n{SPAWN_SELECTION_FIELD} = true\n")
+    }
+
+    /// Loads the spawn selection setting stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(select) = config
+            .get(SPAWN_SELECTION_SECTION, SPAWN_SELECTION_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            self.0 = select;
+        }
+    }
+
+    /// Stores the spawn selection setting in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(SPAWN_SELECTION_SECTION, SPAWN_SELECTION_FIELD, self.0.to_string().into());
+    }
+
+    /// Whether the spawned entities are left selected.
+    #[inline]
+    #[must_use]
+    pub const fn enabled(&self) -> bool { self.0 }
+
+    /// Returns a mutable reference to the value determining whether the spawned entities are left
+    /// selected.
+    #[inline]
+    #[must_use]
+    pub fn enabled_mut(&mut self) -> &mut bool { &mut self.0 }
+}
+
+//=======================================================================//
+
+/// The rectangle drawn on top of the map to show the area the camera of the game can reach.
+/// It is only used as a visual reference and is not stored in the map files.
+#[derive(Clone, Copy)]
+pub struct PlayArea
+{
+    /// Whether the rectangle is drawn.
+    pub visible: bool,
+    /// The left bound.
+    pub left:    f32,
+    /// The right bound.
+    pub right:   f32,
+    /// The bottom bound.
+    pub bottom:  f32,
+    /// The top bound.
+    pub top:     f32
+}
+
+impl Default for PlayArea
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            visible: false,
+            left:    -Self::DEFAULT_HALF_SIZE,
+            right:   Self::DEFAULT_HALF_SIZE,
+            bottom:  -Self::DEFAULT_HALF_SIZE,
+            top:     Self::DEFAULT_HALF_SIZE
+        }
+    }
+}
+
+impl PlayArea
+{
+    /// The default half size of the rectangle.
+    const DEFAULT_HALF_SIZE: f32 = 1024f32;
+
+    /// Returns the default play area config file section.
+    #[inline]
+    #[must_use]
+    fn default_play_area() -> String
+    {
+        let half_size = Self::DEFAULT_HALF_SIZE;
+
+        format!(
+            "[{PLAY_AREA_SECTION}]\n{PLAY_AREA_VISIBLE_FIELD} = false\n\
+             {PLAY_AREA_LEFT_FIELD} = -{half_size}\n{PLAY_AREA_RIGHT_FIELD} = {half_size}\n\
+             {PLAY_AREA_BOTTOM_FIELD} = -{half_size}\n{PLAY_AREA_TOP_FIELD} = {half_size}\n"
+        )
+    }
+
+    /// Loads the play area stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(visible) = config
+            .get(PLAY_AREA_SECTION, PLAY_AREA_VISIBLE_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            self.visible = visible;
+        }
+
+        for (field, value) in [
+            (PLAY_AREA_LEFT_FIELD, &mut self.left),
+            (PLAY_AREA_RIGHT_FIELD, &mut self.right),
+            (PLAY_AREA_BOTTOM_FIELD, &mut self.bottom),
+            (PLAY_AREA_TOP_FIELD, &mut self.top)
+        ]
+        {
+            if let Some(bound) = config
+                .get(PLAY_AREA_SECTION, field)
+                .and_then(|value| value.parse::<f32>().ok())
+            {
+                *value = bound;
+            }
+        }
+    }
+
+    /// Stores the play area in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(PLAY_AREA_SECTION, PLAY_AREA_VISIBLE_FIELD, self.visible.to_string().into());
+
+        for (field, value) in [
+            (PLAY_AREA_LEFT_FIELD, self.left),
+            (PLAY_AREA_RIGHT_FIELD, self.right),
+            (PLAY_AREA_BOTTOM_FIELD, self.bottom),
+            (PLAY_AREA_TOP_FIELD, self.top)
+        ]
+        {
+            config.0.set(PLAY_AREA_SECTION, field, value.to_string().into());
+        }
+    }
+
+    /// Returns the [`Hull`] describing the rectangle, if it should be drawn.
+    #[inline]
+    #[must_use]
+    pub fn hull(&self) -> Option<Hull>
+    {
+        if !self.visible
+        {
+            return None;
+        }
+
+        Hull::from_opposite_vertexes(
+            Vec2::new(self.left, self.bottom),
+            Vec2::new(self.right, self.top)
+        )
+    }
+
+    /// Resets the settings to the default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+}
+
+//=======================================================================//
+
+/// The normalization of the numeric values written to the map files, which makes the files of
+/// semantically identical maps identical.
+#[derive(Clone, Copy)]
+pub struct CanonicalSave
+{
+    /// Whether the values are normalized.
+    pub enabled: bool,
+    /// The amount of decimal places the floating point values are rounded to.
+    decimals:    u8
+}
+
+impl Default for CanonicalSave
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            enabled:  false,
+            decimals: Self::DEFAULT_DECIMALS
+        }
+    }
+}
+
+impl CanonicalSave
+{
+    /// The default amount of decimal places.
+    const DEFAULT_DECIMALS: u8 = 3;
+    /// The range of the amount of decimal places.
+    pub const DECIMALS_RANGE: std::ops::RangeInclusive<u8> = 2..=6;
+
+    /// Returns the default canonical save config file section.
+    #[inline]
+    #[must_use]
+    fn default_canonical_save() -> String
+    {
+        format!(
+            "[{CANONICAL_SAVE_SECTION}]\n{CANONICAL_SAVE_ENABLED_FIELD} = false\n\
+             {CANONICAL_SAVE_DECIMALS_FIELD} = {}\n",
+            Self::DEFAULT_DECIMALS
+        )
+    }
+
+    /// Loads the canonical save settings stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(enabled) = config
+            .get(CANONICAL_SAVE_SECTION, CANONICAL_SAVE_ENABLED_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            self.enabled = enabled;
+        }
+
+        if let Some(decimals) = config
+            .get(CANONICAL_SAVE_SECTION, CANONICAL_SAVE_DECIMALS_FIELD)
+            .and_then(|value| value.parse::<u8>().ok())
+        {
+            self.decimals =
+                decimals.clamp(*Self::DECIMALS_RANGE.start(), *Self::DECIMALS_RANGE.end());
+        }
+    }
+
+    /// Stores the canonical save settings in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            CANONICAL_SAVE_SECTION,
+            CANONICAL_SAVE_ENABLED_FIELD,
+            self.enabled.to_string().into()
+        );
+        config.0.set(
+            CANONICAL_SAVE_SECTION,
+            CANONICAL_SAVE_DECIMALS_FIELD,
+            self.decimals.to_string().into()
+        );
+    }
+
+    /// Returns the amount of decimal places the values are rounded to, if the normalization is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn decimals(&self) -> Option<u8> { self.enabled.then_some(self.decimals) }
+
+    /// Returns a mutable reference to the amount of decimal places.
+    #[inline]
+    #[must_use]
+    pub fn decimals_mut(&mut self) -> &mut u8 { &mut self.decimals }
+
+    /// Resets the settings to the default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+}
+
+//=======================================================================//
+
+/// The export format written alongside the map file every time it is saved.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum SaveExport
+{
+    /// Only the map file is written.
+    #[default]
+    None,
+    /// The binary layout written by [`Exporter::write_binary`](crate::Exporter::write_binary).
+    Binary,
+    /// The binary layout written by
+    /// [`Exporter::write_binary_with_bounds`](crate::Exporter::write_binary_with_bounds).
+    BinaryWithBounds,
+    /// The TMX map written by [`Exporter::write_tmx`](crate::Exporter::write_tmx).
+    Tmx
+}
+
+impl SaveExport
+{
+    /// All the formats.
+    pub const ALL: [Self; 4] = [Self::None, Self::Binary, Self::BinaryWithBounds, Self::Tmx];
+
+    /// Returns the default save export config file section.
+    #[inline]
+    #[must_use]
+    fn default_save_export() -> String
+    {
+        format!(
+            "[{SAVE_EXPORT_SECTION}]\n{SAVE_EXPORT_FORMAT_FIELD} = {}\n",
+            Self::None.config_value()
+        )
+    }
+
+    /// Returns the value representing `self` in the config file.
+    #[inline]
+    #[must_use]
+    const fn config_value(self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "none",
+            Self::Binary => "binary",
+            Self::BinaryWithBounds => "binary_with_bounds",
+            Self::Tmx => "tmx"
+        }
+    }
+
+    /// Loads the save export format stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(format) =
+            config.get(SAVE_EXPORT_SECTION, SAVE_EXPORT_FORMAT_FIELD).and_then(|value| {
+                Self::ALL.into_iter().find(|format| format.config_value() == value)
+            })
+        {
+            *self = format;
+        }
+    }
+
+    /// Stores the save export format in `config`.
+    #[inline]
+    fn save(self, config: &mut IniConfig)
+    {
+        config.0.set(
+            SAVE_EXPORT_SECTION,
+            SAVE_EXPORT_FORMAT_FIELD,
+            self.config_value().to_owned().into()
+        );
+    }
+
+    /// The name of the format shown in the UI.
+    #[inline]
+    #[must_use]
+    pub const fn label(self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "None",
+            Self::Binary => "Binary",
+            Self::BinaryWithBounds => "Binary with bounds",
+            Self::Tmx => "TMX"
+        }
+    }
+
+    /// The extension of the file written in the format, if any.
+    #[inline]
+    #[must_use]
+    pub const fn extension(self) -> Option<&'static str>
+    {
+        match self
+        {
+            Self::None => None,
+            Self::Binary | Self::BinaryWithBounds => Some("bin"),
+            Self::Tmx => Some("tmx")
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The amount of recently assigned textures shown in the quick bar of the texture editor.
+#[derive(Clone, Copy)]
+pub struct RecentTextures(usize);
+
+impl Default for RecentTextures
+{
+    #[inline]
+    fn default() -> Self { Self(Self::DEFAULT_AMOUNT) }
+}
+
+impl RecentTextures
+{
+    /// The default amount of textures.
+    const DEFAULT_AMOUNT: usize = 8;
+    /// The range of the amount of textures.
+    pub const AMOUNT_RANGE: std::ops::RangeInclusive<usize> = 0..=16;
+
+    /// Returns the default recent textures config file section.
+    #[inline]
+    #[must_use]
+    fn default_recent_textures() -> String
+    {
+        format!(
+            "[{RECENT_TEXTURES_SECTION}]\n{RECENT_TEXTURES_AMOUNT_FIELD} = {}\n",
+            Self::DEFAULT_AMOUNT
+        )
+    }
+
+    /// Loads the recent textures setting stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(amount) = config
+            .get(RECENT_TEXTURES_SECTION, RECENT_TEXTURES_AMOUNT_FIELD)
+            .and_then(|value| value.parse::<usize>().ok())
+        {
+            self.0 = amount.min(*Self::AMOUNT_RANGE.end());
+        }
+    }
+
+    /// Stores the recent textures setting in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            RECENT_TEXTURES_SECTION,
+            RECENT_TEXTURES_AMOUNT_FIELD,
+            self.0.to_string().into()
+        );
+    }
+
+    /// Returns the amount of textures shown.
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> usize { self.0 }
+
+    /// Returns a mutable reference to the amount of textures shown.
+    #[inline]
+    #[must_use]
+    pub fn amount_mut(&mut self) -> &mut usize { &mut self.0 }
+}
+
+//=======================================================================//
+
+/// The amount of selected entities from which the destructive operations on the selection must be
+/// confirmed. A threshold equal to zero disables the confirmation.
+#[derive(Clone, Copy)]
+pub struct BulkConfirmation(usize);
+
+impl Default for BulkConfirmation
+{
+    #[inline]
+    fn default() -> Self { Self(Self::DEFAULT_THRESHOLD) }
+}
+
+impl BulkConfirmation
+{
+    /// The default threshold.
+    const DEFAULT_THRESHOLD: usize = 100;
+    /// The range of the threshold.
+    pub const THRESHOLD_RANGE: std::ops::RangeInclusive<usize> = 0..=100_000;
+
+    /// Returns the default bulk confirmation config file section.
+    #[inline]
+    #[must_use]
+    fn default_bulk_confirmation() -> String
+    {
+        format!(
+            "[{BULK_CONFIRMATION_SECTION}]\n{BULK_CONFIRMATION_THRESHOLD_FIELD} = {}\n",
+            Self::DEFAULT_THRESHOLD
+        )
+    }
+
+    /// Loads the bulk confirmation setting stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(threshold) = config
+            .get(BULK_CONFIRMATION_SECTION, BULK_CONFIRMATION_THRESHOLD_FIELD)
+            .and_then(|value| value.parse::<usize>().ok())
+        {
+            self.0 = threshold.min(*Self::THRESHOLD_RANGE.end());
+        }
+    }
+
+    /// Stores the bulk confirmation setting in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            BULK_CONFIRMATION_SECTION,
+            BULK_CONFIRMATION_THRESHOLD_FIELD,
+            self.0.to_string().into()
+        );
+    }
+
+    /// Returns the threshold.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> usize { self.0 }
+
+    /// Returns a mutable reference to the threshold.
+    #[inline]
+    #[must_use]
+    pub fn threshold_mut(&mut self) -> &mut usize { &mut self.0 }
+}
+
+//=======================================================================//
+
+/// The distance, in screen pixels, within which the snapping features snap the cursor to the
+/// nearby geometry. It is converted to world units through the camera scale, so that snapping
+/// feels the same at every zoom level.
+#[derive(Clone, Copy)]
+pub struct SnapThreshold(f32);
+
+impl Default for SnapThreshold
+{
+    #[inline]
+    fn default() -> Self { Self(Self::DEFAULT_PIXELS) }
+}
+
+impl SnapThreshold
+{
+    /// The default threshold.
+    const DEFAULT_PIXELS: f32 = 10f32;
+    /// The range of the threshold.
+    pub const PIXELS_RANGE: std::ops::RangeInclusive<f32> = 1f32..=64f32;
+
+    /// Returns the default snap threshold config file section.
+    #[inline]
+    #[must_use]
+    fn default_snap_threshold() -> String
+    {
+        format!("[{SNAP_SECTION}]\n{SNAP_THRESHOLD_FIELD} = {}\n", Self::DEFAULT_PIXELS)
+    }
+
+    /// Loads the snap threshold stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(pixels) = config
+            .get(SNAP_SECTION, SNAP_THRESHOLD_FIELD)
+            .and_then(|value| value.parse::<f32>().ok())
+        {
+            self.0 = pixels.clamp(*Self::PIXELS_RANGE.start(), *Self::PIXELS_RANGE.end());
+        }
+    }
+
+    /// Stores the snap threshold in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(SNAP_SECTION, SNAP_THRESHOLD_FIELD, self.0.to_string().into());
+    }
+
+    /// Returns the threshold converted to world units at `camera_scale`.
+    #[inline]
+    #[must_use]
+    pub fn distance(&self, camera_scale: f32) -> f32 { self.0 * camera_scale }
+
+    /// Returns a mutable reference to the threshold, in pixels.
+    #[inline]
+    #[must_use]
+    pub fn pixels_mut(&mut self) -> &mut f32 { &mut self.0 }
+
+    /// Resets the threshold to the default value.
+    #[inline]
+    pub fn reset(&mut self) { self.0 = Self::DEFAULT_PIXELS; }
+}
+
+//=======================================================================//
+
+/// The maximum distance between two things of the same type for them to be considered
+/// overlapping. The overlapping things are listed in a warning when the map is saved.
+#[derive(Clone, Copy)]
+pub struct OverlapTolerance(f32);
+
+impl Default for OverlapTolerance
+{
+    #[inline]
+    fn default() -> Self { Self(Self::DEFAULT_DISTANCE) }
+}
+
+impl OverlapTolerance
+{
+    /// The default tolerance.
+    const DEFAULT_DISTANCE: f32 = 1f32;
+    /// The range of the tolerance.
+    pub const DISTANCE_RANGE: std::ops::RangeInclusive<f32> = 0f32..=64f32;
+
+    /// Returns the default overlapping things config file section.
+    #[inline]
+    #[must_use]
+    fn default_overlap_tolerance() -> String
+    {
+        format!(
+            "[{OVERLAPPING_THINGS_SECTION}]\n{OVERLAPPING_THINGS_TOLERANCE_FIELD} = {}\n",
+            Self::DEFAULT_DISTANCE
+        )
+    }
+
+    /// Loads the overlapping things tolerance stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        if let Some(distance) = config
+            .get(OVERLAPPING_THINGS_SECTION, OVERLAPPING_THINGS_TOLERANCE_FIELD)
+            .and_then(|value| value.parse::<f32>().ok())
+        {
+            self.0 = distance.clamp(*Self::DISTANCE_RANGE.start(), *Self::DISTANCE_RANGE.end());
+        }
+    }
+
+    /// Stores the overlapping things tolerance in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            OVERLAPPING_THINGS_SECTION,
+            OVERLAPPING_THINGS_TOLERANCE_FIELD,
+            self.0.to_string().into()
+        );
+    }
+
+    /// Returns the tolerance distance.
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self) -> f32 { self.0 }
+
+    /// Returns a mutable reference to the tolerance distance.
+    #[inline]
+    #[must_use]
+    pub fn distance_mut(&mut self) -> &mut f32 { &mut self.0 }
+
+    /// Resets the tolerance to the default value.
+    #[inline]
+    pub fn reset(&mut self) { self.0 = Self::DEFAULT_DISTANCE; }
+}
+
+//=======================================================================//
+
+/// The names of the tools and subtools hidden from the toolbar. Hidden tools can still be enabled
+/// through their binds.
+#[derive(Clone, Default)]
+pub struct HiddenTools(Vec<String>);
+
+impl HiddenTools
+{
+    /// Returns the default hidden tools config file section.
+    #[inline]
+    #[must_use]
+    fn default_hidden_tools() -> String
+    {
+        format!("[{HIDDEN_TOOLS_SECTION}]\n{HIDDEN_TOOLS_FIELD}\n")
+    }
+
+    /// Loads the hidden tools stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        let value = return_if_none!(config.get(HIDDEN_TOOLS_SECTION, HIDDEN_TOOLS_FIELD));
+
+        self.0 = value
+            .split(HIDDEN_TOOLS_SEPARATOR)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_owned)
+            .collect();
+    }
+
+    /// Stores the hidden tools in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        config.0.set(
+            HIDDEN_TOOLS_SECTION,
+            HIDDEN_TOOLS_FIELD,
+            (!self.0.is_empty()).then(|| self.0.join(&format!("{HIDDEN_TOOLS_SEPARATOR} ")))
+        );
+    }
+
+    /// Returns an iterator to the names of the hidden tools.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> { self.0.iter().map(String::as_str) }
+
+    /// Whether the tool or subtool named `name` is hidden from the toolbar.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool
+    {
+        self.0.iter().any(|hidden| hidden.eq_ignore_ascii_case(name))
+    }
+}
+
+//=======================================================================//
+
+/// The maximum amount of brushes, vertexes, and things the map should contain. A limit equal to
+/// zero is not enforced.
+#[derive(Clone, Copy, Default)]
+pub struct ComplexityLimits
+{
+    /// The maximum amount of brushes.
+    pub brushes:  usize,
+    /// The maximum amount of vertexes of all the brushes.
+    pub vertexes: usize,
+    /// The maximum amount of things.
+    pub things:   usize
+}
+
+impl ComplexityLimits
+{
+    /// Returns the default complexity limits config file section.
+    #[inline]
+    #[must_use]
+    fn default_complexity_limits() -> String
+    {
+        format!(
+            "[{COMPLEXITY_LIMITS_SECTION}]\n{COMPLEXITY_LIMITS_BRUSHES_FIELD} = 0\n\
+             {COMPLEXITY_LIMITS_VERTEXES_FIELD} = 0\n{COMPLEXITY_LIMITS_THINGS_FIELD} = 0\n"
+        )
+    }
+
+    /// Loads the complexity limits stored in `config`.
+    #[inline]
+    fn load(&mut self, config: &Ini)
+    {
+        for (field, value) in [
+            (COMPLEXITY_LIMITS_BRUSHES_FIELD, &mut self.brushes),
+            (COMPLEXITY_LIMITS_VERTEXES_FIELD, &mut self.vertexes),
+            (COMPLEXITY_LIMITS_THINGS_FIELD, &mut self.things)
+        ]
+        {
+            if let Some(limit) = config
+                .get(COMPLEXITY_LIMITS_SECTION, field)
+                .and_then(|value| value.parse::<usize>().ok())
+            {
+                *value = limit;
+            }
+        }
+    }
+
+    /// Stores the complexity limits in `config`.
+    #[inline]
+    fn save(&self, config: &mut IniConfig)
+    {
+        for (field, value) in [
+            (COMPLEXITY_LIMITS_BRUSHES_FIELD, self.brushes),
+            (COMPLEXITY_LIMITS_VERTEXES_FIELD, self.vertexes),
+            (COMPLEXITY_LIMITS_THINGS_FIELD, self.things)
+        ]
+        {
+            config.0.set(COMPLEXITY_LIMITS_SECTION, field, value.to_string().into());
+        }
+    }
+
+    /// Returns a description of the limits exceeded by `brushes`, `vertexes`, and `things`, if
+    /// any.
+    #[inline]
+    #[must_use]
+    pub fn exceeded(&self, brushes: usize, vertexes: usize, things: usize) -> Option<String>
+    {
+        let mut exceeded = String::new();
+
+        for (name, amount, limit) in [
+            ("brushes", brushes, self.brushes),
+            ("vertexes", vertexes, self.vertexes),
+            ("things", things, self.things)
+        ]
+        {
+            if limit == 0 || amount <= limit
+            {
+                continue;
+            }
+
+            if !exceeded.is_empty()
+            {
+                exceeded.push_str(", ");
+            }
+
+            exceeded.push_str(&format!("{name} {amount}/{limit}"));
+        }
+
+        (!exceeded.is_empty()).then_some(exceeded)
+    }
+
+    /// Resets the settings to the default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+}
+
+//=======================================================================//
+
+/// Where the log of the operations performed on the map is written.
+#[derive(Clone)]
+pub enum OperationsLogTarget
+{
+    /// The standard output.
+    Stdout,
+    /// The file at the path, to which the log is appended.
+    File(PathBuf)
+}
+
+impl OperationsLogTarget
+{
+    /// Returns the [`OperationsLogTarget`] described by the `value` of the config file, if any.
+    #[inline]
+    fn from_config_value(value: String) -> Option<Self>
+    {
+        if value.is_empty()
+        {
+            return None;
+        }
+
+        if value == OPERATIONS_LOG_STDOUT
+        {
+            return Self::Stdout.into();
+        }
+
+        Self::File(PathBuf::from(value)).into()
+    }
+
+    /// Returns the value representing `self` in the config file.
+    #[inline]
+    #[must_use]
+    fn config_value(&self) -> String
+    {
+        match self
+        {
+            Self::Stdout => OPERATIONS_LOG_STDOUT.to_owned(),
+            Self::File(path) => path.as_os_str().to_str().unwrap().to_owned()
+        }
+    }
+}
+
+//=======================================================================//
+
 #[derive(Default, Resource)]
 pub struct Config
 {
     /// The keyboard binds.
-    pub binds:     BindsKeyCodes,
+    pub binds:           BindsKeyCodes,
+    /// The key repeat timing of the nudge actions.
+    pub key_repeat:      KeyRepeat,
     /// The file being edited.
-    pub open_file: OpenFile,
+    pub open_file:       OpenFile,
     /// The executable to export the map.
-    pub exporter:  Option<PathBuf>,
-    pub colors:    ColorResources
+    pub exporter:        Option<PathBuf>,
+    /// The map file loaded as the starting content of new maps.
+    pub template:        Option<PathBuf>,
+    /// The culling of the entities too small to be drawn.
+    pub detail_culling:  DetailCulling,
+    /// Where the log of the operations performed on the map is written, if enabled.
+    pub operations_log:  Option<OperationsLogTarget>,
+    /// The limits of the complexity of the map.
+    pub limits:          ComplexityLimits,
+    /// Whether the spawned entities are left selected.
+    pub spawn_selection: SpawnSelection,
+    /// The rectangle representing the area reachable by the camera of the game.
+    pub play_area:       PlayArea,
+    /// The normalization of the values written to the map files.
+    pub canonical_save:  CanonicalSave,
+    /// The export format written alongside the map file when saving.
+    pub save_export:     SaveExport,
+    /// The amount of recently assigned textures shown in the texture editor.
+    pub recent_textures: RecentTextures,
+    /// The tools and subtools hidden from the toolbar.
+    pub hidden_tools:    HiddenTools,
+    /// The amount of selected entities from which the bulk destructive operations must be
+    /// confirmed.
+    pub bulk_confirm:    BulkConfirmation,
+    /// The distance, in pixels, within which the snapping features snap to the nearby geometry.
+    pub snap_threshold:  SnapThreshold,
+    /// The distance within which things of the same type are considered overlapping.
+    pub thing_overlap:   OverlapTolerance,
+    pub colors:          ColorResources
 }
 
 //=======================================================================//
@@ -148,6 +1155,18 @@ impl FromWorld for IniConfig
             let mut config = world.get_resource_mut::<Config>().unwrap();
 
             config.binds.load(&ini_config);
+            config.key_repeat.load(&ini_config);
+            config.detail_culling.load(&ini_config);
+            config.limits.load(&ini_config);
+            config.spawn_selection.load(&ini_config);
+            config.play_area.load(&ini_config);
+            config.canonical_save.load(&ini_config);
+            config.save_export.load(&ini_config);
+            config.recent_textures.load(&ini_config);
+            config.hidden_tools.load(&ini_config);
+            config.bulk_confirm.load(&ini_config);
+            config.snap_threshold.load(&ini_config);
+            config.thing_overlap.load(&ini_config);
 
             if let Some(file) = ini_config.get(OPEN_FILE_SECTION, OPEN_FILE_FIELD)
             {
@@ -167,6 +1186,20 @@ impl FromWorld for IniConfig
                 }
             }
 
+            if let Some(file) = ini_config.get(TEMPLATE_SECTION, TEMPLATE_FIELD)
+            {
+                let file = PathBuf::from(file);
+
+                if file.exists()
+                {
+                    config.template = file.into();
+                }
+            }
+
+            config.operations_log = ini_config
+                .get(OPERATIONS_LOG_SECTION, OPERATIONS_LOG_FIELD)
+                .and_then(OperationsLogTarget::from_config_value);
+
             config.colors.load(&ini_config, &mut materials);
         });
 
@@ -196,9 +1229,24 @@ fn create_default_config_file() -> std::io::Result<()>
     let mut file = File::create(CONFIG_FILE_NAME)?;
 
     let mut config = format!(
-        "[{OPEN_FILE_SECTION}]\n{OPEN_FILE_FIELD}\n[{EXPORTER_SECTION}]\n{EXPORTER_FIELD}\n"
+        "[{OPEN_FILE_SECTION}]\n{OPEN_FILE_FIELD}\n[{EXPORTER_SECTION}]\n{EXPORTER_FIELD}\n\
+         [{TEMPLATE_SECTION}]\n{TEMPLATE_FIELD}\n\
+         [{OPERATIONS_LOG_SECTION}]\n{OPERATIONS_LOG_FIELD}\n"
     );
     config.push_str(&Bind::default_binds());
+    config.push_str(&SubToolChord::default_chords());
+    config.push_str(&KeyRepeat::default_key_repeat());
+    config.push_str(&DetailCulling::default_detail_culling());
+    config.push_str(&ComplexityLimits::default_complexity_limits());
+    config.push_str(&SpawnSelection::default_spawn_selection());
+    config.push_str(&PlayArea::default_play_area());
+    config.push_str(&CanonicalSave::default_canonical_save());
+    config.push_str(&SaveExport::default_save_export());
+    config.push_str(&RecentTextures::default_recent_textures());
+    config.push_str(&HiddenTools::default_hidden_tools());
+    config.push_str(&BulkConfirmation::default_bulk_confirmation());
+    config.push_str(&SnapThreshold::default_snap_threshold());
+    config.push_str(&OverlapTolerance::default_overlap_tolerance());
     config.push_str(&Color::default_colors());
 
     file.write_all(config.as_bytes())?;
@@ -234,7 +1282,34 @@ fn save_config(
             .map(|path| path.as_os_str().to_str().unwrap().to_owned())
     );
 
+    ini_config.0.set(
+        TEMPLATE_SECTION,
+        TEMPLATE_FIELD,
+        config
+            .template
+            .as_ref()
+            .map(|path| path.as_os_str().to_str().unwrap().to_owned())
+    );
+
+    ini_config.0.set(
+        OPERATIONS_LOG_SECTION,
+        OPERATIONS_LOG_FIELD,
+        config.operations_log.as_ref().map(OperationsLogTarget::config_value)
+    );
+
     config.binds.save(&mut ini_config);
+    config.key_repeat.save(&mut ini_config);
+    config.detail_culling.save(&mut ini_config);
+    config.limits.save(&mut ini_config);
+    config.spawn_selection.save(&mut ini_config);
+    config.play_area.save(&mut ini_config);
+    config.canonical_save.save(&mut ini_config);
+    config.save_export.save(&mut ini_config);
+    config.recent_textures.save(&mut ini_config);
+    config.hidden_tools.save(&mut ini_config);
+    config.bulk_confirm.save(&mut ini_config);
+    config.snap_threshold.save(&mut ini_config);
+    config.thing_overlap.save(&mut ini_config);
     config.colors.save(&mut ini_config);
 
     if ini_config.0.write(CONFIG_FILE_NAME).is_err()
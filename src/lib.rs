@@ -37,7 +37,7 @@ use bevy::{
 };
 use config::ConfigPlugin;
 use embedded_assets::EmbeddedPlugin;
-use hill_vacuum_proc_macros::str_array;
+use hill_vacuum_proc_macros::{str_array, EnumIter};
 use map::MapEditorPlugin;
 
 //=======================================================================//
@@ -49,17 +49,36 @@ pub use crate::map::{
         mover::{Motor, Mover},
         BrushViewer as Brush
     },
+    collider::ThingCollider,
+    collision_edge::CollisionEdge,
     containers::{HvHashMap, HvHashSet, HvVec},
+    custom_tool::{CustomTool, CustomToolContext, CustomToolDrawer, CustomTools},
+    decal::Decal,
+    exported_brush::{ExportedBrush, ExportedBrushGroup, GroupMember, VertexesCoordinates},
+    exported_path::{ExportedPath, NodesCoordinates},
     drawer::{
         animation::{Animation, Atlas, List},
         texture::{Sprite, TextureInterface, TextureSettings}
     },
+    light::AreaLight,
+    lightmap::LightmapMesh,
+    navmesh::{BrushesAdjacency, NavMeshPolygon, NavMeshRegion},
     path::{
         nodes::{Movement, Node},
         Path
     },
-    properties::{BrushProperties, ThingProperties, ToValue, Value},
+    properties::{
+        BrushProperties,
+        ThingProperties,
+        ToValue,
+        Value,
+        MATERIAL_PROPERTY,
+        SPAWN_ORDER_PROPERTY
+    },
+    properties_editor::PropertiesEditor,
+    render_pass::RenderPass,
     thing::{catalog::HardcodedThings, MapThing, Thing, ThingId, ThingViewer as ThingInstance},
+    ContentHash,
     Exporter
 };
 pub use crate::utils::{hull::Hull, identifiers::Id};
@@ -169,6 +188,7 @@ enum EditorState
 //=======================================================================//
 
 /// Actions with hardcoded key binds.
+#[derive(Clone, Copy, EnumIter)]
 enum HardcodedActions
 {
     /// New file.
@@ -181,6 +201,10 @@ enum HardcodedActions
     Export,
     /// Select all.
     SelectAll,
+    /// Deselect the selected brushes.
+    DeselectBrushes,
+    /// Deselect the selected things.
+    DeselectThings,
     /// Copy.
     Copy,
     /// Paste.
@@ -219,6 +243,8 @@ impl HardcodedActions
             Self::Open => "Ctrl+O",
             Self::Export => "Ctrl+E",
             Self::SelectAll => "Ctrl+A",
+            Self::DeselectBrushes => "Ctrl+B",
+            Self::DeselectThings => "Ctrl+T",
             Self::Copy => "Ctrl+C",
             Self::Paste => "Ctrl+V",
             Self::Cut => "Ctrl+X",
@@ -247,6 +273,8 @@ impl HardcodedActions
             Self::Fullscreen => KeyCode::Enter,
             Self::ToggleManual => KeyCode::Backquote,
             Self::SelectAll => KeyCode::KeyA,
+            Self::DeselectBrushes => KeyCode::KeyB,
+            Self::DeselectThings => KeyCode::KeyT,
             Self::Copy => KeyCode::KeyC,
             Self::Paste => KeyCode::KeyV,
             Self::Cut => KeyCode::KeyX,
@@ -0,0 +1,158 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::io::Write;
+
+use crate::map::{
+    brush::BrushViewer,
+    containers::{hv_hash_map, hv_vec},
+    drawer::texture::TextureInterface,
+    ContentHash
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The magic bytes at the start of a binary brushes file.
+const BINARY_MAGIC: [u8; 4] = *b"HVBN";
+/// The version of the binary brushes layout.
+const BINARY_VERSION: u32 = 4;
+/// The texture index of the brushes without a texture.
+const BINARY_NO_TEXTURE: u32 = u32::MAX;
+/// Flag set if the brush has collision enabled.
+const BINARY_FLAG_COLLISION: u32 = 1;
+/// Flag set if the texture of the brush is rendered as a sprite.
+const BINARY_FLAG_SPRITE: u32 = 1 << 1;
+/// Flag set if the brush has a path.
+const BINARY_FLAG_PATH: u32 = 1 << 2;
+/// Flag set if the record is followed by the centroid and the bounding box of the brush.
+const BINARY_FLAG_BOUNDS: u32 = 1 << 3;
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Writes `brushes` to `writer` with the following little-endian layout:
+/// - header: magic bytes `HVBN`, `u32` version, `u32` strings amount, `u32` brushes amount,
+///   `f32` scale applied to the coordinates at export time, `u32` version of the content hash,
+///   `u64` content hash of the map;
+/// - string table: for each texture name a `u32` length followed by the UTF-8 bytes;
+/// - brush records, sorted by id: `u64` id, `u32` flags, `u32` texture index (`u32::MAX` if
+///   none), `u32` vertexes amount, followed by the `f32` x and y coordinates of each vertex and,
+///   if `bounds` is true, by the `f32` x and y coordinates of the area-weighted centroid and of
+///   the minimum and maximum corners of the bounding box.
+///
+/// # Errors
+/// Returns an error if writing to `writer` fails or if an amount does not fit in a `u32`.
+#[inline]
+pub(in crate::map) fn write_brushes<'a, I, W>(
+    brushes: I,
+    scale: f32,
+    hash: ContentHash,
+    bounds: bool,
+    writer: &mut W
+) -> std::io::Result<()>
+where
+    I: Iterator<Item = &'a BrushViewer>,
+    W: Write
+{
+    /// Converts `value` to a `u32`, or returns an error if it does not fit.
+    #[inline]
+    fn to_u32(value: usize) -> std::io::Result<u32>
+    {
+        u32::try_from(value)
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "Amount too large"))
+    }
+
+    let mut brushes = brushes.collect::<Vec<_>>();
+    brushes.sort_by_key(|brush| brush.id.value());
+
+    let mut strings = hv_vec![];
+    let mut indexes = hv_hash_map![];
+
+    for name in brushes
+        .iter()
+        .filter_map(|brush| brush.texture.as_ref().map(TextureInterface::name))
+    {
+        if indexes.get(name).is_none()
+        {
+            _ = indexes.insert(name, to_u32(strings.len())?);
+            strings.push(name);
+        }
+    }
+
+    writer.write_all(&BINARY_MAGIC)?;
+    writer.write_all(&BINARY_VERSION.to_le_bytes())?;
+    writer.write_all(&to_u32(strings.len())?.to_le_bytes())?;
+    writer.write_all(&to_u32(brushes.len())?.to_le_bytes())?;
+    writer.write_all(&scale.to_le_bytes())?;
+    writer.write_all(&u32::from(hash.version).to_le_bytes())?;
+    writer.write_all(&hash.value.to_le_bytes())?;
+
+    for name in &strings
+    {
+        writer.write_all(&to_u32(name.len())?.to_le_bytes())?;
+        writer.write_all(name.as_bytes())?;
+    }
+
+    for brush in &brushes
+    {
+        let texture = brush.texture.as_ref();
+        let mut flags = 0;
+
+        if brush.collision
+        {
+            flags |= BINARY_FLAG_COLLISION;
+        }
+
+        if texture.is_some_and(TextureInterface::sprite)
+        {
+            flags |= BINARY_FLAG_SPRITE;
+        }
+
+        if brush.mover.has_path()
+        {
+            flags |= BINARY_FLAG_PATH;
+        }
+
+        if bounds
+        {
+            flags |= BINARY_FLAG_BOUNDS;
+        }
+
+        writer.write_all(&(brush.id.value() as u64).to_le_bytes())?;
+        writer.write_all(&flags.to_le_bytes())?;
+        writer.write_all(
+            &texture
+                .map_or(BINARY_NO_TEXTURE, |texture| *indexes.get(texture.name()).unwrap())
+                .to_le_bytes()
+        )?;
+        writer.write_all(&to_u32(brush.vertexes.len())?.to_le_bytes())?;
+
+        for vx in &brush.vertexes
+        {
+            writer.write_all(&vx.x.to_le_bytes())?;
+            writer.write_all(&vx.y.to_le_bytes())?;
+        }
+
+        if !bounds
+        {
+            continue;
+        }
+
+        let aabb = brush.aabb();
+
+        for vx in [brush.centroid(), aabb.bottom_left(), aabb.top_right()]
+        {
+            writer.write_all(&vx.x.to_le_bytes())?;
+            writer.write_all(&vx.y.to_le_bytes())?;
+        }
+    }
+
+    Ok(())
+}
@@ -5,6 +5,7 @@
 
 use std::{
     borrow::Cow,
+    f32::consts::FRAC_PI_2,
     fmt::{Debug, Write}
 };
 
@@ -48,7 +49,6 @@ use crate::{
         },
         HvVec,
         OutOfBounds,
-        MAP_RANGE,
         TOOLTIP_OFFSET
     },
     utils::{
@@ -73,6 +73,7 @@ use crate::{
                 are_vxs_ccw,
                 is_polygon_convex,
                 rotate_point,
+                rotate_point_quarter,
                 sort_vxs_ccw,
                 vertexes_orientation,
                 vxs_center,
@@ -527,7 +528,7 @@ impl XtrusionInfo
 
     #[inline]
     #[must_use]
-    fn extruded_side(&self, distance: Vec2) -> Option<[Vec2; 2]>
+    fn extruded_side(&self, distance: Vec2, bound: f32) -> Option<[Vec2; 2]>
     {
         let line = self.xtrusion_side(distance);
         let side = [
@@ -535,7 +536,7 @@ impl XtrusionInfo
             lines_intersection(&self.next_side, &line).unwrap().0
         ];
 
-        (!side.iter().any(OutOfBounds::out_of_bounds)).then_some(side)
+        (!side.iter().any(|vx| vx.out_of_bounds(bound))).then_some(side)
     }
 
     #[inline]
@@ -586,7 +587,8 @@ impl XtrusionInfo
     pub fn create_extrusion_polygon(
         &self,
         distance: Vec2,
-        texture: Option<&TextureSettings>
+        texture: Option<&TextureSettings>,
+        bound: f32
     ) -> Option<ConvexPolygon>
     {
         // Create the test polygon.
@@ -599,7 +601,7 @@ impl XtrusionInfo
         }
 
         // Generate the extruded side.
-        let extruded_side = self.extruded_side(distance)?;
+        let extruded_side = self.extruded_side(distance, bound)?;
 
         if extruded_side[0].around_equal_narrow(&extruded_side[1])
         {
@@ -634,7 +636,12 @@ impl XtrusionInfo
     }
 
     #[inline]
-    pub fn check_side_extrusion(&self, polygon: &ConvexPolygon, distance: Vec2) -> ExtrusionResult
+    pub fn check_side_extrusion(
+        &self,
+        polygon: &ConvexPolygon,
+        distance: Vec2,
+        bound: f32
+    ) -> ExtrusionResult
     {
         // Create a test polygon.
         let mut test_polygon = [None; 4];
@@ -646,7 +653,7 @@ impl XtrusionInfo
         }
 
         // Extrude the side and check validity.
-        let extruded_side = self.extruded_side(distance);
+        let extruded_side = self.extruded_side(distance, bound);
 
         if extruded_side.is_none()
         {
@@ -893,6 +900,12 @@ impl<'b> TextureInterface for MovingTextureSettings<'b>
 
     #[inline]
     fn animation(&self) -> &Animation { self.texture.animation() }
+
+    #[inline]
+    fn trim(&self) -> Option<&str> { self.texture.trim() }
+
+    #[inline]
+    fn brightness(&self) -> f32 { self.texture.brightness() }
 }
 
 impl<'b> TextureInterfaceExtra for MovingTextureSettings<'b>
@@ -1212,6 +1225,32 @@ impl ConvexPolygon
         (vec, texture).into()
     }
 
+    /// Returns a new [`ConvexPolygon`] with vertexes `vxs` sorted in counter-clockwise order, if
+    /// they describe a valid convex polygon.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn new_checked<T>(vxs: T) -> Option<Self>
+    where
+        T: Iterator<Item = Vec2>
+    {
+        let mut vxs = hv_vec![collect; vxs];
+
+        if vxs.len() < 3
+        {
+            return None;
+        }
+
+        let center = vxs_center(vxs.iter().copied());
+        vxs.sort_by(|a, b| sort_vxs_ccw(*a, *b, center));
+
+        let valid = vxs.pair_iter().unwrap().all(|[a, b]| !a.around_equal_narrow(b)) &&
+            vxs.triplet_iter()
+                .unwrap()
+                .all(|[a, b, c]| are_vxs_ccw(&[*a, *b, *c]));
+
+        valid.then(|| vxs.into())
+    }
+
     #[inline]
     #[must_use]
     fn new_cleaned_up<T: Iterator<Item = Vec2>>(vxs: T) -> Option<Self>
@@ -1379,6 +1418,31 @@ impl ConvexPolygon
         self.vxs_valid()
     }
 
+    /// Whether the polygon would still be valid if its vertexes were replaced by the ones
+    /// returned by `f`.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn valid_mapped<F>(&self, f: F) -> bool
+    where
+        F: Fn(Vec2) -> Vec2
+    {
+        let vxs = hv_vec![collect; self.vertexes().map(f)];
+        let len = vxs.len();
+
+        for i in 0..len - 1
+        {
+            for j in i + 1..len
+            {
+                if vxs[i].around_equal_narrow(&vxs[j])
+                {
+                    return false;
+                }
+            }
+        }
+
+        vxs.triplet_iter().unwrap().all(|[a, b, c]| are_vxs_ccw(&[*a, *b, *c]))
+    }
+
     #[inline]
     #[must_use]
     fn vxs_valid(&self) -> bool
@@ -1418,15 +1482,32 @@ impl ConvexPolygon
 
     #[inline]
     #[must_use]
-    pub(in crate::map::brush) fn sprite_hull_out_of_bounds(&self, center: Vec2) -> bool
+    pub(in crate::map::brush) fn sprite_hull_out_of_bounds(&self, center: Vec2, bound: f32) -> bool
     {
         let texture = return_if_none!(self.texture_settings(), false);
-        texture.sprite() && texture.sprite_hull(center).out_of_bounds()
+        texture.sprite() && texture.sprite_hull(center).out_of_bounds(bound)
     }
 
     //============================================================
     // General Editing
 
+    /// Whether the vertexes are stored in counter-clockwise order, computed through the sign of
+    /// the area of the polygon.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn ccw_winding(&self) -> bool
+    {
+        self.vertexes
+            .pair_iter()
+            .unwrap()
+            .fold(0f32, |area, [vx_i, vx_j]| area + vx_i.vec.perp_dot(vx_j.vec)) >
+            0f32
+    }
+
+    /// Reverses the order of the vertexes.
+    #[inline]
+    pub(in crate::map) fn reverse_winding(&mut self) { self.vertexes.reverse(); }
+
     /// Sorts vxs in a clockwise order.
     #[inline]
     fn sort_vertexes_ccw(&mut self)
@@ -1454,10 +1535,9 @@ impl ConvexPolygon
 
         if self.has_sprite()
         {
-            let center = self.center;
+            let delta = old_center - self.center;
             self.texture_updated = true;
-            self.texture_settings_mut()
-                .move_offset(drawing_resources, old_center - center, center);
+            self.texture_settings_mut().move_offset(drawing_resources, delta);
         }
     }
 
@@ -1471,16 +1551,16 @@ impl ConvexPolygon
 
     /// Moves the polygon by the amount delta.
     #[inline]
-    pub(in crate::map) fn check_move(&self, delta: Vec2, move_texture: bool) -> bool
+    pub(in crate::map) fn check_move(&self, delta: Vec2, move_texture: bool, bound: f32) -> bool
     {
-        if (self.hull + delta).out_of_bounds()
+        if (self.hull + delta).out_of_bounds(bound)
         {
             return false;
         }
 
         if move_texture && self.has_sprite()
         {
-            return self.texture_settings().unwrap().check_move(delta, self.center);
+            return self.texture_settings().unwrap().check_move(delta, self.center, bound);
         }
 
         true
@@ -1508,7 +1588,6 @@ impl ConvexPolygon
             return;
         }
 
-        let center = self.center;
         self.texture_updated = true;
         let texture = self.texture_settings_mut();
         let sprite = texture.sprite();
@@ -1517,12 +1596,12 @@ impl ConvexPolygon
         {
             if sprite
             {
-                texture.move_offset(drawing_resources, -delta, center);
+                texture.move_offset(drawing_resources, -delta);
             }
         }
         else if !sprite
         {
-            texture.move_offset(drawing_resources, Vec2::new(-delta.x, delta.y), center);
+            texture.move_offset(drawing_resources, Vec2::new(-delta.x, delta.y));
         }
     }
 
@@ -1578,16 +1657,45 @@ impl ConvexPolygon
     pub(in crate::map::brush) fn check_texture_change(
         &mut self,
         drawing_resources: &DrawingResources,
-        texture: &str
+        texture: &str,
+        bound: f32
     ) -> bool
     {
         match &mut self.texture
         {
-            Some(tex_set) => tex_set.check_texture_change(drawing_resources, texture, self.center),
+            Some(tex_set) =>
+            {
+                tex_set.check_texture_change(drawing_resources, texture, self.center, bound)
+            },
             None => true
         }
     }
 
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn check_texture_sprite_change(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        texture: &str,
+        bound: f32
+    ) -> bool
+    {
+        let center = self.center;
+
+        match &mut self.texture
+        {
+            Some(tex_set) =>
+            {
+                tex_set.check_texture_sprite_change(drawing_resources, texture, center, bound)
+            },
+            None =>
+            {
+                TextureSettings::from(drawing_resources.texture_or_error(texture))
+                    .check_sprite(drawing_resources, true, center, bound)
+            }
+        }
+    }
+
     #[inline]
     #[must_use]
     pub(in crate::map::brush) fn set_texture(
@@ -1600,7 +1708,7 @@ impl ConvexPolygon
         {
             Some(tex_set) =>
             {
-                match tex_set.set_texture(drawing_resources, texture, self.center)
+                match tex_set.set_texture(drawing_resources, texture)
                 {
                     Some(prev) => TextureSetResult::Changed(prev),
                     None => TextureSetResult::Unchanged
@@ -1636,9 +1744,9 @@ impl ConvexPolygon
     }
 
     #[inline]
-    pub(in crate::map::brush) fn check_texture_move(&self, value: Vec2) -> bool
+    pub(in crate::map::brush) fn check_texture_move(&self, value: Vec2, bound: f32) -> bool
     {
-        self.texture_settings().unwrap().check_move(value, self.center)
+        self.texture_settings().unwrap().check_move(value, self.center, bound)
     }
 
     #[inline]
@@ -1648,12 +1756,11 @@ impl ConvexPolygon
         value: Vec2
     )
     {
-        let center = self.center;
         self.texture_updated = true;
         let texture = self.texture_settings_mut();
         let x = if texture.sprite() { value.x } else { -value.x };
-        _ = texture.set_offset_x(drawing_resources, texture.offset_x() + x, center);
-        _ = texture.set_offset_y(drawing_resources, texture.offset_y() + value.y, center);
+        _ = texture.set_offset_x(drawing_resources, texture.offset_x() + x);
+        _ = texture.set_offset_y(drawing_resources, texture.offset_y() + value.y);
     }
 
     #[inline]
@@ -1661,12 +1768,13 @@ impl ConvexPolygon
     pub(in crate::map::brush) fn check_texture_offset_x(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_offset_x(drawing_resources, value, center)
+            .check_offset_x(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1677,10 +1785,7 @@ impl ConvexPolygon
         value: f32
     ) -> Option<f32>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_offset_x(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_offset_x(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1690,12 +1795,13 @@ impl ConvexPolygon
     pub(in crate::map::brush) fn check_texture_offset_y(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_offset_y(drawing_resources, value, center)
+            .check_offset_y(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1706,10 +1812,7 @@ impl ConvexPolygon
         value: f32
     ) -> Option<f32>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_offset_y(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_offset_y(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1718,12 +1821,13 @@ impl ConvexPolygon
     pub(in crate::map::brush) fn check_texture_scale_x(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_scale_x(drawing_resources, value, center)
+            .check_scale_x(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1734,10 +1838,7 @@ impl ConvexPolygon
         value: f32
     ) -> Option<f32>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_scale_x(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_scale_x(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1748,24 +1849,24 @@ impl ConvexPolygon
         drawing_resources: &DrawingResources
     )
     {
-        let center = self.center;
         self.texture_updated = true;
         let texture = self.texture_settings_mut();
         let scale = texture.scale_x();
-        _ = texture.set_scale_x(drawing_resources, -scale, center);
+        _ = texture.set_scale_x(drawing_resources, -scale);
     }
 
     #[inline]
     pub(in crate::map::brush) fn check_texture_scale_y(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_updated = true;
         self.texture_settings_mut()
-            .check_scale_y(drawing_resources, value, center)
+            .check_scale_y(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1776,10 +1877,7 @@ impl ConvexPolygon
         value: f32
     ) -> Option<f32>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_scale_y(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_scale_y(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1790,11 +1888,10 @@ impl ConvexPolygon
         drawing_resources: &DrawingResources
     )
     {
-        let center = self.center;
         self.texture_updated = true;
         let texture = self.texture_settings_mut();
         let scale = texture.scale_y();
-        _ = texture.set_scale_y(drawing_resources, -scale, center);
+        _ = texture.set_scale_y(drawing_resources, -scale);
     }
 
     #[inline]
@@ -1847,12 +1944,13 @@ impl ConvexPolygon
     pub(in crate::map::brush) fn check_texture_angle(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_angle(drawing_resources, value, center)
+            .check_angle(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1863,10 +1961,7 @@ impl ConvexPolygon
         value: f32
     ) -> Option<f32>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_angle(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_angle(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1881,16 +1976,36 @@ impl ConvexPolygon
         self.set_texture_updated(result)
     }
 
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn set_texture_brightness(&mut self, value: f32) -> Option<f32>
+    {
+        let result = self.texture_settings_mut().set_brightness(value);
+        self.set_texture_updated(result)
+    }
+
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn set_texture_trim(
+        &mut self,
+        value: Option<String>
+    ) -> Option<Option<String>>
+    {
+        let result = self.texture_settings_mut().set_trim(value);
+        self.set_texture_updated(result)
+    }
+
     #[inline]
     pub(in crate::map::brush) fn check_texture_sprite(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: bool
+        value: bool,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_sprite(drawing_resources, value, center)
+            .check_sprite(drawing_resources, value, center, bound)
     }
 
     #[inline]
@@ -1901,10 +2016,7 @@ impl ConvexPolygon
         value: impl Into<Sprite>
     ) -> Option<(Sprite, f32, f32)>
     {
-        let center = self.center;
-        let result = self
-            .texture_settings_mut()
-            .set_sprite(drawing_resources, value, center);
+        let result = self.texture_settings_mut().set_sprite(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -1918,21 +2030,22 @@ impl ConvexPolygon
         }
 
         target.texture.clone_from(&self.texture);
-        let center = target.center;
-        let delta = self.center - center;
+        let delta = self.center - target.center;
         target.texture_updated = true;
-        target
-            .texture_settings_mut()
-            .move_offset(drawing_resources, delta, center);
+        target.texture_settings_mut().move_offset(drawing_resources, delta);
     }
 
     #[inline]
     #[must_use]
-    pub fn check_texture_within_bounds(&mut self, drawing_resources: &DrawingResources) -> bool
+    pub fn check_texture_within_bounds(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        bound: f32
+    ) -> bool
     {
         self.texture_settings()
             .unwrap()
-            .check_within_bounds(drawing_resources, self.center)
+            .check_within_bounds(drawing_resources, self.center, bound)
     }
 
     #[inline]
@@ -1940,12 +2053,13 @@ impl ConvexPolygon
     pub fn check_texture_animation_change(
         &mut self,
         drawing_resources: &DrawingResources,
-        animation: &Animation
+        animation: &Animation,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut()
-            .check_animation_change(drawing_resources, animation, center)
+            .check_animation_change(drawing_resources, animation, center, bound)
     }
 
     #[inline]
@@ -1955,10 +2069,9 @@ impl ConvexPolygon
         animation: Animation
     ) -> Animation
     {
-        let center = self.center;
         self.texture_updated = true;
         self.texture_settings_mut()
-            .set_animation(drawing_resources, animation, center)
+            .set_animation(drawing_resources, animation)
     }
 
     #[inline]
@@ -1968,10 +2081,9 @@ impl ConvexPolygon
         texture: &str
     ) -> Animation
     {
-        let center = self.center;
         self.texture_updated = true;
         self.texture_settings_mut()
-            .set_list_animation(drawing_resources, texture, center)
+            .set_list_animation(drawing_resources, texture)
     }
 
     #[inline]
@@ -1980,10 +2092,8 @@ impl ConvexPolygon
         drawing_resources: &DrawingResources
     ) -> Animation
     {
-        let center = self.center;
         self.texture_updated = true;
-        self.texture_settings_mut()
-            .generate_list_animation(drawing_resources, center)
+        self.texture_settings_mut().generate_list_animation(drawing_resources)
     }
 
     #[inline]
@@ -1991,14 +2101,16 @@ impl ConvexPolygon
     pub fn check_atlas_animation_x_partition(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: u32
+        value: u32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut().check_atlas_animation_x_partition(
             drawing_resources,
             value,
-            center
+            center,
+            bound
         )
     }
 
@@ -2010,12 +2122,9 @@ impl ConvexPolygon
         value: u32
     ) -> Option<u32>
     {
-        let center = self.center;
-        let result = self.texture_settings_mut().set_atlas_animation_x_partition(
-            drawing_resources,
-            value,
-            center
-        );
+        let result = self
+            .texture_settings_mut()
+            .set_atlas_animation_x_partition(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -2025,14 +2134,16 @@ impl ConvexPolygon
     pub fn check_atlas_animation_y_partition(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: u32
+        value: u32,
+        bound: f32
     ) -> bool
     {
         let center = self.center;
         self.texture_settings_mut().check_atlas_animation_y_partition(
             drawing_resources,
             value,
-            center
+            center,
+            bound
         )
     }
 
@@ -2044,12 +2155,9 @@ impl ConvexPolygon
         value: u32
     ) -> Option<u32>
     {
-        let center = self.center;
-        let result = self.texture_settings_mut().set_atlas_animation_y_partition(
-            drawing_resources,
-            value,
-            center
-        );
+        let result = self
+            .texture_settings_mut()
+            .set_atlas_animation_y_partition(drawing_resources, value);
 
         self.set_texture_updated(result)
     }
@@ -2792,6 +2900,56 @@ impl ConvexPolygon
         true
     }
 
+    /// Returns a copy of the polygon where the only selected side is replaced by an arc of
+    /// radius `radius` made of `segments` segments, bulging outward. Returns None if the arc
+    /// cannot be built, the polygon would lose convexity, have more than [`u8::MAX`] vertexes, or
+    /// go out of bounds.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn side_arc(
+        &self,
+        drawing_resources: &DrawingResources,
+        radius: f32,
+        segments: u8,
+        bound: f32
+    ) -> Option<Self>
+    {
+        let mut vxs = self.selected_sides_vertexes()?;
+        let (start, end) = (vxs.next()?, vxs.next()?);
+        let half_chord = (end - start).length() / 2f32;
+
+        if segments < 2 || radius < half_chord || half_chord.around_equal_narrow(&0f32)
+        {
+            return None;
+        }
+
+        let side = end - start;
+        let center = (start + end) / 2f32 -
+            Vec2::new(side.y, -side.x).normalize() *
+                (radius * radius - half_chord * half_chord).sqrt();
+        let step = 2f32 * (half_chord / radius).min(1f32).asin() / f32::from(segments);
+        let index = self.vertexes.iter().position(|svx| svx.vec == end)?;
+
+        let mut polygon = self.clone();
+
+        for i in 1..segments
+        {
+            polygon.vertexes.insert(
+                index + usize::from(i) - 1,
+                SelectableVector::new(rotate_point(start, center, step * f32::from(i)))
+            );
+        }
+
+        if polygon.sides() > usize::from(u8::MAX) ||
+            !is_polygon_convex(&hv_vec![collect; polygon.vertexes()])
+        {
+            return None;
+        }
+
+        polygon.update_center_hull_vertexes(drawing_resources);
+        polygon.check_move(Vec2::ZERO, true, bound).then_some(polygon)
+    }
+
     /// Returns the index the projection of `cursor_pos` on the polygon would
     /// have if it were added to the polygon. Returns None if it's not
     /// valid.
@@ -2936,14 +3094,15 @@ impl ConvexPolygon
     #[inline]
     pub(in crate::map::brush) fn check_selected_vertexes_move(
         &mut self,
-        delta: Vec2
+        delta: Vec2,
+        bound: f32
     ) -> VertexesMoveResult
     {
         let mut moved_vxs = hv_vec![];
 
         for (idx, svx) in self.vertexes.iter().enumerate().filter(|(_, svx)| svx.selected)
         {
-            if (svx.vec + delta).out_of_bounds()
+            if (svx.vec + delta).out_of_bounds(bound)
             {
                 return VertexesMoveResult::Invalid;
             }
@@ -3522,11 +3681,12 @@ impl ConvexPolygon
     #[inline]
     pub(in crate::map::brush) fn check_selected_sides_move(
         &mut self,
-        delta: Vec2
+        delta: Vec2,
+        bound: f32
     ) -> VertexesMoveResult
     {
         let vertexes_to_deselect = self.select_vertexes_of_selected_sides();
-        let move_result = self.check_selected_vertexes_move(delta);
+        let move_result = self.check_selected_vertexes_move(delta, bound);
 
         for idx in vertexes_to_deselect
         {
@@ -4034,7 +4194,8 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         info: &ScaleInfo,
-        scale_texture: bool
+        scale_texture: bool,
+        bound: f32
     ) -> ScaleResult
     {
         let mut vxs = hv_vec![capacity; self.sides()];
@@ -4044,7 +4205,7 @@ impl ConvexPolygon
         {
             let vx = info.scaled_point(vx);
 
-            if vx.out_of_bounds()
+            if vx.out_of_bounds(bound)
             {
                 return ScaleResult::Invalid;
             }
@@ -4063,7 +4224,8 @@ impl ConvexPolygon
                     drawing_resources,
                     info,
                     center,
-                    new_center
+                    new_center,
+                    bound
                 ),
                 ScaleResult::Invalid
             );
@@ -4088,7 +4250,8 @@ impl ConvexPolygon
         drawing_resources: &DrawingResources,
         info: &ScaleInfo,
         flip_queue: &ArrayVec<Flip, 2>,
-        scale_texture: bool
+        scale_texture: bool,
+        bound: f32
     ) -> ScaleResult
     {
         let mut vxs = hv_vec![capacity; self.sides()];
@@ -4119,7 +4282,7 @@ impl ConvexPolygon
 
             let vx = info.scaled_point(vx);
 
-            if vx.out_of_bounds()
+            if vx.out_of_bounds(bound)
             {
                 return ScaleResult::Invalid;
             }
@@ -4140,7 +4303,8 @@ impl ConvexPolygon
                     info,
                     &flip_queue,
                     center,
-                    new_center
+                    new_center,
+                    bound
                 ),
                 ScaleResult::Invalid
             );
@@ -4166,7 +4330,8 @@ impl ConvexPolygon
     #[inline]
     pub(in crate::map::brush) fn check_horizontal_shear(
         &self,
-        info: &ShearInfo
+        info: &ShearInfo,
+        bound: f32
     ) -> Option<(Vec2, HvVec<f32>)>
     {
         let mut xs = hv_vec![capacity; self.sides()];
@@ -4176,7 +4341,7 @@ impl ConvexPolygon
         {
             let vx_x = vx.x + info.delta * ((vx.y - info.pivot).abs() / info.opposite_dimension);
 
-            if !MAP_RANGE.contains(&vx_x)
+            if vx_x.out_of_bounds(bound)
             {
                 return None;
             }
@@ -4187,7 +4352,7 @@ impl ConvexPolygon
 
         new_center /= self.sides_f32();
 
-        if self.sprite_hull_out_of_bounds(new_center)
+        if self.sprite_hull_out_of_bounds(new_center, bound)
         {
             return None;
         }
@@ -4211,7 +4376,8 @@ impl ConvexPolygon
     #[inline]
     pub(in crate::map::brush) fn check_vertical_shear(
         &self,
-        info: &ShearInfo
+        info: &ShearInfo,
+        bound: f32
     ) -> Option<(Vec2, HvVec<f32>)>
     {
         let mut ys = hv_vec![capacity; self.sides()];
@@ -4221,7 +4387,7 @@ impl ConvexPolygon
         {
             let vx_y = vx.y + info.delta * ((vx.x - info.pivot).abs() / info.opposite_dimension);
 
-            if vx_y.out_of_bounds()
+            if vx_y.out_of_bounds(bound)
             {
                 return None;
             }
@@ -4232,7 +4398,7 @@ impl ConvexPolygon
 
         new_center /= self.sides_f32();
 
-        if self.sprite_hull_out_of_bounds(new_center)
+        if self.sprite_hull_out_of_bounds(new_center, bound)
         {
             return None;
         }
@@ -4255,24 +4421,60 @@ impl ConvexPolygon
     //==============================================================
     // Rotate
 
-    #[allow(clippy::cast_precision_loss)]
     #[inline]
     pub(in crate::map::brush) fn check_rotation(
         &mut self,
         drawing_resources: &DrawingResources,
         pivot: Vec2,
         angle: f32,
-        rotate_texture: bool
+        rotate_texture: bool,
+        bound: f32
     ) -> RotateResult
+    {
+        self.check_vertexes_rotation(drawing_resources, pivot, angle, rotate_texture, bound, |vx| {
+            rotate_point(vx, pivot, angle)
+        })
+    }
+
+    #[inline]
+    pub(in crate::map::brush) fn check_quarter_rotation(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        pivot: Vec2,
+        clockwise: bool,
+        rotate_texture: bool,
+        bound: f32
+    ) -> RotateResult
+    {
+        let angle = if clockwise { -FRAC_PI_2 } else { FRAC_PI_2 };
+
+        self.check_vertexes_rotation(drawing_resources, pivot, angle, rotate_texture, bound, |vx| {
+            rotate_point_quarter(vx, pivot, clockwise)
+        })
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    fn check_vertexes_rotation<F>(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        pivot: Vec2,
+        angle: f32,
+        rotate_texture: bool,
+        bound: f32,
+        rotate: F
+    ) -> RotateResult
+    where
+        F: Fn(Vec2) -> Vec2
     {
         let mut new_center = Vec2::ZERO;
         let mut vxs = hv_vec![capacity; self.sides()];
 
         for vx in self.vertexes()
         {
-            let vx = rotate_point(vx, pivot, angle);
+            let vx = rotate(vx);
 
-            if vx.out_of_bounds()
+            if vx.out_of_bounds(bound)
             {
                 return RotateResult::Invalid;
             }
@@ -4292,7 +4494,8 @@ impl ConvexPolygon
                     pivot,
                     angle,
                     center,
-                    new_center
+                    new_center,
+                    bound
                 ),
                 RotateResult::Invalid
             );
@@ -4331,12 +4534,13 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         y: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         let y = 2f32 * y;
 
-        if self.vertexes().any(|vx| (y - vx.y).out_of_bounds())
+        if self.vertexes().any(|vx| (y - vx.y).out_of_bounds(bound))
         {
             return None;
         }
@@ -4349,11 +4553,11 @@ impl ConvexPolygon
 
             return self
                 .texture_settings_mut()
-                .check_y_flip(drawing_resources, y, center, new_center)
+                .check_y_flip(drawing_resources, y, center, new_center, bound)
                 .then_some(new_center);
         }
 
-        (!self.sprite_hull_out_of_bounds(new_center)).then_some(new_center)
+        (!self.sprite_hull_out_of_bounds(new_center, bound)).then_some(new_center)
     }
 
     #[inline]
@@ -4361,12 +4565,13 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         x: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         let x = 2f32 * x;
 
-        if self.vertexes().any(|vx| (x - vx.x).out_of_bounds())
+        if self.vertexes().any(|vx| (x - vx.x).out_of_bounds(bound))
         {
             return None;
         }
@@ -4379,11 +4584,11 @@ impl ConvexPolygon
 
             return self
                 .texture_settings_mut()
-                .check_x_flip(drawing_resources, x, center, new_center)
+                .check_x_flip(drawing_resources, x, center, new_center, bound)
                 .then_some(new_center);
         }
 
-        (!self.sprite_hull_out_of_bounds(new_center)).then_some(new_center)
+        (!self.sprite_hull_out_of_bounds(new_center, bound)).then_some(new_center)
     }
 
     #[inline]
@@ -4392,7 +4597,8 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         y: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         assert!(
@@ -4400,7 +4606,7 @@ impl ConvexPolygon
             "Y vertical flip pivot {y} is lower than the hull's top {}",
             self.hull.top()
         );
-        self.check_y_flip(drawing_resources, y, flip_texture)
+        self.check_y_flip(drawing_resources, y, flip_texture, bound)
     }
 
     #[inline]
@@ -4425,7 +4631,8 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         y: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         assert!(
@@ -4433,7 +4640,7 @@ impl ConvexPolygon
             "Y vertical flip pivot {y} is lower than the hull's top {}",
             self.hull.bottom()
         );
-        self.check_y_flip(drawing_resources, y, flip_texture)
+        self.check_y_flip(drawing_resources, y, flip_texture, bound)
     }
 
     #[inline]
@@ -4488,7 +4695,8 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         x: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         assert!(
@@ -4496,7 +4704,7 @@ impl ConvexPolygon
             "Y vertical flip pivot {x} is higher than the hull's left {}",
             self.hull.left()
         );
-        self.check_x_flip(drawing_resources, x, flip_texture)
+        self.check_x_flip(drawing_resources, x, flip_texture, bound)
     }
 
     #[inline]
@@ -4521,7 +4729,8 @@ impl ConvexPolygon
         &mut self,
         drawing_resources: &DrawingResources,
         x: f32,
-        flip_texture: bool
+        flip_texture: bool,
+        bound: f32
     ) -> Option<Vec2>
     {
         assert!(
@@ -4529,7 +4738,7 @@ impl ConvexPolygon
             "Y vertical flip pivot {x} is lower than the hull's right {}",
             self.hull.right()
         );
-        self.check_x_flip(drawing_resources, x, flip_texture)
+        self.check_x_flip(drawing_resources, x, flip_texture, bound)
     }
 
     #[inline]
@@ -5010,6 +5219,11 @@ impl ConvexPolygon
         settings: &T
     )
     {
+        if !drawer.show_sprites()
+        {
+            return;
+        }
+
         let hull = settings.sprite_hull(self.center);
         let hull_center = hull.center();
 
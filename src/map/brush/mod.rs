@@ -61,7 +61,7 @@ use super::{
         NodesDeletionPayload,
         Path
     },
-    properties::{Properties, PropertiesRefactor, Value},
+    properties::{Properties, PropertiesRefactor, Value, MATERIAL_PROPERTY},
     selectable_vector::VectorSelectionResult,
     thing::catalog::ThingsCatalog,
     HvVec
@@ -69,7 +69,7 @@ use super::{
 use crate::utils::{
     hull::{EntityHull, Flip, Hull},
     identifiers::{EntityCenter, EntityId, Id},
-    iterators::SlicePairIter,
+    iterators::{PairIterator, SlicePairIter},
     math::lines_and_segments::{line_equation, LineEquation}
 };
 
@@ -82,11 +82,11 @@ macro_rules! flip_funcs {
     ($($side:ident),+) => { paste::paste! { $(
         #[inline]
         #[must_use]
-        pub fn [< check_flip_ $side >](&mut self, drawing_resources: &DrawingResources, value: f32, flip_texture: bool) -> bool
+        pub fn [< check_flip_ $side >](&mut self, drawing_resources: &DrawingResources, value: f32, flip_texture: bool, bound: f32) -> bool
         {
-            match self.data.polygon.[< check_flip_ $side >](drawing_resources, value, flip_texture)
+            match self.data.polygon.[< check_flip_ $side >](drawing_resources, value, flip_texture, bound)
             {
-                Some(new_center) => !self.path_hull_out_of_bounds(new_center),
+                Some(new_center) => !self.path_hull_out_of_bounds(new_center, bound),
                 None => false
             }
         }
@@ -283,7 +283,7 @@ pub(in crate::map) enum ScaleResult
 impl ScaleResult
 {
     #[inline]
-    fn from_result(value: convex_polygon::ScaleResult, brush: &Brush) -> Self
+    fn from_result(value: convex_polygon::ScaleResult, brush: &Brush, bound: f32) -> Self
     {
         use convex_polygon::ScaleResult;
 
@@ -296,7 +296,7 @@ impl ScaleResult
                 texture_move
             } =>
             {
-                if brush.path_hull_out_of_bounds(new_center)
+                if brush.path_hull_out_of_bounds(new_center, bound)
                 {
                     return Self::Invalid;
                 }
@@ -324,13 +324,13 @@ pub(in crate::map) enum ShearResult
 impl ShearResult
 {
     #[inline]
-    fn from_result(value: Option<(Vec2, HvVec<f32>)>, brush: &Brush) -> Self
+    fn from_result(value: Option<(Vec2, HvVec<f32>)>, brush: &Brush, bound: f32) -> Self
     {
         match value
         {
             Some((new_center, xys)) =>
             {
-                if brush.path_hull_out_of_bounds(new_center)
+                if brush.path_hull_out_of_bounds(new_center, bound)
                 {
                     return Self::Invalid;
                 }
@@ -359,7 +359,7 @@ pub(in crate::map) enum RotateResult
 impl RotateResult
 {
     #[inline]
-    fn from_result(value: convex_polygon::RotateResult, brush: &Brush) -> Self
+    fn from_result(value: convex_polygon::RotateResult, brush: &Brush, bound: f32) -> Self
     {
         use convex_polygon::RotateResult;
 
@@ -372,7 +372,7 @@ impl RotateResult
                 texture_move
             } =>
             {
-                if brush.path_hull_out_of_bounds(new_center)
+                if brush.path_hull_out_of_bounds(new_center, bound)
                 {
                     return Self::Invalid;
                 }
@@ -387,6 +387,38 @@ impl RotateResult
 #[derive(Debug)]
 pub(in crate::map) struct RotatePayload(Id, HvVec<Vec2>, Option<TextureRotation>);
 
+impl RotatePayload
+{
+    /// Makes the side going from the vertex closest to `a` to the one closest to `b` exactly
+    /// horizontal or vertical, whichever it is closer to.
+    #[inline]
+    pub fn straighten_side(&mut self, a: Vec2, b: Vec2)
+    {
+        let closest = |p: Vec2| {
+            (0..self.1.len())
+                .min_by(|i, j| {
+                    self.1[*i].distance_squared(p).total_cmp(&self.1[*j].distance_squared(p))
+                })
+                .unwrap()
+        };
+        let (i, j) = (closest(a), closest(b));
+        let side = self.1[j] - self.1[i];
+
+        if side.x.abs() >= side.y.abs()
+        {
+            let y = (self.1[i].y + self.1[j].y) / 2f32;
+            self.1[i].y = y;
+            self.1[j].y = y;
+        }
+        else
+        {
+            let x = (self.1[i].x + self.1[j].x) / 2f32;
+            self.1[i].x = x;
+            self.1[j].x = x;
+        }
+    }
+}
+
 //=======================================================================//
 // TYPES
 //
@@ -803,6 +835,17 @@ impl Brush
         self.data.polygon.vertexes()
     }
 
+    /// Whether the polygon would still be valid if its vertexes were replaced by the ones
+    /// returned by `f`.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn valid_mapped_vertexes<F>(&self, f: F) -> bool
+    where
+        F: Fn(Vec2) -> Vec2
+    {
+        self.data.polygon.valid_mapped(f)
+    }
+
     /// Returns an iterator to the vertexes of the underlying `ConvexPolygon`.
     #[inline]
     pub fn selected_vertexes(&self) -> Option<impl Iterator<Item = Vec2>>
@@ -833,6 +876,28 @@ impl Brush
     #[inline]
     pub fn polygon(&self) -> ConvexPolygon { self.data.polygon.clone() }
 
+    /// Returns the area of the underlying `ConvexPolygon`, computed through the shoelace formula.
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32
+    {
+        self.vertexes()
+            .zip(self.vertexes().cycle().skip(1))
+            .fold(0f32, |area, (a, b)| area + a.perp_dot(b))
+            .abs() /
+            2f32
+    }
+
+    /// Returns the perimeter of the underlying `ConvexPolygon`.
+    #[inline]
+    #[must_use]
+    pub fn perimeter(&self) -> f32
+    {
+        self.vertexes()
+            .zip(self.vertexes().cycle().skip(1))
+            .fold(0f32, |perimeter, (a, b)| perimeter + a.distance(b))
+    }
+
     #[inline]
     #[must_use]
     pub fn anchors_hull(&self, brushes: Brushes) -> Option<Hull>
@@ -912,16 +977,16 @@ impl Brush
 
     /// Moves the `Brush` by the amount delta.
     #[inline]
-    pub fn check_move(&self, delta: Vec2, move_texture: bool) -> bool
+    pub fn check_move(&self, delta: Vec2, move_texture: bool, bound: f32) -> bool
     {
-        self.data.polygon.check_move(delta, move_texture) &&
-            !self.path_hull_out_of_bounds(self.center() + delta)
+        self.data.polygon.check_move(delta, move_texture, bound) &&
+            !self.path_hull_out_of_bounds(self.center() + delta, bound)
     }
 
     #[inline]
-    pub fn check_texture_move(&self, delta: Vec2) -> bool
+    pub fn check_texture_move(&self, delta: Vec2, bound: f32) -> bool
     {
-        !self.has_texture() || self.data.polygon.check_texture_move(delta)
+        !self.has_texture() || self.data.polygon.check_texture_move(delta, bound)
     }
 
     /// Moves the `Brush` by the amount delta.
@@ -965,6 +1030,21 @@ impl Brush
         std::mem::swap(&mut self.data.polygon, polygon);
     }
 
+    /// Reverses the order of the vertexes if they are not stored in counter-clockwise order.
+    /// Returns the previous `ConvexPolygon` if that was the case.
+    #[inline]
+    pub fn fix_winding(&mut self) -> Option<ConvexPolygon>
+    {
+        if self.data.polygon.ccw_winding()
+        {
+            return None;
+        }
+
+        let prev = self.polygon();
+        self.data.polygon.reverse_winding();
+        prev.into()
+    }
+
     //==============================================================
     // Snap
 
@@ -1137,10 +1217,27 @@ impl Brush
     pub fn check_texture_change(
         &mut self,
         drawing_resources: &DrawingResources,
-        texture: &str
+        texture: &str,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_change(drawing_resources, texture)
+        self.data
+            .polygon
+            .check_texture_change(drawing_resources, texture, bound)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn check_texture_sprite_change(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        texture: &str,
+        bound: f32
+    ) -> bool
+    {
+        self.data
+            .polygon
+            .check_texture_sprite_change(drawing_resources, texture, bound)
     }
 
     #[inline]
@@ -1168,10 +1265,13 @@ impl Brush
     pub fn check_texture_offset_x(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_offset_x(drawing_resources, value)
+        self.data
+            .polygon
+            .check_texture_offset_x(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1190,10 +1290,13 @@ impl Brush
     pub fn check_texture_offset_y(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_offset_y(drawing_resources, value)
+        self.data
+            .polygon
+            .check_texture_offset_y(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1211,10 +1314,13 @@ impl Brush
     pub fn check_texture_scale_x(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_scale_x(drawing_resources, value)
+        self.data
+            .polygon
+            .check_texture_scale_x(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1238,10 +1344,13 @@ impl Brush
     pub fn check_texture_scale_y(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32
+        value: f32,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_scale_y(drawing_resources, value)
+        self.data
+            .polygon
+            .check_texture_scale_y(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1288,10 +1397,14 @@ impl Brush
     }
 
     #[inline]
-    pub fn check_texture_angle(&mut self, drawing_resources: &DrawingResources, value: f32)
-        -> bool
+    pub fn check_texture_angle(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        value: f32,
+        bound: f32
+    ) -> bool
     {
-        self.data.polygon.check_texture_angle(drawing_resources, value)
+        self.data.polygon.check_texture_angle(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1312,14 +1425,31 @@ impl Brush
         self.data.polygon.set_texture_height(value)
     }
 
+    #[inline]
+    #[must_use]
+    pub fn set_texture_brightness(&mut self, value: f32) -> Option<f32>
+    {
+        self.data.polygon.set_texture_brightness(value)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn set_texture_trim(&mut self, value: Option<String>) -> Option<Option<String>>
+    {
+        self.data.polygon.set_texture_trim(value)
+    }
+
     #[inline]
     pub fn check_texture_sprite(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: bool
+        value: bool,
+        bound: f32
     ) -> bool
     {
-        self.data.polygon.check_texture_sprite(drawing_resources, value)
+        self.data
+            .polygon
+            .check_texture_sprite(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1335,9 +1465,15 @@ impl Brush
 
     #[inline]
     #[must_use]
-    pub fn check_texture_within_bounds(&mut self, drawing_resources: &DrawingResources) -> bool
+    pub fn check_texture_within_bounds(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        bound: f32
+    ) -> bool
     {
-        self.data.polygon.check_texture_within_bounds(drawing_resources)
+        self.data
+            .polygon
+            .check_texture_within_bounds(drawing_resources, bound)
     }
 
     #[inline]
@@ -1345,12 +1481,13 @@ impl Brush
     pub fn check_texture_animation_change(
         &mut self,
         drawing_resources: &DrawingResources,
-        animation: &Animation
+        animation: &Animation,
+        bound: f32
     ) -> bool
     {
         self.data
             .polygon
-            .check_texture_animation_change(drawing_resources, animation)
+            .check_texture_animation_change(drawing_resources, animation, bound)
     }
 
     #[inline]
@@ -1386,12 +1523,13 @@ impl Brush
     pub fn check_atlas_animation_x_partition(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: u32
+        value: u32,
+        bound: f32
     ) -> bool
     {
         self.data
             .polygon
-            .check_atlas_animation_x_partition(drawing_resources, value)
+            .check_atlas_animation_x_partition(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1412,12 +1550,13 @@ impl Brush
     pub fn check_atlas_animation_y_partition(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: u32
+        value: u32,
+        bound: f32
     ) -> bool
     {
         self.data
             .polygon
-            .check_atlas_animation_y_partition(drawing_resources, value)
+            .check_atlas_animation_y_partition(drawing_resources, value, bound)
     }
 
     #[inline]
@@ -1600,14 +1739,27 @@ impl Brush
     #[must_use]
     pub fn selected_sides_amount(&self) -> u8 { self.data.polygon.selected_sides_amount() }
 
+    /// Returns the position of a vertex within `snap_distance` from `cursor_pos`, if any.
+    #[inline]
+    #[must_use]
+    pub fn snap_vertex(&self, cursor_pos: Vec2, snap_distance: f32) -> Option<Vec2>
+    {
+        self.vertexes().find(|vx| vx.distance(cursor_pos) <= snap_distance)
+    }
+
+    /// Returns the index and position of the vertex close to `cursor_pos`, if any.
     #[inline]
     #[must_use]
-    pub fn nearby_vertex(&self, cursor_pos: Vec2, camera_scale: f32) -> Option<Vec2>
+    pub fn nearby_vertex_with_index(
+        &self,
+        cursor_pos: Vec2,
+        camera_scale: f32
+    ) -> Option<(usize, Vec2)>
     {
         self.data
             .polygon
             .nearby_vertex(cursor_pos, camera_scale)
-            .map(|idx| self.data.polygon.vertex_at_index(idx))
+            .map(|idx| (idx, self.data.polygon.vertex_at_index(idx)))
     }
 
     /// Returns a `VertexSelectionResult` describing the state of the `SelectableVertex` closest to
@@ -1726,6 +1878,21 @@ impl Brush
             .try_vertex_insertion_at_index(drawing_resources, pos, index, selected)
     }
 
+    /// Returns the polygon resulting from replacing the only selected side with an arc of radius
+    /// `radius` made of `segments` segments, if valid.
+    #[inline]
+    #[must_use]
+    pub fn check_side_arc(
+        &self,
+        drawing_resources: &DrawingResources,
+        radius: f32,
+        segments: u8,
+        bound: f32
+    ) -> Option<ConvexPolygon>
+    {
+        self.data.polygon.side_arc(drawing_resources, radius, segments, bound)
+    }
+
     /// Inserts a new vertex with position `pos` at `index`.
     #[inline]
     pub fn insert_vertex_at_index(
@@ -1787,9 +1954,12 @@ impl Brush
 
     /// Moves the selected `SelectableVertexes` by the amount `delta`.
     #[inline]
-    pub fn check_selected_vertexes_move(&mut self, delta: Vec2) -> VertexesMoveResult
+    pub fn check_selected_vertexes_move(&mut self, delta: Vec2, bound: f32) -> VertexesMoveResult
     {
-        VertexesMoveResult::from_result(self.data.polygon.check_selected_vertexes_move(delta), self)
+        VertexesMoveResult::from_result(
+            self.data.polygon.check_selected_vertexes_move(delta, bound),
+            self
+        )
     }
 
     /// Applies the vertexes move described by `payload`.
@@ -2011,9 +2181,12 @@ impl Brush
 
     /// Moves the selected lines by the amount `delta`.
     #[inline]
-    pub fn check_selected_sides_move(&mut self, delta: Vec2) -> VertexesMoveResult
+    pub fn check_selected_sides_move(&mut self, delta: Vec2, bound: f32) -> VertexesMoveResult
     {
-        VertexesMoveResult::from_result(self.data.polygon.check_selected_sides_move(delta), self)
+        VertexesMoveResult::from_result(
+            self.data.polygon.check_selected_sides_move(delta, bound),
+            self
+        )
     }
 
     //==============================================================
@@ -2066,6 +2239,64 @@ impl Brush
         self.data.polygon.clip(drawing_resources, clip_line)
     }
 
+    /// Splits the underlying [`ConvexPolygon`] into `pieces` strips of equal size, separated by
+    /// vertical lines if `vertical` is true, horizontal ones otherwise. The strips are returned
+    /// from left to right, or bottom to top, and only the last one retains the texture if it is
+    /// rendered as a sprite.
+    /// # Panics
+    /// Panics if `pieces` is lower than 2.
+    #[inline]
+    pub fn split(
+        &self,
+        drawing_resources: &DrawingResources,
+        pieces: u8,
+        vertical: bool
+    ) -> Option<HvVec<ConvexPolygon>>
+    {
+        assert!(pieces > 1, "Invalid amount of pieces {pieces}.");
+
+        let hull = self.hull();
+        let mut polygons = hv_vec![];
+        let mut remainder = Cow::Borrowed(&self.data.polygon);
+
+        for i in 1..pieces
+        {
+            let step = f32::from(i) / f32::from(pieces);
+            let clip_line = if vertical
+            {
+                let x = hull.left() + hull.width() * step;
+                [Vec2::new(x, hull.bottom()), Vec2::new(x, hull.top())]
+            }
+            else
+            {
+                let y = hull.bottom() + hull.height() * step;
+                [Vec2::new(hull.left(), y), Vec2::new(hull.right(), y)]
+            };
+
+            let [a, b] = remainder.clip(drawing_resources, &clip_line)?;
+            let (mut piece, rest) = if (vertical && a.center().x < b.center().x) ||
+                (!vertical && a.center().y < b.center().y)
+            {
+                (a, b)
+            }
+            else
+            {
+                (b, a)
+            };
+
+            if piece.has_sprite()
+            {
+                _ = piece.remove_texture();
+            }
+
+            polygons.push(piece);
+            remainder = Cow::Owned(rest);
+        }
+
+        polygons.push(remainder.into_owned());
+        Some(polygons)
+    }
+
     //==============================================================
     // Shatter
 
@@ -2141,12 +2372,16 @@ impl Brush
         &mut self,
         drawing_resources: &DrawingResources,
         info: &ScaleInfo,
-        scale_texture: bool
+        scale_texture: bool,
+        bound: f32
     ) -> ScaleResult
     {
         ScaleResult::from_result(
-            self.data.polygon.check_scale(drawing_resources, info, scale_texture),
-            self
+            self.data
+                .polygon
+                .check_scale(drawing_resources, info, scale_texture, bound),
+            self,
+            bound
         )
     }
 
@@ -2157,14 +2392,16 @@ impl Brush
         drawing_resources: &DrawingResources,
         info: &ScaleInfo,
         flip_queue: &ArrayVec<Flip, 2>,
-        scale_texture: bool
+        scale_texture: bool,
+        bound: f32
     ) -> ScaleResult
     {
         ScaleResult::from_result(
             self.data
                 .polygon
-                .check_flip_scale(drawing_resources, info, flip_queue, scale_texture),
-            self
+                .check_flip_scale(drawing_resources, info, flip_queue, scale_texture, bound),
+            self,
+            bound
         )
     }
 
@@ -2203,9 +2440,9 @@ impl Brush
 
     /// Returns a [`ShearResult`] describing the validity of the vertical shear.
     #[inline]
-    pub fn check_horizontal_shear(&self, info: &ShearInfo) -> ShearResult
+    pub fn check_horizontal_shear(&self, info: &ShearInfo, bound: f32) -> ShearResult
     {
-        ShearResult::from_result(self.data.polygon.check_horizontal_shear(info), self)
+        ShearResult::from_result(self.data.polygon.check_horizontal_shear(info, bound), self, bound)
     }
 
     /// Sets the x coordinates of the vertexes based on `payload`.
@@ -2218,9 +2455,9 @@ impl Brush
 
     /// Returns a [`ShearResult`] describing the validity of the vertical shear.
     #[inline]
-    pub fn check_vertical_shear(&self, info: &ShearInfo) -> ShearResult
+    pub fn check_vertical_shear(&self, info: &ShearInfo, bound: f32) -> ShearResult
     {
-        ShearResult::from_result(self.data.polygon.check_vertical_shear(info), self)
+        ShearResult::from_result(self.data.polygon.check_vertical_shear(info, bound), self, bound)
     }
 
     /// Sets the y coordinates of the vertexes based on `payload`.
@@ -2241,14 +2478,41 @@ impl Brush
         drawing_resources: &DrawingResources,
         pivot: Vec2,
         angle: f32,
-        rotate_texture: bool
+        rotate_texture: bool,
+        bound: f32
     ) -> RotateResult
     {
         RotateResult::from_result(
             self.data
                 .polygon
-                .check_rotation(drawing_resources, pivot, angle, rotate_texture),
-            self
+                .check_rotation(drawing_resources, pivot, angle, rotate_texture, bound),
+            self,
+            bound
+        )
+    }
+
+    /// Checks whether `self` can be rotated by 90 degrees around `pivot`, clockwise if `clockwise`
+    /// is true. The vertexes coordinates are swapped rather than computed through trigonometry.
+    #[inline]
+    pub fn check_quarter_rotate(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        pivot: Vec2,
+        clockwise: bool,
+        rotate_texture: bool,
+        bound: f32
+    ) -> RotateResult
+    {
+        RotateResult::from_result(
+            self.data.polygon.check_quarter_rotation(
+                drawing_resources,
+                pivot,
+                clockwise,
+                rotate_texture,
+                bound
+            ),
+            self,
+            bound
         )
     }
 
@@ -2488,6 +2752,52 @@ impl BrushViewer
         }
     }
 
+    /// Returns the surface material stored in the built-in material property, which is empty if
+    /// it was not assigned.
+    #[inline]
+    #[must_use]
+    pub fn material(&self) -> &str
+    {
+        match self.properties.get(MATERIAL_PROPERTY)
+        {
+            Some(Value::String(material)) => material,
+            _ => ""
+        }
+    }
+
+    /// Returns the area-weighted centroid of the polygon. It is only computed when requested, so
+    /// that exporters not needing it do not pay for it.
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    #[must_use]
+    pub fn centroid(&self) -> Vec2
+    {
+        let origin = self.vertexes[0];
+        let mut area = 0f32;
+        let mut centroid = Vec2::ZERO;
+
+        for [a, b] in self.vertexes.pair_iter().unwrap()
+        {
+            let (a, b) = (*a - origin, *b - origin);
+            let cross = a.perp_dot(b);
+            area += cross;
+            centroid += (a + b) * cross;
+        }
+
+        if area.abs() < f32::EPSILON
+        {
+            return self.vertexes.iter().sum::<Vec2>() / self.vertexes.len() as f32;
+        }
+
+        origin + centroid / (3f32 * area)
+    }
+
+    /// Returns the axis-aligned bounding box of the polygon, with the minimum coordinates as
+    /// bottom left corner and the maximum ones as top right corner. It is only computed when
+    /// requested.
+    #[inline]
+    pub fn aabb(&self) -> Hull { Hull::from_points(self.vertexes.iter().copied()).unwrap() }
+
     /// Sets the [`Animation`] of the texture.
     #[inline]
     pub(in crate::map) fn set_texture_animation(&mut self, animation: Animation)
@@ -2496,4 +2806,88 @@ impl BrushViewer
             self.texture.as_mut().unwrap().unsafe_set_animation(animation);
         }
     }
+
+    /// Multiplies the coordinates, the texture, and the path of `self` by `factor`.
+    #[inline]
+    pub(in crate::map) fn export_scale(&mut self, factor: f32)
+    {
+        for vx in &mut self.vertexes
+        {
+            *vx *= factor;
+        }
+
+        if let Some(texture) = &mut self.texture
+        {
+            texture.export_scale(factor);
+        }
+
+        if self.mover.has_path()
+        {
+            self.mover.path_mut().export_scale(factor);
+        }
+    }
+}
+
+//=======================================================================//
+// TESTS
+//
+//=======================================================================//
+
+#[cfg(test)]
+mod tests
+{
+    use bevy::prelude::Vec2;
+
+    use super::BrushViewer;
+    use crate::{
+        map::{
+            brush::mover::Mover,
+            containers::{hv_hash_map, hv_vec}
+        },
+        utils::{hull::Hull, identifiers::Id, math::AroundEqual}
+    };
+
+    /// Returns a [`BrushViewer`] with vertexes `vxs`.
+    #[inline]
+    fn viewer(vxs: &[Vec2]) -> BrushViewer
+    {
+        BrushViewer {
+            id:         Id::ZERO,
+            vertexes:   hv_vec![collect; vxs.iter().copied()],
+            texture:    None,
+            mover:      Mover::None,
+            collision:  true,
+            properties: hv_hash_map![]
+        }
+    }
+
+    #[test]
+    fn unit_square()
+    {
+        let brush = viewer(&[
+            Vec2::new(0f32, 0f32),
+            Vec2::new(1f32, 0f32),
+            Vec2::new(1f32, 1f32),
+            Vec2::new(0f32, 1f32)
+        ]);
+
+        assert!(brush.centroid().around_equal(&Vec2::splat(0.5f32)));
+        assert_eq!(brush.aabb(), Hull::new(1f32, 0f32, 0f32, 1f32));
+    }
+
+    #[test]
+    fn l_shape()
+    {
+        let brush = viewer(&[
+            Vec2::new(0f32, 0f32),
+            Vec2::new(2f32, 0f32),
+            Vec2::new(2f32, 1f32),
+            Vec2::new(1f32, 1f32),
+            Vec2::new(1f32, 2f32),
+            Vec2::new(0f32, 2f32)
+        ]);
+
+        assert!(brush.centroid().around_equal(&Vec2::splat(5f32 / 6f32)));
+        assert_eq!(brush.aabb(), Hull::new(2f32, 0f32, 0f32, 2f32));
+    }
 }
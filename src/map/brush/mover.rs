@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
 use crate::{
     map::{
-        containers::{hv_hash_set, Ids},
+        containers::{hv_hash_set, serialize_sorted_ids, Ids},
         AssertedInsertRemove
     },
     utils::{identifiers::Id, misc::TakeValue},
@@ -32,7 +32,7 @@ pub enum Mover
     #[default]
     None,
     /// Attached [`Brush`]es.
-    Anchors(Ids),
+    Anchors(#[serde(serialize_with = "serialize_sorted_ids")] Ids),
     /// Motor.
     Motor(Motor),
     /// Attached to a [`Brush`].
@@ -184,6 +184,7 @@ pub struct Motor
     /// The [`Path`].
     path:             Path,
     /// The [`Id`]s of the attached [`Brush`]es.
+    #[serde(serialize_with = "serialize_sorted_ids")]
     anchored_brushes: Ids
 }
 
@@ -3,7 +3,7 @@
 //
 //=======================================================================//
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, ops::RangeInclusive};
 
 use bevy::prelude::{Transform, Vec2, Vec3, Window};
 
@@ -16,6 +16,14 @@ use super::editor::state::ui::{
 };
 use crate::utils::{hull::Hull, misc::Camera};
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The range of the scale of the camera.
+const SCALE_RANGE: RangeInclusive<f32> = 0.125..=5f32;
+
 //=======================================================================//
 // TYPES
 //
@@ -65,7 +73,9 @@ impl Camera for Transform
     fn change_scale(&mut self, units: f32) -> f32
     {
         let prev_scale = self.scale();
-        self.scale = Vec3::splat((self.scale() - units * 0.125).clamp(0.125, 5f32));
+        self.scale = Vec3::splat(
+            (self.scale() - units * 0.125).clamp(*SCALE_RANGE.start(), *SCALE_RANGE.end())
+        );
         prev_scale
     }
 
@@ -76,6 +86,16 @@ impl Camera for Transform
         self.translate(-ui_camera_displacement() * (self.scale() - prev_scale));
     }
 
+    #[inline]
+    fn set_zoom_percentage(&mut self, percentage: f32)
+    {
+        let prev_scale = self.scale();
+        self.scale = Vec3::splat(
+            (100f32 / percentage.max(f32::EPSILON)).clamp(*SCALE_RANGE.start(), *SCALE_RANGE.end())
+        );
+        self.translate(-ui_camera_displacement() * (self.scale() - prev_scale));
+    }
+
     #[inline]
     fn scale_viewport_ui_constricted_to_hull(&mut self, window: &Window, hull: &Hull, padding: f32)
     {
@@ -0,0 +1,64 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        containers::{hv_vec, HvVec},
+        properties::Value,
+        thing::ThingViewer
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The collision footprint of a solid thing.
+#[must_use]
+pub struct ThingCollider
+{
+    /// The [`Id`] of the thing.
+    pub id:       Id,
+    /// The vertexes of the footprint, in world coordinates and counter-clockwise order.
+    pub vertexes: HvVec<Vec2>
+}
+
+impl ThingCollider
+{
+    /// Returns the [`ThingCollider`]s of the things in `things` that have the property `solid`
+    /// set to true.
+    /// The footprint is the shape returned by `shape`, expressed relative to the position of the
+    /// thing, or, if [`None`], the rectangle of its [`Hull`](crate::Hull).
+    #[inline]
+    pub(in crate::map) fn colliders<'a, I, F>(things: I, solid: &str, shape: F) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a ThingViewer>,
+        F: Fn(&ThingViewer) -> Option<HvVec<Vec2>>
+    {
+        hv_vec![collect; things
+            .filter(|thing| matches!(thing.properties.get(solid), Some(Value::Bool(true))))
+            .map(|thing| {
+                let vertexes = match shape(thing)
+                {
+                    Some(mut vertexes) =>
+                    {
+                        for vx in vertexes.iter_mut()
+                        {
+                            *vx += thing.pos;
+                        }
+
+                        vertexes
+                    },
+                    None => hv_vec![collect; thing.hull.rectangle()]
+                };
+
+                Self { id: thing.id, vertexes }
+            })]
+    }
+}
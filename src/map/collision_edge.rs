@@ -0,0 +1,84 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        properties::Value
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A solid segment of the boundary of a brush with collision enabled.
+#[must_use]
+pub struct CollisionEdge
+{
+    /// The [`Id`] of the brush.
+    pub id:      Id,
+    /// The start of the segment.
+    pub start:   Vec2,
+    /// The end of the segment, which follows `start` in the counter-clockwise order of the
+    /// vertexes of the brush.
+    pub end:     Vec2,
+    /// The normalized outward normal.
+    pub normal:  Vec2,
+    /// Whether the edge only blocks what crosses it from the side `normal` points to.
+    pub one_way: bool
+}
+
+impl CollisionEdge
+{
+    /// Returns the [`CollisionEdge`]s of the brushes in `brushes` with collision enabled.
+    /// Brushes with the property `one_way` set to true are treated as one-way platforms: only
+    /// the edges facing upward are solid, and they are flagged as one-way. Non-solid edges are
+    /// omitted. The edges are sorted by the id of their brush.
+    #[inline]
+    pub(in crate::map) fn edges<'a, I>(brushes: I, one_way: &str) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        let mut brushes = brushes.filter(|brush| brush.collision).collect::<Vec<_>>();
+        brushes.sort_by_key(|brush| brush.id.value());
+
+        let mut edges = hv_vec![];
+
+        for brush in brushes
+        {
+            let platform = matches!(brush.properties.get(one_way), Some(Value::Bool(true)));
+            let len = brush.vertexes.len();
+
+            for i in 0..len
+            {
+                let start = brush.vertexes[i];
+                let end = brush.vertexes[(i + 1) % len];
+                let dir = end - start;
+                let normal = Vec2::new(dir.y, -dir.x).normalize_or_zero();
+
+                if platform && normal.y <= 0f32
+                {
+                    continue;
+                }
+
+                edges.push(Self {
+                    id: brush.id,
+                    start,
+                    end,
+                    normal,
+                    one_way: platform
+                });
+            }
+        }
+
+        edges
+    }
+}
@@ -474,6 +474,15 @@ impl<T> HvVec<T>
     #[inline]
     pub(in crate::map) fn reverse(&mut self) { self.0.reverse(); }
 
+    /// Removes consecutive repeated elements in the vector.
+    #[inline]
+    pub(in crate::map) fn dedup(&mut self)
+    where
+        T: PartialEq
+    {
+        self.0.dedup();
+    }
+
     /// Retains only the elements specified by the predicate.
     #[inline]
     pub(in crate::map) fn retain_mut<F>(&mut self, f: F)
@@ -1072,6 +1081,20 @@ pub(in crate::map) type HvBox<T> = Box<T>;
 //
 //=======================================================================//
 
+/// Serializes `ids` sorted by value, so that the serialized bytes do not depend on the iteration
+/// order of the set.
+#[inline]
+pub(in crate::map) fn serialize_sorted_ids<S>(ids: &Ids, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer
+{
+    let mut ids = ids.iter().copied().collect::<Vec<_>>();
+    ids.sort_unstable_by_key(|id| id.value());
+    serializer.collect_seq(ids)
+}
+
+//=======================================================================//
+
 #[cfg(feature = "arena_alloc")]
 /// Returns a static reference to the arena allocator.
 #[inline]
@@ -0,0 +1,356 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::{Resource, Vec2};
+
+use crate::{
+    map::{
+        containers::{hv_vec, HvVec},
+        drawer::{color::Color, EditDrawer}
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// TRAITS
+//
+//=======================================================================//
+
+/// A trait to define tools that the editor dispatches alongside the built-in ones.
+/// The tools must be registered through the [`CustomTools`] resource before startup and can be
+/// enabled from the "Tools" menu.
+/// # Example
+/// ```
+/// use bevy::prelude::Vec2;
+/// use hill_vacuum::{CustomTool, CustomToolContext, CustomToolDrawer, CustomTools};
+///
+/// struct Stamp;
+///
+/// impl CustomTool for Stamp
+/// {
+///     fn name(&self) -> &str { "Stamp" }
+///
+///     fn update(&mut self, context: &mut CustomToolContext)
+///     {
+///         if !context.left_mouse_just_pressed()
+///         {
+///             return;
+///         }
+///
+///         let pos = context.cursor_snapped_pos();
+///         context.spawn_brush([
+///             pos,
+///             pos + Vec2::new(64f32, 0f32),
+///             pos + Vec2::new(64f32, 64f32),
+///             pos + Vec2::new(0f32, 64f32)
+///         ]);
+///     }
+///
+///     fn draw(&self, drawer: &mut CustomToolDrawer)
+///     {
+///         drawer.square_highlight(drawer.cursor_snapped_pos());
+///     }
+/// }
+///
+/// let mut custom_tools = CustomTools::new();
+/// custom_tools.push(Stamp);
+///
+/// let mut app = bevy::prelude::App::new();
+/// app.insert_resource(custom_tools);
+/// ```
+pub trait CustomTool: Send + Sync + 'static
+{
+    /// The name of the tool shown in the "Tools" menu.
+    #[must_use]
+    fn name(&self) -> &str;
+
+    /// Whether the tool can be enabled, or can stay enabled, based on the amount of selected
+    /// brushes and things.
+    #[inline]
+    #[must_use]
+    fn change_conditions_met(&self, _selected_brushes: usize, _selected_things: usize) -> bool
+    {
+        true
+    }
+
+    /// Called every time the tool is enabled.
+    #[inline]
+    fn enable(&mut self) {}
+
+    /// Updates the tool. Called every frame while the tool is enabled.
+    fn update(&mut self, context: &mut CustomToolContext);
+
+    /// Draws the tool on top of the map. Called every frame while the tool is enabled.
+    #[inline]
+    fn draw(&self, _drawer: &mut CustomToolDrawer) {}
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A resource containing all the [`CustomTool`]s to be made available in the editor.
+#[must_use]
+#[derive(Resource, Default)]
+pub struct CustomTools(Vec<Box<dyn CustomTool>>);
+
+impl CustomTools
+{
+    /// Returns a new empty [`CustomTools`].
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Pushes a new [`CustomTool`].
+    #[inline]
+    pub fn push<T: CustomTool>(&mut self, tool: T) { self.0.push(Box::new(tool)); }
+
+    /// Returns an iterator to the indexes and names of the tools, along with whether their change
+    /// conditions are met.
+    #[inline]
+    pub(in crate::map) fn iter(
+        &self,
+        selected_brushes: usize,
+        selected_things: usize
+    ) -> impl Iterator<Item = (usize, &str, bool)> + '_
+    {
+        self.0.iter().enumerate().map(move |(index, tool)| {
+            (index, tool.name(), tool.change_conditions_met(selected_brushes, selected_things))
+        })
+    }
+
+    /// Whether the change conditions of the tool at `index` are met.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn change_conditions_met(
+        &self,
+        index: usize,
+        selected_brushes: usize,
+        selected_things: usize
+    ) -> bool
+    {
+        self.0[index].change_conditions_met(selected_brushes, selected_things)
+    }
+
+    /// Enables the tool at `index`.
+    #[inline]
+    pub(in crate::map) fn enable(&mut self, index: usize) { self.0[index].enable(); }
+
+    /// Updates the tool at `index`.
+    #[inline]
+    pub(in crate::map) fn update(&mut self, index: usize, context: &mut CustomToolContext)
+    {
+        self.0[index].update(context);
+    }
+
+    /// Draws the tool at `index`.
+    #[inline]
+    pub(in crate::map) fn draw(&self, index: usize, drawer: &mut CustomToolDrawer)
+    {
+        self.0[index].draw(drawer);
+    }
+}
+
+//=======================================================================//
+
+/// The state of the editor exposed to a [`CustomTool`] during its update, and the edits it
+/// requested.
+#[must_use]
+pub struct CustomToolContext
+{
+    /// The position of the cursor on the map.
+    cursor_pos:         Vec2,
+    /// The grid snapped position of the cursor, if snap is enabled.
+    cursor_snapped_pos: Vec2,
+    /// The press state of the left mouse button, pressed and just pressed.
+    left_mouse:         (bool, bool),
+    /// The press state of the right mouse button, pressed and just pressed.
+    right_mouse:        (bool, bool),
+    /// The [`Id`]s and vertexes of the selected brushes.
+    selected_brushes:   HvVec<(Id, HvVec<Vec2>)>,
+    /// The [`Id`]s and positions of the selected things.
+    selected_things:    HvVec<(Id, Vec2)>,
+    /// The vertexes of the brushes to spawn.
+    spawns:             HvVec<HvVec<Vec2>>,
+    /// The [`Id`]s of the selected brushes to despawn.
+    despawns:           HvVec<Id>
+}
+
+impl CustomToolContext
+{
+    /// Returns a new [`CustomToolContext`].
+    #[inline]
+    pub(in crate::map) fn new(
+        cursor_pos: Vec2,
+        cursor_snapped_pos: Vec2,
+        left_mouse: (bool, bool),
+        right_mouse: (bool, bool),
+        selected_brushes: impl Iterator<Item = (Id, HvVec<Vec2>)>,
+        selected_things: impl Iterator<Item = (Id, Vec2)>
+    ) -> Self
+    {
+        Self {
+            cursor_pos,
+            cursor_snapped_pos,
+            left_mouse,
+            right_mouse,
+            selected_brushes: hv_vec![collect; selected_brushes],
+            selected_things: hv_vec![collect; selected_things],
+            spawns: hv_vec![],
+            despawns: hv_vec![]
+        }
+    }
+
+    /// Returns the position of the cursor on the map.
+    #[inline]
+    #[must_use]
+    pub const fn cursor_pos(&self) -> Vec2 { self.cursor_pos }
+
+    /// Returns the grid snapped position of the cursor on the map if snap is enabled, otherwise
+    /// returns the regular map position.
+    #[inline]
+    #[must_use]
+    pub const fn cursor_snapped_pos(&self) -> Vec2 { self.cursor_snapped_pos }
+
+    /// Whether the left mouse button is pressed.
+    #[inline]
+    #[must_use]
+    pub const fn left_mouse_pressed(&self) -> bool { self.left_mouse.0 }
+
+    /// Whether the left mouse button has just been pressed.
+    #[inline]
+    #[must_use]
+    pub const fn left_mouse_just_pressed(&self) -> bool { self.left_mouse.1 }
+
+    /// Whether the right mouse button is pressed.
+    #[inline]
+    #[must_use]
+    pub const fn right_mouse_pressed(&self) -> bool { self.right_mouse.0 }
+
+    /// Whether the right mouse button has just been pressed.
+    #[inline]
+    #[must_use]
+    pub const fn right_mouse_just_pressed(&self) -> bool { self.right_mouse.1 }
+
+    /// Returns an iterator to the [`Id`]s and vertexes of the selected brushes.
+    #[inline]
+    pub fn selected_brushes(&self) -> impl ExactSizeIterator<Item = (Id, &[Vec2])> + '_
+    {
+        self.selected_brushes.iter().map(|(id, vxs)| (*id, &**vxs))
+    }
+
+    /// Returns an iterator to the [`Id`]s and positions of the selected things.
+    #[inline]
+    pub fn selected_things(&self) -> impl ExactSizeIterator<Item = (Id, Vec2)> + '_
+    {
+        self.selected_things.iter().copied()
+    }
+
+    /// Requests the spawn of a brush with vertexes `vertexes`. The brush is spawned at the end of
+    /// the update only if the vertexes describe a valid convex polygon within the map bounds.
+    #[inline]
+    pub fn spawn_brush(&mut self, vertexes: impl IntoIterator<Item = Vec2>)
+    {
+        self.spawns.push(hv_vec![collect; vertexes]);
+    }
+
+    /// Requests the despawn of the selected brush with [`Id`] `identifier`. The request is ignored
+    /// if the brush is not selected.
+    #[inline]
+    pub fn despawn_brush(&mut self, identifier: Id) { self.despawns.push(identifier); }
+
+    /// Returns the vertexes of the brushes to spawn and the [`Id`]s of the brushes to despawn.
+    #[inline]
+    pub(in crate::map) fn into_requests(self) -> (HvVec<HvVec<Vec2>>, HvVec<Id>)
+    {
+        (self.spawns, self.despawns)
+    }
+}
+
+//=======================================================================//
+
+/// The shapes drawn by a [`CustomTool`] on top of the map.
+#[must_use]
+pub struct CustomToolDrawer
+{
+    /// The position of the cursor on the map.
+    cursor_pos:         Vec2,
+    /// The grid snapped position of the cursor, if snap is enabled.
+    cursor_snapped_pos: Vec2,
+    /// The lines to draw.
+    lines:              HvVec<[Vec2; 2]>,
+    /// The centers of the square highlights to draw.
+    highlights:         HvVec<Vec2>
+}
+
+impl CustomToolDrawer
+{
+    /// Returns a new [`CustomToolDrawer`].
+    #[inline]
+    pub(in crate::map) fn new(cursor_pos: Vec2, cursor_snapped_pos: Vec2) -> Self
+    {
+        Self {
+            cursor_pos,
+            cursor_snapped_pos,
+            lines: hv_vec![],
+            highlights: hv_vec![]
+        }
+    }
+
+    /// Returns the position of the cursor on the map.
+    #[inline]
+    #[must_use]
+    pub const fn cursor_pos(&self) -> Vec2 { self.cursor_pos }
+
+    /// Returns the grid snapped position of the cursor on the map if snap is enabled, otherwise
+    /// returns the regular map position.
+    #[inline]
+    #[must_use]
+    pub const fn cursor_snapped_pos(&self) -> Vec2 { self.cursor_snapped_pos }
+
+    /// Draws a line from `start` to `end`.
+    #[inline]
+    pub fn line(&mut self, start: Vec2, end: Vec2) { self.lines.push([start, end]); }
+
+    /// Draws the closed polygonal chain described by `vertexes`.
+    #[inline]
+    pub fn sides(&mut self, vertexes: impl IntoIterator<Item = Vec2>)
+    {
+        let mut vertexes = vertexes.into_iter();
+        let first = match vertexes.next()
+        {
+            Some(vx) => vx,
+            None => return
+        };
+        let mut start = first;
+
+        for end in vertexes
+        {
+            self.line(start, end);
+            start = end;
+        }
+
+        self.line(start, first);
+    }
+
+    /// Draws a square highlight centered in `center`.
+    #[inline]
+    pub fn square_highlight(&mut self, center: Vec2) { self.highlights.push(center); }
+
+    /// Draws the stored shapes through `drawer`.
+    #[inline]
+    pub(in crate::map) fn draw(self, drawer: &mut EditDrawer)
+    {
+        for [start, end] in self.lines
+        {
+            drawer.line(start, end, Color::ToolCursor);
+        }
+
+        for center in self.highlights
+        {
+            drawer.square_highlight(center, Color::ToolCursor);
+        }
+    }
+}
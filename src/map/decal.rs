@@ -0,0 +1,57 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        drawer::texture::{TextureInterface, TextureSettings}
+    },
+    utils::{identifiers::Id, math::points::vxs_center}
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A visual-only texture of a brush rendered as a sprite.
+#[must_use]
+pub struct Decal
+{
+    /// The [`Id`] of the brush the sprite belongs to.
+    pub id:       Id,
+    /// The texture.
+    pub texture:  TextureSettings,
+    /// The center of the brush the sprite is anchored to.
+    pub center:   Vec2,
+    /// The vertexes of the rectangle the sprite is drawn in, in world coordinates.
+    pub vertexes: [Vec2; 4]
+}
+
+impl Decal
+{
+    /// Returns the [`Decal`]s of the brushes in `brushes` whose texture is rendered as a sprite.
+    #[inline]
+    pub(in crate::map) fn decals<'a, I>(brushes: I) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        hv_vec![collect; brushes.filter_map(|brush| {
+            let texture = brush.texture.as_ref().filter(|texture| texture.sprite())?;
+            let center = vxs_center(brush.vertexes.iter().copied());
+
+            Self {
+                id: brush.id,
+                texture: texture.clone(),
+                center,
+                vertexes: texture.sprite_vertexes(center)
+            }
+            .into()
+        })]
+    }
+}
@@ -72,6 +72,8 @@ pub(crate) enum Color
     NonSelectedEntity,
     /// The color of the selected brushes.
     SelectedEntity,
+    /// The color of the sides of the selected brushes when the brushes are drawn in wireframe.
+    WireframeSelectedEntity,
     /// The color the highlighted non selected brush.
     HighlightedNonSelectedEntity,
     /// The color of the highlighted selected brush.
@@ -115,7 +117,23 @@ pub(crate) enum Color
     /// The color of the [`Hull`] of the cursor cursor polygons of the draw tools.
     CursorPolygonHull,
     /// The color drawn on top of an entity that caused an edit to fail.
-    ErrorHighlight
+    ErrorHighlight,
+    /// The color of the entities that are not present in the compared map.
+    ComparisonAdded,
+    /// The color of the entities of the compared map that are not present in the edited one.
+    ComparisonRemoved,
+    /// The color of the entities of the compared map that were moved or reshaped.
+    ComparisonMoved,
+    /// The color of the lines of the measurement grid.
+    MeasurementGridLines,
+    /// The color of the outline of the play area.
+    PlayArea,
+    /// The color of the editor-only markers and their labels.
+    Marker,
+    /// The color of the icons and text of the editor-only entity notes.
+    Note,
+    /// The color of the outline of the brushes sharing the texture of the selected ones.
+    SameTexture
 }
 
 impl Color
@@ -128,7 +146,7 @@ impl Color
         HullExtensions,
         ClippedPolygonsNotToSpawn | OpaqueEntity,
         NonSelectedEntity,
-        SelectedEntity,
+        SelectedEntity | WireframeSelectedEntity,
         SubtracteeBrush,
         ClippedPolygonsToSpawn,
         HighlightedSelectedEntity | HighlightedNonSelectedEntity,
@@ -145,6 +163,12 @@ impl Color
         CursorPolygonHull,
         DefaultCursor,
         ToolCursor | CursorPolygon,
+        ComparisonAdded | ComparisonRemoved | ComparisonMoved,
+        MeasurementGridLines,
+        PlayArea,
+        Marker,
+        Note,
+        SameTexture,
         ErrorHighlight
     );
 
@@ -185,6 +209,7 @@ impl Color
             Self::OriginGridLines => BevyColor::WHITE,
             Self::HullExtensions => BevyColor::INDIGO,
             Self::NonSelectedEntity => BevyColor::ANTIQUE_WHITE,
+            Self::WireframeSelectedEntity => BevyColor::ORANGE_RED,
             Self::SelectedEntity |
             Self::SubtractorBrush |
             Self::SelectedVertex |
@@ -203,7 +228,15 @@ impl Color
             Self::Hull => BevyColor::AQUAMARINE,
             Self::CursorPolygonHull => BevyColor::DARK_GREEN,
             Self::CursorPolygon => BevyColor::CYAN,
-            Self::DefaultCursor => BevyColor::GRAY
+            Self::DefaultCursor => BevyColor::GRAY,
+            Self::ComparisonAdded => BevyColor::LIME_GREEN,
+            Self::ComparisonRemoved => BevyColor::TOMATO,
+            Self::ComparisonMoved => BevyColor::rgb(0.2, 0.6, 1f32),
+            Self::MeasurementGridLines => BevyColor::TEAL,
+            Self::PlayArea => BevyColor::FUCHSIA,
+            Self::Marker => BevyColor::rgb(1f32, 0.85, 0.4),
+            Self::Note => BevyColor::rgb(0.55, 0.85, 1f32),
+            Self::SameTexture => BevyColor::AQUAMARINE
         }
     }
 }
@@ -5,7 +5,7 @@
 
 use std::{
     fs::File,
-    io::{BufReader, BufWriter},
+    io::{BufReader, BufWriter, Read},
     ops::{Deref, DerefMut}
 };
 
@@ -47,11 +47,12 @@ use crate::{
         },
         indexed_map::IndexedMap,
         thing::{catalog::ThingsCatalog, ThingInterface},
-        AssertedInsertRemove
+        AssertedInsertRemove,
+        ContentHash
     },
     utils::{
         hull::Hull,
-        math::{points::rotate_point_around_origin, HashVec2},
+        math::{points::rotate_point_around_origin, AroundEqual, HashVec2},
         misc::{vertex_highlight_square, Camera}
     }
 };
@@ -401,16 +402,11 @@ impl DrawingResources
         }
     }
 
-    /// The amount of default texture animations.
-    #[inline]
-    #[must_use]
-    pub fn animations_amount(&self) -> usize { self.animated_textures.len() }
-
     /// Returns the vector of [`DefaultAnimation`]s contained in `file`.
     #[inline]
     pub fn file_animations(
         amount: usize,
-        file: &mut BufReader<File>
+        file: &mut impl Read
     ) -> Result<Vec<DefaultAnimation>, &'static str>
     {
         let mut animations = vec![];
@@ -451,14 +447,17 @@ impl DrawingResources
         }
     }
 
-    /// Exports the default texture animations to `writer`.
+    /// The amount of default texture animations.
     #[inline]
-    pub fn export_animations(
-        &mut self,
-        mut writer: &mut BufWriter<&mut Vec<u8>>
-    ) -> Result<(), &'static str>
+    #[must_use]
+    pub fn animations_amount(&self) -> usize { self.animated_textures.len() }
+
+    /// Returns the default texture animations, sorted by texture name so that their order does
+    /// not depend on the iteration order of the animated textures.
+    #[inline]
+    pub fn default_animations(&self) -> Vec<DefaultAnimation>
     {
-        match self
+        let mut animations = self
             .animated_textures
             .iter()
             .map(|tex| {
@@ -469,7 +468,23 @@ impl DrawingResources
                     animation: texture.animation().clone()
                 }
             })
-            .find(|animation| ciborium::ser::into_writer(&animation, &mut writer).is_err())
+            .collect::<Vec<_>>();
+
+        animations.sort_unstable_by(|a, b| a.texture.cmp(&b.texture));
+        animations
+    }
+
+    /// Exports the default texture animations to `writer`.
+    #[inline]
+    pub fn export_animations(
+        &self,
+        mut writer: &mut BufWriter<&mut Vec<u8>>
+    ) -> Result<(), &'static str>
+    {
+        match self
+            .default_animations()
+            .iter()
+            .find(|animation| ciborium::ser::into_writer(animation, &mut writer).is_err())
         {
             Some(_) => Err("Error saving animations"),
             None => Ok(())
@@ -580,6 +595,52 @@ impl DrawingResources
     #[inline]
     pub fn texture_index(&self, name: &str) -> Option<usize> { self.textures.index(name) }
 
+    /// Returns the groups of non animated textures whose images have identical content, sorted
+    /// by name. The first texture of each group is the one the others can be replaced with.
+    #[inline]
+    #[must_use]
+    pub fn duplicate_textures(&self, images: &Assets<Image>) -> HvVec<HvVec<String>>
+    {
+        let mut keys = hv_hash_map![];
+        let mut groups: HvVec<(&[u8], HvVec<String>)> = hv_vec![];
+
+        for i in 0..self.textures.len()
+        {
+            let texture = &self.textures[i].texture;
+
+            if !matches!(texture.animation(), Animation::None)
+            {
+                continue;
+            }
+
+            let data = continue_if_none!(images.get(&texture.handle())).data.as_slice();
+            let key = (texture.size(), ContentHash::new(data).value);
+
+            match keys.get(&key)
+            {
+                Some(index) =>
+                {
+                    let (first, names) = &mut groups[*index];
+
+                    // Guard against hash collisions.
+                    if *first == data
+                    {
+                        names.push(texture.name().to_owned());
+                    }
+                },
+                None =>
+                {
+                    keys.asserted_insert((key, groups.len()));
+                    groups.push((data, hv_vec![texture.name().to_owned()]));
+                }
+            };
+        }
+
+        hv_vec![collect; groups
+            .into_iter()
+            .filter_map(|(_, names)| (names.len() > 1).then_some(names))]
+    }
+
     /// Returns a reference to the [`TextureMaterials`] of the texture named `name`.
     #[inline]
     pub(in crate::map::drawer) fn texture_materials(&self, name: &str) -> &TextureMaterials
@@ -627,12 +688,14 @@ impl DrawingResources
         IndexedMap::new(textures, |tex| tex.texture.name().to_owned())
     }
 
-    /// Reloads the textures.
+    /// Reloads the textures. If `changed` contains a value only the textures with the contained
+    /// names are replaced by the ones in `textures`, or removed if they are not there.
     #[inline]
     pub fn reload_textures(
         &mut self,
         materials: &mut Assets<ColorMaterial>,
-        textures: Vec<(Texture, egui::TextureId)>
+        textures: Vec<(Texture, egui::TextureId)>,
+        changed: Option<&HvHashSet<String>>
     )
     {
         let mut textures = Self::sort_textures(textures, materials);
@@ -640,6 +703,11 @@ impl DrawingResources
 
         for t in &self.animated_textures
         {
+            if changed.is_some_and(|changed| !changed.contains(t))
+            {
+                continue;
+            }
+
             let tex_materials = match textures.get_mut(t)
             {
                 Some(texture) => texture,
@@ -659,7 +727,21 @@ impl DrawingResources
             self.animated_textures.asserted_remove(&t);
         }
 
-        self.textures = textures;
+        let changed = match changed
+        {
+            Some(changed) => changed,
+            None =>
+            {
+                self.textures = textures;
+                return;
+            }
+        };
+
+        let mut vec = std::mem::take(&mut self.textures).into_values();
+        vec.retain_mut(|tex| !changed.iter().any(|name| name == tex.texture.name()));
+        vec.extend(textures.into_values());
+        vec.sort_by(|a, b| a.texture.name().cmp(b.texture.name()));
+        self.textures = IndexedMap::new(vec, |tex| tex.texture.name().to_owned());
     }
 
     //==============================================================
@@ -1317,6 +1399,31 @@ impl<'a> MeshGenerator<'a>
     #[inline]
     pub fn push_colors(&mut self, iter: impl IntoIterator<Item = VxColor>) { self.1.extend(iter); }
 
+    /// Colors the vertexes so that the texture is rendered with the brightness of `settings`, if
+    /// it is not the default one.
+    #[inline]
+    pub fn set_brightness<T: TextureInterface>(&mut self, settings: &T)
+    {
+        let brightness = settings.brightness();
+
+        if brightness.around_equal_narrow(&1f32)
+        {
+            return;
+        }
+
+        let len = self.0.len();
+        self.1.extend(std::iter::repeat([brightness, brightness, brightness, 1f32]).take(len));
+    }
+
+    /// Colors the vertexes so that the texture is rendered tinted with `tint`, if any.
+    #[inline]
+    pub fn set_tint(&mut self, tint: Option<[f32; 3]>)
+    {
+        let [r, g, b] = return_if_none!(tint);
+        let len = self.0.len();
+        self.1.extend(std::iter::repeat([r, g, b, 1f32]).take(len));
+    }
+
     /// Returns the UV of the sprite.
     #[allow(clippy::cast_precision_loss)]
     #[inline]
@@ -1512,28 +1619,7 @@ impl<'a> MeshGenerator<'a>
         parallax_enabled: bool
     )
     {
-        /// Returns the UV coordinates of a vertex.
-        #[inline]
-        #[must_use]
-        fn uv_coordinate<T: TextureInterface>(
-            vx: [f32; 2],
-            texture: &Texture,
-            settings: &T,
-            elapsed_time: f32,
-            parallax: Vec2
-        ) -> Uv
-        {
-            let size = texture.size().as_vec2();
-
-            [
-                (vx[0] + settings.offset_x() + settings.draw_scroll_x(elapsed_time) + parallax.x) /
-                    (size.x * settings.scale_x()),
-                (-vx[1] + settings.offset_y() + settings.draw_scroll_y(elapsed_time) + parallax.y) /
-                    (size.y * settings.scale_y())
-            ]
-        }
-
-        self.texture_uv(camera, settings, center, elapsed_time, parallax_enabled, uv_coordinate);
+        self.texture_uv(camera, settings, center, elapsed_time, parallax_enabled, trimmed_uv);
     }
 
     /// Sets the UV to the one of an animated texture.
@@ -1548,30 +1634,6 @@ impl<'a> MeshGenerator<'a>
         parallax_enabled: bool
     )
     {
-        /// Returns the UV coordinates of a vertex.
-        #[inline]
-        #[must_use]
-        fn uv_coordinate<T: TextureInterface>(
-            vx: [f32; 2],
-            texture: &Texture,
-            settings: &T,
-            elapsed_time: f32,
-            parallax: Vec2,
-            pivot: Uv
-        ) -> Uv
-        {
-            let size = texture.size().as_vec2();
-
-            [
-                (vx[0] + settings.offset_x() + settings.draw_scroll_x(elapsed_time) + parallax.x) /
-                    (size.x * settings.scale_x()) +
-                    pivot[0],
-                (-vx[1] + settings.offset_y() + settings.draw_scroll_y(elapsed_time) + parallax.y) /
-                    (size.y * settings.scale_y()) +
-                    pivot[1]
-            ]
-        }
-
         let pivot = animator.pivot();
 
         self.texture_uv(
@@ -1581,7 +1643,8 @@ impl<'a> MeshGenerator<'a>
             elapsed_time,
             parallax_enabled,
             |vx, texture, settings, elapsed_time, parallax| {
-                uv_coordinate(vx, texture, settings, elapsed_time, parallax, pivot)
+                let uv = trimmed_uv(vx, texture, settings, elapsed_time, parallax);
+                [uv[0] + pivot[0], uv[1] + pivot[1]]
             }
         );
     }
@@ -1742,3 +1805,40 @@ impl<'a> TextureMut<'a>
         .into()
     }
 }
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the UV coordinates of the vertex `vx` of a surface textured with `settings`.
+/// If `settings` refers to a trim sheet region of `texture` the coordinates are computed relative
+/// to the region and then mapped inside it. The region only repeats seamlessly along the axes it
+/// spans the whole texture on.
+#[inline]
+#[must_use]
+fn trimmed_uv<T: TextureInterface>(
+    vx: [f32; 2],
+    texture: &Texture,
+    settings: &T,
+    elapsed_time: f32,
+    parallax: Vec2
+) -> Uv
+{
+    let size = texture.size().as_vec2();
+    let trim = settings.trim().and_then(|name| texture.trim(name));
+    let region_size = trim.map_or(size, |trim| trim.size().as_vec2());
+
+    let uv = Vec2::new(
+        (vx[0] + settings.offset_x() + settings.draw_scroll_x(elapsed_time) + parallax.x) /
+            (region_size.x * settings.scale_x()),
+        (-vx[1] + settings.offset_y() + settings.draw_scroll_y(elapsed_time) + parallax.y) /
+            (region_size.y * settings.scale_y())
+    );
+
+    match trim
+    {
+        Some(trim) => ((trim.pos().as_vec2() + uv * region_size) / size).to_array(),
+        None => uv.to_array()
+    }
+}
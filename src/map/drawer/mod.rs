@@ -22,7 +22,11 @@ use self::{
     texture::{TextureInterface, TextureInterfaceExtra}
 };
 use super::{
-    editor::state::{clipboard::PropCameras, editor_state::ToolsSettings, grid::Grid},
+    editor::state::{
+        clipboard::PropCameras,
+        editor_state::ToolsSettings,
+        grid::{Grid, MeasurementGrid}
+    },
     thing::{catalog::ThingsCatalog, ThingInterface}
 };
 use crate::utils::{
@@ -99,6 +103,12 @@ pub(in crate::map) struct EditDrawer<'w, 's, 'a>
     elapsed_time:           f32,
     /// Whether the collision overlay of the brushes should be shown.
     show_collision_overlay: bool,
+    /// Whether the brushes should be drawn with their sides only.
+    wireframe:              bool,
+    /// Whether the [`Hull`]s of the things should be drawn on top of their sprites.
+    show_things_hulls:      bool,
+    /// Whether the sprites of the brushes should be drawn.
+    show_sprites:           bool,
     /// Whether parallax is enabled.
     parallax_enabled:       bool
 }
@@ -200,23 +210,32 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         let mesh = mesh.grid_mesh();
         self.push_grid_mesh(mesh);
 
-        // The x and y axis.
+        // The x and y axis, which are not axis aligned if the grid is rotated.
         let side = camera.scale() / 3f32;
+        let axis_vertexes = |(a, b): (Vec2, Vec2)| {
+            let normal = (b - a).perp().normalize() * side;
+            [b + normal, a + normal, a - normal, b - normal]
+        };
 
-        if let Some((a, b)) = axis.x
+        if let Some(x) = axis.x
         {
-            self.polygon(
-                Hull::new(a.y + side, a.y - side, a.x, b.x).vertexes(),
-                Color::OriginGridLines
-            );
+            self.polygon(axis_vertexes(x).into_iter(), Color::OriginGridLines);
         }
 
-        let (a, b) = return_if_none!(axis.y);
+        let y = return_if_none!(axis.y);
+        self.polygon(axis_vertexes(y).into_iter(), Color::OriginGridLines);
+    }
 
-        self.polygon(
-            Hull::new(a.y, b.y, a.x - side, a.x + side).vertexes(),
-            Color::OriginGridLines
-        );
+    /// Draws `measurement_grid`.
+    #[inline]
+    pub fn measurement_grid(
+        &mut self,
+        measurement_grid: MeasurementGrid,
+        window: &Window,
+        camera: &Transform
+    )
+    {
+        self.lines(measurement_grid.lines(window, camera));
     }
 
     /// Draws the lines returned by `lines`.
@@ -532,6 +551,7 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
             self.elapsed_time,
             self.parallax_enabled
         );
+        mesh_generator.set_brightness(settings);
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
 
         self.resources
@@ -550,6 +570,11 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         collision: bool
     )
     {
+        if self.wireframe
+        {
+            return;
+        }
+
         if let Some(texture) = texture
         {
             if !texture.sprite()
@@ -568,7 +593,8 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         self.push_mesh(mesh, self.color_resources.brush_material(color), color.height());
     }
 
-    /// Draws `settings` as a brush also drawing the sides.
+    /// Draws `settings` as a brush also drawing the sides. In wireframe mode the sides of the
+    /// selected brushes are drawn with their own color.
     #[inline]
     pub fn brush<T: TextureInterface>(
         &mut self,
@@ -580,6 +606,12 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         collision: bool
     )
     {
+        if self.wireframe && color == Color::SelectedEntity
+        {
+            self.sides(vertexes, Color::WireframeSelectedEntity);
+            return;
+        }
+
         self.sides(vertexes.clone(), color);
         self.sideless_brush(camera, vertexes, center, color, texture, collision);
     }
@@ -636,11 +668,17 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         color: Color
     )
     {
+        if !self.show_sprites
+        {
+            return;
+        }
+
         let mut mesh_generator = self.resources.mesh_generator();
         mesh_generator.set_indexes(4);
 
         mesh_generator.push_positions(settings.sprite_vertexes(brush_center));
         mesh_generator.set_sprite_uv(settings.name(), settings, self.elapsed_time);
+        mesh_generator.set_brightness(settings);
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
 
         self.resources
@@ -843,10 +881,16 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         mesh_generator.set_indexes(4);
         mesh_generator.push_positions(vxs.rectangle());
         mesh_generator.set_thing_uv(catalog, thing);
+        mesh_generator.set_tint(catalog.tint(thing.thing()));
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
 
         self.resources
             .push_thing(self.meshes.add(mesh).into(), catalog, thing, color);
+
+        if self.show_things_hulls
+        {
+            self.hull(&thing.hull(), Color::Hull);
+        }
     }
 
     //==============================================================
@@ -881,7 +925,10 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         mut elapsed_time: f32,
         camera_scale: f32,
         paint_tool_camera_scale: f32,
-        show_collision_overlay: bool
+        show_collision_overlay: bool,
+        wireframe: bool,
+        show_things_hulls: bool,
+        show_sprites: bool
     ) -> Self
     {
         resources.setup_frame(
@@ -906,7 +953,10 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
             camera_scale,
             elapsed_time,
             parallax_enabled: settings.parallax_enabled,
-            show_collision_overlay
+            show_collision_overlay,
+            wireframe,
+            show_things_hulls,
+            show_sprites
         }
     }
 
@@ -917,6 +967,11 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
     #[inline]
     pub const fn color_resources(&self) -> &ColorResources { self.color_resources }
 
+    /// Whether the sprites of the brushes should be drawn.
+    #[inline]
+    #[must_use]
+    pub const fn show_sprites(&self) -> bool { self.show_sprites }
+
     /// Returns the [`egui::Color32`] associated with [`Color`].
     #[inline]
     #[must_use]
@@ -1125,6 +1180,7 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
             }
         };
 
+        mesh_generator.set_brightness(settings);
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
         resources.push_map_preview_textured_mesh(self.meshes.add(mesh).into(), texture, settings);
     }
@@ -1155,6 +1211,7 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
             }
         }
 
+        mesh_generator.set_brightness(settings);
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
 
         self.resources
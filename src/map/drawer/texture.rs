@@ -3,9 +3,12 @@
 //
 //=======================================================================//
 
+use std::ops::RangeInclusive;
+
 use arrayvec::ArrayVec;
 use bevy::prelude::{Assets, Handle, Image, UVec2, Vec2};
 use hill_vacuum_shared::{
+    continue_if_none,
     draw_height_to_world,
     match_or_panic,
     return_if_no_match,
@@ -35,6 +38,14 @@ use crate::{
     }
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The range of the brightness multiplier applied to the textures in the editor.
+pub(in crate::map) const TEXTURE_BRIGHTNESS_RANGE: RangeInclusive<f32> = 0f32..=4f32;
+
 //=======================================================================//
 // MACROS
 //
@@ -45,7 +56,7 @@ macro_rules! xy {
     ($($xy:ident),+) => { paste::paste! { $(
         #[inline]
         #[must_use]
-        pub(in crate::map) fn [< check_offset_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2) -> bool
+        pub(in crate::map) fn [< check_offset_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2, bound: f32) -> bool
         {
             if !self.sprite.enabled() || value.around_equal_narrow(&self.[< offset_ $xy >])
             {
@@ -53,7 +64,7 @@ macro_rules! xy {
             }
 
             let prev = std::mem::replace(&mut self.[< offset_ $xy >], value);
-            let result = self.check_sprite_vxs(drawing_resources, center);
+            let result = self.check_sprite_vxs(drawing_resources, center, bound);
             self.[< offset_ $xy >] = prev;
 
             result.is_ok()
@@ -61,7 +72,7 @@ macro_rules! xy {
 
         #[inline]
         #[must_use]
-        pub(in crate::map) fn [< set_offset_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2) -> Option<f32>
+        pub(in crate::map) fn [< set_offset_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32) -> Option<f32>
         {
             if value.around_equal_narrow(&self.[< offset_ $xy >])
             {
@@ -69,12 +80,12 @@ macro_rules! xy {
             }
 
             let prev = std::mem::replace(&mut self.[< offset_ $xy >], value);
-            self.update_sprite_vxs(drawing_resources, center);
+            self.update_sprite_vxs(drawing_resources);
             prev.into()
         }
 
         #[inline]
-        pub(in crate::map) fn [< check_scale_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2) -> bool
+        pub(in crate::map) fn [< check_scale_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2, bound: f32) -> bool
         {
             assert!(value != 0f32, "Scale is 0.");
 
@@ -84,7 +95,7 @@ macro_rules! xy {
             }
 
             let prev = std::mem::replace(&mut self.[< scale_ $xy >], value);
-            let result = self.check_sprite_vxs(drawing_resources, center);
+            let result = self.check_sprite_vxs(drawing_resources, center, bound);
             self.[< scale_ $xy >] = prev;
 
             result.is_ok()
@@ -92,7 +103,7 @@ macro_rules! xy {
 
         #[inline]
         #[must_use]
-        pub(in crate::map) fn [< set_scale_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32, center: Vec2) -> Option<f32>
+        pub(in crate::map) fn [< set_scale_ $xy >](&mut self, drawing_resources: &DrawingResources, value: f32) -> Option<f32>
         {
             assert!(value != 0f32, "Scale is 0.");
 
@@ -102,12 +113,12 @@ macro_rules! xy {
             }
 
             let prev = std::mem::replace(&mut self.[< scale_ $xy >], value);
-            self.update_sprite_vxs(drawing_resources, center);
+            self.update_sprite_vxs(drawing_resources);
             prev.into()
         }
 
         #[inline]
-        pub(in crate::map) fn [< check_ $xy _flip >](&mut self, drawing_resources: &DrawingResources, mirror: f32, old_center: Vec2, new_center: Vec2) -> bool
+        pub(in crate::map) fn [< check_ $xy _flip >](&mut self, drawing_resources: &DrawingResources, mirror: f32, old_center: Vec2, new_center: Vec2, bound: f32) -> bool
         {
             if !self.sprite.enabled()
             {
@@ -117,7 +128,7 @@ macro_rules! xy {
             let sprite_center = self.sprite_hull(old_center).center();
             let [< new_offset_ $xy >] = mirror - sprite_center.$xy - new_center.$xy;
             let [< prev_offset_ $xy >] = std::mem::replace(&mut self.[< offset_ $xy >], [< new_offset_ $xy >]);
-            let result = self.check_sprite_vxs(drawing_resources, new_center);
+            let result = self.check_sprite_vxs(drawing_resources, new_center, bound);
 
             self.[< offset_ $xy >] = [< prev_offset_ $xy >];
 
@@ -136,7 +147,7 @@ macro_rules! xy {
 
             let sprite_center = self.sprite_hull(old_center).center();
             self.[< offset_ $xy >] = mirror - sprite_center.$xy - new_center.$xy;
-            self.update_sprite_vxs(drawing_resources, new_center);
+            self.update_sprite_vxs(drawing_resources);
         }
 
         #[inline]
@@ -160,7 +171,8 @@ macro_rules! xy {
             &mut self,
             drawing_resources: &DrawingResources,
             value: u32,
-            center: Vec2
+            center: Vec2,
+            bound: f32
         ) -> bool
         {
             let prev = {
@@ -176,7 +188,7 @@ macro_rules! xy {
                 prev
             };
 
-            let result = self.check_sprite_vxs(drawing_resources, center).is_ok();
+            let result = self.check_sprite_vxs(drawing_resources, center, bound).is_ok();
             _ = self.animation
                 .get_atlas_animation_mut()
                 .[< set _$xy _partition >](prev);
@@ -188,14 +200,13 @@ macro_rules! xy {
         pub(in crate::map) fn [< set_atlas_animation_ $xy _partition >](
             &mut self,
             drawing_resources: &DrawingResources,
-            value: u32,
-            center: Vec2
+            value: u32
         ) -> Option<u32>
         {
             let atlas = self.animation.get_atlas_animation_mut();
             let prev = atlas.[< set _$xy _partition >](value);
             prev?;
-            self.update_sprite_vxs(drawing_resources, center);
+            self.update_sprite_vxs(drawing_resources);
             prev
         }
     )+}};
@@ -278,6 +289,16 @@ pub trait TextureInterface
 
     /// Returns a reference to the [`Animation`].
     fn animation(&self) -> &Animation;
+
+    /// Returns the name of the trim sheet region the texture is constrained to, if any. Defaults
+    /// to no region.
+    #[inline]
+    fn trim(&self) -> Option<&str> { None }
+
+    /// Returns the multiplier of the color of the texture, used to preview it brighter or darker.
+    /// Defaults to no adjustment.
+    #[inline]
+    fn brightness(&self) -> f32 { 1f32 }
 }
 
 //=======================================================================//
@@ -546,22 +567,109 @@ pub(in crate::map) struct TextureScale
 
 //=======================================================================//
 
+/// A named sub-rectangle of a trim sheet texture.
+#[must_use]
+#[derive(Clone, Debug)]
+pub(in crate::map) struct TrimRegion
+{
+    /// The name.
+    name: String,
+    /// The top left corner, in pixels.
+    pos:  UVec2,
+    /// The size, in pixels.
+    size: UVec2
+}
+
+impl TrimRegion
+{
+    /// Returns the [`TrimRegion`]s described by the content of a trims sidecar file, discarding
+    /// the ones that are malformed or that do not fit inside a texture of size `texture_size`.
+    /// Each line must be in the format `name x y width height`. Lines starting with `#` are
+    /// ignored.
+    #[inline]
+    pub fn parse_sidecar(text: &str, texture_size: UVec2) -> Vec<Self>
+    {
+        /// Returns the [`TrimRegion`] described by `line`, if valid.
+        #[inline]
+        fn parse_line(line: &str, texture_size: UVec2) -> Option<TrimRegion>
+        {
+            let mut values = line.split_whitespace();
+            let name = values.next()?.to_owned();
+            let mut coordinates = [0u32; 4];
+
+            for c in &mut coordinates
+            {
+                *c = values.next()?.parse().ok()?;
+            }
+
+            if values.next().is_some()
+            {
+                return None;
+            }
+
+            let [x, y, width, height] = coordinates;
+            let pos = UVec2::new(x, y);
+            let size = UVec2::new(width, height);
+
+            (width != 0 &&
+                height != 0 &&
+                (pos + size).cmple(texture_size).all())
+            .then_some(TrimRegion { name, pos, size })
+        }
+
+        let mut regions = Vec::<Self>::new();
+
+        for line in text.lines().map(str::trim)
+        {
+            if line.is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+
+            let region = continue_if_none!(parse_line(line, texture_size));
+
+            if regions.iter().all(|r| r.name != region.name)
+            {
+                regions.push(region);
+            }
+        }
+
+        regions
+    }
+
+    /// The name.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str { &self.name }
+
+    /// The top left corner, in pixels.
+    #[inline]
+    pub const fn pos(&self) -> UVec2 { self.pos }
+
+    /// The size, in pixels.
+    #[inline]
+    pub const fn size(&self) -> UVec2 { self.size }
+}
+
+//=======================================================================//
+
 /// The overall settings of the textures of the selected brushes.
 #[allow(clippy::missing_docs_in_private_items)]
 #[derive(Debug, Default)]
 pub(in crate::map) struct OverallTextureSettings
 {
-    name:      OverallValue<String>,
-    scale_x:   OverallValue<f32>,
-    scale_y:   OverallValue<f32>,
-    offset_x:  OverallValue<f32>,
-    offset_y:  OverallValue<f32>,
-    scroll_x:  OverallValue<f32>,
-    scroll_y:  OverallValue<f32>,
-    angle:     OverallValue<f32>,
-    height:    OverallValue<i8>,
-    sprite:    OverallSprite,
-    animation: OverallAnimation
+    name:       OverallValue<String>,
+    scale_x:    OverallValue<f32>,
+    scale_y:    OverallValue<f32>,
+    offset_x:   OverallValue<f32>,
+    offset_y:   OverallValue<f32>,
+    scroll_x:   OverallValue<f32>,
+    scroll_y:   OverallValue<f32>,
+    angle:      OverallValue<f32>,
+    height:     OverallValue<i8>,
+    brightness: OverallValue<f32>,
+    sprite:     OverallSprite,
+    animation:  OverallAnimation
 }
 
 impl From<Option<&TextureSettings>> for OverallTextureSettings
@@ -575,33 +683,35 @@ impl From<Option<&TextureSettings>> for OverallTextureSettings
             Some(value) =>
             {
                 Self {
-                    name:      value.texture.clone().into(),
-                    scale_x:   value.scale_x.into(),
-                    scale_y:   value.scale_y.into(),
-                    offset_x:  value.offset_x.into(),
-                    offset_y:  value.offset_y.into(),
-                    scroll_x:  value.scroll_x.into(),
-                    scroll_y:  value.scroll_y.into(),
-                    height:    value.height().into(),
-                    angle:     value.angle.into(),
-                    sprite:    (&value.sprite).into(),
-                    animation: (&value.animation).into()
+                    name:       value.texture.clone().into(),
+                    scale_x:    value.scale_x.into(),
+                    scale_y:    value.scale_y.into(),
+                    offset_x:   value.offset_x.into(),
+                    offset_y:   value.offset_y.into(),
+                    scroll_x:   value.scroll_x.into(),
+                    scroll_y:   value.scroll_y.into(),
+                    height:     value.height().into(),
+                    angle:      value.angle.into(),
+                    brightness: value.brightness.into(),
+                    sprite:     (&value.sprite).into(),
+                    animation:  (&value.animation).into()
                 }
             },
             None =>
             {
                 Self {
-                    name:      OverallValue::None,
-                    scale_x:   OverallValue::None,
-                    scale_y:   OverallValue::None,
-                    offset_x:  OverallValue::None,
-                    offset_y:  OverallValue::None,
-                    scroll_x:  OverallValue::None,
-                    scroll_y:  OverallValue::None,
-                    height:    OverallValue::None,
-                    angle:     OverallValue::None,
-                    sprite:    OverallSprite::None,
-                    animation: OverallAnimation::NoSelection
+                    name:       OverallValue::None,
+                    scale_x:    OverallValue::None,
+                    scale_y:    OverallValue::None,
+                    offset_x:   OverallValue::None,
+                    offset_y:   OverallValue::None,
+                    scroll_x:   OverallValue::None,
+                    scroll_y:   OverallValue::None,
+                    height:     OverallValue::None,
+                    angle:      OverallValue::None,
+                    brightness: OverallValue::None,
+                    sprite:     OverallSprite::None,
+                    animation:  OverallAnimation::NoSelection
                 }
             },
         }
@@ -636,7 +746,8 @@ impl OverallValueInterface<Option<&TextureSettings>> for OverallTextureSettings
             (&mut self.offset_y, &other.offset_y),
             (&mut self.scroll_x, &other.scroll_x),
             (&mut self.scroll_y, &other.scroll_y),
-            (&mut self.angle, &other.angle)
+            (&mut self.angle, &other.angle),
+            (&mut self.brightness, &other.brightness)
         ]
         {
             uniform |= !v_0.merge(*v_1);
@@ -661,6 +772,7 @@ impl OverallValueInterface<Option<&TextureSettings>> for OverallTextureSettings
             self.scroll_y.is_not_uniform() &&
             self.height.is_not_uniform() &&
             self.angle.is_not_uniform() &&
+            self.brightness.is_not_uniform() &&
             self.sprite.is_not_uniform() &&
             self.animation.is_not_uniform()
     }
@@ -690,6 +802,7 @@ pub(in crate::map) struct UiOverallTextureSettings
     pub scroll_y:   UiOverallValue<f32>,
     pub height:     UiOverallValue<i8>,
     pub angle:      UiOverallValue<f32>,
+    pub brightness: UiOverallValue<f32>,
     pub sprite:     OverallValue<bool>,
     pub parallax_x: Option<UiOverallValue<f32>>,
     pub parallax_y: Option<UiOverallValue<f32>>,
@@ -723,6 +836,7 @@ impl From<OverallTextureSettings> for UiOverallTextureSettings
             scroll_y: value.scroll_y.ui(),
             height: value.height.ui(),
             angle: value.angle.ui(),
+            brightness: value.brightness.ui(),
             sprite,
             parallax_x,
             parallax_y,
@@ -748,6 +862,7 @@ pub(in crate::map) struct Texture
     size_str:  String,
     handle:    Handle<Image>,
     animation: Animation,
+    trims:     Vec<TrimRegion>,
     hull:      Hull,
     dirty:     bool
 }
@@ -764,6 +879,7 @@ impl Clone for Texture
             size_str:  self.size_str.clone(),
             handle:    self.handle.clone_weak(),
             animation: self.animation.clone(),
+            trims:     self.trims.clone(),
             dirty:     false,
             hull:      self.hull
         }
@@ -789,6 +905,7 @@ impl Placeholder for Texture
             hull:      Hull::new(1f32, 0f32, 0f32, 1f32),
             handle:    Handle::default(),
             animation: Animation::default(),
+            trims:     Vec::new(),
             dirty:     false
         }
     }
@@ -820,9 +937,14 @@ impl Texture
         Hull::new(half_height, -half_height, -half_width, half_width)
     }
 
-    /// Returns a new [`Texture`].
+    /// Returns a new [`Texture`] with the trim sheet regions `trims`.
     #[inline]
-    pub fn new(name: impl Into<String>, image: Image, images: &mut Assets<Image>) -> Self
+    pub fn new(
+        name: impl Into<String>,
+        image: Image,
+        trims: Vec<TrimRegion>,
+        images: &mut Assets<Image>
+    ) -> Self
     {
         let name = Into::<String>::into(name);
         let size = image.size();
@@ -836,6 +958,7 @@ impl Texture
             size_str,
             handle: images.add(image),
             animation: Animation::None,
+            trims,
             hull: Self::create_hull(size),
             dirty: false
         }
@@ -856,6 +979,7 @@ impl Texture
             size_str,
             handle,
             animation: Animation::None,
+            trims: Vec::new(),
             hull: Self::create_hull(size),
             dirty: false
         }
@@ -894,6 +1018,19 @@ impl Texture
     #[inline(always)]
     pub fn animation_mut(&mut self) -> &mut Animation { &mut self.animation }
 
+    /// Returns the trim sheet regions of the texture.
+    #[inline(always)]
+    #[must_use]
+    pub fn trims(&self) -> &[TrimRegion] { &self.trims }
+
+    /// Returns the trim sheet region named `name`, if any.
+    #[inline]
+    #[must_use]
+    pub fn trim(&self, name: &str) -> Option<&TrimRegion>
+    {
+        self.trims.iter().find(|trim| trim.name == name)
+    }
+
     /// Returns a mutable reference to the texture [`Animation`] and marks it as changed.
     #[inline(always)]
     pub fn animation_mut_set_dirty(&mut self) -> &mut Animation
@@ -931,7 +1068,11 @@ pub struct TextureSettings
     angle: f32,
     height: i8,
     sprite: Sprite,
-    animation: Animation
+    animation: Animation,
+    #[serde(default)]
+    trim: Option<String>,
+    #[serde(default = "default_brightness")]
+    brightness: f32
 }
 
 impl From<&Texture> for TextureSettings
@@ -940,20 +1081,22 @@ impl From<&Texture> for TextureSettings
     fn from(value: &Texture) -> Self
     {
         Self {
-            texture:   value.name.clone(),
-            scale_x:   1f32,
-            scale_y:   1f32,
-            offset_x:  0f32,
-            offset_y:  0f32,
-            scroll_x:  0f32,
-            scroll_y:  0f32,
-            angle:     0f32,
-            height:    0,
-            sprite:    Sprite::False {
+            texture:    value.name.clone(),
+            scale_x:    1f32,
+            scale_y:    1f32,
+            offset_x:   0f32,
+            offset_y:   0f32,
+            scroll_x:   0f32,
+            scroll_y:   0f32,
+            angle:      0f32,
+            height:     0,
+            sprite:     Sprite::False {
                 parallax_x: 0f32,
                 parallax_y: 0f32
             },
-            animation: Animation::None
+            animation:  Animation::None,
+            trim:       None,
+            brightness: 1f32
         }
     }
 }
@@ -1024,6 +1167,12 @@ impl TextureInterface for TextureSettings
 
     #[inline]
     fn animation(&self) -> &Animation { &self.animation }
+
+    #[inline]
+    fn trim(&self) -> Option<&str> { self.trim.as_deref() }
+
+    #[inline]
+    fn brightness(&self) -> f32 { self.brightness }
 }
 
 impl TextureInterfaceExtra for TextureSettings
@@ -1081,9 +1230,9 @@ impl TextureSettings
 
     /// Checks whether the move is valid.
     #[inline]
-    pub(in crate::map) fn check_move(&self, delta: Vec2, center: Vec2) -> bool
+    pub(in crate::map) fn check_move(&self, delta: Vec2, center: Vec2, bound: f32) -> bool
     {
-        !self.sprite.enabled() || !(self.sprite_hull(center) + delta).out_of_bounds()
+        !self.sprite.enabled() || !(self.sprite_hull(center) + delta).out_of_bounds(bound)
     }
 
     /// Checks whether the scale is valid. Returns a [`TextureScale`] describing the outcome if it
@@ -1094,7 +1243,8 @@ impl TextureSettings
         drawing_resources: &DrawingResources,
         info: &ScaleInfo,
         old_center: Vec2,
-        new_center: Vec2
+        new_center: Vec2,
+        bound: f32
     ) -> Option<TextureScale>
     {
         let scale_x = self.scale_x * info.width_multi();
@@ -1116,7 +1266,7 @@ impl TextureSettings
         let prev_scale_x = std::mem::replace(&mut self.scale_x, scale_x);
         let prev_scale_y = std::mem::replace(&mut self.scale_y, scale_y);
 
-        let result = self.check_sprite_vxs(drawing_resources, new_center);
+        let result = self.check_sprite_vxs(drawing_resources, new_center, bound);
 
         self.offset_x = prev_offset_x;
         self.offset_y = prev_offset_y;
@@ -1147,7 +1297,8 @@ impl TextureSettings
         info: &ScaleInfo,
         flip_queue: &ArrayVec<Flip, 2>,
         old_center: Vec2,
-        new_center: Vec2
+        new_center: Vec2,
+        bound: f32
     ) -> Option<TextureScale>
     {
         let mut scale_x = self.scale_x * info.width_multi();
@@ -1195,7 +1346,7 @@ impl TextureSettings
         let prev_scale_x = std::mem::replace(&mut self.scale_x, scale_x);
         let prev_scale_y = std::mem::replace(&mut self.scale_y, scale_y);
 
-        let result = self.check_sprite_vxs(drawing_resources, new_center);
+        let result = self.check_sprite_vxs(drawing_resources, new_center, bound);
 
         self.offset_x = prev_offset_x;
         self.offset_y = prev_offset_y;
@@ -1223,7 +1374,8 @@ impl TextureSettings
         &mut self,
         drawing_resources: &DrawingResources,
         texture: &str,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> bool
     {
         if !self.sprite.enabled()
@@ -1232,7 +1384,32 @@ impl TextureSettings
         }
 
         let prev = std::mem::replace(&mut self.texture, texture.to_owned());
-        let result = self.check_sprite_vxs(drawing_resources, center).is_ok();
+        let result = self.check_sprite_vxs(drawing_resources, center, bound).is_ok();
+        self.texture = prev;
+        result
+    }
+
+    /// Whether changing the texture to `texture` and rendering it as a sprite would generate a
+    /// sprite within the map bounds.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn check_texture_sprite_change(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        texture: &str,
+        center: Vec2,
+        bound: f32
+    ) -> bool
+    {
+        let prev = std::mem::replace(&mut self.texture, texture.to_owned());
+        let result = if self.sprite.enabled()
+        {
+            self.check_sprite_vxs(drawing_resources, center, bound).is_ok()
+        }
+        else
+        {
+            self.check_sprite(drawing_resources, true, center, bound)
+        };
         self.texture = prev;
         result
     }
@@ -1242,13 +1419,12 @@ impl TextureSettings
     pub(in crate::map) fn set_texture(
         &mut self,
         drawing_resources: &DrawingResources,
-        texture: &str,
-        center: Vec2
+        texture: &str
     ) -> Option<String>
     {
         if self.texture == texture
         {
-            self.update_sprite_vxs(drawing_resources, center);
+            self.update_sprite_vxs(drawing_resources);
             return None;
         }
 
@@ -1256,7 +1432,7 @@ impl TextureSettings
             &mut self.texture,
             drawing_resources.texture_or_error(texture).name.clone()
         );
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
         prev.into()
     }
 
@@ -1265,13 +1441,40 @@ impl TextureSettings
     pub(in crate::map) fn move_offset(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: Vec2,
-        center: Vec2
+        value: Vec2
     )
     {
         self.offset_x += value.x;
         self.offset_y += value.y;
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
+    }
+
+    /// Sets the trim sheet region, returns the previous value if different.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn set_trim(&mut self, value: Option<String>) -> Option<Option<String>>
+    {
+        if value == self.trim
+        {
+            return None;
+        }
+
+        std::mem::replace(&mut self.trim, value).into()
+    }
+
+    /// Sets the brightness multiplier, returns the previous value if different.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn set_brightness(&mut self, value: f32) -> Option<f32>
+    {
+        let value = value.clamp(*TEXTURE_BRIGHTNESS_RANGE.start(), *TEXTURE_BRIGHTNESS_RANGE.end());
+
+        if value.around_equal_narrow(&self.brightness)
+        {
+            return None;
+        }
+
+        std::mem::replace(&mut self.brightness, value).into()
     }
 
     /// Sets the draw height, returns the previous value if different.
@@ -1295,7 +1498,8 @@ impl TextureSettings
         &mut self,
         drawing_resources: &DrawingResources,
         value: f32,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> bool
     {
         let angle = value.floor().rem_euclid(360f32);
@@ -1306,7 +1510,7 @@ impl TextureSettings
         }
 
         let prev = std::mem::replace(&mut self.angle, angle);
-        let result = self.check_sprite_vxs(drawing_resources, center);
+        let result = self.check_sprite_vxs(drawing_resources, center, bound);
         self.angle = prev;
 
         result.is_ok()
@@ -1321,7 +1525,8 @@ impl TextureSettings
         pivot: Vec2,
         angle: f32,
         old_center: Vec2,
-        new_center: Vec2
+        new_center: Vec2,
+        bound: f32
     ) -> Option<TextureRotation>
     {
         let end_angle = (self.angle() - angle.to_degrees().floor()).rem_euclid(360f32);
@@ -1341,7 +1546,7 @@ impl TextureSettings
         let prev_offset_y = std::mem::replace(&mut self.offset_y, new_offset.y);
         let prev_angle = std::mem::replace(&mut self.angle, end_angle);
 
-        let result = self.check_sprite_vxs(drawing_resources, new_center);
+        let result = self.check_sprite_vxs(drawing_resources, new_center, bound);
 
         self.offset_x = prev_offset_x;
         self.offset_y = prev_offset_y;
@@ -1367,8 +1572,7 @@ impl TextureSettings
     pub(in crate::map) fn set_angle(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: f32,
-        center: Vec2
+        value: f32
     ) -> Option<f32>
     {
         let angle = value.floor().rem_euclid(360f32);
@@ -1379,7 +1583,7 @@ impl TextureSettings
         }
 
         let prev = std::mem::replace(&mut self.angle, angle);
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
         prev.into()
     }
 
@@ -1390,7 +1594,8 @@ impl TextureSettings
         &mut self,
         drawing_resources: &DrawingResources,
         value: bool,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> bool
     {
         if !value || value == self.sprite.enabled()
@@ -1416,7 +1621,7 @@ impl TextureSettings
         };
 
         let prev_sprite = std::mem::replace(&mut self.sprite, new);
-        let result = self.check_sprite_vxs(drawing_resources, center).is_ok();
+        let result = self.check_sprite_vxs(drawing_resources, center, bound).is_ok();
 
         self.offset_x = prev_offset_x;
         self.offset_y = prev_offset_y;
@@ -1432,8 +1637,7 @@ impl TextureSettings
     pub(in crate::map) fn set_sprite(
         &mut self,
         drawing_resources: &DrawingResources,
-        value: impl Into<Sprite>,
-        center: Vec2
+        value: impl Into<Sprite>
     ) -> Option<(Sprite, f32, f32)>
     {
         let value = Into::<Sprite>::into(value);
@@ -1451,7 +1655,7 @@ impl TextureSettings
         {
             self.offset_x = 0f32;
             self.offset_y = 0f32;
-            self.update_sprite_vxs(drawing_resources, center);
+            self.update_sprite_vxs(drawing_resources);
         }
 
         (prev, offset_x, offset_y).into()
@@ -1463,10 +1667,11 @@ impl TextureSettings
     pub(in crate::map) fn check_within_bounds(
         &self,
         drawing_resources: &DrawingResources,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> bool
     {
-        self.check_sprite_vxs(drawing_resources, center).is_ok()
+        self.check_sprite_vxs(drawing_resources, center, bound).is_ok()
     }
 
     /// Checks whether changing animation makes the sprite, if any, go out of bounds.
@@ -1476,7 +1681,8 @@ impl TextureSettings
         &mut self,
         drawing_resources: &DrawingResources,
         animation: &Animation,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> bool
     {
         if !self.sprite.enabled()
@@ -1485,7 +1691,7 @@ impl TextureSettings
         }
 
         let prev = std::mem::replace(&mut self.animation, animation.clone());
-        let result = self.check_sprite_vxs(drawing_resources, center).is_ok();
+        let result = self.check_sprite_vxs(drawing_resources, center, bound).is_ok();
         self.animation = prev;
         result
     }
@@ -1497,17 +1703,44 @@ impl TextureSettings
         self.animation = animation;
     }
 
+    /// Multiplies the scale, offset, scroll, and sprite size by `factor`, so that the texture
+    /// keeps covering the same area of a brush scaled by `factor` with respect to the origin.
+    #[inline]
+    pub(in crate::map) fn export_scale(&mut self, factor: f32)
+    {
+        for value in [
+            &mut self.scale_x,
+            &mut self.scale_y,
+            &mut self.offset_x,
+            &mut self.offset_y,
+            &mut self.scroll_x,
+            &mut self.scroll_y
+        ]
+        {
+            *value *= factor;
+        }
+
+        if let Sprite::True { vxs, hull } = &mut self.sprite
+        {
+            for vx in vxs
+            {
+                *vx *= factor;
+            }
+
+            *hull *= factor;
+        }
+    }
+
     /// Sets the [`Animation`].
     #[inline]
     pub(in crate::map) fn set_animation(
         &mut self,
         drawing_resources: &DrawingResources,
-        animation: Animation,
-        center: Vec2
+        animation: Animation
     ) -> Animation
     {
         let prev = std::mem::replace(&mut self.animation, animation);
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
         prev
     }
 
@@ -1517,12 +1750,11 @@ impl TextureSettings
     pub(in crate::map) fn set_list_animation(
         &mut self,
         drawing_resources: &DrawingResources,
-        texture: &str,
-        center: Vec2
+        texture: &str
     ) -> Animation
     {
         let prev = std::mem::replace(&mut self.animation, Animation::list_animation(texture));
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
         prev
     }
 
@@ -1530,12 +1762,11 @@ impl TextureSettings
     #[inline]
     pub(in crate::map) fn generate_list_animation(
         &mut self,
-        drawing_resources: &DrawingResources,
-        center: Vec2
+        drawing_resources: &DrawingResources
     ) -> Animation
     {
         let prev = std::mem::replace(&mut self.animation, Animation::list_animation(&self.texture));
-        self.update_sprite_vxs(drawing_resources, center);
+        self.update_sprite_vxs(drawing_resources);
         prev
     }
 
@@ -1714,14 +1945,15 @@ impl TextureSettings
     pub(in crate::map) fn check_sprite_vxs(
         &self,
         drawing_resources: &DrawingResources,
-        center: Vec2
+        center: Vec2,
+        bound: f32
     ) -> Result<Option<[Vec2; 4]>, ()>
     {
         match self.sprite_vxs(drawing_resources)
         {
             Some(rect) =>
             {
-                if rect.iter().any(|vx| (*vx + center).out_of_bounds())
+                if rect.iter().any(|vx| (*vx + center).out_of_bounds(bound))
                 {
                     Result::Err(())
                 }
@@ -1736,11 +1968,9 @@ impl TextureSettings
 
     /// Updates the bounds of the sprite.
     #[inline]
-    fn update_sprite_vxs(&mut self, drawing_resources: &DrawingResources, center: Vec2)
+    fn update_sprite_vxs(&mut self, drawing_resources: &DrawingResources)
     {
-        self.sprite.update_bounds(&return_if_none!(self
-            .check_sprite_vxs(drawing_resources, center)
-            .unwrap()));
+        self.sprite.update_bounds(&return_if_none!(self.sprite_vxs(drawing_resources)));
     }
 }
 
@@ -1756,3 +1986,13 @@ pub(in crate::map) struct DefaultAnimation
     /// The animation.
     pub animation: Animation
 }
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// The default brightness of the textures, for the maps saved before it was introduced.
+#[inline]
+#[must_use]
+const fn default_brightness() -> f32 { 1f32 }
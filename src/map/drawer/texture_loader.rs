@@ -4,8 +4,15 @@
 //=======================================================================//
 
 use std::{
+    collections::HashMap,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex}
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{channel, Receiver},
+        Arc,
+        Mutex
+    },
+    time::{Duration, SystemTime}
 };
 
 use arrayvec::ArrayVec;
@@ -17,10 +24,14 @@ use bevy::{
     }
 };
 use bevy_egui::{egui, EguiUserTextures};
+use hill_vacuum_shared::continue_if_none;
 use threadpool::ThreadPool;
 
-use super::texture::Texture;
-use crate::map::editor::state::ui::centered_window;
+use super::texture::{Texture, TrimRegion};
+use crate::map::{
+    containers::{hv_hash_set, HvHashSet},
+    editor::state::ui::centered_window
+};
 
 //=======================================================================//
 // CONSTANTS
@@ -29,6 +40,8 @@ use crate::map::editor::state::ui::centered_window;
 
 /// The path of the folder containing the textures.
 const TEXTURES_PATH: &str = "assets/textures/";
+/// The extension of the sidecar files describing the trim sheet regions of the textures.
+const TRIMS_EXTENSION: &str = "trims";
 
 //=======================================================================//
 // ENUMS
@@ -62,7 +75,7 @@ enum LoadedImages
     /// In progress.
     Loading(PartialImages),
     /// Completed.
-    Loaded(Vec<(String, Image)>)
+    Loaded(Vec<LoadedImage>)
 }
 
 //=======================================================================//
@@ -70,13 +83,18 @@ enum LoadedImages
 //
 //=======================================================================//
 
+/// The name, [`Image`], and trim sheet regions of a loaded texture.
+type LoadedImage = (String, Image, Vec<TrimRegion>);
+
+//=======================================================================//
+
 /// The [`PathBuf`]s of the textures to load, distributed to the threads.
 type Paths = [Vec<PathBuf>; TextureLoader::THREADS_AMOUNT];
 
 //=======================================================================//
 
 /// The collection of the load-in-progress [`Images`].
-type PartialImages = Arc<Mutex<Vec<(String, Image)>>>;
+type PartialImages = Arc<Mutex<Vec<LoadedImage>>>;
 
 //=======================================================================//
 
@@ -100,7 +118,9 @@ pub(in crate::map) struct TextureLoader
     /// The frames required to read the files.
     file_reading_cycles: usize,
     /// The frames required to load the textures.
-    total_cycles:        f32
+    total_cycles:        f32,
+    /// The paths of the changed texture files, if only those should be reloaded.
+    changed:             Option<Vec<PathBuf>>
 }
 
 impl Default for TextureLoader
@@ -118,7 +138,8 @@ impl Default for TextureLoader
             active_workers:      0,
             cycles:              0,
             file_reading_cycles: 0,
-            total_cycles:        0f32
+            total_cycles:        0f32,
+            changed:             None
         }
     }
 }
@@ -145,9 +166,29 @@ impl TextureLoader
         std::mem::take(&mut self.textures)
     }
 
+    /// Sets the texture reload to only involve the texture files at `files`.
+    #[inline]
+    pub fn set_changed_files(&mut self, files: HvHashSet<PathBuf>)
+    {
+        self.changed = Vec::from_iter(files).into();
+    }
+
+    /// Returns the names of the textures that were reloaded because their files changed, if the
+    /// reload did not involve all of them.
+    #[inline]
+    #[must_use]
+    pub fn take_changed_textures(&mut self) -> Option<HvHashSet<String>>
+    {
+        self.changed.take().map(|paths| {
+            hv_hash_set![collect; paths
+                .iter()
+                .filter_map(|path| path.file_stem()?.to_str().map(str::to_owned))]
+        })
+    }
+
     /// Extracts the vector inside `images`.
     #[inline]
-    fn extract_images(mut images: PartialImages) -> Vec<(String, Image)>
+    fn extract_images(mut images: PartialImages) -> Vec<LoadedImage>
     {
         Arc::try_unwrap(std::mem::replace(&mut images, Arc::new(Mutex::new(vec![]))))
             .unwrap()
@@ -176,6 +217,11 @@ impl TextureLoader
                     continue;
                 }
 
+                if child_path.extension().is_some_and(|ext| ext == TRIMS_EXTENSION)
+                {
+                    continue;
+                }
+
                 paths[*len % TextureLoader::THREADS_AMOUNT].push(child_path);
                 *len += 1;
             }
@@ -183,7 +229,20 @@ impl TextureLoader
 
         let mut paths = Self::DEFAULT_PATHS;
         let mut textures_len = 0;
-        collect_paths_recursive(TEXTURES_PATH, &mut paths, &mut textures_len);
+
+        match &self.changed
+        {
+            Some(changed) =>
+            {
+                // Removed files only need to be dropped from the loaded textures.
+                for path in changed.iter().filter(|path| path.is_file())
+                {
+                    paths[textures_len % Self::THREADS_AMOUNT].push(path.clone());
+                    textures_len += 1;
+                }
+            },
+            None => collect_paths_recursive(TEXTURES_PATH, &mut paths, &mut textures_len)
+        };
         self.active_workers = 0;
 
         for vec in &paths
@@ -259,9 +318,17 @@ impl TextureLoader
                             )
                             .unwrap();
 
+                            let trims =
+                                std::fs::read_to_string(path.with_extension(TRIMS_EXTENSION))
+                                    .map_or_else(
+                                        |_| Vec::new(),
+                                        |text| TrimRegion::parse_sidecar(&text, image.size())
+                                    );
+
                             textures.push((
                                 path.file_stem().unwrap().to_str().unwrap().to_owned(),
-                                image
+                                image,
+                                trims
                             ));
                         }
 
@@ -282,8 +349,8 @@ impl TextureLoader
             {
                 for _ in 0..Self::PER_FRAME_TEXTURE_GENERATION.min(vec.len())
                 {
-                    let (name, image) = vec.pop().unwrap();
-                    let texture = Texture::new(name, image, images);
+                    let (name, image, trims) = vec.pop().unwrap();
+                    let texture = Texture::new(name, image, trims, images);
                     let tex_id = user_textures.add_image(texture.handle());
                     self.textures.push((texture, tex_id));
                 }
@@ -325,3 +392,176 @@ impl TextureLoader
         egui_context.move_to_top(id);
     }
 }
+
+//=======================================================================//
+
+/// A watcher of the texture files that detects, on a background thread, whether any of them was
+/// added, removed, or modified.
+#[must_use]
+pub(in crate::map) struct TexturesWatcher
+{
+    /// The receiver of the paths of the texture files that changed.
+    receiver: Receiver<Vec<PathBuf>>,
+    /// Whether the background thread should stop.
+    stop:     Arc<AtomicBool>
+}
+
+impl Default for TexturesWatcher
+{
+    #[inline]
+    fn default() -> Self
+    {
+        let (sender, receiver) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        std::thread::spawn(move || {
+            let mut snapshot = Self::snapshot();
+
+            while !thread_stop.load(Ordering::Relaxed)
+            {
+                std::thread::sleep(Self::POLL_INTERVAL);
+
+                let new_snapshot = Self::snapshot();
+                let mut changed = new_snapshot
+                    .iter()
+                    .filter_map(|(path, time)| {
+                        (snapshot.get(path) != Some(time)).then(|| path.clone())
+                    })
+                    .collect::<Vec<_>>();
+                changed.extend(
+                    snapshot.keys().filter(|path| !new_snapshot.contains_key(*path)).cloned()
+                );
+                snapshot = new_snapshot;
+
+                if !changed.is_empty() && sender.send(changed).is_err()
+                {
+                    return;
+                }
+            }
+        });
+
+        Self { receiver, stop }
+    }
+}
+
+impl Drop for TexturesWatcher
+{
+    #[inline]
+    fn drop(&mut self) { self.stop.store(true, Ordering::Relaxed); }
+}
+
+impl TexturesWatcher
+{
+    /// The time interval between two polls.
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Returns the last modification time of the texture files, which also accounts for the
+    /// modification time of their trims sidecars.
+    #[inline]
+    fn snapshot() -> HashMap<PathBuf, SystemTime>
+    {
+        /// Iterates through the subfolders collecting the modification times of the textures.
+        #[inline]
+        fn snapshot_recursive<P: AsRef<Path>>(path: P, snapshot: &mut HashMap<PathBuf, SystemTime>)
+        {
+            /// Returns the modification time of the file at `path`, if any.
+            #[inline]
+            fn modified(path: &Path) -> Option<SystemTime>
+            {
+                std::fs::metadata(path).and_then(|md| md.modified()).ok()
+            }
+
+            let entries = match std::fs::read_dir(path)
+            {
+                Ok(entries) => entries,
+                Err(_) => return
+            };
+
+            for child_path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            {
+                if child_path.is_dir()
+                {
+                    snapshot_recursive(child_path, snapshot);
+                    continue;
+                }
+
+                if child_path.extension().is_some_and(|ext| ext == TRIMS_EXTENSION)
+                {
+                    continue;
+                }
+
+                let time = continue_if_none!(modified(&child_path));
+                let time = modified(&child_path.with_extension(TRIMS_EXTENSION))
+                    .map_or(time, |trims| time.max(trims));
+                _ = snapshot.insert(child_path, time);
+            }
+        }
+
+        let mut snapshot = HashMap::new();
+        snapshot_recursive(TEXTURES_PATH, &mut snapshot);
+        snapshot
+    }
+
+    /// Returns the paths of the texture files that were added, removed, or modified since the
+    /// last call, if any.
+    #[inline]
+    #[must_use]
+    pub fn changed_files(&self) -> Option<HvHashSet<PathBuf>>
+    {
+        let mut changed = hv_hash_set![];
+
+        for paths in self.receiver.try_iter()
+        {
+            changed.extend(paths);
+        }
+
+        (!changed.is_empty()).then_some(changed)
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Copies the files of the textures named `names`, along with their trims sidecars, inside `dir`,
+/// retaining the subfolders layout of the textures folder.
+/// # Errors
+/// Returns an error if a file could not be copied.
+#[inline]
+pub(in crate::map) fn copy_texture_files(
+    names: &HvHashSet<&str>,
+    dir: &Path
+) -> std::io::Result<()>
+{
+    /// Iterates through the subfolders copying the files of the textures.
+    #[inline]
+    fn copy_recursive(path: &Path, names: &HvHashSet<&str>, dir: &Path) -> std::io::Result<()>
+    {
+        for child_path in std::fs::read_dir(path)?.filter_map(|entry| entry.ok().map(|e| e.path()))
+        {
+            if child_path.is_dir()
+            {
+                copy_recursive(&child_path, names, dir)?;
+                continue;
+            }
+
+            if !child_path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| names.contains(&stem))
+            {
+                continue;
+            }
+
+            let target = dir.join(child_path.strip_prefix(TEXTURES_PATH).unwrap());
+            std::fs::create_dir_all(target.parent().unwrap())?;
+            std::fs::copy(&child_path, target)?;
+        }
+
+        Ok(())
+    }
+
+    copy_recursive(Path::new(TEXTURES_PATH), names, dir)
+}
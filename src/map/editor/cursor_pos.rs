@@ -150,7 +150,7 @@ impl Cursor
         self.previous_world_snapped = self.world_grid_snapped;
         self.world = to_world_coordinates(ui, window, camera);
         self.grid_square = state.grid_square_coordinates(self.world);
-        self.world_grid_snapped = self.grid_square.nearest_corner_to_point(self.world);
+        self.world_grid_snapped = state.grid_nearest_vertex(self.world);
         let p = to_egui_coordinates(self.world_grid_snapped, window, camera);
         self.ui_grid_snapped = Vec2::new(p.x, p.y);
         self.snap = state.cursor_snap();
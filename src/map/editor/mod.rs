@@ -11,7 +11,8 @@ use std::fs::File;
 use bevy::{
     input::mouse::{MouseScrollUnit, MouseWheel},
     prelude::*,
-    sprite::Mesh2dHandle
+    sprite::Mesh2dHandle,
+    window::WindowResolution
 };
 use bevy_egui::{egui, EguiUserTextures};
 
@@ -20,6 +21,7 @@ use self::state::{
     ui::{ui_left_space, ui_right_space, ui_top_space}
 };
 use super::{
+    custom_tool::CustomTools,
     drawer::{
         color::ColorResources,
         drawing_resources::DrawingResources,
@@ -28,17 +30,19 @@ use super::{
         EditDrawer,
         MapPreviewDrawer
     },
+    map_render::MapRenderRequest,
     properties::{BrushProperties, DefaultProperties, ThingProperties},
     thing::catalog::ThingsCatalog
 };
 use crate::{
-    config::{controls::BindsKeyCodes, Config},
+    config::{controls::BindsKeyCodes, Config, SnapThreshold},
     map::{
+        containers::HvHashSet,
         editor::{cursor_pos::Cursor, state::editor_state::State},
         hv_vec,
         MAP_HALF_SIZE
     },
-    utils::{math::AroundEqual, misc::Camera},
+    utils::{hull::Hull, math::AroundEqual, misc::Camera},
     EditorState,
     HardcodedActions,
     HardcodedThings,
@@ -98,7 +102,8 @@ struct StateUpdateBundle<'world, 'state, 'a, 'b, 'c>
     drawing_resources:  &'b mut DrawingResources,
     default_properties: &'b mut AllDefaultProperties<'b>,
     next_editor_state:  &'a mut NextState<EditorState>,
-    next_tex_load:      &'a mut NextState<TextureLoadingProgress>
+    next_tex_load:      &'a mut NextState<TextureLoadingProgress>,
+    texture_loader:     &'a mut TextureLoader
 }
 
 impl<'world, 'state, 'a, 'b, 'c> StateUpdateBundle<'world, 'state, 'a, 'b, 'c>
@@ -129,7 +134,8 @@ struct ToolUpdateBundle<'world, 'state, 'a, 'b, 'c>
     drawing_resources:          &'b DrawingResources,
     cursor:                     &'b Cursor,
     brushes_default_properties: &'b DefaultProperties,
-    things_default_properties:  &'b DefaultProperties
+    things_default_properties:  &'b DefaultProperties,
+    snap_threshold:             SnapThreshold
 }
 
 //=======================================================================//
@@ -157,6 +163,8 @@ struct DrawBundle<
     paint_tool_camera: &'a Transform,
     things_catalog:    &'b ThingsCatalog,
     cursor:            &'b Cursor,
+    play_area:         Option<Hull>,
+    snap_threshold:    SnapThreshold,
     #[cfg(feature = "debug")]
     gizmos:            &'a mut Gizmos<'t, 'u>
 }
@@ -235,6 +243,7 @@ impl Editor
         config: &Config,
         texture_loader: &mut TextureLoader,
         hardcoded_things: Option<Res<HardcodedThings>>,
+        custom_tools: Option<ResMut<CustomTools>>,
         brush_properties: Option<ResMut<BrushProperties>>,
         thing_properties: Option<ResMut<ThingProperties>>
     ) -> Self
@@ -257,7 +266,7 @@ impl Editor
                     Ok(file) =>
                     {
                         window.title = window_title(path.file_stem().unwrap().to_str());
-                        file.into()
+                        (file, path).into()
                     },
                     Err(_) => None
                 }
@@ -265,14 +274,12 @@ impl Editor
             None => None
         };
 
-        let brushes_default_properties = brush_properties
-            .map_or(DefaultProperties::default(), |mut d_p| {
-                DefaultProperties::new(std::mem::take(&mut d_p.0))
-            });
-        let things_default_properties = thing_properties
-            .map_or(DefaultProperties::default(), |mut d_p| {
-                DefaultProperties::new(std::mem::take(&mut d_p.0))
-            });
+        let brushes_default_properties = DefaultProperties::with_material(
+            brush_properties.map_or(Vec::new(), |mut d_p| std::mem::take(&mut d_p.0))
+        );
+        let things_default_properties = DefaultProperties::with_spawn_order(
+            thing_properties.map_or(Vec::new(), |mut d_p| std::mem::take(&mut d_p.0))
+        );
         let mut map_brushes_default_properties = brushes_default_properties.clone();
         let mut map_things_default_properties = things_default_properties.clone();
 
@@ -283,7 +290,7 @@ impl Editor
             map_things:  &mut map_things_default_properties
         };
 
-        let state = State::new(
+        let mut state = State::new(
             asset_server,
             images,
             prop_cameras,
@@ -293,6 +300,11 @@ impl Editor
             &mut default_properties,
             file
         );
+        state.set_operations_log(config.operations_log.as_ref());
+        State::check_hidden_tools(&config.hidden_tools);
+        state.set_custom_tools(
+            custom_tools.map_or(CustomTools::default(), |mut tools| std::mem::take(&mut *tools))
+        );
 
         Self {
             state,
@@ -323,7 +335,8 @@ impl Editor
         key_inputs: &mut ButtonInput<KeyCode>,
         config: &mut Config,
         next_editor_state: &mut NextState<EditorState>,
-        next_tex_load: &mut NextState<TextureLoadingProgress>
+        next_tex_load: &mut NextState<TextureLoadingProgress>,
+        texture_loader: &mut TextureLoader
     ) -> bool
     {
         self.state.quit(
@@ -350,7 +363,8 @@ impl Editor
                     map_things:  &mut self.map_things_default_properties
                 },
                 next_editor_state,
-                next_tex_load
+                next_tex_load,
+                texture_loader
             },
             rfd::MessageButtons::YesNo
         )
@@ -377,7 +391,8 @@ impl Editor
         key_inputs: &mut ButtonInput<KeyCode>,
         config: &mut Config,
         next_editor_state: &mut NextState<EditorState>,
-        next_tex_load: &mut NextState<TextureLoadingProgress>
+        next_tex_load: &mut NextState<TextureLoadingProgress>,
+        texture_loader: &mut TextureLoader
     )
     {
         if !window.focused
@@ -412,7 +427,8 @@ impl Editor
                 map_things:  &mut self.map_things_default_properties
             },
             next_editor_state,
-            next_tex_load
+            next_tex_load,
+            texture_loader
         })
         {
             // Move view around, if the UI is not being hovered.
@@ -421,6 +437,7 @@ impl Editor
     }
 
     /// Update the currently active tool.
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn update_active_tool(
         &mut self,
@@ -430,7 +447,8 @@ impl Editor
         prop_cameras: &mut PropCamerasMut,
         paint_tool_camera: (&mut bevy::prelude::Camera, &mut Transform),
         time: &Time,
-        user_textures: &mut EguiUserTextures
+        user_textures: &mut EguiUserTextures,
+        snap_threshold: SnapThreshold
     )
     {
         // Manipulate entities.
@@ -446,7 +464,8 @@ impl Editor
             things_catalog: &self.things_catalog,
             drawing_resources: &self.drawing_resources,
             brushes_default_properties: &self.map_brushes_default_properties,
-            things_default_properties: &self.map_things_default_properties
+            things_default_properties: &self.map_things_default_properties,
+            snap_threshold
         });
     }
 
@@ -685,6 +704,8 @@ impl Editor
         egui_context: &mut egui::Context,
         meshes_query: &Query<Entity, With<Mesh2dHandle>>,
         color_resources: &ColorResources,
+        play_area: Option<Hull>,
+        snap_threshold: SnapThreshold,
         #[cfg(feature = "debug")] gizmos: &mut Gizmos
     )
     {
@@ -692,6 +713,25 @@ impl Editor
 
         if self.state.map_preview()
         {
+            // Draw the whole map if it is being rendered to an image.
+            let render_window;
+            let render_camera;
+            let (window, camera) = match self.state.map_render_view()
+            {
+                Some((transform, size)) =>
+                {
+                    #[allow(clippy::cast_precision_loss)]
+                    let resolution = WindowResolution::new(size.x as f32, size.y as f32);
+                    render_window = Window {
+                        resolution,
+                        ..Default::default()
+                    };
+                    render_camera = transform;
+                    (&render_window, &render_camera)
+                },
+                None => (window, camera)
+            };
+
             self.state.draw_map_preview(&mut DrawBundleMapPreview {
                 window,
                 egui_context,
@@ -725,13 +765,18 @@ impl Editor
                 elapsed_time,
                 camera.scale(),
                 paint_tool_camera.scale(),
-                self.state.show_collision_overlay()
+                self.state.show_collision_overlay(),
+                self.state.wireframe(),
+                self.state.show_things_hulls(),
+                self.state.show_sprites()
             ),
             camera,
             prop_cameras,
             paint_tool_camera,
             things_catalog: &self.things_catalog,
             cursor: &self.cursor_pos,
+            play_area,
+            snap_threshold,
             #[cfg(feature = "debug")]
             gizmos
         });
@@ -740,7 +785,8 @@ impl Editor
     //==============================================================
     // Misc
 
-    /// Reloads the stored textures.
+    /// Reloads the stored textures. If `changed` contains a value only the textures with the
+    /// contained names are replaced.
     #[inline]
     pub fn reload_textures(
         &mut self,
@@ -748,10 +794,11 @@ impl Editor
         images: &mut Assets<Image>,
         materials: &mut Assets<ColorMaterial>,
         user_textures: &mut EguiUserTextures,
-        textures: Vec<(Texture, egui::TextureId)>
+        textures: Vec<(Texture, egui::TextureId)>,
+        changed: Option<&HvHashSet<String>>
     )
     {
-        self.drawing_resources.reload_textures(materials, textures);
+        self.drawing_resources.reload_textures(materials, textures, changed);
         self.state.finish_textures_reload(
             prop_cameras,
             images,
@@ -760,6 +807,18 @@ impl Editor
         );
     }
 
+    /// Updates the render of the map preview to an image file.
+    #[inline]
+    pub fn update_map_render(
+        &mut self,
+        images: &mut Assets<Image>,
+        camera: (&mut bevy::prelude::Camera, &mut Transform),
+        request: &mut MapRenderRequest
+    )
+    {
+        self.state.update_map_render(images, camera, request);
+    }
+
     /// Shutdown cleanup.
     #[inline]
     pub fn cleanup(&self, meshes: &mut Assets<Mesh>) { self.drawing_resources.cleanup(meshes); }
@@ -0,0 +1,326 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    str::FromStr
+};
+
+use bevy::prelude::Vec2;
+
+use super::{
+    edits_history::EditsHistory,
+    manager::{EntitiesManager, TextureResult}
+};
+use crate::{
+    map::{
+        brush::convex_polygon::ConvexPolygon,
+        drawer::drawing_resources::DrawingResources,
+        properties::{DefaultProperties, Value},
+        thing::{catalog::ThingsCatalog, ThingId},
+        OutOfBounds
+    },
+    utils::{hull::Hull, identifiers::EntityId}
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The resources required to execute a batch commands file.
+#[allow(clippy::missing_docs_in_private_items)]
+pub(in crate::map::editor::state) struct BatchBundle<'a>
+{
+    pub drawing_resources:          &'a DrawingResources,
+    pub things_catalog:             &'a ThingsCatalog,
+    pub manager:                    &'a mut EntitiesManager,
+    pub edits_history:              &'a mut EditsHistory,
+    pub brushes_default_properties: &'a DefaultProperties,
+    pub things_default_properties:  &'a DefaultProperties
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Executes the commands contained in `file`, one per line, and returns the descriptions of the
+/// errors that occurred, if any. Lines starting with `#` are ignored.
+/// The available commands are:
+/// - `deselect`: deselects all entities;
+/// - `brush x y x y x y ...`: spawns a selected brush with the listed vertexes;
+/// - `thing id x y`: spawns a selected thing with [`ThingId`] `id` centered at `x y`;
+/// - `texture name`: sets the texture of the selected brushes;
+/// - `property key value`: sets the property `key` of the selected entities to `value`.
+///
+/// Unknown or malformed commands are reported and skipped. The edits of all the executed commands
+/// are grouped in a single undoable step, which can only contain the edits of one property, so
+/// the `property` commands concerning a different key than the first one are reported as well.
+#[inline]
+#[must_use]
+pub(in crate::map::editor::state) fn run(file: File, bundle: &mut BatchBundle) -> Vec<String>
+{
+    let mut errors = Vec::new();
+    bundle.edits_history.begin_group();
+
+    for (i, line) in BufReader::new(file).lines().enumerate()
+    {
+        let line = match line
+        {
+            Ok(line) => line,
+            Err(_) =>
+            {
+                errors.push(format!("Line {}: could not be read", i + 1));
+                break;
+            }
+        };
+
+        let mut args = line.split_whitespace();
+        let command = match args.next()
+        {
+            Some(command) if !command.starts_with('#') => command,
+            _ => continue
+        };
+        let args = args.collect::<Vec<_>>();
+
+        let result = match command
+        {
+            "deselect" => deselect(bundle, &args),
+            "brush" => brush(bundle, &args),
+            "thing" => thing(bundle, &args),
+            "texture" => texture(bundle, &args),
+            "property" => property(bundle, &args),
+            _ => Err("unknown command")
+        };
+
+        if let Err(err) = result
+        {
+            errors.push(format!("Line {} ({command}): {err}", i + 1));
+        }
+    }
+
+    bundle.edits_history.end_group();
+    errors
+}
+
+//=======================================================================//
+
+/// Parses `args` as a list of coordinates.
+#[inline]
+fn parse_points(args: &[&str]) -> Result<Vec<Vec2>, &'static str>
+{
+    if args.len() % 2 != 0
+    {
+        return Err("odd amount of coordinates");
+    }
+
+    args.chunks(2)
+        .map(|xy| {
+            match (f32::from_str(xy[0]), f32::from_str(xy[1]))
+            {
+                (Ok(x), Ok(y)) => Ok(Vec2::new(x, y)),
+                _ => Err("invalid coordinate")
+            }
+        })
+        .collect()
+}
+
+//=======================================================================//
+
+/// Deselects all entities.
+#[inline]
+fn deselect(bundle: &mut BatchBundle, args: &[&str]) -> Result<(), &'static str>
+{
+    if !args.is_empty()
+    {
+        return Err("unexpected arguments");
+    }
+
+    bundle.manager.deselect_selected_entities(bundle.edits_history);
+    Ok(())
+}
+
+//=======================================================================//
+
+/// Spawns a brush.
+#[inline]
+fn brush(bundle: &mut BatchBundle, args: &[&str]) -> Result<(), &'static str>
+{
+    let polygon = ConvexPolygon::new_checked(parse_points(args)?.into_iter())
+        .ok_or("vertexes do not describe a convex polygon")?;
+
+    if polygon.out_of_bounds(bundle.manager.map_bound())
+    {
+        return Err("brush out of bounds");
+    }
+
+    _ = bundle.manager.spawn_brush(
+        polygon,
+        bundle.edits_history,
+        bundle.brushes_default_properties.instance()
+    );
+    Ok(())
+}
+
+//=======================================================================//
+
+/// Spawns a thing.
+#[inline]
+fn thing(bundle: &mut BatchBundle, args: &[&str]) -> Result<(), &'static str>
+{
+    let (id, pos) = match args
+    {
+        [id, pos @ ..] if pos.len() == 2 =>
+        {
+            (
+                u16::from_str(id).map_err(|_| "invalid thing id")?,
+                parse_points(pos)?[0]
+            )
+        },
+        _ => return Err("expected thing id and position")
+    };
+
+    let thing = bundle
+        .things_catalog
+        .thing(ThingId::new(id))
+        .ok_or("thing does not exist")?;
+
+    let (half_width, half_height) = (thing.width() / 2f32, thing.height() / 2f32);
+
+    if Hull::new(
+        pos.y + half_height,
+        pos.y - half_height,
+        pos.x - half_width,
+        pos.x + half_width
+    )
+    .out_of_bounds(bundle.manager.map_bound())
+    {
+        return Err("thing out of bounds");
+    }
+
+    _ = bundle.manager.spawn_thing(
+        bundle.things_catalog,
+        bundle.edits_history,
+        ThingId::new(id),
+        pos,
+        bundle.things_default_properties
+    );
+    Ok(())
+}
+
+//=======================================================================//
+
+/// Sets the texture of the selected brushes.
+#[inline]
+fn texture(bundle: &mut BatchBundle, args: &[&str]) -> Result<(), &'static str>
+{
+    let [name] = args
+    else
+    {
+        return Err("expected texture name");
+    };
+
+    if bundle.drawing_resources.texture(name).is_none()
+    {
+        return Err("texture does not exist");
+    }
+
+    if !bundle.manager.any_selected_brushes()
+    {
+        return Err("no selected brushes");
+    }
+
+    match bundle.manager.set_selected_brushes_texture(
+        bundle.drawing_resources,
+        bundle.edits_history,
+        name
+    )
+    {
+        TextureResult::Invalid => Err("texture could not be applied"),
+        TextureResult::Valid => Ok(()),
+        TextureResult::ValidRefreshOutline =>
+        {
+            bundle.manager.schedule_outline_update();
+            Ok(())
+        }
+    }
+}
+
+//=======================================================================//
+
+/// Sets a property of the selected entities.
+#[inline]
+fn property(bundle: &mut BatchBundle, args: &[&str]) -> Result<(), &'static str>
+{
+    /// Returns the value of the property `key` of `default_properties`, if any, converted from
+    /// `value`.
+    #[inline]
+    fn parsed(
+        default_properties: &DefaultProperties,
+        key: &str,
+        value: &Value,
+        err: &'static str
+    ) -> Result<Option<Value>, &'static str>
+    {
+        default_properties
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, default)| default.parse(value).ok_or(err))
+            .transpose()
+    }
+
+    let [key, value] = args
+    else
+    {
+        return Err("expected property key and value");
+    };
+
+    if !bundle.edits_history.accepts_property(key)
+    {
+        return Err("only one property can be set per file");
+    }
+
+    let value = Value::from_str(value).unwrap();
+
+    let brushes_value = parsed(
+        bundle.brushes_default_properties,
+        key,
+        &value,
+        "invalid brush property value"
+    )?;
+    let things_value =
+        parsed(bundle.things_default_properties, key, &value, "invalid thing property value")?;
+
+    if brushes_value.is_none() && things_value.is_none()
+    {
+        return Err("unknown property");
+    }
+
+    if let Some(value) = brushes_value
+    {
+        bundle.edits_history.property(
+            key,
+            bundle.manager.selected_brushes_mut().filter_map(|mut brush| {
+                brush.set_property(key, &value).map(|prev| (brush.id(), prev))
+            })
+        );
+        bundle.manager.schedule_overall_brushes_property_update(key);
+    }
+
+    if let Some(value) = things_value
+    {
+        bundle.edits_history.property(
+            key,
+            bundle.manager.selected_things_mut().filter_map(|mut thing| {
+                thing.set_property(key, &value).map(|prev| (thing.id(), prev))
+            })
+        );
+        bundle.manager.schedule_overall_things_property_update(key);
+    }
+
+    Ok(())
+}
@@ -56,12 +56,13 @@ use super::{editor_state::InputsPresses, edits_history::EditsHistory, manager::E
 use crate::{
     error_message,
     map::{
-        brush::BrushData,
+        brush::{Brush, BrushData, RotateResult},
         camera::scale_viewport,
         drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{DrawBundle, StateUpdateBundle, ToolUpdateBundle},
         hv_vec,
-        thing::{catalog::ThingsCatalog, ThingInstanceData},
+        thing::{catalog::ThingsCatalog, ThingInstance, ThingInstanceData, ThingInterface},
+        ContentHash,
         HvVec,
         MapHeader,
         OutOfBounds,
@@ -69,7 +70,8 @@ use crate::{
     },
     utils::{
         hull::{EntityHull, Hull},
-        identifiers::{EntityCenter, EntityId, Id}
+        identifiers::{EntityCenter, EntityId, Id},
+        math::points::rotate_point
     },
     Path
 };
@@ -190,7 +192,10 @@ impl ClipboardData
     /// Whether `self` is out of bounds if moved by the amount `delta`.
     #[inline]
     #[must_use]
-    fn out_of_bounds(&self, delta: Vec2) -> bool { (self.hull() + delta).out_of_bounds() }
+    fn out_of_bounds(&self, delta: Vec2, bound: f32) -> bool
+    {
+        (self.hull() + delta).out_of_bounds(bound)
+    }
 
     /// Draws the [`ClipboardData`] at its position moved by `delta`
     #[inline]
@@ -252,6 +257,91 @@ pub(in crate::map) type PropCamerasMut<'world, 'state, 'a> = Query<
 
 //=======================================================================//
 
+/// A group of entities on the map linked to a [`Prop`], which are replaced when the [`Prop`] is
+/// updated.
+#[must_use]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PropInstance
+{
+    /// The [`Id`]s of the entities.
+    ids:         HvVec<Id>,
+    /// The distance of the entities from the ones stored in the [`Prop`].
+    delta:       Vec2,
+    /// The fingerprint of the entities when they were last synchronized with the [`Prop`].
+    fingerprint: u64
+}
+
+impl PropInstance
+{
+    /// Returns a new [`PropInstance`] made of the entities with [`Id`]s contained in `ids`.
+    #[inline]
+    fn new(manager: &EntitiesManager, ids: HvVec<Id>, delta: Vec2) -> Self
+    {
+        Self {
+            fingerprint: Self::fingerprint(manager, &ids),
+            ids,
+            delta
+        }
+    }
+
+    /// Returns the fingerprint of the entities with [`Id`]s contained in `ids`.
+    #[inline]
+    #[must_use]
+    fn fingerprint(manager: &EntitiesManager, ids: &[Id]) -> u64
+    {
+        let mut bytes = Vec::new();
+
+        for id in ids
+        {
+            if !manager.entity_exists(*id)
+            {
+                bytes.push(0);
+                continue;
+            }
+
+            let mut item = manager.entity(*id).copy_to_clipboard();
+
+            // The anchors are stored in a set, so their serialization order is not stable.
+            if let ClipboardData::Brush(data, _) = &mut item
+            {
+                let anchors = data.anchors().map(|ids| hv_vec![collect; ids.iter().copied()]);
+
+                for anchor in anchors.into_iter().flatten()
+                {
+                    data.remove_anchor(anchor);
+                }
+            }
+
+            _ = ciborium::ser::into_writer(&item, &mut bytes);
+        }
+
+        ContentHash::new(&bytes).value
+    }
+
+    /// Whether the entities were edited after they were last synchronized with the [`Prop`].
+    #[inline]
+    #[must_use]
+    fn overridden(&self, manager: &EntitiesManager) -> bool
+    {
+        Self::fingerprint(manager, &self.ids) != self.fingerprint
+    }
+
+    /// Whether all the entities of the instance still exist.
+    #[inline]
+    #[must_use]
+    fn exists(&self, manager: &EntitiesManager) -> bool
+    {
+        self.ids.iter().all(|id| manager.entity_exists(*id))
+    }
+
+    /// Whether the instance contains an entity with [`Id`] `identifier`.
+    #[inline]
+    #[must_use]
+    fn contains(&self, identifier: Id) -> bool { self.ids.contains(&identifier) }
+}
+
+//=======================================================================//
+
 /// An agglomeration of entities that can be spawned around the map.
 #[must_use]
 #[derive(Debug, Serialize, Deserialize)]
@@ -268,7 +358,10 @@ pub(in crate::map) struct Prop
     /// The range of indexes of `data` in which anchored brushes are stored.
     anchored_range: Range<usize>,
     /// The optional texture screenshot.
-    screenshot:     Option<egui::TextureId>
+    screenshot:     Option<egui::TextureId>,
+    /// The groups of entities on the map linked to `self`.
+    #[serde(default)]
+    instances:      HvVec<PropInstance>
 }
 
 impl Default for Prop
@@ -282,7 +375,8 @@ impl Default for Prop
             pivot:          Vec2::ZERO,
             anchor_owners:  0,
             anchored_range: 0..0,
-            screenshot:     None
+            screenshot:     None,
+            instances:      hv_vec![]
         }
     }
 }
@@ -364,6 +458,23 @@ impl Prop
     #[must_use]
     fn has_data(&self) -> bool { !self.data.is_empty() }
 
+    /// Whether `self` is linked to groups of entities on the map.
+    #[inline]
+    #[must_use]
+    fn is_linked(&self) -> bool { !self.instances.is_empty() }
+
+    /// Returns the [`Id`]s of the stored entities.
+    #[inline]
+    fn data_ids(&self) -> HvVec<Id> { hv_vec![collect; self.data.iter().map(EntityId::id)] }
+
+    /// Whether any of the entities stored in `self` would be out of bounds if moved by `delta`.
+    #[inline]
+    #[must_use]
+    fn out_of_bounds(&self, delta: Vec2, bound: f32) -> bool
+    {
+        self.data.iter().any(|item| item.out_of_bounds(delta, bound))
+    }
+
     /// Returns a reference to the screenshot image id.
     /// # Panics
     /// Panics if `self` has no stored screenshot.
@@ -383,6 +494,18 @@ impl Prop
     //==============================================================
     // Update
 
+    /// Links to `self` the entities on the map with the [`Id`]s of the stored entities, which are
+    /// placed `delta` away from them.
+    #[inline]
+    pub(in crate::map::editor::state) fn link_instance(
+        &mut self,
+        manager: &EntitiesManager,
+        delta: Vec2
+    )
+    {
+        self.instances.push(PropInstance::new(manager, self.data_ids(), delta));
+    }
+
     /// Fills `self` with copies of the entities provided by `iter`.
     #[inline]
     fn fill<'a, D>(&mut self, iter: impl Iterator<Item = &'a D>)
@@ -556,15 +679,16 @@ impl Prop
     //==============================================================
     // Spawn
 
-    /// Spawns a copy of `self` moved by `delta`.
+    /// Spawns a copy of `self` moved by `delta`. Returns whether the copy was spawned.
     #[inline]
+    #[must_use]
     fn spawn(
         &mut self,
         drawing_resources: &DrawingResources,
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         delta: Vec2
-    )
+    ) -> bool
     {
         /// Spawns the entities stored in `prop`.
         #[inline]
@@ -596,10 +720,10 @@ impl Prop
 
         assert!(self.has_data(), "Prop contains no entities.");
 
-        if self.data.iter().any(|item| item.out_of_bounds(delta))
+        if self.out_of_bounds(delta, manager.map_bound())
         {
             error_message("Cannot spawn copy: out of bounds");
-            return;
+            return false;
         }
 
         spawn_regular(
@@ -641,6 +765,8 @@ impl Prop
             (0..self.anchored_range.start).rev(),
             delta
         );
+
+        true
     }
 
     /// Spawns a copy of `self` the copy-paste way.
@@ -666,7 +792,10 @@ impl Prop
             }
         }
 
-        self.spawn(drawing_resources, manager, edits_history, delta);
+        if self.spawn(drawing_resources, manager, edits_history, delta)
+        {
+            manager.deselect_spawned_entities(edits_history, &self.data_ids());
+        }
     }
 
     /// Spawns a copy of `self` as if it were a brush of a image editing software.
@@ -679,7 +808,88 @@ impl Prop
         cursor_pos: Vec2
     )
     {
-        self.spawn(drawing_resources, manager, edits_history, self.spawn_delta(cursor_pos));
+        if self.spawn(drawing_resources, manager, edits_history, self.spawn_delta(cursor_pos))
+        {
+            manager.deselect_spawned_entities(edits_history, &self.data_ids());
+        }
+    }
+
+    /// Returns a copy of `self` with the entities moved by `delta` and, if `angle` contains a
+    /// value, rotated by such angle in radians around the position the pivot is moved to.
+    /// Returns [`None`] if any of the entities would be out of bounds.
+    #[inline]
+    fn transformed(
+        &self,
+        drawing_resources: &DrawingResources,
+        delta: Vec2,
+        angle: Option<f32>,
+        bound: f32
+    ) -> Option<Self>
+    {
+        let pivot = self.data_center - self.pivot + delta;
+        let mut data = hv_vec![capacity; self.data.len()];
+
+        for item in &self.data
+        {
+            let item = match item
+            {
+                ClipboardData::Brush(brush, id) =>
+                {
+                    let mut brush = Brush::from_parts(brush.clone(), *id);
+                    brush.move_by_delta(drawing_resources, delta, true);
+
+                    if let Some(angle) = angle
+                    {
+                        match brush.check_rotate(drawing_resources, pivot, angle, false, bound)
+                        {
+                            RotateResult::Invalid => return None,
+                            RotateResult::Valid(payload) =>
+                            {
+                                brush.set_rotation_coordinates(drawing_resources, payload);
+                            }
+                        };
+                    }
+
+                    brush.copy_to_clipboard()
+                },
+                ClipboardData::Thing(thing, id) =>
+                {
+                    let mut thing = ThingInstance::from_parts(*id, thing.clone());
+                    thing.move_by_delta(delta);
+
+                    if let Some(angle) = angle
+                    {
+                        thing.move_by_delta(rotate_point(thing.pos(), pivot, angle) - thing.pos());
+                        _ = thing.set_angle(thing.angle() + angle.to_degrees());
+                    }
+
+                    if !thing.check_move(Vec2::ZERO, bound)
+                    {
+                        return None;
+                    }
+
+                    thing.copy_to_clipboard()
+                }
+            };
+
+            if item.out_of_bounds(Vec2::ZERO, bound)
+            {
+                return None;
+            }
+
+            data.push(item);
+        }
+
+        Self {
+            data,
+            data_center: self.data_center + delta,
+            pivot: self.pivot,
+            anchor_owners: self.anchor_owners,
+            anchored_range: self.anchored_range.clone(),
+            screenshot: None,
+            instances: hv_vec![]
+        }
+        .into()
     }
 
     //==============================================================
@@ -835,14 +1045,24 @@ impl Clipboard
             update_func: Self::delay_update
         };
 
-        match clip.import_props(images, prop_cameras, user_textures, catalog, header.props, file)
+        match clip.import_props(
+            images,
+            prop_cameras,
+            user_textures,
+            catalog,
+            header.props,
+            file,
+            false
+        )
         {
             Ok(()) => Ok(clip),
             Err(err) => Err(err)
         }
     }
 
-    /// Import the [`Prop`]s in `file`.
+    /// Import the [`Prop`]s in `file`. If `unlink` is true the links of the [`Prop`]s to the
+    /// entities of the map they were created in are removed.
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn import_props(
         &mut self,
@@ -851,7 +1071,8 @@ impl Clipboard
         user_textures: &mut EguiUserTextures,
         catalog: &ThingsCatalog,
         props_amount: usize,
-        file: &mut BufReader<File>
+        file: &mut BufReader<File>,
+        unlink: bool
     ) -> Result<(), &'static str>
     {
         let mut props = hv_vec![];
@@ -862,6 +1083,11 @@ impl Clipboard
             {
                 Ok(mut prop) =>
                 {
+                    if unlink
+                    {
+                        prop.instances.clear();
+                    }
+
                     _ = prop.reload_things(catalog);
                     props.push(prop);
                 },
@@ -1059,13 +1285,25 @@ impl Clipboard
         prop.screenshot = user_textures.add_image(image).into();
     }
 
-    /// Writes the serialized [`Prop`]s in `writer`.
+    /// Returns an iterator to the [`Prop`]s to be saved in the map file.
     #[inline]
-    pub fn export_props(&self, writer: &mut BufWriter<&mut Vec<u8>>) -> Result<(), &'static str>
+    pub fn props(&self) -> impl Iterator<Item = &Prop> { self.props.iter() }
+
+    /// Writes the serialized [`Prop`]s in `writer`, without their links to the entities of the
+    /// map, which are meaningless outside of it.
+    #[inline]
+    pub fn export_props(
+        &mut self,
+        writer: &mut BufWriter<&mut Vec<u8>>
+    ) -> Result<(), &'static str>
     {
-        for prop in &self.props
+        for prop in &mut self.props
         {
-            if ciborium::ser::into_writer(prop, &mut *writer).is_err()
+            let instances = std::mem::take(&mut prop.instances);
+            let result = ciborium::ser::into_writer(prop, &mut *writer);
+            prop.instances = instances;
+
+            if result.is_err()
             {
                 return Err("Error saving prop");
             }
@@ -1283,7 +1521,8 @@ impl Clipboard
             .paint_copy(bundle.drawing_resources, manager, edits_history, cursor_pos);
     }
 
-    /// Spawns the selected [`Prop`] on the map.
+    /// Spawns the selected [`Prop`] on the map. If the [`Prop`] is linked the spawned entities
+    /// are linked to it as well.
     #[inline]
     pub fn spawn_selected_prop(
         &mut self,
@@ -1293,12 +1532,276 @@ impl Clipboard
         cursor_pos: Vec2
     )
     {
-        self.props[self.selected_prop.unwrap()].paint_copy(
-            bundle.drawing_resources,
-            manager,
-            edits_history,
-            cursor_pos
-        );
+        let prop = &mut self.props[self.selected_prop.unwrap()];
+        let delta = prop.spawn_delta(cursor_pos);
+
+        if !prop.spawn(bundle.drawing_resources, manager, edits_history, delta)
+        {
+            return;
+        }
+
+        if prop.is_linked()
+        {
+            prop.link_instance(manager, delta);
+            self.props_changed = true;
+        }
+
+        manager.deselect_spawned_entities(edits_history, &prop.data_ids());
+    }
+
+    /// Spawns copies of the selected [`Prop`], or of the copied entities if `slotted` is false,
+    /// with their pivot placed along `side` every `spacing` units starting from its first vertex.
+    /// If `align` is true the copies are rotated to follow the direction of `side`.
+    /// Returns the amount of spawned copies.
+    /// # Errors
+    /// Returns an error if there are no entities to copy or any of the copies would be out of
+    /// bounds, in which case nothing is spawned.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    #[inline]
+    pub fn stamp_along_side(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        side: [Vec2; 2],
+        spacing: f32,
+        align: bool,
+        slotted: bool
+    ) -> Result<usize, &'static str>
+    {
+        let template = if slotted
+        {
+            match self.selected_prop
+            {
+                Some(index) => &self.props[index],
+                None => return Err("No prop selected")
+            }
+        }
+        else
+        {
+            &self.copy_paste
+        };
+
+        if !template.has_data()
+        {
+            return Err("No entities to stamp");
+        }
+
+        let [start, end] = side;
+        let length = start.distance(end);
+        let direction = (end - start) / length;
+        let angle = align.then(|| direction.y.atan2(direction.x));
+        let bound = manager.map_bound();
+        let mut copies = hv_vec![];
+
+        for i in 0..=(length / spacing).floor() as usize
+        {
+            let delta = template.spawn_delta(start + direction * (spacing * i as f32));
+
+            copies.push(
+                template
+                    .transformed(drawing_resources, delta, angle, bound)
+                    .ok_or("Cannot stamp copies: out of bounds")?
+            );
+        }
+
+        let amount = copies.len();
+
+        for mut copy in copies
+        {
+            _ = copy.spawn(drawing_resources, manager, edits_history, Vec2::ZERO);
+            manager.deselect_spawned_entities(edits_history, &copy.data_ids());
+        }
+
+        manager.schedule_outline_update();
+        Ok(amount)
+    }
+
+    /// Replaces each selected entity with a copy of the selected [`Prop`] centered at its center.
+    /// The angle of the replaced things is transferred to the things of the spawned copies.
+    /// If the [`Prop`] is linked the copies are linked to it as well.
+    #[inline]
+    pub fn replace_selected_with_prop(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    ) -> Result<(), &'static str>
+    {
+        let prop = match self.selected_prop
+        {
+            Some(index) => &mut self.props[index],
+            None => return Err("No prop selected")
+        };
+
+        let targets = hv_vec![collect; manager
+            .selected_brushes()
+            .map(|brush| (brush.center() - prop.data_center, None))
+            .chain(
+                manager
+                    .selected_things()
+                    .map(|thing| (thing.pos() - prop.data_center, thing.angle().into()))
+            )];
+
+        if targets.is_empty()
+        {
+            return Err("No entities selected");
+        }
+
+        if targets
+            .iter()
+            .any(|(delta, _)| prop.out_of_bounds(*delta, manager.map_bound()))
+        {
+            return Err("Cannot replace entities: out of bounds");
+        }
+
+        manager.despawn_selected_entities(edits_history);
+
+        for (delta, angle) in targets
+        {
+            if !prop.spawn(drawing_resources, manager, edits_history, delta)
+            {
+                continue;
+            }
+
+            // Linked before the angle is transferred so that the rotated things are reported as
+            // local overrides.
+            if prop.is_linked()
+            {
+                prop.link_instance(manager, delta);
+                self.props_changed = true;
+            }
+
+            let angle = continue_if_none!(angle);
+
+            for item in &prop.data
+            {
+                let id = continue_if_no_match!(item, ClipboardData::Thing(_, id), *id);
+                let prev = continue_if_none!(manager.thing_mut(id).set_angle(angle));
+                edits_history.thing_angle(id, prev);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the index of the selected [`Prop`] and the index of its instance the selected
+    /// entities belong to.
+    #[inline]
+    fn selected_linked_instance(
+        &self,
+        manager: &EntitiesManager
+    ) -> Result<(usize, usize), &'static str>
+    {
+        let index = match self.selected_prop
+        {
+            Some(index) => index,
+            None => return Err("No prop selected")
+        };
+
+        let prop = &self.props[index];
+
+        if !prop.is_linked()
+        {
+            return Err("The selected prop is not linked");
+        }
+
+        match manager.selected_entities().find_map(|entity| {
+            let id = entity.id();
+            prop.instances.iter().position(|instance| instance.contains(id))
+        })
+        {
+            Some(instance) => Ok((index, instance)),
+            None => Err("The selected entities are not an instance of the selected prop")
+        }
+    }
+
+    /// Returns the amount of instances of the selected [`Prop`], other than the one the selected
+    /// entities belong to, that were edited after they were last synchronized with it.
+    #[inline]
+    pub fn linked_prop_overrides(&self, manager: &EntitiesManager) -> Result<usize, &'static str>
+    {
+        let (index, source) = self.selected_linked_instance(manager)?;
+
+        Ok(self.props[index]
+            .instances
+            .iter()
+            .enumerate()
+            .filter(|(i, instance)| {
+                *i != source && instance.exists(manager) && instance.overridden(manager)
+            })
+            .count())
+    }
+
+    /// Stores the selected entities, which must belong to an instance of the selected [`Prop`], as
+    /// the entities of the [`Prop`], and replaces the entities of its other instances with copies
+    /// of the updated [`Prop`]. Returns the amount of instances that could not be updated because
+    /// they would be out of bounds, and the amount of instances that were unlinked because some
+    /// of their entities no longer exist.
+    #[inline]
+    pub fn update_linked_prop(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    ) -> Result<(usize, usize), &'static str>
+    {
+        let (index, source) = self.selected_linked_instance(manager)?;
+        let prop = &mut self.props[index];
+        let source_delta = prop.instances[source].delta;
+        let source_ids = hv_vec![collect; manager.selected_entities().map(EntityId::id)];
+
+        prop.fill(manager.selected_entities());
+
+        let mut instances = std::mem::take(&mut prop.instances);
+        instances[source] = PropInstance::new(manager, source_ids.clone(), Vec2::ZERO);
+        let mut out_of_bounds = 0;
+        let mut unlinked = hv_vec![];
+
+        for (i, instance) in instances.iter_mut().enumerate()
+        {
+            if i == source
+            {
+                continue;
+            }
+
+            instance.ids.retain_mut(|id| !source_ids.contains(id));
+
+            // The entities of the instance must be the ones it was last synchronized with, if any
+            // was deleted the instance is unlinked.
+            if !instance.exists(manager)
+            {
+                unlinked.push(i);
+                continue;
+            }
+
+            instance.delta -= source_delta;
+
+            if prop.out_of_bounds(instance.delta, manager.map_bound())
+            {
+                out_of_bounds += 1;
+                continue;
+            }
+
+            manager.select_entities_from_ids(edits_history, &instance.ids);
+            manager.despawn_selected_entities(edits_history);
+            _ = prop.spawn(bundle.drawing_resources, manager, edits_history, instance.delta);
+            *instance = PropInstance::new(manager, prop.data_ids(), instance.delta);
+        }
+
+        for i in unlinked.iter().rev()
+        {
+            _ = instances.remove(*i);
+        }
+
+        prop.instances = instances;
+        manager.select_entities_from_ids(edits_history, &source_ids);
+        self.props_changed = true;
+
+        let camera = bundle.prop_cameras.iter_mut().find(|camera| !camera.1.is_active);
+        self.queue_prop_screenshot(bundle.images, bundle.user_textures, camera, index);
+
+        Ok((out_of_bounds, unlinked.len()))
     }
 
     //==============================================================
@@ -0,0 +1,209 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{fs::File, io::BufReader};
+
+use super::manager::EntitiesManager;
+use crate::{
+    map::{
+        brush::Brush,
+        containers::{hv_hash_map, HvHashMap},
+        drawer::{color::Color, drawing_resources::DrawingResources},
+        editor::DrawBundle,
+        properties::DefaultProperties,
+        thing::{ThingInstance, ThingInterface},
+        AssertedInsertRemove,
+        MapHeader
+    },
+    utils::{
+        hull::EntityHull,
+        identifiers::{EntityId, Id},
+        math::AroundEqual,
+        misc::Camera
+    }
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A read-only map loaded from file whose entities are compared, by [`Id`], against the ones of
+/// the map being edited.
+#[must_use]
+pub(in crate::map::editor::state) struct MapComparison
+{
+    /// The brushes of the compared map.
+    brushes: HvHashMap<Id, Brush>,
+    /// The [`ThingInstance`]s of the compared map.
+    things:  HvHashMap<Id, ThingInstance>
+}
+
+impl MapComparison
+{
+    /// Returns a new [`MapComparison`] generated from the content of `file`.
+    /// Returns `Err` if the file could not be properly read.
+    #[inline]
+    pub fn from_file(file: File) -> Result<Self, &'static str>
+    {
+        /// Tests the validity of `value`.
+        macro_rules! test {
+            ($value:expr, $error:literal) => {
+                match $value
+                {
+                    Ok(value) => value,
+                    Err(_) => return Err($error)
+                }
+            };
+        }
+
+        let mut file = BufReader::new(file);
+
+        let header = test!(
+            ciborium::from_reader::<MapHeader, _>(&mut file),
+            "Error reading comparison file header"
+        );
+
+        for _ in 0..2
+        {
+            _ = test!(
+                ciborium::from_reader::<DefaultProperties, _>(&mut file),
+                "Error reading comparison file default properties"
+            );
+        }
+
+        _ = DrawingResources::file_animations(header.animations, &mut file)?;
+
+        let mut brushes = hv_hash_map![capacity; header.brushes];
+
+        for _ in 0..header.brushes
+        {
+            let brush = test!(
+                ciborium::from_reader::<Brush, _>(&mut file),
+                "Error reading comparison file brushes"
+            );
+            brushes.asserted_insert((brush.id(), brush));
+        }
+
+        let mut things = hv_hash_map![capacity; header.things];
+
+        for _ in 0..header.things
+        {
+            let thing = test!(
+                ciborium::from_reader::<ThingInstance, _>(&mut file),
+                "Error reading comparison file things"
+            );
+            things.asserted_insert((thing.id(), thing));
+        }
+
+        Ok(Self { brushes, things })
+    }
+
+    /// Draws the differences between the compared map and the one being edited.
+    /// Entities only present in the edited map are drawn with the added color, the ones only
+    /// present in the compared map with the removed color, and the ones present in both but with
+    /// a different shape or position with the moved color, together with an arrow going from their
+    /// previous to their current position.
+    #[inline]
+    pub fn draw(&self, bundle: &mut DrawBundle, manager: &EntitiesManager)
+    {
+        let viewport = bundle.camera.viewport(bundle.window);
+
+        for brush in manager.brushes().iter()
+        {
+            let hull = brush.hull();
+
+            match self.brushes.get(brush.id_as_ref())
+            {
+                Some(cmp) =>
+                {
+                    let cmp_hull = cmp.hull();
+
+                    if (!hull.overlaps(&viewport) && !cmp_hull.overlaps(&viewport)) ||
+                        !Self::brush_moved(brush, cmp)
+                    {
+                        continue;
+                    }
+
+                    bundle.drawer.sides(cmp.vertexes(), Color::ComparisonMoved);
+                    bundle.drawer.arrowed_line(
+                        cmp.center(),
+                        brush.center(),
+                        Color::ComparisonMoved
+                    );
+                },
+                None =>
+                {
+                    if hull.overlaps(&viewport)
+                    {
+                        bundle.drawer.sides(brush.vertexes(), Color::ComparisonAdded);
+                    }
+                }
+            };
+        }
+
+        for thing in manager.things()
+        {
+            let hull = thing.hull();
+
+            match self.things.get(thing.id_as_ref())
+            {
+                Some(cmp) =>
+                {
+                    let cmp_hull = cmp.hull();
+
+                    if (!hull.overlaps(&viewport) && !cmp_hull.overlaps(&viewport)) ||
+                        (thing.pos().around_equal_narrow(&cmp.pos()) &&
+                            hull.around_equal_narrow(&cmp_hull))
+                    {
+                        continue;
+                    }
+
+                    bundle.drawer.sides(cmp_hull.vertexes(), Color::ComparisonMoved);
+                    bundle
+                        .drawer
+                        .arrowed_line(cmp.pos(), thing.pos(), Color::ComparisonMoved);
+                },
+                None =>
+                {
+                    if hull.overlaps(&viewport)
+                    {
+                        bundle.drawer.sides(hull.vertexes(), Color::ComparisonAdded);
+                    }
+                }
+            };
+        }
+
+        for brush in self
+            .brushes
+            .values()
+            .filter(|brush| !manager.entity_exists(brush.id()) && brush.hull().overlaps(&viewport))
+        {
+            bundle.drawer.sides(brush.vertexes(), Color::ComparisonRemoved);
+        }
+
+        for hull in self
+            .things
+            .values()
+            .filter(|thing| !manager.entity_exists(thing.id()))
+            .map(EntityHull::hull)
+            .filter(|hull| hull.overlaps(&viewport))
+        {
+            bundle.drawer.sides(hull.vertexes(), Color::ComparisonRemoved);
+        }
+    }
+
+    /// Whether the shape of `brush` is different from the one of `cmp`.
+    #[inline]
+    #[must_use]
+    fn brush_moved(brush: &Brush, cmp: &Brush) -> bool
+    {
+        brush.vertexes().len() != cmp.vertexes().len() ||
+            brush
+                .vertexes()
+                .zip(cmp.vertexes())
+                .any(|(a, b)| !a.around_equal_narrow(&b))
+    }
+}
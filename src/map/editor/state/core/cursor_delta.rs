@@ -127,7 +127,7 @@ impl CursorDelta
         let delta = if snap
         {
             let delta = cursor_pos - prev_step;
-            let target = prev_step + grid.square(delta).nearest_corner_to_point(delta);
+            let target = prev_step + grid.nearest_vertex(delta);
 
             if prev_step.around_equal(&target)
             {
@@ -472,10 +472,10 @@ impl Default for CircleCursorPolygon
 
 impl CircleCursorPolygon
 {
-    /// The maximum circle resolution.
-    const MAX_CIRCLE_RESOLUTION: u8 = 8;
+    /// The maximum circle resolution, the maximum amount of vertexes a drawn circle can have.
+    const MAX_CIRCLE_RESOLUTION: u8 = 32;
     /// The minimum circle resolution.
-    const MIN_CIRCLE_RESOLUTION: u8 = 1;
+    const MIN_CIRCLE_RESOLUTION: u8 = 3;
 
     /// Returns a new [`CircleCursorPolygon`].
     #[inline]
@@ -517,7 +517,11 @@ impl CircleCursorPolygon
     #[inline]
     fn vertex_gen(hull: &Hull, settings: &ToolsSettings) -> CircleIterator
     {
-        hull.circle(settings.circle_draw_resolution * 4)
+        hull.circle(
+            settings
+                .circle_draw_resolution
+                .clamp(Self::MIN_CIRCLE_RESOLUTION, Self::MAX_CIRCLE_RESOLUTION)
+        )
     }
 
     /// Updates the state of `self`.
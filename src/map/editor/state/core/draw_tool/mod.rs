@@ -207,6 +207,17 @@ impl DrawTool
         manager.despawn_drawn_brushes(&mut self.drawn_brushes, edits_history);
     }
 
+    /// Deselects the drawn brushes if the spawned entities must not be left selected.
+    #[inline]
+    pub fn deselect_drawn_brushes(
+        &self,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    )
+    {
+        manager.deselect_spawned_entities(edits_history, &self.drawn_brushes);
+    }
+
     /// Updates the tool.
     #[inline]
     pub fn update(
@@ -362,7 +373,7 @@ impl DrawTool
         ui.label(egui::RichText::new("CIRCLE TOOL"));
 
         ui.horizontal(|ui| {
-            ui.label(egui::RichText::new("Resolution:"));
+            ui.label(egui::RichText::new("Sides:"));
             ui.add(
                 egui::Slider::new(
                     &mut settings.circle_draw_resolution,
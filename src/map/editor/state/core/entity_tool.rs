@@ -26,6 +26,7 @@ use super::{
 use crate::{
     map::{
         brush::Brush,
+        containers::{hv_vec, HvVec},
         drawer::EditDrawer,
         editor::{
             cursor_pos::Cursor,
@@ -480,6 +481,7 @@ impl EntityTool
                                         manager,
                                         inputs,
                                         edits_history,
+                                        settings,
                                         id
                                     );
                                 }
@@ -493,6 +495,7 @@ impl EntityTool
                                             manager,
                                             inputs,
                                             edits_history,
+                                            settings,
                                             id
                                         );
                                     }
@@ -531,7 +534,10 @@ impl EntityTool
                 {
                     if settings.entity_editing()
                     {
-                        manager.despawn_selected_entities(edits_history);
+                        if manager.confirm_bulk_operation("Delete")
+                        {
+                            manager.despawn_selected_entities(edits_history);
+                        }
                     }
                     else
                     {
@@ -564,9 +570,19 @@ impl EntityTool
                 edit_target!(
                     settings.target_switch(),
                     |move_texture| {
-                        if Self::move_selected_entities(bundle, manager, dir, move_texture)
+                        let attachments =
+                            Self::realigned_attachments(manager, settings, move_texture);
+
+                        if Self::move_selected_entities(
+                            bundle,
+                            manager,
+                            &attachments,
+                            dir,
+                            move_texture
+                        )
                         {
                             edits_history.entity_move_cluster(manager, dir, move_texture);
+                            edits_history.textures_move_cluster(attachments, dir);
                         }
                     },
                     {
@@ -621,13 +637,28 @@ impl EntityTool
                     drag.conditional_update(bundle.cursor, grid, |delta| {
                         if *drag_spawn
                         {
-                            return Self::move_selected_entities(bundle, manager, delta, true);
+                            return Self::move_selected_entities(
+                                bundle,
+                                manager,
+                                &[],
+                                delta,
+                                true
+                            );
                         }
 
                         edit_target!(
                             settings.target_switch(),
                             |move_texture| {
-                                Self::move_selected_entities(bundle, manager, delta, move_texture)
+                                let attachments =
+                                    Self::realigned_attachments(manager, settings, move_texture);
+
+                                Self::move_selected_entities(
+                                    bundle,
+                                    manager,
+                                    &attachments,
+                                    delta,
+                                    move_texture
+                                )
                             },
                             Self::move_selected_textures(bundle, manager, delta)
                         )
@@ -757,6 +788,10 @@ impl EntityTool
                     settings.target_switch(),
                     |move_texture| {
                         edits_history.entity_move_cluster(manager, drag_delta, move_texture);
+                        edits_history.textures_move_cluster(
+                            Self::realigned_attachments(manager, settings, move_texture),
+                            drag_delta
+                        );
                     },
                     edits_history.texture_move_cluster(manager, drag_delta)
                 );
@@ -777,16 +812,17 @@ impl EntityTool
         manager: &mut EntitiesManager,
         inputs: &InputsPresses,
         edits_history: &mut EditsHistory,
+        settings: &ToolsSettings,
         identifier: Id
     )
     {
         if manager.is_selected(identifier)
         {
-            manager.deselect_entity(identifier, inputs, edits_history);
+            manager.deselect_entity(identifier, inputs, edits_history, settings);
             return;
         }
 
-        manager.select_entity(identifier, inputs, edits_history);
+        manager.select_entity(identifier, inputs, edits_history, settings);
     }
 
     /// Exclusively selects the entity with [`Id`] `identifier`.
@@ -795,11 +831,12 @@ impl EntityTool
         manager: &mut EntitiesManager,
         inputs: &InputsPresses,
         edits_history: &mut EditsHistory,
+        settings: &ToolsSettings,
         identifier: Id
     )
     {
         manager.deselect_selected_entities(edits_history);
-        manager.select_entity(identifier, inputs, edits_history);
+        manager.select_entity(identifier, inputs, edits_history, settings);
     }
 
     /// Move spawn the selected entities.
@@ -824,7 +861,7 @@ impl EntityTool
         );
     }
 
-    /// Selects the entities inside the drag selection.
+    /// Selects the entities inside the drag selection, or deselects them if Alt is pressed.
     #[inline]
     fn select_entities_from_drag_selection(
         manager: &mut EntitiesManager,
@@ -834,6 +871,13 @@ impl EntityTool
         settings: &ToolsSettings
     )
     {
+        // Subtractive selection.
+        if inputs.alt_pressed()
+        {
+            manager.deselect_entities_in_range(drag_selection, edits_history, settings);
+            return;
+        }
+
         // Inclusive selection.
         if inputs.shift_pressed()
         {
@@ -849,22 +893,52 @@ impl EntityTool
         );
     }
 
-    /// Moves the selected entities.
+    /// Returns the [`Id`]s of the unselected textured brushes attached to the selected ones if
+    /// their textures must be moved along with the ones of the selected brushes, which is the case
+    /// if `move_texture` is true.
+    #[inline]
+    fn realigned_attachments(
+        manager: &EntitiesManager,
+        settings: &ToolsSettings,
+        move_texture: bool
+    ) -> HvVec<Id>
+    {
+        if !(settings.realign_attachments && move_texture)
+        {
+            return hv_vec![];
+        }
+
+        manager.unselected_textured_attachments()
+    }
+
+    /// Moves the selected entities. The textures of the brushes with [`Id`]s contained in
+    /// `attachments` are moved by the same amount so that they stay aligned with the ones of their
+    /// owners.
     #[inline]
     fn move_selected_entities(
         bundle: &ToolUpdateBundle,
         manager: &mut EntitiesManager,
+        attachments: &[Id],
         delta: Vec2,
         move_texture: bool
     ) -> bool
     {
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager
                 .selected_brushes()
-                .find_map(|brush| (!brush.check_move(delta, move_texture)).then_some(brush.id()))
+                .find_map(|brush| {
+                    (!brush.check_move(delta, move_texture, bound)).then_some(brush.id())
+                })
+                .or_else(|| {
+                    attachments
+                        .iter()
+                        .copied()
+                        .find(|id| !manager.brush(*id).check_texture_move(delta, bound))
+                })
                 .or(manager
                     .selected_things()
-                    .find_map(|thing| (!thing.check_move(delta)).then_some(thing.id())))
+                    .find_map(|thing| (!thing.check_move(delta, bound)).then_some(thing.id())))
         });
 
         if !valid
@@ -877,6 +951,11 @@ impl EntityTool
             brush.move_by_delta(bundle.drawing_resources, delta, move_texture);
         }
 
+        for id in attachments
+        {
+            manager.brush_mut(*id).move_texture(bundle.drawing_resources, delta);
+        }
+
         for mut thing in manager.selected_things_mut()
         {
             thing.move_by_delta(delta);
@@ -893,10 +972,11 @@ impl EntityTool
         delta: Vec2
     ) -> bool
     {
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager
                 .selected_brushes_with_sprites()
-                .find_map(|brush| (!brush.check_texture_move(delta)).then_some(brush.id()))
+                .find_map(|brush| (!brush.check_texture_move(delta, bound)).then_some(brush.id()))
         });
 
         if !valid
@@ -1123,12 +1203,27 @@ impl EntityTool
         }
     }
 
-    /// Draws the tool's UI.
+    /// Draws the tool's UI. Returns whether the bounds margin field has focus.
     #[inline]
-    pub fn ui(&self, ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    #[must_use]
+    pub fn ui(&self, ui: &mut egui::Ui, settings: &mut ToolsSettings) -> bool
     {
         ui.label(egui::RichText::new("ENTITY TOOL"));
         settings.ui(ui, !self.ongoing_multi_frame_change());
+        ui.checkbox(&mut settings.select_attachments, "Ctrl selects attachments");
+        ui.checkbox(&mut settings.realign_attachments, "Keep attachments textures aligned");
+
+        ui.horizontal(|ui| {
+            ui.label("Bounds margin");
+            ui.add(
+                egui::DragValue::new(&mut settings.bound_margin)
+                    .clamp_range(0f32..=1024f32)
+                    .speed(1)
+            )
+            .on_hover_text("Distance from the map bounds within which entities are selected")
+            .has_focus()
+        })
+        .inner
     }
 
     /// Draws the subtools.
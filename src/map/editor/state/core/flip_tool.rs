@@ -65,7 +65,7 @@ impl FlipTool
             |flip_texture| {
                 #[allow(clippy::missing_docs_in_private_items)]
                 type FlipSteps = (
-                    fn(&mut Brush, &DrawingResources, f32, bool) -> bool,
+                    fn(&mut Brush, &DrawingResources, f32, bool, f32) -> bool,
                     fn(&mut Brush, &DrawingResources, f32, bool),
                     Flip
                 );
@@ -87,10 +87,17 @@ impl FlipTool
                     (Brush::check_flip_right, Brush::flip_right, Flip::Right(self.0.right()))
                 };
 
+                let bound = manager.map_bound();
                 let valid = manager.test_operation_validity(|manager| {
                     manager.selected_brushes_mut().find_map(|mut brush| {
-                        (!check(&mut brush, bundle.drawing_resources, flip.mirror(), flip_texture))
-                            .then_some(brush.id())
+                        (!check(
+                            &mut brush,
+                            bundle.drawing_resources,
+                            flip.mirror(),
+                            flip_texture,
+                            bound
+                        ))
+                        .then_some(brush.id())
                     })
                 });
 
@@ -3,13 +3,18 @@
 //
 //=======================================================================//
 
+use bevy_egui::egui;
+
 use super::{tool::ActiveTool, PreviousActiveTool};
 use crate::{
     map::{
         containers::{hv_box, HvVec},
         drawer::drawing_resources::DrawingResources,
         editor::{
-            state::manager::{Animators, EntitiesManager},
+            state::{
+                editor_state::ToolsSettings,
+                manager::{Animators, EntitiesManager}
+            },
             DrawBundleMapPreview,
             ToolUpdateBundle
         },
@@ -58,15 +63,40 @@ impl MapPreviewTool
 
     /// Updates the tool.
     #[inline]
-    pub fn update(&mut self, bundle: &ToolUpdateBundle, manager: &EntitiesManager)
+    pub fn update(
+        &mut self,
+        bundle: &ToolUpdateBundle,
+        manager: &EntitiesManager,
+        settings: &ToolsSettings
+    )
     {
         for sim in &mut self.movement
         {
             sim.update(manager.moving(sim.id()), bundle.delta_time);
         }
 
-        self.animators
-            .update(bundle.drawing_resources, manager, bundle.delta_time);
+        self.animators.update(
+            bundle.drawing_resources,
+            manager,
+            bundle.delta_time * settings.animation_speed
+        );
+    }
+
+    /// Draws the UI.
+    #[inline]
+    pub fn ui(ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    {
+        ui.label(egui::RichText::new("MAP PREVIEW"));
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Animation speed:"));
+            ui.add(
+                egui::Slider::new(&mut settings.animation_speed, 0.05..=2f32)
+                    .show_value(false)
+                    .text_color(egui::Color32::WHITE)
+            );
+            ui.label(egui::RichText::new(format!("{:.2}x", settings.animation_speed)));
+        });
     }
 
     /// Draws the tool.
@@ -26,7 +26,7 @@ mod zoom_tool;
 
 use bevy::prelude::Vec2;
 use bevy_egui::egui;
-use hill_vacuum_shared::{match_or_panic, return_if_no_match};
+use hill_vacuum_shared::{continue_if_none, match_or_panic, return_if_no_match, return_if_none};
 
 use self::{
     cursor_delta::CursorDelta,
@@ -49,8 +49,12 @@ use super::{
 };
 use crate::{
     map::{
-        brush::{convex_polygon::TextureSetResult, BrushData},
-        containers::HvBox,
+        brush::{
+            convex_polygon::{ConvexPolygon, TextureSetResult},
+            BrushData
+        },
+        containers::{hv_vec, HvBox},
+        custom_tool::{CustomToolContext, CustomToolDrawer, CustomTools},
         drawer::{
             drawing_resources::DrawingResources,
             texture::{Sprite, TextureSettings}
@@ -63,12 +67,22 @@ use crate::{
             ToolUpdateBundle
         },
         properties::Value,
-        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData}
+        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData, ThingInterface},
+        OutOfBounds
     },
     utils::identifiers::{EntityId, Id},
     Path
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The amount of brushes requested by a custom tool from which they are spawned without updating
+/// the quad trees for each one of them.
+const BULK_SPAWN_THRESHOLD: usize = 64;
+
 //=======================================================================//
 // MACROS
 //
@@ -402,6 +416,10 @@ impl<'a> UndoRedoInterface<'a>
         }
     }
 
+    /// Returns a reference to the [`EntitiesManager`].
+    #[inline]
+    pub fn manager(&self) -> &EntitiesManager { self.manager }
+
     /// Selects the entity with [`Id`] `identifier`.
     #[inline]
     pub fn select_entity(&mut self, identifier: Id)
@@ -528,6 +546,20 @@ impl<'a> UndoRedoInterface<'a>
         self.manager.disanchor(platform, anchor);
     }
 
+    /// Sets the note of the entity with [`Id`] `identifier`, and returns the previous one.
+    #[inline]
+    #[must_use]
+    pub fn set_note(&mut self, identifier: Id, note: String) -> String
+    {
+        self.manager.set_note(identifier, note)
+    }
+
+    /// Sets the size of half of the square the entities can occupy to `bound`, and returns the
+    /// previous one.
+    #[inline]
+    #[must_use]
+    pub fn replace_map_bound(&mut self, bound: f32) -> f32 { self.manager.replace_map_bound(bound) }
+
     /// Sets the texture of the brush with [`Id`] identifier.
     /// Returns the name of the replaced texture, if any.
     #[inline]
@@ -683,7 +715,9 @@ pub(in crate::map::editor::state) struct Core
     /// The active tool.
     active_tool:         ActiveTool,
     /// The [`EditingTarget`] of the previous frame.
-    prev_editing_target: EditingTarget
+    prev_editing_target: EditingTarget,
+    /// The tools registered by the library user.
+    custom_tools:        CustomTools
 }
 
 impl EnabledTool for Core
@@ -712,6 +746,11 @@ impl Core
     #[must_use]
     pub const fn entity_tool(&self) -> bool { self.active_tool.entity_tool() }
 
+    /// Whether the path tool is active and it is not simulating the movement of the paths.
+    #[inline]
+    #[must_use]
+    pub const fn path_tool(&self) -> bool { self.active_tool.path_tool() }
+
     /// Whether the active tool has texture editing capabilities.
     #[inline]
     #[must_use]
@@ -722,6 +761,26 @@ impl Core
     #[must_use]
     pub const fn map_preview(&self) -> bool { self.active_tool.map_preview() }
 
+    /// Returns an iterator to the indexes and names of the [`CustomTools`], along with whether
+    /// they can be enabled.
+    #[inline]
+    pub fn custom_tools<'a>(
+        &'a self,
+        manager: &EntitiesManager
+    ) -> impl Iterator<Item = (usize, &'a str, bool)>
+    {
+        let available = !self.active_tool.ongoing_multi_frame_change();
+
+        self.custom_tools
+            .iter(manager.selected_brushes_amount(), manager.selected_things_amount())
+            .map(move |(index, name, met)| (index, name, available && met))
+    }
+
+    /// Returns the index of the active custom tool, if any.
+    #[inline]
+    #[must_use]
+    pub const fn custom_tool(&self) -> Option<usize> { self.active_tool.custom_tool() }
+
     //==============================================================
     // Save
 
@@ -853,6 +912,17 @@ impl Core
     #[inline]
     pub fn disable_subtool(&mut self) { self.active_tool.disable_subtool(); }
 
+    /// Sets the [`CustomTools`].
+    #[inline]
+    pub fn set_custom_tools(&mut self, custom_tools: CustomTools)
+    {
+        self.custom_tools = custom_tools;
+    }
+
+    /// Takes the [`CustomTools`], leaving none in their place.
+    #[inline]
+    pub fn take_custom_tools(&mut self) -> CustomTools { std::mem::take(&mut self.custom_tools) }
+
     /// Toggles the map preview.
     #[inline]
     pub fn toggle_map_preview(
@@ -907,11 +977,18 @@ impl Core
         settings: &mut ToolsSettings
     )
     {
-        self.active_tool
-            .update(bundle, manager, inputs, edits_history, clipboard, grid, settings);
+        match self.active_tool.custom_tool()
+        {
+            Some(index) => self.update_custom_tool(index, bundle, manager, inputs, edits_history),
+            None =>
+            {
+                self.active_tool
+                    .update(bundle, manager, inputs, edits_history, clipboard, grid, settings);
+            }
+        };
 
         // Close the edit history.
-        edits_history.push_frame_edit();
+        edits_history.push_frame_edit(manager);
     }
 
     /// Changes the active tool.
@@ -938,6 +1015,130 @@ impl Core
         );
     }
 
+    /// Updates the custom tool at `index` and executes the edits it requested.
+    #[inline]
+    fn update_custom_tool(
+        &mut self,
+        index: usize,
+        bundle: &ToolUpdateBundle,
+        manager: &mut EntitiesManager,
+        inputs: &InputsPresses,
+        edits_history: &mut EditsHistory
+    )
+    {
+        let mut context = CustomToolContext::new(
+            bundle.cursor.world(),
+            bundle.cursor.world_snapped(),
+            (inputs.left_mouse.pressed(), inputs.left_mouse.just_pressed()),
+            (inputs.right_mouse.pressed(), inputs.right_mouse.just_pressed()),
+            manager
+                .selected_brushes()
+                .map(|brush| (brush.id(), hv_vec![collect; brush.vertexes()])),
+            manager.selected_things().map(|thing| (thing.id(), thing.pos()))
+        );
+        self.custom_tools.update(index, &mut context);
+
+        let (spawns, despawns) = context.into_requests();
+
+        if spawns.is_empty() && despawns.is_empty()
+        {
+            return;
+        }
+
+        for id in despawns
+        {
+            if !manager.entity_exists(id) || manager.is_thing(id) || !manager.is_selected(id)
+            {
+                continue;
+            }
+
+            let brush = manager.brush(id);
+
+            if brush.has_anchors() || brush.anchored().is_some()
+            {
+                continue;
+            }
+
+            manager.despawn_selected_brush(id, edits_history);
+        }
+
+        let bulk = spawns.len() >= BULK_SPAWN_THRESHOLD;
+
+        if bulk
+        {
+            manager.start_bulk();
+        }
+
+        for vxs in spawns
+        {
+            let polygon = continue_if_none!(ConvexPolygon::new_checked(vxs.into_iter()));
+
+            if polygon.out_of_bounds(manager.map_bound())
+            {
+                continue;
+            }
+
+            _ = manager.spawn_brush(
+                polygon,
+                edits_history,
+                bundle.brushes_default_properties.instance()
+            );
+        }
+
+        if bulk
+        {
+            manager.finish_bulk();
+        }
+
+        manager.schedule_outline_update();
+    }
+
+    /// Enables the custom tool at `index`, if its change conditions are met.
+    #[inline]
+    pub fn change_custom_tool(
+        &mut self,
+        index: usize,
+        manager: &EntitiesManager,
+        edits_history: &EditsHistory
+    )
+    {
+        if self.active_tool.ongoing_multi_frame_change() ||
+            edits_history.multiframe_edit() ||
+            edits_history.grouping() ||
+            !self.custom_tools.change_conditions_met(
+                index,
+                manager.selected_brushes_amount(),
+                manager.selected_things_amount()
+            )
+        {
+            return;
+        }
+
+        self.active_tool = ActiveTool::Custom(index);
+        self.custom_tools.enable(index);
+    }
+
+    /// Generates a new brush from the intersection of the two selected brushes, keeping the
+    /// originals.
+    #[inline]
+    pub fn intersection_copy(
+        &mut self,
+        bundle: &StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings,
+        grid: Grid
+    )
+    {
+        self.active_tool.intersection_copy_tool(
+            bundle.default_properties.brushes,
+            manager,
+            edits_history,
+            settings,
+            grid
+        );
+    }
+
     /// Executes the update of the frame start.
     #[inline]
     pub fn frame_start_update(
@@ -947,7 +1148,7 @@ impl Core
         clipboard: &Clipboard
     )
     {
-        self.active_tool.fallback(manager, clipboard);
+        self.active_tool.fallback(manager, clipboard, &self.custom_tools);
 
         let editing_target = self.active_tool.editing_target(self.prev_editing_target);
 
@@ -978,7 +1179,7 @@ impl Core
         self.prev_editing_target = editing_target;
     }
 
-    /// Executes a snap to a [`Grid`] with size 2.
+    /// Executes a snap to a non rotated [`Grid`] with size 2.
     #[inline]
     pub fn quick_snap(
         &mut self,
@@ -994,7 +1195,7 @@ impl Core
             manager,
             edits_history,
             settings,
-            Grid::new(2, true, grid_shifted)
+            Grid::new(2, true, grid_shifted, 0f32)
         );
     }
 
@@ -1012,6 +1213,12 @@ impl Core
     )
     {
         self.active_tool.draw(bundle, manager, settings, show_tooltips);
+
+        let index = return_if_none!(self.active_tool.custom_tool());
+        let mut drawer =
+            CustomToolDrawer::new(bundle.cursor.world(), bundle.cursor.world_snapped());
+        self.custom_tools.draw(index, &mut drawer);
+        drawer.draw(&mut bundle.drawer);
     }
 
     /// Draws the map preview.
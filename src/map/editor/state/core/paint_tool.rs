@@ -130,6 +130,8 @@ pub(in crate::map::editor::state::core) struct PaintTool
 {
     /// The slot where to store the created [`Prop`].
     slot:                    String,
+    /// Whether the entities the slotted [`Prop`] is created from should be linked to it.
+    linked:                  bool,
     /// The state of the tool.
     status:                  Status,
     /// The maximum height of the bottom panel.
@@ -162,6 +164,7 @@ impl PaintTool
     {
         ActiveTool::Paint(PaintTool {
             slot:                    String::new(),
+            linked:                  false,
             status:                  Status::default(),
             max_bottom_panel_height: 0f32
         })
@@ -205,7 +208,7 @@ impl PaintTool
                     clipboard.delete_selected_prop(prop_cameras);
                 }
 
-                if inputs.enter.just_pressed() && manager.any_selected_entities()
+                if inputs.prop_creation.just_pressed() && manager.any_selected_entities()
                 {
                     self.status = Status::SetPivot(Self::outline(manager, grid).unwrap());
                 }
@@ -259,6 +262,11 @@ impl PaintTool
 
                 if let Ok(slot) = self.slot.parse()
                 {
+                    if self.linked
+                    {
+                        prop.link_instance(manager, Vec2::ZERO);
+                    }
+
                     clipboard.insert_prop(std::mem::take(prop), slot);
                     self.status = Status::default();
                 }
@@ -410,6 +418,7 @@ impl PaintTool
                         ));
                     }
 
+                    ui.checkbox(&mut self.linked, "Link the selected entities to the slotted prop");
                     ui.image((texture, PROP_SNAPSHOT_FRAME));
 
                     response.inner
@@ -33,7 +33,7 @@ use crate::{
             state::{
                 clipboard::Clipboard,
                 core::{rect, tool::subtools_buttons},
-                editor_state::InputsPresses,
+                editor_state::{InputsPresses, ToolsSettings},
                 edits_history::EditsHistory,
                 grid::Grid,
                 manager::EntitiesManager,
@@ -384,6 +384,29 @@ impl PathTool
         Some(value)
     }
 
+    /// The cursor position to be used while editing a single entity, snapped to the center or a
+    /// vertex of a brush within `snap_distance` if the path geometry snap is enabled.
+    #[inline]
+    #[must_use]
+    fn single_editing_cursor_pos(
+        status: &Status,
+        cursor: &Cursor,
+        manager: &EntitiesManager,
+        snap_distance: f32,
+        settings: &ToolsSettings
+    ) -> Vec2
+    {
+        let cursor_pos = Self::cursor_pos(status, cursor).unwrap();
+
+        if !settings.path_geometry_snap
+        {
+            return cursor_pos;
+        }
+
+        let id = match_or_panic!(status, Status::SingleEditing(id, _), *id);
+        brush_snap_pos(manager, id, cursor.world(), snap_distance).unwrap_or(cursor_pos)
+    }
+
     /// Returns the [`Id`] of the selected moving entity beneath the cursor, if any.
     #[inline]
     #[must_use]
@@ -444,7 +467,8 @@ impl PathTool
         manager: &mut EntitiesManager,
         inputs: &InputsPresses,
         edits_history: &mut EditsHistory,
-        grid: Grid
+        grid: Grid,
+        settings: &ToolsSettings
     )
     {
         let item_beneath_cursor = self.selector.item_beneath_cursor(
@@ -542,7 +566,7 @@ impl PathTool
                     }
                 );
 
-                if inputs.enter.just_pressed() && manager.selected_moving_amount() != 0
+                if inputs.path_simulation.just_pressed() && manager.selected_moving_amount() != 0
                 {
                     // Initiate paths simulation.
                     self.enable_simulation(manager);
@@ -613,7 +637,7 @@ impl PathTool
             },
             status @ Status::SingleEditing(..) =>
             {
-                if !Self::single_editing(bundle, manager, status, inputs, edits_history)
+                if !Self::single_editing(bundle, manager, status, inputs, edits_history, settings)
                 {
                     return;
                 }
@@ -828,10 +852,11 @@ impl PathTool
     ) -> bool
     {
         let mut move_payloads = hv_vec![];
+        let bound = manager.map_bound();
 
         for moving in manager.selected_moving()
         {
-            match moving.check_selected_path_nodes_move(delta)
+            match moving.check_selected_path_nodes_move(delta, bound)
             {
                 IdNodesMoveResult::None => (),
                 IdNodesMoveResult::Invalid => return false,
@@ -876,10 +901,17 @@ impl PathTool
         manager: &mut EntitiesManager,
         status: &mut Status,
         inputs: &InputsPresses,
-        edits_history: &mut EditsHistory
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings
     ) -> bool
     {
-        let cursor_pos = Self::cursor_pos(status, bundle.cursor).unwrap();
+        let cursor_pos = Self::single_editing_cursor_pos(
+            status,
+            bundle.cursor,
+            manager,
+            bundle.snap_threshold.distance(bundle.camera.scale()),
+            settings
+        );
         let (id, editing) =
             match_or_panic!(status, Status::SingleEditing(id, editing), (*id, editing));
 
@@ -1020,7 +1052,13 @@ impl PathTool
 
     /// Draws the tool.
     #[inline]
-    pub fn draw(&self, bundle: &mut DrawBundle, manager: &EntitiesManager, show_tooltips: bool)
+    pub fn draw(
+        &self,
+        bundle: &mut DrawBundle,
+        manager: &EntitiesManager,
+        settings: &ToolsSettings,
+        show_tooltips: bool
+    )
     {
         let DrawBundle {
             window,
@@ -1029,6 +1067,7 @@ impl PathTool
             camera,
             cursor,
             things_catalog,
+            snap_threshold,
             ..
         } = bundle;
 
@@ -1159,7 +1198,13 @@ impl PathTool
         if matches!(self.status, Status::SingleEditing(..))
         {
             drawer.square_highlight(
-                PathTool::cursor_pos(&self.status, cursor).unwrap(),
+                PathTool::single_editing_cursor_pos(
+                    &self.status,
+                    cursor,
+                    manager,
+                    snap_threshold.distance(camera.scale()),
+                    settings
+                ),
                 Color::CursorPolygon
             );
         }
@@ -1325,9 +1370,13 @@ impl PathTool
         edits_history: &mut EditsHistory,
         clipboard: &mut Clipboard,
         inputs: &InputsPresses,
-        ui: &mut egui::Ui
+        ui: &mut egui::Ui,
+        settings: &mut ToolsSettings
     ) -> bool
     {
+        ui.checkbox(&mut settings.path_geometry_snap, "Snap nodes to brushes")
+            .on_hover_text("Snaps the new nodes to the nearby brush centers and vertexes");
+
         self.nodes_editor.show(
             manager,
             edits_history,
@@ -1407,6 +1456,44 @@ fn is_anchored_to_selected_moving(manager: &EntitiesManager, identifier: Id) ->
 
 //=======================================================================//
 
+/// Returns the center or vertex of a brush, other than the one with [`Id`] `identifier`, within
+/// `snap_distance` from `cursor_pos`, if any.
+#[inline]
+#[must_use]
+fn brush_snap_pos(
+    manager: &EntitiesManager,
+    identifier: Id,
+    cursor_pos: Vec2,
+    snap_distance: f32
+) -> Option<Vec2>
+{
+    let range = Hull::new(
+        cursor_pos.y + snap_distance,
+        cursor_pos.y - snap_distance,
+        cursor_pos.x - snap_distance,
+        cursor_pos.x + snap_distance
+    );
+    let ids_in_range = manager.brushes_in_range(&range);
+
+    ids_in_range
+        .iter()
+        .copied()
+        .filter_set(identifier)
+        .map(|id| manager.brush(id))
+        .find_map(|brush| {
+            let center = brush.center();
+
+            if center.distance(cursor_pos) <= snap_distance
+            {
+                return center.into();
+            }
+
+            brush.snap_vertex(cursor_pos, snap_distance)
+        })
+}
+
+//=======================================================================//
+
 /// Whether the entity with [`Id`] `identifier` moves.
 #[inline]
 #[must_use]
@@ -15,7 +15,10 @@ use crate::{
             manager::EntitiesManager,
             ui::overall_value_field::{OverallValueField, Response}
         },
-        path::overall_values::{OverallMovement, UiOverallMovement}
+        path::{
+            nodes::Movement,
+            overall_values::{OverallMovement, UiOverallMovement}
+        }
     },
     utils::{
         identifiers::EntityId,
@@ -97,13 +100,37 @@ macro_rules! movement_values {
 //=======================================================================//
 
 /// The [`Node`]s editor.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(in crate::map::editor::state::core) struct NodesEditor
 {
     /// The overall [`Node`]s movement parameters.
     selected_nodes_movement: UiOverallMovement,
     /// The index of the UI element being interacted with.
-    interacting:             [bool; 5]
+    interacting:             [bool; 5],
+    /// The increment the standby times are rounded to by the quantization.
+    standby_time_step:       f32,
+    /// The increment the speeds are rounded to by the quantization.
+    speed_step:              f32,
+    /// The increment the travel percentages are rounded to by the quantization.
+    percentage_step:         f32,
+    /// The copied movement parameters.
+    copied_movement:         Option<Movement>
+}
+
+impl Default for NodesEditor
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            selected_nodes_movement: UiOverallMovement::default(),
+            interacting:             [false; 5],
+            standby_time_step:       0.25,
+            speed_step:              8f32,
+            percentage_step:         5f32,
+            copied_movement:         None
+        }
+    }
 }
 
 impl NodesEditor
@@ -167,7 +194,7 @@ impl NodesEditor
         self.interacting = [false; 5];
         ui.label(egui::RichText::new("PLATFORM TOOL"));
 
-        egui::Grid::new("nodes_editor")
+        let mut focused = egui::Grid::new("nodes_editor")
             .num_columns(2)
             .spacing([10f32, 4f32])
             .striped(true)
@@ -206,7 +233,136 @@ impl NodesEditor
                         simulation_active,
                     )
             })
-            .inner
+            .inner;
+
+        ui.separator();
+
+        focused |= egui::Grid::new("nodes_quantization")
+            .num_columns(2)
+            .spacing([10f32, 4f32])
+            .striped(true)
+            .show(ui, |ui| {
+                let mut focused = false;
+
+                for (label, step, range) in [
+                    ("Standby step", &mut self.standby_time_step, 0.01..=60f32),
+                    ("Speed step", &mut self.speed_step, 0.1..=1024f32),
+                    ("Percentage step", &mut self.percentage_step, 1f32..=50f32)
+                ]
+                {
+                    ui.label(label);
+                    focused |= ui
+                        .add(egui::DragValue::new(step).speed(0.1).clamp_range(range))
+                        .has_focus();
+                    ui.end_row();
+                }
+
+                focused
+            })
+            .inner;
+
+        if ui
+            .add_enabled(!simulation_active, egui::Button::new("Quantize timing"))
+            .clicked()
+        {
+            self.quantize(manager, edits_history);
+        }
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            let movement = Self::selected_node_movement(manager);
+
+            if ui
+                .add_enabled(movement.is_some(), egui::Button::new("Copy movement"))
+                .on_hover_text("Copy the movement parameters of the selected node")
+                .clicked()
+            {
+                self.copied_movement = movement;
+            }
+
+            if ui
+                .add_enabled(
+                    !simulation_active && self.copied_movement.is_some(),
+                    egui::Button::new("Paste movement")
+                )
+                .on_hover_text("Apply the copied movement parameters to the selected nodes")
+                .clicked()
+            {
+                self.paste_movement(manager, edits_history);
+            }
+        });
+
+        focused
+    }
+
+    /// Returns the [`Movement`] of the selected [`Node`], if there is exactly one.
+    #[inline]
+    #[must_use]
+    fn selected_node_movement(manager: &EntitiesManager) -> Option<Movement>
+    {
+        let mut movements = manager
+            .selected_moving()
+            .flat_map(|moving| moving.path().unwrap().selected_nodes_movements());
+        let movement = *movements.next()?;
+        movements.next().is_none().then_some(movement)
+    }
+
+    /// Sets the movement values of the selected [`Node`]s to the ones of the copied [`Movement`].
+    #[inline]
+    fn paste_movement(&mut self, manager: &mut EntitiesManager, edits_history: &mut EditsHistory)
+    {
+        let movement = return_if_none!(self.copied_movement);
+        let mut overall = OverallMovement::new();
+
+        // The maximum speed and the acceleration are set first so that the minimum speed and
+        // the deceleration can always be set to the copied values.
+        _ = Self::set_standby_time(manager, edits_history, movement.standby_time(), &mut overall);
+        _ = Self::set_max_speed(manager, edits_history, movement.max_speed(), &mut overall);
+        _ = Self::set_min_speed(manager, edits_history, movement.min_speed(), &mut overall);
+        _ = Self::set_accel_travel_percentage(
+            manager,
+            edits_history,
+            movement.accel_travel_percentage(),
+            &mut overall
+        );
+        _ = Self::set_decel_travel_percentage(
+            manager,
+            edits_history,
+            movement.decel_travel_percentage(),
+            &mut overall
+        );
+
+        self.update_overall_node(manager);
+    }
+
+    /// Rounds the movement values of the selected [`Node`]s to the closest multiples of their
+    /// respective steps.
+    #[inline]
+    fn quantize(&mut self, manager: &mut EntitiesManager, edits_history: &mut EditsHistory)
+    {
+        /// Quantizes the value and stores the edits in the history.
+        macro_rules! quantize {
+            ($(($value:ident, $step:ident, $clamp:ident)),+) => { paste::paste! { $(
+                edits_history.[< path_nodes_ $value _cluster >](
+                    manager.selected_movings_mut().filter_map(|mut entity| {
+                        entity
+                            .[< quantize_selected_path_nodes_ $value >](self.$step, $clamp)
+                            .map(|edit| (entity.id(), edit))
+                    })
+                );
+            )+}};
+        }
+
+        quantize!(
+            (standby_time, standby_time_step, zero_clamp),
+            (max_speed, speed_step, one_clamp),
+            (min_speed, speed_step, zero_clamp),
+            (accel_travel_percentage, percentage_step, travel_percentage_clamp),
+            (decel_travel_percentage, percentage_step, travel_percentage_clamp)
+        );
+
+        self.update_overall_node(manager);
     }
 
     /// Updates the overall [`Node`]s info.
@@ -24,6 +24,7 @@ use crate::{
                 core::tool::subtools_buttons,
                 editor_state::{edit_target, InputsPresses, ToolsSettings},
                 edits_history::EditsHistory,
+                grid::Grid,
                 manager::EntitiesManager,
                 ui::ToolsButtons
             },
@@ -301,10 +302,11 @@ impl RotateTool
         inputs: &InputsPresses,
         edits_history: &mut EditsHistory,
         settings: &mut ToolsSettings,
-        grid_size: i16
+        grid: Grid
     )
     {
         let ToolUpdateBundle { camera, cursor, .. } = bundle;
+        let snap = cursor.snap();
 
         if inputs.plus.just_pressed()
         {
@@ -323,9 +325,9 @@ impl RotateTool
             {
                 if inputs.alt_pressed()
                 {
-                    if let Some(dir) = inputs.directional_keys_vector(grid_size)
+                    if let Some(dir) = inputs.directional_keys_vector(grid.size())
                     {
-                        self.pivot += dir;
+                        self.move_pivot(dir, grid, snap);
                     }
                 }
                 else if inputs.right.just_pressed()
@@ -359,9 +361,9 @@ impl RotateTool
             },
             Status::MovePivotUi =>
             {
-                if let Some(dir) = inputs.directional_keys_vector(grid_size)
+                if let Some(dir) = inputs.directional_keys_vector(grid.size())
                 {
-                    self.pivot += dir;
+                    self.move_pivot(dir, grid, snap);
                 }
                 else if inputs.left_mouse.pressed()
                 {
@@ -549,9 +551,16 @@ impl RotateTool
     {
         let mut payloads = hv_vec![];
 
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager.selected_brushes_mut().find_map(|mut brush| {
-                match brush.check_rotate(bundle.drawing_resources, pivot, angle, rotate_texture)
+                match brush.check_rotate(
+                    bundle.drawing_resources,
+                    pivot,
+                    angle,
+                    rotate_texture,
+                    bound
+                )
                 {
                     RotateResult::Invalid => brush.id().into(),
                     RotateResult::Valid(payload) =>
@@ -591,12 +600,16 @@ impl RotateTool
     {
         let angle = angle.to_degrees();
 
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager.selected_brushes_with_sprite_mut().find_map(|mut brush| {
                 let prev_angle = brush.texture_settings().unwrap().angle();
 
-                (!brush
-                    .check_texture_angle(bundle.drawing_resources, prev_angle - angle.to_degrees()))
+                (!brush.check_texture_angle(
+                    bundle.drawing_resources,
+                    prev_angle - angle.to_degrees(),
+                    bound
+                ))
                 .then_some(brush.id())
             })
         });
@@ -615,6 +628,19 @@ impl RotateTool
         true
     }
 
+    /// Moves the pivot by `delta`. If `snap` is true the pivot is also snapped to the closest
+    /// vertex of `grid`.
+    #[inline]
+    fn move_pivot(&mut self, delta: Vec2, grid: Grid, snap: bool)
+    {
+        self.pivot += delta;
+
+        if snap
+        {
+            self.pivot = grid.snap_point(self.pivot).unwrap_or(self.pivot);
+        }
+    }
+
     /// Updates the rotation pivot.
     #[inline]
     pub fn update_pivot(&mut self, manager: &EntitiesManager, settings: &ToolsSettings)
@@ -277,14 +277,15 @@ impl ScaleTool
                     Corner::BottomRight => Vec2::new(dir.x, -dir.y)
                 };
 
+                let bound = manager.map_bound();
                 let valid = manager.test_operation_validity(|manager| {
                     manager.selected_textured_brushes_mut().find_map(|mut brush| {
                         let texture = brush.texture_settings().unwrap();
                         let scale_x = texture.scale_x() + dir.x;
                         let scale_y = texture.scale_y() + dir.y;
 
-                        (!brush.check_texture_scale_x(drawing_resources, scale_x) ||
-                            !brush.check_texture_scale_y(drawing_resources, scale_y))
+                        (!brush.check_texture_scale_x(drawing_resources, scale_x, bound) ||
+                            !brush.check_texture_scale_y(drawing_resources, scale_y, bound))
                         .then_some(brush.id())
                     })
                 });
@@ -322,6 +323,7 @@ impl ScaleTool
         scale_texture: bool
     )
     {
+        let bound = manager.map_bound();
         let (new_hull, payloads) = match hull.scaled(selected_corner, new_corner_position)
         {
             ScaleResult::None => return,
@@ -334,7 +336,12 @@ impl ScaleTool
                     manager.selected_brushes_mut().find_map(|mut brush| {
                         use crate::map::brush::ScaleResult;
 
-                        match brush.check_scale(bundle.drawing_resources, &info, scale_texture)
+                        match brush.check_scale(
+                            bundle.drawing_resources,
+                            &info,
+                            scale_texture,
+                            bound
+                        )
                         {
                             ScaleResult::Invalid => brush.id().into(),
                             ScaleResult::Valid(payload) =>
@@ -368,7 +375,8 @@ impl ScaleTool
                             bundle.drawing_resources,
                             &info,
                             &flip_queue,
-                            scale_texture
+                            scale_texture,
+                            bound
                         )
                         {
                             ScaleResult::Invalid => brush.id().into(),
@@ -430,14 +438,15 @@ impl ScaleTool
         let info = ScaleInfo::new(hull, &new_hull).unwrap();
         let multi = Vec2::new(info.width_multi(), info.height_multi());
 
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager.selected_textured_brushes_mut().find_map(|mut brush| {
                 let texture = brush.texture_settings().unwrap();
                 let scale_x = texture.scale_x() * multi.x;
                 let scale_y = texture.scale_y() * multi.y;
 
-                (!brush.check_texture_scale_x(drawing_resources, scale_x) ||
-                    !brush.check_texture_scale_y(drawing_resources, scale_y))
+                (!brush.check_texture_scale_x(drawing_resources, scale_x, bound) ||
+                    !brush.check_texture_scale_y(drawing_resources, scale_y, bound))
                 .then_some(brush.id())
             })
         });
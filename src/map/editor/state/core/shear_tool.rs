@@ -214,10 +214,11 @@ impl ShearTool
             ($xy:ident, $dimension:ident, $pivot:ident, $check:ident, $shear:ident) => {{
                 let info = ShearInfo::new(delta.$xy, outline.$dimension(), outline.$pivot());
                 let mut payloads = hv_vec![capacity; manager.selected_brushes_amount()];
+                let bound = manager.map_bound();
 
                 let valid = manager.test_operation_validity(|manager| {
                     manager.selected_brushes().find_map(|brush| {
-                        match brush.$check(&info)
+                        match brush.$check(&info, bound)
                         {
                             ShearResult::Valid(payload) =>
                             {
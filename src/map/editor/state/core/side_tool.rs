@@ -3,9 +3,11 @@
 //
 //=======================================================================//
 
+use std::f32::consts::FRAC_PI_2;
+
 use bevy::prelude::Vec2;
 use bevy_egui::egui;
-use hill_vacuum_shared::{match_or_panic, return_if_no_match, return_if_none};
+use hill_vacuum_shared::{match_or_panic, return_if_no_match, return_if_none, NextValue};
 
 use super::{
     cursor_delta::CursorDelta,
@@ -37,6 +39,7 @@ use crate::{
                 XtrusionInfo
             },
             Brush,
+            RotateResult,
             SidesDeletionResult,
             VertexesMoveResult,
             XtrusionPayload,
@@ -48,7 +51,7 @@ use crate::{
             cursor_pos::Cursor,
             state::{
                 core::rect::{self, RectTrait},
-                editor_state::InputsPresses,
+                editor_state::{InputsPresses, ToolsSettings},
                 edits_history::EditsHistory,
                 grid::Grid,
                 manager::EntitiesManager,
@@ -63,7 +66,12 @@ use crate::{
         hull::{EntityHull, Hull},
         identifiers::{EntityId, Id},
         iterators::FilterSet,
-        math::{lines_and_segments::closest_point_on_line, AroundEqual, HashVec2},
+        math::{
+            lines_and_segments::closest_point_on_line,
+            points::rotate_point,
+            AroundEqual,
+            HashVec2
+        },
         misc::{Camera, TakeValue}
     }
 };
@@ -459,7 +467,8 @@ impl SideTool
         manager: &mut EntitiesManager,
         inputs: &InputsPresses,
         edits_history: &mut EditsHistory,
-        grid: Grid
+        grid: Grid,
+        settings: &ToolsSettings
     )
     {
         let cursor_pos = Self::cursor_pos(bundle.cursor);
@@ -548,6 +557,22 @@ impl SideTool
                     return;
                 }
 
+                if inputs.enter.just_pressed()
+                {
+                    if inputs.ctrl_pressed()
+                    {
+                        // Side arc.
+                        Self::bulge_selected_side(bundle, manager, edits_history, settings);
+                    }
+                    else
+                    {
+                        // Side alignment.
+                        Self::align_selected_side(bundle, manager, edits_history);
+                    }
+
+                    return;
+                }
+
                 if inputs.ctrl_pressed()
                 {
                     return;
@@ -767,9 +792,10 @@ impl SideTool
         // Evaluate if the move is valid for all vertexes/sides.
         let mut move_payloads = hv_vec![];
 
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager.selected_brushes_mut().find_map(|mut brush| {
-                match brush.check_selected_sides_move(delta)
+                match brush.check_selected_sides_move(delta, bound)
                 {
                     VertexesMoveResult::None => (),
                     VertexesMoveResult::Invalid => return brush.id().into(),
@@ -883,6 +909,122 @@ impl SideTool
         }));
     }
 
+    /// Rotates the brush with the only selected side around its center so that the side becomes
+    /// horizontal or vertical, whichever requires the smallest rotation.
+    #[inline]
+    fn align_selected_side(
+        bundle: &ToolUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    )
+    {
+        let id = {
+            let mut iter = manager
+                .selected_brushes()
+                .filter(|brush| brush.selected_sides_amount() != 0);
+            let brush = return_if_none!(iter.next());
+
+            if brush.selected_sides_amount() != 1 || iter.next().is_some()
+            {
+                return;
+            }
+
+            brush.id()
+        };
+
+        let (pivot, angle, a, b) = {
+            let brush = manager.brush(id);
+            let mut vxs = brush.selected_sides_vertexes().unwrap();
+            let (a, b) = (vxs.next_value(), vxs.next_value());
+            let side = a - b;
+            let angle = side.y.atan2(side.x);
+
+            (brush.center(), (angle / FRAC_PI_2).round() * FRAC_PI_2 - angle, a, b)
+        };
+
+        if angle.around_equal_narrow(&0f32)
+        {
+            return;
+        }
+
+        let mut payload = None;
+        let bound = manager.map_bound();
+        let valid = manager.test_operation_validity(|manager| {
+            match manager
+                .brush_mut(id)
+                .check_rotate(bundle.drawing_resources, pivot, angle, true, bound)
+            {
+                RotateResult::Invalid => id.into(),
+                RotateResult::Valid(p) =>
+                {
+                    payload = p.into();
+                    None
+                }
+            }
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        // The trigonometric rotation leaves the side slightly off axis.
+        let mut payload = payload.unwrap();
+        payload.straighten_side(rotate_point(a, pivot, angle), rotate_point(b, pivot, angle));
+
+        edits_history.polygon_edit(id, manager.brush(id).polygon());
+        manager
+            .brush_mut(id)
+            .set_rotation_coordinates(bundle.drawing_resources, payload);
+    }
+
+    /// Replaces the only selected side with an arc bulging outward, with the radius and amount of
+    /// segments specified in `settings`.
+    #[inline]
+    fn bulge_selected_side(
+        bundle: &ToolUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings
+    )
+    {
+        let id = {
+            let mut iter = manager
+                .selected_brushes()
+                .filter(|brush| brush.selected_sides_amount() != 0);
+            let brush = return_if_none!(iter.next());
+
+            if brush.selected_sides_amount() != 1 || iter.next().is_some()
+            {
+                return;
+            }
+
+            brush.id()
+        };
+
+        let mut polygon = None;
+        let bound = manager.map_bound();
+        let valid = manager.test_operation_validity(|manager| {
+            polygon = manager.brush(id).check_side_arc(
+                bundle.drawing_resources,
+                settings.arc_radius,
+                settings.arc_segments,
+                bound
+            );
+
+            polygon.is_none().then_some(id)
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        let mut polygon = polygon.unwrap();
+        manager.brush_mut(id).swap_polygon(&mut polygon);
+        edits_history.polygon_edit(id, polygon);
+    }
+
     /// Selects the sides that fit in the drag selection.
     #[inline]
     fn select_sides_from_drag_selection(
@@ -962,6 +1104,8 @@ impl SideTool
         grid: Grid
     )
     {
+        let bound = manager.map_bound();
+
         drag.conditional_update(bundle.cursor, grid, line, |delta| {
             let XtrusionMode::Xtrusion(payloads) = mode
             else
@@ -995,7 +1139,8 @@ impl SideTool
                         payloads.take_value().into_iter().find_map(|payload| {
                             match payload.info().create_extrusion_polygon(
                                 delta,
-                                manager.brush(payload.id()).texture_settings()
+                                manager.brush(payload.id()).texture_settings(),
+                                bound
                             )
                             {
                                 Some(poly) =>
@@ -1056,12 +1201,14 @@ impl SideTool
         grid: Grid
     )
     {
+        let bound = manager.map_bound();
+
         drag.conditional_update(bundle.cursor, grid, line, |delta| {
             let mut extruded_sides = Vec::with_capacity(polygons.len());
 
             let valid = manager.test_operation_validity(|_| {
                 polygons.iter().find_map(|(id, info, poly)| {
-                    match info.check_side_extrusion(poly, delta)
+                    match info.check_side_extrusion(poly, delta, bound)
                     {
                         ExtrusionResult::Invalid => (*id).into(),
                         ExtrusionResult::Valid(pl) =>
@@ -1262,6 +1409,34 @@ impl SideTool
         };
     }
 
+    /// Draws the UI. Returns whether the arc radius or segments fields have focus.
+    #[inline]
+    #[must_use]
+    pub fn ui(ui: &mut egui::Ui, settings: &mut ToolsSettings) -> bool
+    {
+        ui.label(egui::RichText::new("SIDE TOOL"));
+
+        egui::Grid::new("side_arc")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Arc radius");
+                let mut focused = ui
+                    .add(egui::DragValue::new(&mut settings.arc_radius).clamp_range(1f32..=4096f32))
+                    .on_hover_text("Ctrl+Enter replaces the selected side with an arc")
+                    .has_focus();
+                ui.end_row();
+
+                ui.label("Segments");
+                focused |= ui
+                    .add(egui::DragValue::new(&mut settings.arc_segments).clamp_range(2..=32))
+                    .has_focus();
+                ui.end_row();
+
+                focused
+            })
+            .inner
+    }
+
     /// Draws the subtools.
     #[inline]
     pub fn draw_subtools(
@@ -152,6 +152,17 @@ impl ThingTool
         }
     }
 
+    /// Deselects the drawn things if the spawned entities must not be left selected.
+    #[inline]
+    pub fn deselect_drawn_things(
+        &self,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    )
+    {
+        manager.deselect_spawned_entities(edits_history, &self.drawn_things);
+    }
+
     /// Post undo/redo spawn.
     #[inline]
     pub fn undo_redo_spawn(&mut self, manager: &EntitiesManager, identifier: Id)
@@ -231,14 +242,16 @@ impl ThingTool
         }
     }
 
-    /// The left UI panel.
+    /// The left UI panel. Returns whether a value field has focus.
     #[inline]
-    pub fn left_panel(ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    #[must_use]
+    pub fn left_panel(ui: &mut egui::Ui, settings: &mut ToolsSettings) -> bool
     {
         /// The width of the label.
         const LABEL_WIDTH: f32 = 50f32;
 
         ui.spacing_mut().item_spacing.x = 2f32;
+        let mut focused = false;
 
         egui_extras::StripBuilder::new(ui)
             .sizes(egui_extras::Size::exact(18f32), 4)
@@ -255,7 +268,32 @@ impl ThingTool
                             settings.thing_pivot.ui(&mut strip);
                         });
                 });
+
+                strip.strip(|strip| {
+                    strip
+                        .size(egui_extras::Size::exact(LABEL_WIDTH))
+                        .size(egui_extras::Size::remainder())
+                        .horizontal(|mut strip| {
+                            strip.cell(|ui| {
+                                ui.label("First spawn");
+                            });
+
+                            strip.cell(|ui| {
+                                focused |= ui
+                                    .add(
+                                        egui::DragValue::new(&mut settings.first_spawn_order)
+                                            .speed(1)
+                                    )
+                                    .on_hover_text(
+                                        "Spawn order assigned to the first of the selected things"
+                                    )
+                                    .has_focus();
+                            });
+                        });
+                });
             });
+
+        focused
     }
 
     /// Bottom UI panel.
@@ -315,10 +353,11 @@ impl ThingTool
         self.status = Status::Inactive(());
 
         let clicked = things_catalog.thing_at_index(clicked);
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager
                 .selected_things()
-                .find_map(|thing| (!thing.check_thing_change(clicked)).then_some(thing.id()))
+                .find_map(|thing| (!thing.check_thing_change(clicked, bound)).then_some(thing.id()))
         });
 
         if !valid
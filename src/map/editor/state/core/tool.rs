@@ -12,6 +12,7 @@ use hill_vacuum_shared::{match_or_panic, return_if_no_match, return_if_none, Nex
 
 use super::{
     clip_tool::ClipTool,
+    draw_selected_and_non_selected_brushes,
     draw_selected_and_non_selected_things,
     draw_tool::{cursor_polygon::FreeDrawStatus, DrawTool},
     entity_tool::EntityTool,
@@ -32,10 +33,11 @@ use super::{
     Core
 };
 use crate::{
-    config::controls::{bind::Bind, BindsKeyCodes},
+    config::controls::{bind::Bind, chord::SubToolChord, BindsKeyCodes},
     map::{
         brush::{convex_polygon::ConvexPolygon, Brush},
         containers::HvHashSet,
+        custom_tool::CustomTools,
         drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{
             state::{
@@ -144,6 +146,16 @@ where
 
 //=======================================================================//
 
+/// A trait to return the name of the tool in the config file.
+pub(in crate::map::editor::state) trait ConfigName
+{
+    /// The name of the tool in the config file.
+    #[must_use]
+    fn config_name(self) -> &'static str;
+}
+
+//=======================================================================//
+
 /// A trait to return whether the tool is enabled.
 pub(in crate::map::editor::state) trait EnabledTool
 {
@@ -329,7 +341,8 @@ pub(in crate::map::editor::state::core) enum ActiveTool
     Path(PathTool),
     Paint(PaintTool),
     Thing(ThingTool),
-    MapPreview(MapPreviewTool)
+    MapPreview(MapPreviewTool),
+    Custom(usize)
 }
 
 impl Default for ActiveTool
@@ -362,7 +375,7 @@ impl EnabledTool for ActiveTool
             Self::Path(_) => Tool::Path,
             Self::Paint(_) => Tool::Paint,
             Self::Thing(_) => Tool::Thing,
-            Self::MapPreview { .. } => return false
+            Self::MapPreview { .. } | Self::Custom(_) => return false
         }
     }
 }
@@ -452,6 +465,14 @@ impl ActiveTool
     #[must_use]
     pub const fn entity_tool(&self) -> bool { matches!(self, Self::Entity(_)) }
 
+    /// Whether the path tool is active and it is not simulating the movement of the paths.
+    #[inline]
+    #[must_use]
+    pub const fn path_tool(&self) -> bool
+    {
+        matches!(self, Self::Path(_)) && !self.path_simulation_active()
+    }
+
     /// Whether a tool with texture editing capabilities is available.
     #[inline]
     #[must_use]
@@ -494,6 +515,18 @@ impl ActiveTool
     #[must_use]
     pub const fn map_preview(&self) -> bool { matches!(self, Self::MapPreview { .. }) }
 
+    /// Returns the index of the custom tool, if it is the active one.
+    #[inline]
+    #[must_use]
+    pub const fn custom_tool(&self) -> Option<usize>
+    {
+        match self
+        {
+            Self::Custom(index) => Some(*index),
+            _ => None
+        }
+    }
+
     //==============================================================
     // Copy/Paste
 
@@ -505,7 +538,11 @@ impl ActiveTool
         match self
         {
             Self::Draw(_) | Self::Zoom(_) | Self::MapPreview { .. } => false,
-            Self::Shatter(_) | Self::Subtract(_) | Self::Flip(_) | Self::Thing(_) => true,
+            Self::Shatter(_) |
+            Self::Subtract(_) |
+            Self::Flip(_) |
+            Self::Thing(_) |
+            Self::Custom(_) => true,
             Self::Entity(t) => !t.ongoing_multi_frame_change(),
             Self::Vertex(t) => !t.ongoing_multi_frame_change(),
             Self::Side(t) => !t.ongoing_multi_frame_change(),
@@ -773,7 +810,7 @@ impl ActiveTool
                 let path = return_if_none!(t.update(bundle, manager, inputs, edits_history, grid));
                 *self = PathTool::path_connection(bundle, manager, inputs, path);
             },
-            Self::Side(t) => t.update(bundle, manager, inputs, edits_history, grid),
+            Self::Side(t) => t.update(bundle, manager, inputs, edits_history, grid, settings),
             Self::Clip(t) => t.update(bundle, manager, inputs, edits_history),
             Self::Shatter(t) => t.update(bundle, manager, inputs, edits_history),
             Self::Subtract(t) =>
@@ -788,7 +825,7 @@ impl ActiveTool
             Self::Shear(t) => t.update(bundle, manager, inputs, edits_history, grid),
             Self::Rotate(t) =>
             {
-                t.update(bundle, manager, inputs, edits_history, settings, grid.size());
+                t.update(bundle, manager, inputs, edits_history, settings, grid);
             },
             Self::Flip(t) =>
             {
@@ -798,7 +835,7 @@ impl ActiveTool
             {
                 *self = std::mem::take(return_if_none!(t.update(bundle, inputs)));
             },
-            Self::Path(t) => t.update(bundle, manager, inputs, edits_history, grid),
+            Self::Path(t) => t.update(bundle, manager, inputs, edits_history, grid, settings),
             Self::Paint(t) =>
             {
                 t.update(bundle, manager, inputs, edits_history, clipboard, grid);
@@ -807,7 +844,8 @@ impl ActiveTool
             {
                 t.update(bundle, manager, inputs, edits_history, settings);
             },
-            Self::MapPreview(t) => t.update(bundle, manager)
+            Self::MapPreview(t) => t.update(bundle, manager, settings),
+            Self::Custom(_) => unreachable!()
         };
     }
 
@@ -830,8 +868,8 @@ impl ActiveTool
             "Requested tool change to unavailable tool {tool:?}"
         );
         assert!(
-            !edits_history.multiframe_edit(),
-            "Requested tool change during multiframe edit."
+            !edits_history.multiframe_edit() && !edits_history.grouping(),
+            "Requested tool change during multiframe edit or edit group."
         );
 
         if matches!((&*self, tool), (Self::Zoom(_), Tool::Zoom))
@@ -839,6 +877,51 @@ impl ActiveTool
             return;
         }
 
+        // The transforms of the whole selection must be confirmed if it is large.
+        let bulk_operation = match tool
+        {
+            Tool::Snap => "Snap".into(),
+            Tool::Hollow => "Hollow".into(),
+            Tool::Scale => "Scale".into(),
+            Tool::Shear => "Shear".into(),
+            Tool::Rotate => "Rotate".into(),
+            Tool::Flip => "Flip".into(),
+            Tool::Merge => "Merge".into(),
+            _ => None
+        };
+
+        if let Some(operation) = bulk_operation
+        {
+            if !manager.confirm_bulk_operation(operation)
+            {
+                return;
+            }
+        }
+
+        // Deselect the entities spawned by the tool being replaced, if required.
+        if !matches!(
+            tool,
+            Tool::Zoom | Tool::Snap | Tool::Hollow | Tool::Intersection | Tool::Merge
+        )
+        {
+            match self
+            {
+                Self::Draw(t)
+                    if !matches!(
+                        tool,
+                        Tool::Square | Tool::Triangle | Tool::Circle | Tool::FreeDraw
+                    ) =>
+                {
+                    t.deselect_drawn_brushes(manager, edits_history);
+                },
+                Self::Thing(t) if tool != Tool::Thing =>
+                {
+                    t.deselect_drawn_things(manager, edits_history);
+                },
+                _ => ()
+            };
+        }
+
         // Tool change.
         *self = match tool
         {
@@ -930,8 +1013,10 @@ impl ActiveTool
             grid: Grid
         ) -> bool
         {
+            let bound = manager.map_bound();
+
             manager.selected_things_mut().fold(false, |acc, mut thing| {
-                edits_history.thing_move(thing.id(), return_if_none!(thing.snap(grid), acc));
+                edits_history.thing_move(thing.id(), return_if_none!(thing.snap(grid, bound), acc));
 
                 true
             })
@@ -1095,6 +1180,48 @@ impl ActiveTool
         self.update_outline(manager, settings, grid);
     }
 
+    /// Generates a new brush representing the intersection of the two selected brushes, leaving
+    /// them untouched. If the brushes do not overlap the error is highlighted.
+    #[inline]
+    pub fn intersection_copy_tool(
+        &mut self,
+        brushes_default_properties: &DefaultProperties,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings,
+        grid: Grid
+    )
+    {
+        if manager.selected_brushes_amount() != 2
+        {
+            return;
+        }
+
+        let mut iter = manager.selected_brushes_ids();
+        let id_1 = *iter.next_value();
+        let id_2 = *iter.next_value();
+        drop(iter);
+
+        let intersection = manager.brush(id_1).intersection(manager.brush(id_2));
+
+        if !manager.test_operation_validity(|_| intersection.is_none().then_some(id_1))
+        {
+            return;
+        }
+
+        // Spawn the intersection brush and select only that one.
+        self.draw_tool_despawn(manager, edits_history, |manager, edits_history| {
+            manager.deselect_selected_entities(edits_history);
+            _ = manager.spawn_brush(
+                intersection.unwrap(),
+                edits_history,
+                brushes_default_properties.instance()
+            );
+        });
+
+        self.update_outline(manager, settings, grid);
+    }
+
     /// Merges all selected vertexes.
     #[inline]
     pub fn merge_vertexes(
@@ -1220,7 +1347,12 @@ impl ActiveTool
 
     /// Forcefully disables a tool and replaces it with another if certain circumstances are met.
     #[inline]
-    pub fn fallback(&mut self, manager: &EntitiesManager, clipboard: &Clipboard)
+    pub fn fallback(
+        &mut self,
+        manager: &EntitiesManager,
+        clipboard: &Clipboard,
+        custom_tools: &CustomTools
+    )
     {
         let tool = match self
         {
@@ -1231,6 +1363,19 @@ impl ActiveTool
         match tool
         {
             Self::Draw(..) | Self::MapPreview(_) | Self::Thing(_) => return,
+            Self::Custom(index) =>
+            {
+                if !custom_tools.change_conditions_met(
+                    *index,
+                    manager.selected_brushes_amount(),
+                    manager.selected_things_amount()
+                )
+                {
+                    *tool = Self::default();
+                }
+
+                return;
+            },
             Self::Entity(_) =>
             {
                 if manager.entities_amount() == 0
@@ -1379,7 +1524,7 @@ impl ActiveTool
             {
                 ActiveTool::Draw(t) => t.draw(bundle, manager, show_tooltips),
                 ActiveTool::Entity(t) => t.draw(bundle, manager, settings, show_tooltips),
-                ActiveTool::Vertex(t) => t.draw(bundle, manager, show_tooltips),
+                ActiveTool::Vertex(t) => t.draw(bundle, manager, settings, show_tooltips),
                 ActiveTool::Side(t) => t.draw(bundle, manager, show_tooltips),
                 ActiveTool::Clip(t) => t.draw(bundle, manager),
                 ActiveTool::Shatter(t) => t.draw(bundle, manager),
@@ -1395,11 +1540,15 @@ impl ActiveTool
                         sprites_and_anchors(bundle, manager);
                     }
 
-                    t.draw(bundle, manager, show_tooltips);
+                    t.draw(bundle, manager, settings, show_tooltips);
                     return;
                 },
                 ActiveTool::Paint(t) => t.draw(bundle, manager),
                 ActiveTool::Thing(t) => t.draw(bundle, manager),
+                ActiveTool::Custom(_) =>
+                {
+                    draw_selected_and_non_selected_brushes!(bundle, manager);
+                },
                 _ => unreachable!()
             };
 
@@ -1483,15 +1632,21 @@ impl ActiveTool
         {
             match tool
             {
-                ActiveTool::Thing(_) => ThingTool::left_panel(ui, settings),
-                ActiveTool::Entity(t) => t.ui(ui, settings),
+                ActiveTool::Thing(_) => return ThingTool::left_panel(ui, settings),
+                ActiveTool::Vertex(_) => VertexTool::ui(ui, settings),
+                ActiveTool::Side(_) => return SideTool::ui(ui, settings),
+                ActiveTool::Entity(t) => return t.ui(ui, settings),
                 ActiveTool::Rotate(t) => t.ui(ui, settings),
                 ActiveTool::Draw(t) => t.ui(ui, settings),
                 ActiveTool::Clip(t) => t.ui(ui),
                 ActiveTool::Scale(t) => t.ui(ui, settings),
                 ActiveTool::Shear(t) => t.ui(ui),
                 ActiveTool::Flip(_) => FlipTool::ui(ui, settings),
-                ActiveTool::Path(t) => return t.ui(manager, edits_history, clipboard, inputs, ui),
+                ActiveTool::Path(t) =>
+                {
+                    return t.ui(manager, edits_history, clipboard, inputs, ui, settings);
+                },
+                ActiveTool::MapPreview(_) => MapPreviewTool::ui(ui, settings),
                 ActiveTool::Zoom(tool) =>
                 {
                     return draw_ui(
@@ -1606,6 +1761,39 @@ pub(in crate::map::editor::state) enum Tool
     Paint
 }
 
+impl ConfigName for Tool
+{
+    #[inline]
+    fn config_name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Square => "Square",
+            Self::Triangle => "Triangle",
+            Self::Circle => "Circle",
+            Self::FreeDraw => "FreeDraw",
+            Self::Thing => "Thing",
+            Self::Entity => "Entity",
+            Self::Vertex => "Vertex",
+            Self::Side => "Side",
+            Self::Snap => "Snap",
+            Self::Clip => "Clip",
+            Self::Shatter => "Shatter",
+            Self::Hollow => "Hollow",
+            Self::Scale => "Scale",
+            Self::Shear => "Shear",
+            Self::Rotate => "Rotate",
+            Self::Flip => "Flip",
+            Self::Intersection => "Intersection",
+            Self::Merge => "Merge",
+            Self::Subtract => "Subtract",
+            Self::Path => "Path",
+            Self::Zoom => "Zoom",
+            Self::Paint => "Paint"
+        }
+    }
+}
+
 impl Tool
 {
     /// Whether the bind associated with the tool was pressed.
@@ -1674,6 +1862,33 @@ pub(in crate::map::editor::state) enum SubTool
     ThingChange
 }
 
+impl ConfigName for SubTool
+{
+    #[inline]
+    fn config_name(self) -> &'static str
+    {
+        match self
+        {
+            Self::EntityDragSpawn => "EntityDragSpawn",
+            Self::EntityAnchor => "EntityAnchor",
+            Self::VertexInsert => "VertexInsert",
+            Self::VertexMerge => "VertexMerge",
+            Self::VertexSplit => "VertexSplit",
+            Self::VertexPolygonToPath => "VertexPolygonToPath",
+            Self::SideXtrusion => "SideXtrusion",
+            Self::SideMerge => "SideMerge",
+            Self::ClipSide => "ClipSide",
+            Self::RotatePivot => "RotatePivot",
+            Self::PathFreeDraw => "PathFreeDraw",
+            Self::PathAddNode => "PathAddNode",
+            Self::PathSimulation => "PathSimulation",
+            Self::PaintCreation => "PaintCreation",
+            Self::PaintQuick => "PaintQuick",
+            Self::ThingChange => "ThingChange"
+        }
+    }
+}
+
 impl SubTool
 {
     /// The key combination required to use the subtool.
@@ -1681,6 +1896,8 @@ impl SubTool
     #[must_use]
     fn key_combo(self, binds: &BindsKeyCodes) -> Cow<str>
     {
+        let chord = |subtool: SubToolChord| Cow::Owned(subtool.chord(binds).to_string());
+
         match self
         {
             Self::EntityDragSpawn | Self::SideXtrusion | Self::RotatePivot =>
@@ -1697,11 +1914,11 @@ impl SubTool
             {
                 Cow::Borrowed("Alt + left mouse click")
             },
-            Self::PathSimulation | Self::VertexSplit | Self::PaintCreation =>
-            {
-                Cow::Borrowed("Enter")
-            },
-            Self::VertexPolygonToPath | Self::PaintQuick => Cow::Borrowed("Shift + Enter")
+            Self::VertexSplit => chord(SubToolChord::VertexSplit),
+            Self::VertexPolygonToPath => chord(SubToolChord::VertexPolygonToPath),
+            Self::PathSimulation => chord(SubToolChord::PathSimulation),
+            Self::PaintCreation => chord(SubToolChord::PaintCreation),
+            Self::PaintQuick => Cow::Borrowed("Shift + Enter")
         }
     }
 }
@@ -46,7 +46,7 @@ use crate::{
             cursor_pos::Cursor,
             state::{
                 core::{rect, VertexesToggle},
-                editor_state::InputsPresses,
+                editor_state::{InputsPresses, ToolsSettings},
                 edits_history::EditsHistory,
                 grid::Grid,
                 manager::EntitiesManager,
@@ -64,7 +64,8 @@ use crate::{
         identifiers::{EntityId, Id},
         iterators::FilterSet,
         math::HashVec2,
-        misc::{Camera, TakeValue}
+        misc::{Camera, TakeValue},
+        tooltips::{draw_tooltip_x_centered_above_pos, to_egui_coordinates}
     },
     Path
 };
@@ -467,17 +468,15 @@ impl VertexTool
                     }
                 );
 
-                if inputs.enter.just_pressed()
+                if inputs.polygon_to_path.just_pressed()
                 {
-                    if inputs.shift_pressed()
-                    {
-                        self.0 = Status::PolygonToPath(PathCreation::None);
-                    }
-                    else
-                    {
-                        self.1.split_brushes(bundle.drawing_resources, manager, edits_history);
-                    }
+                    self.0 = Status::PolygonToPath(PathCreation::None);
+                    return None;
+                }
 
+                if inputs.vertex_split.just_pressed()
+                {
+                    self.1.split_brushes(bundle.drawing_resources, manager, edits_history);
                     return None;
                 }
 
@@ -602,11 +601,11 @@ impl VertexTool
 
                 if inputs.left_mouse.just_pressed()
                 {
+                    let snap_distance = bundle.snap_threshold.distance(camera_scale);
                     let pos = return_if_none!(
                         manager
-                            .selected_brushes_at_pos(cursor_pos, camera_scale)
-                            .iter()
-                            .find_map(|brush| brush.nearby_vertex(cursor_pos, camera_scale)),
+                            .selected_brushes()
+                            .find_map(|brush| brush.snap_vertex(cursor_pos, snap_distance)),
                         None
                     );
 
@@ -758,9 +757,10 @@ impl VertexTool
         // Evaluate if the move is valid for all vertexes/sides.
         let mut move_payloads = hv_vec![];
 
+        let bound = manager.map_bound();
         let valid = manager.test_operation_validity(|manager| {
             manager.selected_brushes_mut().find_map(|mut brush| {
-                match brush.check_selected_vertexes_move(delta)
+                match brush.check_selected_vertexes_move(delta, bound)
                 {
                     VertexesMoveResult::None => (),
                     VertexesMoveResult::Invalid => return brush.id().into(),
@@ -922,7 +922,13 @@ impl VertexTool
 
     /// Draws the tool.
     #[inline]
-    pub fn draw(&self, bundle: &mut DrawBundle, manager: &EntitiesManager, show_tooltips: bool)
+    pub fn draw(
+        &self,
+        bundle: &mut DrawBundle,
+        manager: &EntitiesManager,
+        settings: &ToolsSettings,
+        show_tooltips: bool
+    )
     {
         /// Draws the selected and non selected brushes.
         #[inline]
@@ -1027,8 +1033,58 @@ impl VertexTool
                 }
 
                 path.draw(window, camera, egui_context, drawer, show_tooltips, Vec2::ZERO);
+                return;
             }
         };
+
+        if settings.vertex_info
+        {
+            Self::draw_vertex_info(bundle, manager);
+        }
+    }
+
+    /// Draws the index and position of the vertex of the selected brushes beneath the cursor.
+    #[inline]
+    fn draw_vertex_info(bundle: &mut DrawBundle, manager: &EntitiesManager)
+    {
+        /// The label of the tooltip.
+        const LABEL: &str = "vertex_info";
+        /// The color of the tooltip.
+        const TOOLTIP_TEXT_COLOR: egui::Color32 = egui::Color32::from_rgb(127, 255, 212);
+
+        let DrawBundle {
+            window,
+            egui_context,
+            camera,
+            cursor,
+            ..
+        } = bundle;
+
+        let (index, pos) = return_if_none!(manager
+            .selected_brushes()
+            .find_map(|brush| brush.nearby_vertex_with_index(cursor.world(), camera.scale())));
+
+        draw_tooltip_x_centered_above_pos(
+            egui_context,
+            LABEL,
+            egui::Order::Background,
+            &format!("#{index} ({}, {})", pos.x, pos.y),
+            egui::TextStyle::Monospace,
+            to_egui_coordinates(pos, window, *camera),
+            egui::Vec2::new(0f32, -24f32),
+            TOOLTIP_TEXT_COLOR,
+            egui::Color32::from_black_alpha(200),
+            3f32
+        );
+    }
+
+    /// Draws the UI.
+    #[inline]
+    pub fn ui(ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    {
+        ui.label(egui::RichText::new("VERTEX TOOL"));
+        ui.checkbox(&mut settings.vertex_info, "Vertex info")
+            .on_hover_text("Shows the index and position of the vertex beneath the cursor");
     }
 
     /// Draws the subtools.
@@ -75,7 +75,8 @@ impl ZoomTool
 
         rect::update!(
             self.drag_selection,
-            cursor.world_snapped(),
+            // Not snapped, so that the framed area is exactly the drawn one.
+            cursor.world(),
             camera.scale(),
             inputs.left_mouse.pressed(),
             inputs.left_mouse.just_pressed(),
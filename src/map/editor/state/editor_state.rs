@@ -5,39 +5,56 @@
 
 use std::{
     fs::{File, OpenOptions},
-    io::{BufReader, BufWriter, Write},
+    io::{BufReader, BufWriter, Cursor, Write},
     path::{Path, PathBuf}
 };
 
-use bevy::prelude::*;
+use bevy::{prelude::*, render::camera::RenderTarget};
 use bevy_egui::{egui, EguiUserTextures};
 use hill_vacuum_proc_macros::{EnumFromUsize, EnumIter, EnumSize};
 use hill_vacuum_shared::{return_if_none, FILE_EXTENSION};
 use is_executable::IsExecutable;
+use serde::Serialize;
 
 use super::{
+    batch::{self, BatchBundle},
     clipboard::{Clipboard, PropCamerasMut},
+    comparison::MapComparison,
     core::{
         rotate_tool::RotateAngle,
-        tool::{ChangeConditions, Tool}
+        tool::{ChangeConditions, ConfigName, SubTool, Tool}
     },
     edits_history::EditsHistory,
-    grid::Grid,
-    input_press::InputStateHardCoded,
+    grid::{Grid, MeasurementGrid},
+    input_press::{ChordState, InputStateHardCoded},
     manager::EntitiesManager,
-    ui::Interaction
+    ui::Interaction,
+    view_history::ViewHistory
 };
 use crate::{
     config::{
-        controls::{bind::Bind, BindsKeyCodes},
-        OpenFile
+        controls::{bind::Bind, chord::SubToolChord, BindsKeyCodes, KeyRepeat},
+        HiddenTools,
+        OpenFile,
+        OperationsLogTarget,
+        SaveExport
     },
     error_message,
     map::{
+        brush::Brush,
+        containers::{hv_hash_set, hv_vec, HvHashSet, Ids},
+        custom_tool::CustomTools,
         drawer::{
+            animation::Animation,
             color::Color,
             drawing_resources::DrawingResources,
-            texture_loader::TextureLoadingProgress
+            texture::{DefaultAnimation, Texture, TextureInterface},
+            texture_loader::{
+                copy_texture_files,
+                TextureLoader,
+                TextureLoadingProgress,
+                TexturesWatcher
+            }
         },
         editor::{
             state::{
@@ -52,15 +69,19 @@ use crate::{
             StateUpdateBundle,
             ToolUpdateBundle
         },
+        map_render::{self, MapRenderRequest},
         properties::DefaultProperties,
-        thing::{catalog::ThingsCatalog, Thing},
+        thing::{catalog::ThingsCatalog, Thing, ThingInstance, ThingInterface},
+        ContentHash,
         MapHeader
     },
     utils::{
         hull::Hull,
-        misc::{next, prev, Camera, Toggle}
+        identifiers::EntityId,
+        misc::{next, prev, Camera, ReplaceValues, Toggle}
     },
     EditorState,
+    Exporter,
     HardcodedActions,
     NAME
 };
@@ -71,7 +92,7 @@ use crate::{
 //=======================================================================//
 
 /// The filter of the map file types.
-const HV_FILTER_NAME: &str = "HV files (.hv)";
+pub(in crate::map::editor::state) const HV_FILTER_NAME: &str = "HV files (.hv)";
 /// The filter of the animations files.
 const ANIMATIONS_FILTER_NAME: &str = "Animations files (.anms)";
 /// The filter of the props files.
@@ -80,6 +101,8 @@ const PROPS_FILTER_NAME: &str = "Props files (.prps)";
 const ANIMATIONS_EXTENSION: &str = "anms";
 /// The props file extension.
 const PROPS_EXTENSION: &str = "prps";
+/// The amount of selection memory slots.
+const SELECTION_MEMORIES: usize = 9;
 
 //=======================================================================//
 // MACROS
@@ -106,7 +129,9 @@ macro_rules! input_presses {
         $mouse_buttons:ident,
         $key_inputs:ident,
         $binds_inputs:ident,
-        $(($name:ident, $input_type:ty, $key:expr, $source:ident $(, $binds:ident)?)),+
+        $key_repeat:ident,
+        $delta_time:ident,
+        $(($name:ident, $input_type:ty, $key:expr, $source:ident $(, $extra:ident)*)),+
     ) => (
         /// A struct containing the states of all input presses required by the editor.
 		pub(in crate::map::editor::state) struct InputsPresses
@@ -133,10 +158,12 @@ macro_rules! input_presses {
                 &mut self,
                 $mouse_buttons: &ButtonInput<MouseButton>,
                 $key_inputs: &ButtonInput<KeyCode>,
-                $binds_inputs: &mut BindsKeyCodes
+                $binds_inputs: &mut BindsKeyCodes,
+                $key_repeat: &KeyRepeat,
+                $delta_time: f32
             )
 			{
-				$(self.$name.update($source $(, $binds)?);)+
+				$(self.$name.update($source $(, $extra)*);)+
 			}
 
             /// Forcefully resets the input presses to not pressed.
@@ -298,6 +325,25 @@ impl TargetSwitch
 
 //=======================================================================//
 
+/// The stage of the render of the map preview to an image file.
+#[derive(Default)]
+enum MapRender
+{
+    /// No render requested.
+    #[default]
+    None,
+    /// The camera should be set up to render an image of the contained size to the file at the
+    /// contained path.
+    Setup(PathBuf, UVec2),
+    /// The whole map should be drawn as seen by the camera with the contained [`Transform`], and
+    /// the rendered image should be saved.
+    Draw(PathBuf, Transform, UVec2),
+    /// The image is being saved, the camera should be disabled.
+    Capture(Transform, UVec2)
+}
+
+//=======================================================================//
+
 /// The point of the bounding box of a [`ThingInstance`] used as a reference for its spawning.
 #[derive(Default, Clone, Copy, PartialEq, EnumIter, EnumFromUsize, EnumSize)]
 pub(in crate::map::editor::state) enum ThingPivot
@@ -414,6 +460,8 @@ input_presses!(
     mouse_buttons,
     key_inputs,
     binds,
+    key_repeat,
+    delta_time,
     (l_ctrl, InputStateHardCoded<KeyCode>, KeyCode::ControlLeft, key_inputs),
     (r_ctrl, InputStateHardCoded<KeyCode>, KeyCode::ControlRight, key_inputs),
     (l_shift, InputStateHardCoded<KeyCode>, KeyCode::ShiftLeft, key_inputs),
@@ -433,10 +481,14 @@ input_presses!(
     (copy, InputStateHardCoded<KeyCode>, HardcodedActions::Copy.key(), key_inputs),
     (paste, InputStateHardCoded<KeyCode>, HardcodedActions::Paste.key(), key_inputs),
     (cut, InputStateHardCoded<KeyCode>, HardcodedActions::Cut.key(), key_inputs),
-    (left, InputState, Bind::Left, key_inputs, binds),
-    (right, InputState, Bind::Right, key_inputs, binds),
-    (up, InputState, Bind::Up, key_inputs, binds),
-    (down, InputState, Bind::Down, key_inputs, binds)
+    (left, InputState, Bind::Left, key_inputs, binds, key_repeat, delta_time),
+    (right, InputState, Bind::Right, key_inputs, binds, key_repeat, delta_time),
+    (up, InputState, Bind::Up, key_inputs, binds, key_repeat, delta_time),
+    (down, InputState, Bind::Down, key_inputs, binds, key_repeat, delta_time),
+    (vertex_split, ChordState, SubToolChord::VertexSplit, key_inputs, binds),
+    (polygon_to_path, ChordState, SubToolChord::VertexPolygonToPath, key_inputs, binds),
+    (path_simulation, ChordState, SubToolChord::PathSimulation, key_inputs, binds),
+    (prop_creation, ChordState, SubToolChord::PaintCreation, key_inputs, binds)
 );
 
 impl InputsPresses
@@ -478,28 +530,29 @@ impl InputsPresses
     pub const fn cut_just_pressed(&self) -> bool { self.ctrl_pressed() && self.cut.just_pressed() }
 
     /// Returns a vector representing the direction of the pressed arrow keys, if any.
+    /// Held down keys repeat their press based on the [`KeyRepeat`] settings.
     #[inline]
     #[must_use]
     pub fn directional_keys_vector(&self, grid_size: i16) -> Option<Vec2>
     {
         let mut dir = Vec2::ZERO;
 
-        if self.right.just_pressed()
+        if self.right.just_pressed_or_repeated()
         {
             dir.x += 1f32;
         }
 
-        if self.left.just_pressed()
+        if self.left.just_pressed_or_repeated()
         {
             dir.x -= 1f32;
         }
 
-        if self.up.just_pressed()
+        if self.up.just_pressed_or_repeated()
         {
             dir.y += 1f32;
         }
 
-        if self.down.just_pressed()
+        if self.down.just_pressed_or_repeated()
         {
             dir.y -= 1f32;
         }
@@ -530,8 +583,34 @@ pub(in crate::map) struct ToolsSettings
     pub scroll_enabled: bool,
     /// Whether texture parallax is enabled while editing the map.
     pub parallax_enabled: bool,
+    /// Whether changing one axis of the texture scale in the texture editor changes the other
+    /// proportionally.
+    pub(in crate::map::editor::state) scale_aspect_lock_enabled: bool,
     /// The spawn pivot of the [`ThingInstance`] used by the thing tool.
-    pub(in crate::map::editor::state) thing_pivot: ThingPivot
+    pub(in crate::map::editor::state) thing_pivot: ThingPivot,
+    /// The playback speed multiplier of the texture animations in the map preview.
+    pub(in crate::map::editor::state) animation_speed: f32,
+    /// Whether selecting a brush while Ctrl is pressed also selects its attached brushes.
+    pub(in crate::map::editor::state) select_attachments: bool,
+    /// Whether moving the selected brushes along with their textures also moves the textures of
+    /// their unselected attached brushes, so that the textures of the cluster stay aligned.
+    pub(in crate::map::editor::state) realign_attachments: bool,
+    /// The distance from the map bounds within which the entities are selected by the select
+    /// near bounds command.
+    pub(in crate::map::editor::state) bound_margin: f32,
+    /// Whether the path nodes drawn or inserted by the path tool are snapped to the centers and
+    /// vertexes of the nearby brushes.
+    pub(in crate::map::editor::state) path_geometry_snap: bool,
+    /// The spawn order assigned to the first of the selected things by the assign spawn order
+    /// command.
+    pub(in crate::map::editor::state) first_spawn_order: u32,
+    /// The radius of the arcs generated by the side tool.
+    pub(in crate::map::editor::state) arc_radius: f32,
+    /// The amount of segments of the arcs generated by the side tool.
+    pub(in crate::map::editor::state) arc_segments: u8,
+    /// Whether the index and position of the vertex beneath the cursor are shown by the vertex
+    /// tool.
+    pub(in crate::map::editor::state) vertex_info: bool
 }
 
 impl Default for ToolsSettings
@@ -541,14 +620,24 @@ impl Default for ToolsSettings
     fn default() -> Self
     {
         Self {
-            target_switch:          TargetSwitch::default(),
-            can_switch:             false,
-            circle_draw_resolution: 2,
-            texture_scale_interval: 0.5,
-            rotate_angle:           RotateAngle::default(),
-            scroll_enabled:         true,
-            parallax_enabled:       true,
-            thing_pivot:            ThingPivot::default()
+            target_switch:             TargetSwitch::default(),
+            can_switch:                false,
+            circle_draw_resolution:    8,
+            texture_scale_interval:    0.5,
+            rotate_angle:              RotateAngle::default(),
+            scroll_enabled:            true,
+            parallax_enabled:          true,
+            scale_aspect_lock_enabled: false,
+            thing_pivot:               ThingPivot::default(),
+            animation_speed:           1f32,
+            select_attachments:        true,
+            realign_attachments:       false,
+            bound_margin:              64f32,
+            path_geometry_snap:        false,
+            first_spawn_order:         0,
+            arc_radius:                64f32,
+            arc_segments:              4,
+            vertex_info:               false
         }
     }
 }
@@ -646,6 +735,8 @@ pub(in crate::map::editor) struct State
     inputs:             InputsPresses,
     /// The grid of the map.
     grid:               Grid,
+    /// The reference grid drawn on top of the map.
+    measurement_grid:   MeasurementGrid,
     /// The retained settings of the tools.
     tools_settings:     ToolsSettings,
     /// The UI of the editor.
@@ -658,8 +749,28 @@ pub(in crate::map::editor) struct State
     show_cursor:        bool,
     /// Whether the "clip" texture should be drawn on top of the brushes with collision enabled.
     show_collision:     bool,
+    /// Whether the brushes should be drawn with their sides only.
+    wireframe:          bool,
+    /// Whether the [`Hull`]s of the things should be drawn on top of their sprites.
+    show_things_hulls:  bool,
+    /// Whether the sprites of the brushes should be drawn.
+    show_sprites:       bool,
+    /// Whether the entities too small on screen should not be drawn.
+    detail_culling:     bool,
+    /// Whether the brushes with the same texture as the selected ones should be highlighted.
+    texture_highlight:  bool,
     /// Whether textures are currently being reloaded.
     reloading_textures: bool,
+    /// The watcher of the texture files, if the changed textures should be reloaded.
+    textures_watcher:   Option<TexturesWatcher>,
+    /// The map the one being edited is being compared to, if any.
+    comparison:         Option<MapComparison>,
+    /// The [`Id`]s of the entities stored in the selection memory slots.
+    selection_memories: [Ids; SELECTION_MEMORIES],
+    /// The history of the views of the map.
+    view_history:       ViewHistory,
+    /// The stage of the render of the map preview to an image file.
+    map_render:         MapRender,
     #[cfg(feature = "debug")]
     /// Whether debug lines should be drawn on top of the map.
     show_debug_lines:   bool
@@ -677,13 +788,24 @@ impl Placeholder for State
             edits_history: EditsHistory::default(),
             inputs: InputsPresses::default(),
             grid: Grid::default(),
+            measurement_grid: MeasurementGrid::default(),
             tools_settings: ToolsSettings::default(),
             ui: Ui::placeholder(),
             show_tooltips: true,
             cursor_snap: true,
             show_cursor: true,
             show_collision: true,
+            wireframe: false,
+            show_things_hulls: false,
+            show_sprites: true,
+            detail_culling: false,
+            texture_highlight: false,
             reloading_textures: false,
+            textures_watcher: None,
+            comparison: None,
+            selection_memories: std::array::from_fn(|_| hv_hash_set![]),
+            view_history: ViewHistory::default(),
+            map_render: MapRender::None,
             #[cfg(feature = "debug")]
             show_debug_lines: false
         }
@@ -715,7 +837,7 @@ impl State
         drawing_resources: &mut DrawingResources,
         things_catalog: &ThingsCatalog,
         default_properties: &mut AllDefaultProperties,
-        file: Option<File>
+        file: Option<(File, PathBuf)>
     ) -> Self
     {
         /// The [`State`] to default to in case of errors in the file load or if there is no file to
@@ -735,6 +857,7 @@ impl State
                 edits_history: EditsHistory::default(),
                 inputs: InputsPresses::default(),
                 grid: Grid::default(),
+                measurement_grid: MeasurementGrid::default(),
                 ui: Ui::new(
                     asset_server,
                     user_textures,
@@ -746,13 +869,23 @@ impl State
                 cursor_snap: true,
                 show_cursor: true,
                 show_collision: true,
+                wireframe: false,
+                show_things_hulls: false,
+                show_sprites: true,
+                detail_culling: false,
+                texture_highlight: false,
                 reloading_textures: false,
+                textures_watcher: None,
+                comparison: None,
+                selection_memories: std::array::from_fn(|_| hv_hash_set![]),
+                view_history: ViewHistory::default(),
+                map_render: MapRender::None,
                 #[cfg(feature = "debug")]
                 show_debug_lines: false
             }
         }
 
-        let file = return_if_none!(
+        let (file, path) = return_if_none!(
             file,
             default(
                 asset_server,
@@ -772,7 +905,7 @@ impl State
             default_properties
         )
         {
-            Ok((manager, clipboard)) =>
+            Ok((manager, clipboard, grid_angle)) =>
             {
                 let mut state = Self {
                     core: Core::default(),
@@ -781,6 +914,7 @@ impl State
                     edits_history: EditsHistory::default(),
                     inputs: InputsPresses::default(),
                     grid: Grid::default(),
+                    measurement_grid: MeasurementGrid::default(),
                     ui: Ui::new(
                         asset_server,
                         user_textures,
@@ -792,14 +926,35 @@ impl State
                     cursor_snap: true,
                     show_cursor: true,
                     show_collision: true,
+                    wireframe: false,
+                    show_things_hulls: false,
+                    show_sprites: true,
+                    detail_culling: false,
+                    texture_highlight: false,
                     reloading_textures: false,
+                    textures_watcher: None,
+                    comparison: None,
+                    selection_memories: std::array::from_fn(|_| hv_hash_set![]),
+                    view_history: ViewHistory::default(),
+                    map_render: MapRender::None,
                     #[cfg(feature = "debug")]
                     show_debug_lines: false
                 };
 
+                state.grid.set_angle(grid_angle, &mut state.manager);
                 state.manager.finish_things_reload(things_catalog);
                 state.manager.finish_textures_reload(drawing_resources);
 
+                if let Err(err) = state.manager.import_notes(&notes_path(&path))
+                {
+                    error_message(err);
+                }
+
+                if let Err(err) = state.manager.markers_mut().import(&markers_path(&path))
+                {
+                    error_message(err);
+                }
+
                 state
             },
             Err(err) =>
@@ -815,6 +970,51 @@ impl State
         }
     }
 
+    /// Sets the target the operations performed on the map are logged to, if any.
+    #[inline]
+    pub fn set_operations_log(&mut self, target: Option<&OperationsLogTarget>)
+    {
+        self.edits_history.set_operations_log(target);
+    }
+
+    /// Reports the names of the hidden tools that do not match any tool or subtool.
+    #[inline]
+    pub fn check_hidden_tools(hidden_tools: &HiddenTools)
+    {
+        let unknown = hidden_tools
+            .names()
+            .filter(|name| {
+                !Tool::iter()
+                    .map(ConfigName::config_name)
+                    .chain(SubTool::iter().map(ConfigName::config_name))
+                    .any(|tool| tool.eq_ignore_ascii_case(name))
+            })
+            .collect::<Vec<_>>();
+
+        if unknown.is_empty()
+        {
+            return;
+        }
+
+        error_message(&format!("Unknown hidden tools: {}", unknown.join(", ")));
+    }
+
+    /// Sets the [`CustomTools`] available in the editor.
+    #[inline]
+    pub fn set_custom_tools(&mut self, custom_tools: CustomTools)
+    {
+        self.core.set_custom_tools(custom_tools);
+    }
+
+    /// Resets the [`Core`], keeping the registered [`CustomTools`].
+    #[inline]
+    fn reset_core(&mut self)
+    {
+        let custom_tools = self.core.take_custom_tools();
+        self.core = Core::default();
+        self.core.set_custom_tools(custom_tools);
+    }
+
     //==============================================================
     // Info
 
@@ -840,6 +1040,11 @@ impl State
     #[inline]
     pub fn grid_square_coordinates(&self, pos: Vec2) -> Hull { self.grid.square(pos) }
 
+    /// Returns the vertex of the grid closest to `pos`.
+    #[inline]
+    #[must_use]
+    pub fn grid_nearest_vertex(&self, pos: Vec2) -> Vec2 { self.grid.nearest_vertex(pos) }
+
     /// Returns a reference to the tools' stored settings.
     #[inline]
     #[must_use]
@@ -855,6 +1060,21 @@ impl State
     #[must_use]
     pub const fn show_collision_overlay(&self) -> bool { self.show_collision }
 
+    /// Whether the brushes should be drawn in wireframe mode.
+    #[inline]
+    #[must_use]
+    pub const fn wireframe(&self) -> bool { self.wireframe }
+
+    /// Whether the [`Hull`]s of the things should be drawn.
+    #[inline]
+    #[must_use]
+    pub const fn show_things_hulls(&self) -> bool { self.show_things_hulls }
+
+    /// Whether the sprites of the brushes should be drawn.
+    #[inline]
+    #[must_use]
+    pub const fn show_sprites(&self) -> bool { self.show_sprites }
+
     /// Checks whether any hardcoded keyboard input was pressed and executes the necessary piece of
     /// code. Returns true if that was the case.
     #[inline]
@@ -920,6 +1140,18 @@ impl State
             return true;
         }
 
+        if HardcodedActions::DeselectBrushes.pressed(bundle.key_inputs) && self.core.entity_tool()
+        {
+            self.manager.deselect_selected_brushes(&mut self.edits_history);
+            return true;
+        }
+
+        if HardcodedActions::DeselectThings.pressed(bundle.key_inputs) && self.core.entity_tool()
+        {
+            self.manager.deselect_selected_things(&mut self.edits_history);
+            return true;
+        }
+
         if HardcodedActions::Undo.pressed(bundle.key_inputs)
         {
             if !self.core.undo_redo_available()
@@ -942,6 +1174,12 @@ impl State
             return true;
         }
 
+        if let Some(slot) = self.selection_memory_slot(bundle.key_inputs)
+        {
+            self.selection_memory(slot);
+            return true;
+        }
+
         if !self.copy_paste_available()
         {
             return false;
@@ -1000,14 +1238,7 @@ impl State
             .set_description("There are unsaved changes, do you wish to save?")
             .show()
         {
-            rfd::MessageDialogResult::Yes =>
-            {
-                match self.save(bundle, None)
-                {
-                    Err(err) => Err(err),
-                    Ok(()) => Ok(true)
-                }
-            },
+            rfd::MessageDialogResult::Yes => self.save(bundle, None),
             rfd::MessageDialogResult::No => Ok(true),
             rfd::MessageDialogResult::Cancel => Ok(false),
             _ => unreachable!()
@@ -1015,7 +1246,7 @@ impl State
     }
 
     /// Creates a new file, initiates save procedure if the map currently being edited has unsaved
-    /// edits.
+    /// edits. If a new map template is specified its content is loaded in the new map.
     #[inline]
     fn new_file(&mut self, bundle: &mut StateUpdateBundle) -> Result<(), &'static str>
     {
@@ -1026,14 +1257,40 @@ impl State
             _ => ()
         };
 
-        self.core = Core::default();
+        self.reset_core();
         self.manager = EntitiesManager::new();
         self.clipboard = Clipboard::new();
-        self.edits_history = EditsHistory::default();
+        self.clear_selection_memories();
+        self.edits_history.reset();
+        self.view_history = ViewHistory::default();
+        self.grid.set_angle(0f32, &mut self.manager);
         self.inputs = InputsPresses::default();
+        self.comparison = None;
         bundle.config.open_file.clear();
         bundle.update_window_title();
 
+        let template = return_if_none!(bundle.config.template.as_ref(), Ok(()));
+
+        let file = match File::open(template)
+        {
+            Ok(file) => file,
+            Err(_) => return Err("Could not open the new map template file")
+        };
+
+        let (manager, clipboard, grid_angle) = Self::manager_clipboard(
+            bundle.images,
+            bundle.prop_cameras,
+            bundle.user_textures,
+            file,
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.default_properties
+        )?;
+
+        self.manager = manager;
+        self.clipboard = clipboard;
+        self.grid.set_angle(grid_angle, &mut self.manager);
+
         Ok(())
     }
 
@@ -1075,19 +1332,160 @@ impl State
         self.edits_history.no_unsaved_edits() &&
             !self.clipboard.props_changed() &&
             !drawing_resources.default_animations_changed() &&
-            !self.manager.refactored_properties()
+            !self.manager.refactored_properties() &&
+            !self.manager.markers().changed()
+    }
+
+    /// Returns the bytes of the map being edited, in the same format of the map files.
+    #[inline]
+    fn map_data(&self, bundle: &mut StateUpdateBundle) -> Result<Vec<u8>, &'static str>
+    {
+        let animations = bundle.drawing_resources.default_animations();
+
+        Self::entities_data(
+            bundle,
+            &hv_vec![collect; self.manager.brushes().iter()],
+            &hv_vec![collect; self.manager.things()],
+            &animations,
+            Some(&self.clipboard),
+            self.manager.map_bound(),
+            self.grid.angle()
+        )
+    }
+
+    /// Returns the map file data containing `brushes`, `things`, `animations`, the props of
+    /// `clipboard` if any, the map bound, and the rotation of the grid.
+    #[inline]
+    fn entities_data(
+        bundle: &mut StateUpdateBundle,
+        brushes: &[&Brush],
+        things: &[&ThingInstance],
+        animations: &[DefaultAnimation],
+        clipboard: Option<&Clipboard>,
+        bound: f32,
+        grid_angle: f32
+    ) -> Result<Vec<u8>, &'static str>
+    {
+        /// Tests whether `test` is an error and returns an [`Err`] wrapping the error message `err`.
+        macro_rules! test {
+            ($test:expr, $err:literal) => {
+                if $test.is_err()
+                {
+                    return Err($err);
+                }
+            };
+        }
+
+        let decimals = bundle.config.canonical_save.decimals();
+
+        /// Serializes `value` into `writer`, normalizing its numeric values if the canonical save
+        /// is enabled, and returns an [`Err`] wrapping the error message `err` if it fails.
+        macro_rules! serialize {
+            ($writer:ident, $value:expr, $err:literal) => {
+                match decimals
+                {
+                    Some(decimals) =>
+                    {
+                        test!(canonical_into_writer($value, decimals, &mut $writer), $err);
+                    },
+                    None => test!(ciborium::ser::into_writer($value, &mut $writer), $err)
+                }
+            };
+        }
+
+        // Brushes and things, serialized first to store their hash in the header.
+        let mut content = Vec::new();
+        let mut content_writer = BufWriter::new(&mut content);
+
+        for brush in brushes
+        {
+            // Brushes whose rounded vertexes would not describe a valid polygon are stored with
+            // full precision.
+            match decimals
+            {
+                Some(decimals)
+                    if !brush.valid_mapped_vertexes(|vx| canonical_vec(vx, decimals)) =>
+                {
+                    test!(
+                        ciborium::ser::into_writer(brush, &mut content_writer),
+                        "Error saving brushes"
+                    );
+                },
+                _ => serialize!(content_writer, brush, "Error saving brushes")
+            };
+        }
+
+        for thing in things
+        {
+            serialize!(content_writer, thing, "Error saving things");
+        }
+
+        test!(content_writer.flush(), "Error saving file");
+        drop(content_writer);
+
+        let mut data = Vec::new();
+        let mut writer = BufWriter::new(&mut data);
+
+        // Header.
+        serialize!(
+            writer,
+            &MapHeader {
+                brushes:    brushes.len(),
+                things:     things.len(),
+                animations: animations.len(),
+                props:      clipboard.map_or(0, Clipboard::props_amount),
+                bound,
+                grid_angle,
+                hash:       ContentHash::new(&content).into()
+            },
+            "Error saving file header"
+        );
+
+        // Default properties.
+        serialize!(
+            writer,
+            bundle.default_properties.map_brushes,
+            "Error saving brushes default properties"
+        );
+        serialize!(
+            writer,
+            bundle.default_properties.map_things,
+            "Error saving things default properties"
+        );
+
+        // Animations
+        for animation in animations
+        {
+            serialize!(writer, animation, "Error saving animations");
+        }
+
+        // Brushes and things.
+        test!(writer.write_all(&content), "Error saving file");
+
+        // Props.
+        if let Some(clipboard) = clipboard
+        {
+            for prop in clipboard.props()
+            {
+                serialize!(writer, prop, "Error saving prop");
+            }
+        }
+
+        drop(writer);
+        Ok(data)
     }
 
     /// Saves the map being edited. If the file has not being created yet user is asked to specify
     /// where it should be stored. If the file exists, if `save as` contains a value user is
     /// asked to specify in which new file the map should be saved. Otherwise the map is stored
     /// in the previously opened file.
+    /// Returns whether the map was saved, which is not the case if the user cancels the process.
     #[inline]
     fn save(
         &mut self,
         bundle: &mut StateUpdateBundle,
         save_as: Option<&'static str>
-    ) -> Result<(), &'static str>
+    ) -> Result<bool, &'static str>
     {
         /// Tests whether `test` is an error and returns an [`Err`] wrapping the error message `err`.
         macro_rules! test {
@@ -1135,6 +1533,61 @@ impl State
             SaveTarget::New(check_path_extension(path, FILE_EXTENSION))
         }
 
+        /// Shows a warning listing `entries` below `header`, asking whether the save should
+        /// proceed. Returns true if there are no entries or the user chooses to save anyway.
+        #[inline]
+        #[must_use]
+        fn confirm_save_warning(
+            header: &str,
+            entries: impl IntoIterator<Item = impl std::fmt::Display>
+        ) -> bool
+        {
+            let mut entries = entries.into_iter().peekable();
+
+            if entries.peek().is_none()
+            {
+                return true;
+            }
+
+            let mut description = format!("{header}\n");
+
+            for entry in entries
+            {
+                description.push_str(&format!("\n{entry}"));
+            }
+
+            description.push_str("\n\nDo you wish to save anyway?");
+
+            !matches!(
+                rfd::MessageDialog::new()
+                    .set_buttons(rfd::MessageButtons::YesNo)
+                    .set_title("WARNING")
+                    .set_description(description)
+                    .show(),
+                rfd::MessageDialogResult::No
+            )
+        }
+
+        if !confirm_save_warning(
+            "The following brushes reference textures that do not exist:",
+            self.manager
+                .brushes_with_missing_texture(bundle.drawing_resources)
+                .iter()
+                .map(|(id, texture)| format!("{} - {texture}", id.value()))
+        ) || !confirm_save_warning(
+            "The paths of the following entities lead out of the map bounds:",
+            self.manager.entities_with_path_out_of_bounds().iter().map(|id| id.value())
+        ) || !confirm_save_warning(
+            "The following things overlap other things of the same type:",
+            self.manager
+                .overlapping_things(bundle.config.thing_overlap.distance())
+                .iter()
+                .map(|id| id.value())
+        )
+        {
+            return Ok(false);
+        }
+
         let target = match save_as
         {
             Some(msg) => save_as_dialog(msg),
@@ -1160,55 +1613,10 @@ impl State
 
         if let SaveTarget::None = target
         {
-            return Ok(());
-        }
-
-        let mut data = Vec::new();
-        let mut writer = BufWriter::new(&mut data);
-
-        // Header.
-        test!(
-            ciborium::ser::into_writer(
-                &MapHeader {
-                    brushes:    self.manager.brushes_amount(),
-                    things:     self.manager.things_amount(),
-                    animations: bundle.drawing_resources.animations_amount(),
-                    props:      self.clipboard.props_amount()
-                },
-                &mut writer
-            ),
-            "Error saving file header"
-        );
-
-        // Default properties.
-        test!(
-            ciborium::ser::into_writer(bundle.default_properties.map_brushes, &mut writer),
-            "Error saving brushes default properties"
-        );
-        test!(
-            ciborium::ser::into_writer(bundle.default_properties.map_things, &mut writer),
-            "Error saving brushes default properties"
-        );
-
-        // Animations
-        bundle.drawing_resources.export_animations(&mut writer)?;
-
-        // Brushes.
-        for brush in self.manager.brushes().iter()
-        {
-            test!(ciborium::ser::into_writer(brush, &mut writer), "Error saving brushes");
+            return Ok(false);
         }
 
-        // Things.
-        for thing in self.manager.things()
-        {
-            test!(ciborium::ser::into_writer(thing, &mut writer), "Error saving things");
-        }
-
-        // Props.
-        self.clipboard.export_props(&mut writer)?;
-
-        drop(writer);
+        let data = self.map_data(bundle)?;
 
         let mut file = OpenOptions::new();
         let mut file = file.write(true);
@@ -1239,6 +1647,27 @@ impl State
         };
         test!(BufWriter::new(file).write_all(&data), "Error writing file");
 
+        match self.manager.export_notes()?
+        {
+            Some(notes) =>
+            {
+                test!(std::fs::write(notes_path(path), notes), "Error writing notes file");
+            },
+            None => _ = std::fs::remove_file(notes_path(path))
+        };
+
+        match self.manager.markers().export()?
+        {
+            Some(markers) =>
+            {
+                test!(std::fs::write(markers_path(path), markers), "Error writing markers file");
+            },
+            None => _ = std::fs::remove_file(markers_path(path))
+        };
+
+        // The map file was written so the save is complete even if the export fails.
+        let export = self.write_save_export(bundle.config.save_export, &data, path);
+
         if target.is_new()
         {
             bundle.config.open_file = OpenFile::new(path.as_os_str().to_str().unwrap());
@@ -1247,17 +1676,61 @@ impl State
         self.edits_history.reset_last_save_edit();
         self.clipboard.reset_props_changed();
         self.manager.reset_refactored_properties();
+        self.manager.markers_mut().reset_changed();
         bundle.drawing_resources.reset_default_animation_changed();
         bundle.update_window_title();
 
+        if let Err(err) = export
+        {
+            error_message(err);
+        }
+
+        Ok(true)
+    }
+
+    /// Writes the map `data` in the `format` export format to the file whose path is `path` with
+    /// the extension of the format.
+    #[inline]
+    fn write_save_export(
+        &self,
+        format: SaveExport,
+        data: &[u8],
+        path: &Path
+    ) -> Result<(), &'static str>
+    {
+        let extension = return_if_none!(format.extension(), Ok(()));
+        let exporter = Exporter::from_reader(&mut Cursor::new(data))?;
+        let mut writer = match File::create(path.with_extension(extension))
+        {
+            Ok(file) => BufWriter::new(file),
+            Err(_) => return Err("Error creating export file")
+        };
+
+        let result = match format
+        {
+            SaveExport::None => unreachable!(),
+            SaveExport::Binary => exporter.write_binary(&mut writer),
+            SaveExport::BinaryWithBounds => exporter.write_binary_with_bounds(&mut writer),
+            SaveExport::Tmx =>
+            {
+                let tile_size = u32::from(self.grid.size().unsigned_abs());
+                exporter.write_tmx(&mut writer, UVec2::splat(tile_size))
+            },
+        };
+
+        if result.and_then(|()| writer.flush()).is_err()
+        {
+            return Err("Error writing export file");
+        }
+
         Ok(())
     }
 
     //==============================================================
     // Open
 
-    /// Returns new [`EntitiesManager`] and [`Clipboard`] loading the content of `file`.
-    /// Returns `Err` if the file could not be properly read.
+    /// Returns new [`EntitiesManager`] and [`Clipboard`] loading the content of `file`, along with
+    /// the rotation of the grid. Returns `Err` if the file could not be properly read.
     #[inline]
     fn manager_clipboard(
         images: &mut Assets<Image>,
@@ -1267,7 +1740,7 @@ impl State
         drawing_resources: &mut DrawingResources,
         things_catalog: &ThingsCatalog,
         default_properties: &mut AllDefaultProperties
-    ) -> Result<(EntitiesManager, Clipboard), &'static str>
+    ) -> Result<(EntitiesManager, Clipboard, f32), &'static str>
     {
         let mut file = BufReader::new(file);
 
@@ -1277,7 +1750,7 @@ impl State
             Err(_) => return Err("Error reading file header")
         };
 
-        let manager = match EntitiesManager::from_file(
+        let (manager, out_of_bounds) = match EntitiesManager::from_file(
             &header,
             &mut file,
             drawing_resources,
@@ -1290,6 +1763,7 @@ impl State
         };
 
         drawing_resources.import_animations(header.animations, &mut file)?;
+
         drawing_resources.reset_default_animation_changed();
 
         let mut clipboard = match Clipboard::from_file(
@@ -1306,7 +1780,18 @@ impl State
         };
         clipboard.reset_props_changed();
 
-        Ok((manager, clipboard))
+        if out_of_bounds != 0
+        {
+            rfd::MessageDialog::new()
+                .set_title("WARNING")
+                .set_description(format!(
+                    "{out_of_bounds} entities are out of the map bounds and were not loaded."
+                ))
+                .set_buttons(rfd::MessageButtons::Ok)
+                .show();
+        }
+
+        Ok((manager, clipboard, header.grid_angle))
     }
 
     /// Opens a map file, unless the file cannot be properly read. If there are unsaved changes in
@@ -1346,10 +1831,27 @@ impl State
             bundle.default_properties
         )
         {
-            Ok((manager, clipboard)) =>
+            Ok((manager, clipboard, grid_angle)) =>
             {
                 self.manager = manager;
                 self.clipboard = clipboard;
+                self.clear_selection_memories();
+                self.grid.set_angle(grid_angle, &mut self.manager);
+
+                if let Err(err) = self
+                    .manager
+                    .import_notes(&notes_path(bundle.config.open_file.path().unwrap()))
+                {
+                    error_message(err);
+                }
+
+                if let Err(err) = self
+                    .manager
+                    .markers_mut()
+                    .import(&markers_path(bundle.config.open_file.path().unwrap()))
+                {
+                    error_message(err);
+                }
             },
             Err(err) =>
             {
@@ -1359,35 +1861,126 @@ impl State
         };
 
         bundle.update_window_title();
-        self.core = Core::default();
+        self.reset_core();
         self.inputs = InputsPresses::default();
-        self.edits_history = EditsHistory::default();
+        self.edits_history.reset();
+        self.view_history = ViewHistory::default();
+        self.comparison = None;
     }
 
     //==============================================================
-    // Export
+    // Compare
 
-    /// Initiates the map export procedure if an exporter executable is specified.
-    /// If there are unsaved changes inthe currently open map the save procedure is initiated.
+    /// Loads a map file to compare the map being edited to.
     #[inline]
-    fn export(&mut self, bundle: &mut StateUpdateBundle)
+    fn compare(&mut self)
     {
-        match self.unsaved_changes(bundle, rfd::MessageButtons::YesNoCancel)
+        let path = return_if_none!(rfd::FileDialog::new()
+            .set_title("Compare")
+            .add_filter(HV_FILTER_NAME, &[FILE_EXTENSION])
+            .set_directory(std::env::current_dir().unwrap())
+            .pick_file());
+
+        let file = match File::open(path)
         {
-            Ok(false) => return,
-            Err(err) =>
+            Ok(file) => file,
+            Err(_) =>
             {
-                error_message(err);
+                error_message("Could not open the comparison file");
                 return;
-            },
-            _ => ()
+            }
         };
 
-        let exporter = bundle.config.exporter.as_ref().unwrap();
-
-        if !exporter.exists() || !exporter.is_executable()
+        match MapComparison::from_file(file)
         {
-            error_message("Exporter executable does not exist");
+            Ok(comparison) => self.comparison = comparison.into(),
+            Err(err) => error_message(err)
+        };
+    }
+
+    //==============================================================
+    // Batch
+
+    /// Executes the commands contained in a text file chosen by the user.
+    #[inline]
+    fn run_batch(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let path = return_if_none!(rfd::FileDialog::new()
+            .set_title("Run commands file")
+            .add_filter("Commands file", &["txt"])
+            .set_directory(std::env::current_dir().unwrap())
+            .pick_file());
+
+        let file = match File::open(path)
+        {
+            Ok(file) => file,
+            Err(_) =>
+            {
+                error_message("Could not open the commands file");
+                return;
+            }
+        };
+
+        let errors = batch::run(file, &mut BatchBundle {
+            drawing_resources:          bundle.drawing_resources,
+            things_catalog:             bundle.things_catalog,
+            manager:                    &mut self.manager,
+            edits_history:              &mut self.edits_history,
+            brushes_default_properties: bundle.default_properties.map_brushes,
+            things_default_properties:  bundle.default_properties.map_things
+        });
+
+        if !errors.is_empty()
+        {
+            error_message(&errors.join("\n"));
+        }
+    }
+
+    //==============================================================
+    // Export
+
+    /// Initiates the map export procedure if an exporter executable is specified.
+    /// If there are unsaved changes inthe currently open map the save procedure is initiated.
+    #[inline]
+    fn export(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        match self.unsaved_changes(bundle, rfd::MessageButtons::YesNoCancel)
+        {
+            Ok(false) => return,
+            Err(err) =>
+            {
+                error_message(err);
+                return;
+            },
+            _ => ()
+        };
+
+        if let Some(exceeded) = bundle.config.limits.exceeded(
+            self.manager.brushes_amount(),
+            self.manager.vertexes_amount(),
+            self.manager.things_amount()
+        )
+        {
+            let result = rfd::MessageDialog::new()
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .set_title("WARNING")
+                .set_description(format!(
+                    "The map exceeds the complexity limits ({exceeded}), do you wish to export it \
+                     anyway?"
+                ))
+                .show();
+
+            if !matches!(result, rfd::MessageDialogResult::Yes)
+            {
+                return;
+            }
+        }
+
+        let exporter = bundle.config.exporter.as_ref().unwrap();
+
+        if !exporter.exists() || !exporter.is_executable()
+        {
+            error_message("Exporter executable does not exist");
             bundle.config.exporter = None;
             return;
         }
@@ -1401,6 +1994,328 @@ impl State
         }
     }
 
+    /// Runs the [`Exporter`] on the map being edited, without writing any file, and shows a
+    /// summary of the exported data.
+    #[inline]
+    fn export_preview(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let data = match self.map_data(bundle)
+        {
+            Ok(data) => data,
+            Err(err) =>
+            {
+                error_message(err);
+                return;
+            }
+        };
+
+        match Exporter::from_reader(&mut Cursor::new(data))
+        {
+            Ok(exporter) =>
+            {
+                self.ui.set_export_preview(
+                    &exporter,
+                    bundle.drawing_resources,
+                    bundle.things_catalog
+                );
+            },
+            Err(err) => error_message(err)
+        };
+    }
+
+    /// Attaches the selected brushes to the selected brush beneath the cursor, or to the selected
+    /// brush with the lowest id, reporting the brushes that could not be attached.
+    #[inline]
+    fn attach_selected_brushes(&mut self, bundle: &StateUpdateBundle)
+    {
+        let cursor_pos = bundle.cursor.world();
+        let owner = self
+            .manager
+            .selected_brushes_at_pos(cursor_pos, None)
+            .iter()
+            .find_map(|brush| brush.contains_point(cursor_pos).then_some(brush.id()));
+
+        match self.manager.anchor_selected_brushes(&mut self.edits_history, owner)
+        {
+            Ok(skipped) if skipped.is_empty() => (),
+            Ok(skipped) =>
+            {
+                rfd::MessageDialog::new()
+                    .set_title("WARNING")
+                    .set_description(format!(
+                        "{} brushes were not attached because they are attached to another \
+                         brush, have attachments, or have a path.",
+                        skipped.len()
+                    ))
+                    .set_buttons(rfd::MessageButtons::Ok)
+                    .show();
+            },
+            Err(err) => error_message(err)
+        };
+    }
+
+    /// Aligns the texture of one of the two selected brushes to the one of the other across the
+    /// edge they share. The reference brush is the selected one beneath the cursor, if any.
+    #[inline]
+    fn align_selected_textures_across_seam(&mut self, bundle: &StateUpdateBundle)
+    {
+        let cursor_pos = bundle.cursor.world();
+        let reference = self
+            .manager
+            .selected_brushes_at_pos(cursor_pos, None)
+            .iter()
+            .find_map(|brush| brush.contains_point(cursor_pos).then_some(brush.id()));
+
+        if let Err(err) = self.manager.align_selected_textures_across_seam(
+            bundle.drawing_resources,
+            &mut self.edits_history,
+            reference
+        )
+        {
+            error_message(err);
+        }
+    }
+
+    /// Schedules the render of the map preview to a PNG file chosen by the user, with size
+    /// `size`.
+    #[inline]
+    fn render_map_preview(&mut self, size: UVec2)
+    {
+        if !matches!(self.map_render, MapRender::None)
+        {
+            return;
+        }
+
+        let path = return_if_none!(
+            rfd::FileDialog::new()
+                .set_title("Render to image")
+                .add_filter("PNG files (.png)", &["png"])
+                .set_directory(std::env::current_dir().unwrap())
+                .save_file()
+        );
+        self.map_render = MapRender::Setup(check_path_extension(path, "png"), size);
+    }
+
+    /// Updates the render of the map preview to an image file. The render `camera` is framed on
+    /// the whole map and enabled, then `request` is set so that the rendered image is saved, and
+    /// finally the camera is disabled.
+    #[inline]
+    pub fn update_map_render(
+        &mut self,
+        images: &mut Assets<Image>,
+        camera: (&mut bevy::prelude::Camera, &mut Transform),
+        request: &mut MapRenderRequest
+    )
+    {
+        let (camera, transform) = camera;
+
+        self.map_render = match std::mem::take(&mut self.map_render)
+        {
+            MapRender::None => MapRender::None,
+            MapRender::Setup(path, size) =>
+            {
+                #[allow(clippy::cast_precision_loss)]
+                let scale = self.manager.map_bound() * 2f32 / size.min_element() as f32;
+                *transform = Transform::from_scale(Vec3::splat(scale));
+                camera.target = RenderTarget::Image(images.add(map_render::image(size)));
+                camera.is_active = true;
+                MapRender::Draw(path, *transform, size)
+            },
+            MapRender::Draw(path, transform, size) =>
+            {
+                if let RenderTarget::Image(image) = &camera.target
+                {
+                    request.set(image.clone(), path);
+                }
+
+                MapRender::Capture(transform, size)
+            },
+            MapRender::Capture(..) =>
+            {
+                request.clear();
+                camera.is_active = false;
+                camera.target = RenderTarget::Image(images.add(map_render::image(UVec2::ONE)));
+                MapRender::None
+            }
+        };
+    }
+
+    /// Returns the [`Transform`] of the camera rendering the map preview to an image file and the
+    /// size of the image, if the map should be drawn for it in the current frame.
+    #[inline]
+    #[must_use]
+    pub const fn map_render_view(&self) -> Option<(Transform, UVec2)>
+    {
+        match &self.map_render
+        {
+            MapRender::Draw(_, transform, size) | MapRender::Capture(transform, size) =>
+            {
+                Some((*transform, *size))
+            },
+            _ => None
+        }
+    }
+
+    /// Saves the selected entities to a standalone map file chosen by the user. The anchored
+    /// brushes and their anchors are saved along with the selected ones so that the file is
+    /// valid even if only part of them is selected. The props are not saved.
+    /// Only the animations of the referenced textures are saved, and the files of the referenced
+    /// textures are copied in a folder next to the map file.
+    #[inline]
+    fn export_selection(&self, bundle: &mut StateUpdateBundle) -> Result<(), &'static str>
+    {
+        let path = return_if_none!(
+            rfd::FileDialog::new()
+                .set_title("Export selection")
+                .add_filter(HV_FILTER_NAME, &[FILE_EXTENSION])
+                .set_directory(std::env::current_dir().unwrap())
+                .save_file(),
+            Ok(())
+        );
+        let path = check_path_extension(path, FILE_EXTENSION);
+
+        let mut brushes = hv_hash_set![];
+
+        for brush in self.manager.selected_brushes()
+        {
+            let owner = self.manager.brush(brush.anchored().unwrap_or_else(|| brush.id()));
+            brushes.insert(owner.id());
+
+            if let Some(anchors) = owner.anchors_iter()
+            {
+                brushes.extend(anchors.copied());
+            }
+        }
+
+        let mut brushes = hv_vec![collect; brushes.into_iter().map(|id| self.manager.brush(id))];
+        brushes.sort_by(|a, b| a.id().value().cmp(&b.id().value()));
+        let mut things = hv_vec![collect; self.manager.selected_things()];
+        things.sort_by(|a, b| a.id().value().cmp(&b.id().value()));
+
+        // Referenced textures, along with the frames of their list animations.
+        let mut textures = hv_hash_set![];
+
+        for settings in brushes.iter().filter_map(|brush| brush.texture_settings())
+        {
+            textures.insert(settings.name());
+
+            if let Animation::List(list) = settings.animation()
+            {
+                textures.extend(list.frames().iter().map(|(name, _)| name.as_str()));
+            }
+        }
+
+        textures.extend(
+            things
+                .iter()
+                .map(|thing| bundle.things_catalog.thing_or_error(thing.thing()).preview())
+        );
+
+        for name in hv_vec![collect; textures.iter().copied()]
+        {
+            if let Some(Animation::List(list)) =
+                bundle.drawing_resources.texture(name).map(Texture::animation)
+            {
+                textures.extend(list.frames().iter().map(|(name, _)| name.as_str()));
+            }
+        }
+
+        let mut animations = bundle.drawing_resources.default_animations();
+        animations.retain(|animation| textures.contains(&animation.texture.as_str()));
+
+        let stem = path.file_stem().unwrap().to_str().unwrap();
+        let textures_dir = path.with_file_name(format!("{stem}_textures"));
+
+        if copy_texture_files(&textures, &textures_dir).is_err()
+        {
+            return Err("Error copying textures");
+        }
+
+        let data = Self::entities_data(
+            bundle,
+            &brushes,
+            &things,
+            &animations,
+            None,
+            self.manager.map_bound(),
+            self.grid.angle()
+        )?;
+
+        if std::fs::write(path, data).is_err()
+        {
+            return Err("Error writing file");
+        }
+
+        Ok(())
+    }
+
+    //==============================================================
+    // Selection memories
+
+    /// Returns the index of the selection memory slot associated with the digit key that was
+    /// just pressed while Ctrl is pressed, if any.
+    #[inline]
+    #[must_use]
+    fn selection_memory_slot(&self, key_inputs: &ButtonInput<KeyCode>) -> Option<usize>
+    {
+        /// The keys associated with the slots.
+        const DIGITS: [KeyCode; SELECTION_MEMORIES] = [
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9
+        ];
+
+        if !self.inputs.ctrl_pressed()
+        {
+            return None;
+        }
+
+        DIGITS.into_iter().position(|key| key_inputs.just_pressed(key))
+    }
+
+    /// Empties the selection memory slots, whose [`Id`]s refer to the entities of the map that was
+    /// being edited.
+    #[inline]
+    fn clear_selection_memories(&mut self)
+    {
+        self.selection_memories = std::array::from_fn(|_| hv_hash_set![]);
+    }
+
+    /// Stores the selected entities in the selection memory `slot` if Shift is pressed, otherwise
+    /// exclusively selects the entities stored in it, reporting the ones that no longer exist.
+    #[inline]
+    fn selection_memory(&mut self, slot: usize)
+    {
+        if self.inputs.shift_pressed()
+        {
+            self.selection_memories[slot].replace_values(self.manager.selected_entities_ids());
+            return;
+        }
+
+        if !self.core.entity_tool() || self.selection_memories[slot].is_empty()
+        {
+            return;
+        }
+
+        let missing = self
+            .manager
+            .exclusively_select_entities(&mut self.edits_history, &self.selection_memories[slot]);
+
+        if missing != 0
+        {
+            error_message(&format!(
+                "{missing} entities of selection memory {} no longer exist",
+                slot + 1
+            ));
+        }
+    }
+
     //==============================================================
     // Select all
 
@@ -1444,6 +2359,11 @@ impl State
     {
         assert!(self.copy_paste_available(), "Cut cannot be enabled.");
 
+        if !self.manager.confirm_bulk_operation("Cut")
+        {
+            return;
+        }
+
         self.core.cut(
             bundle,
             &mut self.manager,
@@ -1501,8 +2421,22 @@ impl State
         }
 
         // Update inputs.
-        self.inputs
-            .update(bundle.mouse_buttons, bundle.key_inputs, &mut bundle.config.binds);
+        self.inputs.update(
+            bundle.mouse_buttons,
+            bundle.key_inputs,
+            &mut bundle.config.binds,
+            &bundle.config.key_repeat,
+            bundle.delta_time
+        );
+
+        // Store the view if the camera settled.
+        self.view_history.update(bundle.camera, bundle.delta_time);
+
+        // Update the detail culling.
+        let cull_size = self.detail_culling.then_some(bundle.config.detail_culling.min_size());
+        self.manager.set_detail_culling(cull_size);
+        self.manager.set_select_spawned(bundle.config.spawn_selection.enabled());
+        self.manager.set_bulk_confirm_threshold(bundle.config.bulk_confirm.threshold());
 
         // Create UI.
         let tool_change_conditions = ChangeConditions::new(
@@ -1521,9 +2455,11 @@ impl State
             &mut self.inputs,
             &mut self.edits_history,
             &mut self.clipboard,
-            self.grid,
+            &mut self.grid,
+            &mut self.measurement_grid,
             &mut self.tools_settings,
-            &tool_change_conditions
+            &tool_change_conditions,
+            self.comparison.is_some()
         );
 
         if self.reloading_textures
@@ -1531,6 +2467,25 @@ impl State
             return false;
         }
 
+        // Same enable condition of the reload textures command.
+        if !self.core.map_preview()
+        {
+            if let Some(changed) =
+                self.textures_watcher.as_ref().and_then(TexturesWatcher::changed_files)
+            {
+                self.start_texture_reload(
+                    bundle.next_tex_load,
+                    bundle.texture_loader,
+                    changed.into()
+                );
+
+                if self.reloading_textures
+                {
+                    return false;
+                }
+            }
+        }
+
         if ui_interaction.hovered
         {
             self.inputs.left_mouse.clear();
@@ -1630,11 +2585,12 @@ impl State
 
         match ui_interaction.command
         {
-            Command::None => (),
+            Command::None | Command::RenderToImage(_) => (),
             Command::ChangeTool(tool) =>
             {
                 self.change_tool(tool, bundle, tool_change_conditions);
             },
+            Command::ChangeCustomTool(index) => self.change_custom_tool(index),
             Command::New =>
             {
                 if let Err(err) = self.new_file(bundle)
@@ -1657,7 +2613,18 @@ impl State
                 }
             },
             Command::Open => self.open(bundle),
+            Command::Compare => self.compare(),
+            Command::CloseComparison => self.comparison = None,
+            Command::RunBatch => self.run_batch(bundle),
             Command::Export => self.export(bundle),
+            Command::ExportPreview => self.export_preview(bundle),
+            Command::ExportSelection =>
+            {
+                if let Err(err) = self.export_selection(bundle)
+                {
+                    error_message(err);
+                }
+            },
             Command::ImportAnimations =>
             {
                 let mut file = BufReader::new(
@@ -1722,7 +2689,8 @@ impl State
                     bundle.user_textures,
                     bundle.things_catalog,
                     props_amount,
-                    &mut file
+                    &mut file,
+                    true
                 )
                 {
                     error_message(err);
@@ -1730,6 +2698,16 @@ impl State
             },
             Command::ExportProps => save_export!(PROPS, "props", props, self.clipboard),
             Command::SelectAll => self.select_all(),
+            Command::PreviousView => self.view_history.previous(bundle.camera),
+            Command::NextView => self.view_history.next(bundle.camera),
+            Command::DeselectBrushes =>
+            {
+                self.manager.deselect_selected_brushes(&mut self.edits_history);
+            },
+            Command::DeselectThings =>
+            {
+                self.manager.deselect_selected_things(&mut self.edits_history);
+            },
             Command::Copy => self.copy(bundle),
             Command::Paste => self.paste(bundle),
             Command::Cut => self.cut(bundle),
@@ -1744,7 +2722,16 @@ impl State
             Command::ToggleCursorSnap => self.toggle_cursor_snap(),
             Command::ToggleMapPreview => self.toggle_map_preview(bundle.drawing_resources),
             Command::ToggleCollision => self.toggle_collision(),
-            Command::ReloadTextures => self.start_texture_reload(bundle.next_tex_load),
+            Command::ToggleWireframe => self.toggle_wireframe(),
+            Command::ToggleSprites => self.toggle_sprites(),
+            Command::ToggleThingsHulls => self.toggle_things_hulls(),
+            Command::ToggleDetailCulling => self.toggle_detail_culling(),
+            Command::ToggleTextureHighlight => self.toggle_texture_highlight(),
+            Command::ReloadTextures =>
+            {
+                self.start_texture_reload(bundle.next_tex_load, bundle.texture_loader, None);
+            },
+            Command::ToggleTexturesWatch => self.toggle_textures_watch(),
             Command::ReloadThings => self.reload_things(bundle),
             Command::QuickZoom =>
             {
@@ -1757,7 +2744,158 @@ impl State
                     );
                 }
             },
+            Command::ToggleErrorHighlightPin => self.manager.toggle_error_highlight_pin(),
+            Command::ZoomOnError =>
+            {
+                if let Some(hull) = self.manager.error_highlight_hull()
+                {
+                    bundle.camera.scale_viewport_ui_constricted_to_hull(
+                        bundle.window,
+                        &hull,
+                        self.grid_size_f32()
+                    );
+                }
+            },
             Command::QuickSnap => self.quick_snap(bundle.drawing_resources),
+            Command::SnapBoundingBox =>
+            {
+                self.manager.snap_selected_brushes_hulls(
+                    bundle.drawing_resources,
+                    &mut self.edits_history,
+                    self.grid,
+                    false
+                );
+            },
+            Command::FitBoundingBox =>
+            {
+                self.manager.snap_selected_brushes_hulls(
+                    bundle.drawing_resources,
+                    &mut self.edits_history,
+                    self.grid,
+                    true
+                );
+            },
+            Command::FixWinding =>
+            {
+                self.manager.fix_selected_brushes_winding(&mut self.edits_history);
+            },
+            Command::AssignSpawnOrder =>
+            {
+                if let Err(err) = self.manager.assign_selected_things_spawn_order(
+                    &mut self.edits_history,
+                    self.tools_settings.first_spawn_order
+                )
+                {
+                    error_message(err);
+                }
+            },
+            Command::SelectNearMapBound =>
+            {
+                _ = self.manager.select_entities_near_map_bound(
+                    &mut self.edits_history,
+                    self.tools_settings.bound_margin
+                );
+            },
+            Command::ReplaceWithProp =>
+            {
+                if self.manager.confirm_bulk_operation("Replace with prop")
+                {
+                    if let Err(err) = self.clipboard.replace_selected_with_prop(
+                        bundle.drawing_resources,
+                        &mut self.manager,
+                        &mut self.edits_history
+                    )
+                    {
+                        error_message(err);
+                    }
+                }
+            },
+            Command::UpdateLinkedProp => self.update_linked_prop(bundle),
+            Command::MergeDuplicateTextures => self.merge_duplicate_textures(bundle),
+            Command::DisanchorAll =>
+            {
+                _ = self.manager.disanchor_selected_brushes_anchors(&mut self.edits_history);
+            },
+            Command::AttachSelected => self.attach_selected_brushes(bundle),
+            Command::AlignTextureSeam => self.align_selected_textures_across_seam(bundle),
+            Command::SnapPathsNodes =>
+            {
+                let invalid =
+                    self.manager.snap_selected_paths_nodes(&mut self.edits_history, self.grid);
+
+                if !invalid.is_empty()
+                {
+                    rfd::MessageDialog::new()
+                        .set_title("WARNING")
+                        .set_description(format!(
+                            "The paths of {} entities could not be snapped because they would \
+                             become invalid.",
+                            invalid.len()
+                        ))
+                        .set_buttons(rfd::MessageButtons::Ok)
+                        .show();
+                }
+            },
+            Command::SplitPath =>
+            {
+                if let Err(err) = self
+                    .manager
+                    .split_selected_path(bundle.drawing_resources, &mut self.edits_history)
+                {
+                    error_message(err);
+                }
+            },
+            Command::SnapSelection =>
+            {
+                let invalid = self.manager.snap_selected_entities(
+                    bundle.drawing_resources,
+                    &mut self.edits_history,
+                    self.grid
+                );
+
+                if !invalid.is_empty()
+                {
+                    rfd::MessageDialog::new()
+                        .set_title("WARNING")
+                        .set_description(format!(
+                            "The following entities could not be snapped because they would \
+                             become invalid:\n{}",
+                            invalid
+                                .iter()
+                                .map(|id| id.value().to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                        .set_buttons(rfd::MessageButtons::Ok)
+                        .show();
+                }
+            },
+            Command::RotateClockwise => self.rotate_quarter(bundle.drawing_resources, true),
+            Command::RotateCounterClockwise => self.rotate_quarter(bundle.drawing_resources, false),
+            Command::RotateTextures => self.rotate_textures_quarter(bundle.drawing_resources),
+            Command::ResetTextureSettings =>
+            {
+                _ = self.manager.reset_selected_textures_settings(
+                    bundle.drawing_resources,
+                    &mut self.edits_history
+                );
+            },
+            Command::AlignTexturesToGrid =>
+            {
+                _ = self.manager.align_selected_textures_to_grid(
+                    bundle.drawing_resources,
+                    &mut self.edits_history,
+                    self.grid
+                );
+            },
+            Command::BringToFront =>
+            {
+                self.manager.set_selected_draw_order(&mut self.edits_history, true);
+            },
+            Command::SendToBack =>
+            {
+                self.manager.set_selected_draw_order(&mut self.edits_history, false);
+            },
             Command::Quit =>
             {
                 self.quit(bundle, rfd::MessageButtons::YesNoCancel);
@@ -1797,6 +2935,19 @@ impl State
             {
                 self.toggle_collision();
             }
+            else if Bind::ToggleSprites.just_pressed(bundle.key_inputs, &bundle.config.binds)
+            {
+                self.toggle_sprites();
+            }
+            else if Bind::RotateClockwise.just_pressed(bundle.key_inputs, &bundle.config.binds)
+            {
+                self.rotate_quarter(bundle.drawing_resources, true);
+            }
+            else if Bind::RotateCounterClockwise
+                .just_pressed(bundle.key_inputs, &bundle.config.binds)
+            {
+                self.rotate_quarter(bundle.drawing_resources, false);
+            }
             else if HardcodedActions::Fullscreen.pressed(bundle.key_inputs)
             {
                 bundle.window.mode.toggle();
@@ -1806,6 +2957,17 @@ impl State
             {
                 self.quick_snap(bundle.drawing_resources);
             }
+            else if Bind::Intersection.alt_just_pressed(bundle.key_inputs, &bundle.config.binds) &&
+                Tool::Intersection.change_conditions_met(tool_change_conditions)
+            {
+                self.core.intersection_copy(
+                    bundle,
+                    &mut self.manager,
+                    &mut self.edits_history,
+                    &self.tools_settings,
+                    self.grid
+                );
+            }
             else if self.inputs.esc.just_pressed()
             {
                 self.core.disable_subtool();
@@ -1870,7 +3032,12 @@ impl State
         match ui_interaction.command
         {
             Command::ToggleMapPreview => self.toggle_map_preview(bundle.drawing_resources),
-            Command::ReloadTextures => self.start_texture_reload(bundle.next_tex_load),
+            Command::ReloadTextures =>
+            {
+                self.start_texture_reload(bundle.next_tex_load, bundle.texture_loader, None);
+            },
+            Command::ToggleTexturesWatch => self.toggle_textures_watch(),
+            Command::RenderToImage(size) => self.render_map_preview(size),
             Command::Quit =>
             {
                 self.quit(bundle, rfd::MessageButtons::YesNoCancel);
@@ -1914,6 +3081,8 @@ impl State
             self.grid,
             &mut self.tools_settings
         );
+
+        self.edits_history.flush_operations_log();
     }
 
     /// Changes the active tool.
@@ -1941,6 +3110,19 @@ impl State
         );
     }
 
+    /// Enables the custom tool at `index`.
+    #[inline]
+    fn change_custom_tool(&mut self, index: usize)
+    {
+        if self.map_preview()
+        {
+            return;
+        }
+
+        self.core
+            .change_custom_tool(index, &self.manager, &self.edits_history);
+    }
+
     /// Toggles the grid visibiity.
     #[inline]
     fn toggle_grid(&mut self) { self.grid.visible.toggle(); }
@@ -1976,6 +3158,26 @@ impl State
     #[inline]
     fn toggle_collision(&mut self) { self.show_collision.toggle(); }
 
+    /// Toggles the wireframe rendering of the brushes.
+    #[inline]
+    fn toggle_wireframe(&mut self) { self.wireframe.toggle(); }
+
+    /// Toggles the drawing of the sprites of the brushes.
+    #[inline]
+    fn toggle_sprites(&mut self) { self.show_sprites.toggle(); }
+
+    /// Toggles the drawing of the things' [`Hull`]s.
+    #[inline]
+    fn toggle_things_hulls(&mut self) { self.show_things_hulls.toggle(); }
+
+    /// Toggles the culling of the entities too small on screen to be drawn.
+    #[inline]
+    fn toggle_detail_culling(&mut self) { self.detail_culling.toggle(); }
+
+    /// Toggles the highlight of the brushes with the same texture as the selected ones.
+    #[inline]
+    fn toggle_texture_highlight(&mut self) { self.texture_highlight.toggle(); }
+
     /// Reloads the things.
     #[inline]
     fn reload_things(&mut self, bundle: &mut StateUpdateBundle)
@@ -1998,6 +3200,108 @@ impl State
         self.manager.finish_things_reload(bundle.things_catalog);
     }
 
+    /// Updates the selected linked prop with the selected entities and replaces the entities of
+    /// its other instances, upon confirmation if any of them were edited.
+    #[inline]
+    fn update_linked_prop(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let overridden = match self.clipboard.linked_prop_overrides(&self.manager)
+        {
+            Ok(overridden) => overridden,
+            Err(err) =>
+            {
+                error_message(err);
+                return;
+            }
+        };
+
+        if overridden != 0 &&
+            matches!(
+                rfd::MessageDialog::new()
+                    .set_buttons(rfd::MessageButtons::YesNo)
+                    .set_title("WARNING")
+                    .set_description(format!(
+                        "{overridden} instances of the prop were edited after they were last \
+                         updated. Their edits will be overwritten. Are you sure you wish to \
+                         procede?"
+                    ))
+                    .show(),
+                rfd::MessageDialogResult::No
+            )
+        {
+            return;
+        }
+
+        let (out_of_bounds, unlinked) = match self.clipboard.update_linked_prop(
+            bundle,
+            &mut self.manager,
+            &mut self.edits_history
+        )
+        {
+            Ok(result) => result,
+            Err(err) =>
+            {
+                error_message(err);
+                return;
+            }
+        };
+
+        if out_of_bounds != 0
+        {
+            error_message(&format!(
+                "{out_of_bounds} instances could not be updated because they would be out of bounds"
+            ));
+        }
+
+        if unlinked != 0
+        {
+            error_message(&format!(
+                "{unlinked} instances were unlinked from the prop because some of their entities \
+                 were deleted"
+            ));
+        }
+    }
+
+    /// Replaces the textures with identical content with a single one, upon confirmation.
+    #[inline]
+    fn merge_duplicate_textures(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let groups = bundle.drawing_resources.duplicate_textures(bundle.images);
+
+        if groups.is_empty()
+        {
+            error_message("No duplicate textures found");
+            return;
+        }
+
+        let mut description = String::from("The following textures have identical content:\n");
+
+        for (texture, duplicates) in groups.iter().filter_map(|group| group.split_first())
+        {
+            description.push_str(&format!("\n{texture} <- {}", duplicates.join(", ")));
+        }
+
+        description.push_str(
+            "\n\nDo you wish to replace all references to the textures on the right with the ones \
+             on the left?"
+        );
+
+        if let rfd::MessageDialogResult::No = rfd::MessageDialog::new()
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .set_title(NAME)
+            .set_description(description)
+            .show()
+        {
+            return;
+        }
+
+        _ = self.manager.merge_duplicate_textures(
+            bundle.drawing_resources,
+            &mut self.edits_history,
+            &groups
+        );
+    }
+
     /// Starts the application shutdown procedure.
     #[inline]
     pub fn quit(&mut self, bundle: &mut StateUpdateBundle, buttons: rfd::MessageButtons) -> bool
@@ -2042,6 +3346,50 @@ impl State
         );
     }
 
+    /// Rotates the selected entities by 90 degrees, clockwise if `clockwise` is true.
+    #[inline]
+    fn rotate_quarter(&mut self, drawing_resources: &DrawingResources, clockwise: bool)
+    {
+        if !self.core.entity_tool()
+        {
+            return;
+        }
+
+        _ = edit_target!(
+            self.tools_settings.target_switch(),
+            |rotate_texture| {
+                self.manager.rotate_selected_entities_quarter(
+                    drawing_resources,
+                    &mut self.edits_history,
+                    clockwise,
+                    rotate_texture
+                )
+            },
+            self.manager.rotate_selected_textures_quarter(
+                drawing_resources,
+                &mut self.edits_history,
+                clockwise
+            )
+        );
+    }
+
+    /// Increases the angle of the textures of the selected brushes by 90 degrees, regardless of
+    /// the edit target.
+    #[inline]
+    fn rotate_textures_quarter(&mut self, drawing_resources: &DrawingResources)
+    {
+        if !self.core.entity_tool()
+        {
+            return;
+        }
+
+        _ = self.manager.rotate_selected_textures_quarter(
+            drawing_resources,
+            &mut self.edits_history,
+            true
+        );
+    }
+
     #[cfg(feature = "debug")]
     #[inline]
     /// Toggles the debug lines visibility.
@@ -2050,33 +3398,65 @@ impl State
     //==============================================================
     // Texture reload
 
-    /// Starts the texture reload procedure.
+    /// Starts the texture reload procedure. If `changed` contains a value only the textures of
+    /// the contained files are reloaded.
     #[inline]
-    fn start_texture_reload(&mut self, next_tex_load: &mut NextState<TextureLoadingProgress>)
+    fn start_texture_reload(
+        &mut self,
+        next_tex_load: &mut NextState<TextureLoadingProgress>,
+        texture_loader: &mut TextureLoader,
+        changed: Option<HvHashSet<PathBuf>>
+    )
     {
         if self.reloading_textures
         {
             return;
         }
 
+        let description = if changed.is_some()
+        {
+            "Some texture files were added, removed, or modified. Reloading the textures will \
+             erase the history of all texture edits and will set all the textures of brushes \
+             with associated sprites that will result out of bound to errors. Are you sure you \
+             wish to procede?"
+        }
+        else
+        {
+            "Reloading the textures will erase the history of all texture edits and will set all \
+             the textures of brushes with associated sprites that will result out of bound to \
+             errors. Are you sure you wish to procede?"
+        };
+
         if let rfd::MessageDialogResult::No = rfd::MessageDialog::new()
             .set_buttons(rfd::MessageButtons::YesNo)
             .set_title("WARNING")
-            .set_description(
-                "Reloading the textures will erase the history of all texture edits and will set \
-                 all the textures of brushes with associated sprites that will result out of \
-                 bound to errors. Are you sure you wish to procede?"
-            )
+            .set_description(description)
             .show()
         {
             return;
         }
 
+        if let Some(changed) = changed
+        {
+            texture_loader.set_changed_files(changed);
+        }
+
         self.reloading_textures = true;
         self.inputs.clear();
         next_tex_load.set(TextureLoadingProgress::Initiated);
     }
 
+    /// Toggles the prompt to reload the textures whose files are added, removed, or modified.
+    #[inline]
+    fn toggle_textures_watch(&mut self)
+    {
+        self.textures_watcher = match self.textures_watcher
+        {
+            Some(_) => None,
+            None => TexturesWatcher::default().into()
+        };
+    }
+
     /// Concludes the texture reload.
     #[inline]
     pub fn finish_textures_reload(
@@ -2105,10 +3485,30 @@ impl State
     {
         self.clipboard.draw_props_to_photograph(bundle);
         self.grid.draw(bundle.window, &mut bundle.drawer, bundle.camera);
+        self.measurement_grid.draw(bundle.window, &mut bundle.drawer, bundle.camera);
+
+        if let Some(hull) = &bundle.play_area
+        {
+            bundle.drawer.hull(hull, Color::PlayArea);
+        }
+
+        self.manager.markers().draw(bundle);
+        self.manager.draw_notes(bundle);
+
+        if self.texture_highlight
+        {
+            self.manager.draw_same_texture_highlight(bundle);
+        }
+
         self.core
             .draw_active_tool(bundle, &self.manager, &self.tools_settings, self.show_tooltips);
         self.manager.draw_error_highlight(bundle);
 
+        if let Some(comparison) = &self.comparison
+        {
+            comparison.draw(bundle, &self.manager);
+        }
+
         if self.show_cursor
         {
             bundle
@@ -2163,3 +3563,128 @@ fn check_path_extension(path: PathBuf, extension: &'static str) -> PathBuf
     path.push_str(extension);
     PathBuf::from(path)
 }
+
+//=======================================================================//
+
+/// Returns `value` rounded to `decimals` decimal places, with the negative zero replaced by the
+/// positive one. If `value` can be represented by an `f32` the result is as well, so that the
+/// values are stored with the same size they would have had without rounding.
+#[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+#[inline]
+#[must_use]
+fn canonical_float(value: f64, decimals: u8) -> f64
+{
+    if !value.is_finite()
+    {
+        return value;
+    }
+
+    let multiplier = 10f64.powi(i32::from(decimals));
+    let mut rounded = (value * multiplier).round() / multiplier;
+
+    if f64::from(value as f32) == value
+    {
+        rounded = f64::from(rounded as f32);
+    }
+
+    if rounded == 0f64
+    {
+        0f64
+    }
+    else
+    {
+        rounded
+    }
+}
+
+//=======================================================================//
+
+/// Returns `vec` with its coordinates rounded through [`canonical_float`].
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+#[must_use]
+fn canonical_vec(vec: Vec2, decimals: u8) -> Vec2
+{
+    Vec2::new(
+        canonical_float(f64::from(vec.x), decimals) as f32,
+        canonical_float(f64::from(vec.y), decimals) as f32
+    )
+}
+
+//=======================================================================//
+
+/// Rounds all the floating point values contained in `value` to `decimals` decimal places,
+/// replaces the negative zeros with positive ones, and sorts the entries of the maps by the
+/// encoding of their keys.
+#[inline]
+fn canonicalize(value: &mut ciborium::value::Value, decimals: u8)
+{
+    use ciborium::value::Value;
+
+    match value
+    {
+        Value::Float(float) => *float = canonical_float(*float, decimals),
+        Value::Tag(_, value) => canonicalize(value, decimals),
+        Value::Array(values) =>
+        {
+            for value in values
+            {
+                canonicalize(value, decimals);
+            }
+        },
+        Value::Map(pairs) =>
+        {
+            for (key, value) in &mut *pairs
+            {
+                canonicalize(key, decimals);
+                canonicalize(value, decimals);
+            }
+
+            pairs.sort_by_cached_key(|(key, _)| {
+                let mut bytes = Vec::new();
+                _ = ciborium::ser::into_writer(key, &mut bytes);
+                bytes
+            });
+        },
+        _ => ()
+    };
+}
+
+//=======================================================================//
+
+/// Serializes `value` to `writer` with its floating point values normalized through
+/// [`canonicalize`].
+#[inline]
+fn canonical_into_writer<T, W>(value: &T, decimals: u8, writer: W) -> Result<(), ()>
+where
+    T: Serialize + ?Sized,
+    W: Write
+{
+    let mut value = ciborium::value::Value::serialized(value).map_err(|_| ())?;
+    canonicalize(&mut value, decimals);
+    ciborium::ser::into_writer(&value, writer).map_err(|_| ())
+}
+
+//=======================================================================//
+
+/// Returns the path of the file storing the editor-only notes of the map saved at `path`.
+#[inline]
+#[must_use]
+fn notes_path(path: &Path) -> PathBuf
+{
+    let mut path = path.as_os_str().to_os_string();
+    path.push(".notes");
+    PathBuf::from(path)
+}
+
+//=======================================================================//
+
+/// Returns the path of the file storing the editor-only markers of the map saved at `path`.
+#[inline]
+#[must_use]
+fn markers_path(path: &Path) -> PathBuf
+{
+    let mut path = path.as_os_str().to_os_string();
+    path.push(".markers");
+    PathBuf::from(path)
+}
@@ -107,6 +107,18 @@ impl Edit
             })
     }
 
+    /// Returns an iterator to the sub-edits and the [`Id`]s of the entities they affect.
+    #[inline]
+    pub fn sub_edits(&self) -> impl Iterator<Item = (&HvVec<Id>, &EditType)>
+    {
+        self.0.iter().map(|(ids, ed_type)| (ids, ed_type))
+    }
+
+    /// Returns the key of the edited property, if any.
+    #[inline]
+    #[must_use]
+    pub fn property_key(&self) -> Option<&str> { self.1.as_deref() }
+
     //==============================================================
     // Update
 
@@ -140,6 +152,12 @@ impl Edit
 
             false
         }
+        else if matches!(edit, EditType::MapBound(_))
+        {
+            assert!(identifiers.is_empty(), "Identifiers associated to map bound edit.");
+
+            false
+        }
         else if !matches!(
             edit,
             EditType::EntitySelection |
@@ -186,14 +204,24 @@ impl Edit
         self.0.insert(0, (identifiers, edit));
     }
 
-    /// Pushes a property sub-edit.
+    /// Whether a property sub-edit concerning `key` can be pushed, which is the case if no other
+    /// property was edited.
+    #[inline]
+    #[must_use]
+    pub fn accepts_property(&self, key: &str) -> bool { self.1.as_ref().map_or(true, |k| k == key) }
+
+    /// Pushes a property sub-edit. The sub-edits concerning the same property are merged.
+    /// # Panics
+    /// Panics if a different property was already edited.
     #[inline]
     pub fn push_property(&mut self, key: &str, iter: impl Iterator<Item = (Id, Value)>)
     {
-        assert!(
-            std::mem::replace(&mut self.1, key.to_owned().into()).is_none(),
-            "Property edit already stored."
-        );
+        assert!(self.accepts_property(key), "Property edit already stored.");
+
+        if self.1.is_none()
+        {
+            self.1 = key.to_owned().into();
+        }
 
         for (id, value) in iter
         {
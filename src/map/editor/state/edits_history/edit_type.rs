@@ -218,6 +218,10 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     TextureAngleDelta(f32),
     /// Texture draw height change.
     TextureHeight(i8),
+    /// Texture trim sheet region change.
+    TextureTrim(Option<String>),
+    /// Texture brightness change.
+    TextureBrightness(f32),
     /// Texture animation change.
     Animation(Animation),
     /// Texture animation frame info moved up. true -> atlas, false -> list.
@@ -273,7 +277,11 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     /// Brush collision change.
     Collision(bool),
     /// Entity property change.
-    Property(Value)
+    Property(Value),
+    /// Entity note change.
+    Note(String),
+    /// Map bound change.
+    MapBound(f32)
 }
 
 impl EditType
@@ -323,6 +331,8 @@ impl EditType
                 Self::TextureAngle(_) |
                 Self::TextureAngleDelta(_) |
                 Self::TextureHeight(_) |
+                Self::TextureTrim(_) |
+                Self::TextureBrightness(_) |
                 Self::Animation(_) |
                 Self::AnimationMoveUp(..) |
                 Self::AnimationMoveDown(..) |
@@ -540,6 +550,10 @@ impl EditType
                     {
                         *value = brush.set_texture_animation(drawing_resources, std::mem::take(value));
                     },
+                    Self::TextureTrim(value) =>
+                    {
+                        *value = brush.set_texture_trim(std::mem::take(value)).unwrap();
+                    },
                     Self::AtlasAnimationTiming(timing) =>
                     {
                         *timing = brush.set_texture_atlas_animation_timing(std::mem::take(timing).unwrap()).into();
@@ -570,6 +584,7 @@ impl EditType
             (TextureScaleY, scale_y, drawing_resources),
             (TextureAngle, angle, drawing_resources),
             (TextureHeight, height),
+            (TextureBrightness, brightness),
             (AtlasAnimationX, atlas_animation_x_partition, drawing_resources),
             (AtlasAnimationY, atlas_animation_y_partition, drawing_resources),
             (AtlasAnimationLen, atlas_animation_len),
@@ -764,6 +779,12 @@ impl EditType
             return;
         }
 
+        if let Self::MapBound(bound) = self
+        {
+            *bound = interface.replace_map_bound(*bound);
+            return;
+        }
+
         if self.property(interface, single!(), property)
         {
             return;
@@ -867,6 +888,7 @@ impl EditType
             Self::PathCreation(path) => *path = interface.remove_path(single!()).into(),
             Self::Anchor(anchor) => interface.remove_anchor(single!(), *anchor),
             Self::Disanchor(anchor) => interface.insert_anchor(single!(), *anchor),
+            Self::Note(note) => *note = interface.set_note(single!(), std::mem::take(note)),
             Self::PathNodesSelection(idxs) =>
             {
                 interface.schedule_overall_node_update();
@@ -1125,6 +1147,12 @@ impl EditType
             return;
         }
 
+        if let Self::MapBound(bound) = self
+        {
+            *bound = interface.replace_map_bound(*bound);
+            return;
+        }
+
         if self.property(interface, single!(), property)
         {
             return;
@@ -1228,6 +1256,7 @@ impl EditType
             },
             Self::Anchor(anchor) => interface.insert_anchor(single!(), *anchor),
             Self::Disanchor(anchor) => interface.remove_anchor(single!(), *anchor),
+            Self::Note(note) => *note = interface.set_note(single!(), std::mem::take(note)),
             Self::PathNodesSelection(idxs) =>
             {
                 interface.schedule_overall_node_update();
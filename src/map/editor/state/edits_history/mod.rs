@@ -1,5 +1,6 @@
 mod edit;
 pub(in crate::map::editor::state) mod edit_type;
+mod operations_log;
 
 //=======================================================================//
 // IMPORTS
@@ -9,13 +10,15 @@ pub(in crate::map::editor::state) mod edit_type;
 use bevy::prelude::Vec2;
 use hill_vacuum_shared::{continue_if_none, return_if_none};
 
-use self::{edit::Edit, edit_type::EditType};
+use self::{edit::Edit, edit_type::EditType, operations_log::OperationsLog};
 use super::{
     core::{draw_tool::cursor_polygon::FreeDrawStatus, tool::EditingTarget},
     manager::EntitiesManager,
     ui::Ui
 };
 use crate::{
+    config::OperationsLogTarget,
+    error_message,
     map::{
         brush::{
             convex_polygon::{ConvexPolygon, VertexesMove},
@@ -183,6 +186,8 @@ pub(in crate::map::editor::state) struct EditsHistory
     current_edit: Edit,
     /// Whether an edit lasting more than a frame is happening.
     multiframe_edit: bool,
+    /// The amount of open edit groups. Nested groups are flattened into the outermost one.
+    open_groups: usize,
     /// The amount of states we can undo.
     prev_states_amount: usize,
     /// The index of the earliest tool edit, if any.
@@ -195,7 +200,9 @@ pub(in crate::map::editor::state) struct EditsHistory
     /// because it only contains selection edits
     selections_only_edit_halted: bool,
     /// The index of the edit where the file was saved the last time, if any.
-    last_save_edit: Option<usize>
+    last_save_edit: Option<usize>,
+    /// The log of the operations to be read by external tools, if enabled.
+    operations_log: Option<OperationsLog>
 }
 
 impl Default for EditsHistory
@@ -207,12 +214,14 @@ impl Default for EditsHistory
             stack: hv_vec![capacity; 100],
             current_edit: Edit::default(),
             multiframe_edit: false,
+            open_groups: 0,
             prev_states_amount: 0,
             earliest_tool_edit: None,
             earliest_thing_edit: None,
             earliest_texture_edit: None,
             selections_only_edit_halted: false,
-            last_save_edit: 0.into()
+            last_save_edit: 0.into(),
+            operations_log: None
         }
     }
 }
@@ -270,6 +279,8 @@ impl EditsHistory
         (texture_parallax_y, (identifier: Id, value: f32), (hv_vec![identifier], EditType::TextureParallaxY(value))),
         (texture_angle, (identifier: Id, value: f32), (hv_vec![identifier], EditType::TextureAngle(value))),
         (texture_height, (identifier: Id, value: i8), (hv_vec![identifier], EditType::TextureHeight(value))),
+        (texture_trim, (identifier: Id, value: Option<String>), (hv_vec![identifier], EditType::TextureTrim(value))),
+        (texture_brightness, (identifier: Id, value: f32), (hv_vec![identifier], EditType::TextureBrightness(value))),
         (sprite, (identifier: Id, value: Sprite, offset_x: f32, offset_y: f32), (hv_vec![identifier], EditType::Sprite(value, offset_x, offset_y))),
         (animation, (identifier: Id, animation: Animation), (hv_vec![identifier], EditType::Animation(animation))),
         (atlas_x, (identifier: Id, x: u32), (hv_vec![identifier], EditType::AtlasAnimationX(x))),
@@ -278,7 +289,9 @@ impl EditsHistory
         (atlas_timing, (identifier: Id, timing: Timing), (hv_vec![identifier], EditType::AtlasAnimationTiming(timing.into()))),
         (atlas_uniform_time, (identifier: Id, time: f32), (hv_vec![identifier], EditType::AtlasAnimationUniformTime(time))),
         (atlas_frame_time, (identifier: Id, value: (usize, f32)), (hv_vec![identifier], EditType::AtlasAnimationFrameTime(value.0, value.1))),
-        (collision, (identifier: Id, value: bool), (hv_vec![identifier], EditType::Collision(value)))
+        (collision, (identifier: Id, value: bool), (hv_vec![identifier], EditType::Collision(value))),
+        (note, (identifier: Id, note: String), (hv_vec![identifier], EditType::Note(note))),
+        (map_bound, (bound: f32), (hv_vec![], EditType::MapBound(bound)))
 	);
 
     #[rustfmt::skip]
@@ -307,6 +320,8 @@ impl EditsHistory
         (texture_parallax_y, f32),
         (texture_angle, f32),
         (texture_height, i8),
+        (texture_trim, Option<String>),
+        (texture_brightness, f32),
         (texture_scale_flip, (f32, f32)),
         (animation, Animation),
         (atlas_x, u32),
@@ -453,6 +468,13 @@ impl EditsHistory
         self.push_onto_current_edit(identifiers, EditType::ThingMove(delta));
     }
 
+    #[allow(clippy::missing_docs_in_private_items)]
+    #[inline]
+    pub fn brush_move(&mut self, identifier: Id, delta: Vec2, move_texture: bool)
+    {
+        self.push_onto_current_edit(hv_vec![identifier], EditType::BrushMove(delta, move_texture));
+    }
+
     #[allow(clippy::missing_docs_in_private_items)]
     #[inline]
     pub fn thing_move(&mut self, identifier: Id, delta: Vec2)
@@ -480,6 +502,18 @@ impl EditsHistory
         self.push_onto_current_edit(identifiers, EditType::TextureMove(delta));
     }
 
+    #[allow(clippy::missing_docs_in_private_items)]
+    #[inline]
+    pub fn textures_move_cluster(&mut self, identifiers: HvVec<Id>, delta: Vec2)
+    {
+        if identifiers.is_empty()
+        {
+            return;
+        }
+
+        self.push_onto_current_edit(identifiers, EditType::TextureMove(delta));
+    }
+
     #[allow(clippy::missing_docs_in_private_items)]
     #[inline]
     pub fn path_nodes_move(&mut self, nodes_move: HvVec<(Id, HvVec<NodesMove>)>)
@@ -509,6 +543,12 @@ impl EditsHistory
         })
     }
 
+    /// Whether the edit of the property `key` can be stored in the current edit, which can only
+    /// contain the edits of one property.
+    #[inline]
+    #[must_use]
+    pub fn accepts_property(&self, key: &str) -> bool { self.current_edit.accepts_property(key) }
+
     #[allow(clippy::missing_docs_in_private_items)]
     #[inline]
     pub fn property(&mut self, key: &str, iter: impl Iterator<Item = (Id, Value)>)
@@ -612,7 +652,7 @@ impl EditsHistory
     /// Pushes the current [`Edit`] on the history unless it is empty, or it is not concluded, or if
     /// edit push is halted by a selection only edit.
     #[inline]
-    pub fn push_frame_edit(&mut self)
+    pub fn push_frame_edit(&mut self, manager: &EntitiesManager)
     {
         if self.current_edit.is_empty() || !self.concluded_edit()
         {
@@ -627,6 +667,14 @@ impl EditsHistory
             return;
         }
 
+        if let Some(log) = &mut self.operations_log
+        {
+            if log.record(&self.current_edit, manager, None).is_err()
+            {
+                self.disable_operations_log();
+            }
+        }
+
         self.execute_frame_edit_push();
     }
 
@@ -742,6 +790,37 @@ impl EditsHistory
         self.multiframe_edit = false;
     }
 
+    /// Whether there is an open edit group.
+    #[inline]
+    #[must_use]
+    pub const fn grouping(&self) -> bool { self.open_groups != 0 }
+
+    /// Opens an edit group. All the edits pushed until the matching [`EditsHistory::end_group`]
+    /// call, even if spanning several frames, are merged into a single undoable step.
+    /// Groups can be nested, in which case they are flattened into the outermost one.
+    #[inline]
+    pub fn begin_group(&mut self)
+    {
+        // Edits halted by a selection only edit must not be merged into the group.
+        if self.open_groups == 0 && self.selections_only_edit_halted
+        {
+            self.force_push_frame_edit();
+        }
+
+        self.open_groups += 1;
+    }
+
+    /// Closes the last opened edit group. The edits are pushed onto the history at the end of the
+    /// frame if the outermost group was closed.
+    /// # Panics
+    /// Panics if there are no open groups.
+    #[inline]
+    pub fn end_group(&mut self)
+    {
+        assert!(self.open_groups != 0, "No open edit group.");
+        self.open_groups -= 1;
+    }
+
     /// Whether there are no unsaved edits.
     #[inline]
     #[must_use]
@@ -764,7 +843,85 @@ impl EditsHistory
     /// Whether there is no ongoing edit.
     #[inline]
     #[must_use]
-    const fn concluded_edit(&self) -> bool { !self.multiframe_edit }
+    const fn concluded_edit(&self) -> bool { !self.multiframe_edit && self.open_groups == 0 }
+
+    //=======================================================================//
+    // Operations log
+
+    /// Sets the target the operations performed on the map are written to. The log is disabled if
+    /// `target` is [`None`].
+    #[inline]
+    pub fn set_operations_log(&mut self, target: Option<&OperationsLogTarget>)
+    {
+        self.operations_log = target.and_then(|target| {
+            let log = OperationsLog::new(target);
+
+            if log.is_none()
+            {
+                error_message("Error opening the operations log file.");
+            }
+
+            log
+        });
+    }
+
+    /// Clears the history while retaining the operations log, which is notified of the reset.
+    #[inline]
+    pub fn reset(&mut self)
+    {
+        *self = Self {
+            operations_log: self.operations_log.take(),
+            ..Default::default()
+        };
+
+        self.log_event("reset");
+    }
+
+    /// Writes the operation `op` to the operations log, if enabled.
+    #[inline]
+    fn log_event(&mut self, op: &str)
+    {
+        let log = return_if_none!(&mut self.operations_log);
+
+        if log.event(op).is_err()
+        {
+            self.disable_operations_log();
+        }
+    }
+
+    /// Writes the [`Edit`] at `index` of the stack to the operations log, if enabled, as undone or
+    /// redone according to `history`.
+    #[inline]
+    fn log_history_edit(&mut self, index: usize, manager: &EntitiesManager, history: &str)
+    {
+        let log = return_if_none!(&mut self.operations_log);
+
+        if log.record(&self.stack[index], manager, history.into()).is_err()
+        {
+            self.disable_operations_log();
+        }
+    }
+
+    /// Writes the buffered operations to the operations log target, if enabled. Called once at the
+    /// end of the frame.
+    #[inline]
+    pub fn flush_operations_log(&mut self)
+    {
+        let log = return_if_none!(&mut self.operations_log);
+
+        if log.flush().is_err()
+        {
+            self.disable_operations_log();
+        }
+    }
+
+    /// Disables the operations log after a failed write.
+    #[inline]
+    fn disable_operations_log(&mut self)
+    {
+        self.operations_log = None;
+        error_message("Error writing the operations log, logging disabled.");
+    }
 
     //=======================================================================//
     // Undo/redo
@@ -802,6 +959,10 @@ impl EditsHistory
                 *idx -= 1;
             }
         }
+        else
+        {
+            self.log_history_edit(self.prev_states_amount, interface.manager(), "undo");
+        }
     }
 
     /// Redoes a change for a cluster of identifiers that were edited in group.
@@ -835,6 +996,10 @@ impl EditsHistory
                 *idx += 1;
             }
         }
+        else
+        {
+            self.log_history_edit(self.prev_states_amount, interface.manager(), "redo");
+        }
 
         self.prev_states_amount += 1;
     }
@@ -0,0 +1,387 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fmt::Write as _,
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use bevy::prelude::Vec2;
+use hill_vacuum_shared::return_if_none;
+
+use super::{edit::Edit, edit_type::EditType};
+use crate::{
+    config::OperationsLogTarget,
+    map::{
+        drawer::texture::TextureInterface,
+        editor::state::manager::EntitiesManager,
+        path::Moving,
+        properties::Value,
+        thing::ThingInterface
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The data written along with an operation.
+enum Payload<'a>
+{
+    /// No data.
+    None,
+    /// The movement delta.
+    Delta(Vec2),
+    /// The [`Id`] of the entity the operation refers to.
+    Entity(Id),
+    /// The current vertexes of the brush.
+    Brush,
+    /// The current state of the thing.
+    Thing,
+    /// The current texture of the brush.
+    Texture,
+    /// The current nodes of the path.
+    Path,
+    /// The current value of the property with the key.
+    Property(Option<&'a str>)
+}
+
+//=======================================================================//
+
+/// A writer of the high level operations performed on the map, one JSON object per line, to be
+/// consumed by external tools.
+/// The lines are assembled by hand rather than through a serializer, so each line is valid JSON
+/// only because every written value is one of the following: a number, `null`, one of the fixed
+/// operation and history names, a boolean or finite numeric property value, written through its
+/// `Display` implementation, or a string escaped through `push_str`, which escapes quotes,
+/// backslashes, and control characters. Non-finite float property values are written as `null`.
+/// The lines are buffered and only written to the target when [`OperationsLog::flush`] is called,
+/// at the end of the frame, or when the log is dropped.
+pub(in crate::map::editor::state::edits_history) struct OperationsLog(Box<dyn Write + Send + Sync>);
+
+impl Drop for OperationsLog
+{
+    #[inline]
+    fn drop(&mut self) { _ = self.0.flush(); }
+}
+
+impl OperationsLog
+{
+    /// Returns a new [`OperationsLog`] writing to `target`, if it could be opened.
+    #[inline]
+    pub fn new(target: &OperationsLogTarget) -> Option<Self>
+    {
+        let writer: Box<dyn Write + Send + Sync> = match target
+        {
+            OperationsLogTarget::Stdout => Box::new(BufWriter::new(std::io::stdout())),
+            OperationsLogTarget::File(path) =>
+            {
+                Box::new(BufWriter::new(
+                    OpenOptions::new().create(true).append(true).open(path).ok()?
+                ))
+            }
+        };
+
+        Self(writer).into()
+    }
+
+    /// Writes the operations contained in `edit`, with the current state of the entities they
+    /// affected as stored in `manager`. If `history` is not [`None`], `edit` was undone or redone
+    /// and the operations are written along with it.
+    #[inline]
+    pub fn record(
+        &mut self,
+        edit: &Edit,
+        manager: &EntitiesManager,
+        history: Option<&str>
+    ) -> std::io::Result<()>
+    {
+        let mut buffer = String::new();
+
+        for (identifiers, edit_type) in edit.sub_edits()
+        {
+            let (op, payload) = match Self::operation(edit_type, edit.property_key())
+            {
+                Some(value) => value,
+                None => continue
+            };
+
+            if matches!(payload, Payload::None | Payload::Delta(_) | Payload::Entity(_))
+            {
+                Self::push_line(&mut buffer, op, identifiers.iter().copied(), history, |buffer| {
+                    match payload
+                    {
+                        Payload::Delta(delta) =>
+                        {
+                            buffer.push_str(",\"delta\":");
+                            push_vec(buffer, delta);
+                        },
+                        Payload::Entity(id) =>
+                        {
+                            _ = write!(buffer, ",\"entity\":{}", id.value());
+                        },
+                        _ => ()
+                    };
+                });
+
+                continue;
+            }
+
+            for id in identifiers.iter().copied()
+            {
+                // Undone spawns and redone despawns are written without the state.
+                let exists = manager.entity_exists(id);
+
+                if !exists && history.is_none()
+                {
+                    continue;
+                }
+
+                Self::push_line(&mut buffer, op, Some(id).into_iter(), history, |buffer| {
+                    if exists
+                    {
+                        Self::push_payload(buffer, &payload, id, manager);
+                    }
+                });
+            }
+        }
+
+        self.0.write_all(buffer.as_bytes())
+    }
+
+    /// Writes an operation that does not refer to any entity, such as an undo.
+    #[inline]
+    pub fn event(&mut self, op: &str) -> std::io::Result<()>
+    {
+        let mut buffer = String::new();
+        Self::push_line(&mut buffer, op, std::iter::empty(), None, |_| ());
+        self.0.write_all(buffer.as_bytes())
+    }
+
+    /// Writes the buffered operations to the target.
+    #[inline]
+    pub fn flush(&mut self) -> std::io::Result<()> { self.0.flush() }
+
+    /// Returns the name of the operation represented by `edit_type` and the data to be written
+    /// with it, if it should be logged.
+    #[inline]
+    fn operation<'a>(
+        edit_type: &EditType,
+        property_key: Option<&'a str>
+    ) -> Option<(&'static str, Payload<'a>)>
+    {
+        let value = match edit_type
+        {
+            EditType::BrushDraw(_) | EditType::BrushSpawn(..) => ("brush_spawned", Payload::Brush),
+            EditType::DrawnBrushDespawn(_) | EditType::BrushDespawn(..) =>
+            {
+                ("brush_despawned", Payload::None)
+            },
+            EditType::PolygonEdit(_) |
+            EditType::VertexInsertion(_) |
+            EditType::VertexesDeletion(_) |
+            EditType::VertexesMove(_) |
+            EditType::SidesDeletion(_) |
+            EditType::VertexesSnap(_) |
+            EditType::Flip(..) |
+            EditType::Collision(_) => ("brush_edited", Payload::Brush),
+            EditType::BrushMove(delta, _) => ("brush_moved", Payload::Delta(*delta)),
+            EditType::ThingDraw(_) | EditType::ThingSpawn(_) => ("thing_spawned", Payload::Thing),
+            EditType::DrawnThingDespawn(_) | EditType::ThingDespawn(_) =>
+            {
+                ("thing_despawned", Payload::None)
+            },
+            EditType::ThingMove(delta) => ("thing_moved", Payload::Delta(*delta)),
+            EditType::ThingChange(_) | EditType::ThingHeight(_) | EditType::ThingAngle(_) =>
+            {
+                ("thing_edited", Payload::Thing)
+            },
+            EditType::Texture(_) | EditType::TextureRemoval(_) => ("texture_set", Payload::Texture),
+            EditType::TextureMove(delta) => ("texture_moved", Payload::Delta(*delta)),
+            EditType::PathCreation(_) => ("path_created", Payload::Path),
+            EditType::PathDeletion(_) => ("path_deleted", Payload::None),
+            EditType::PathNodeInsertion(_) |
+            EditType::PathNodesMove(_) |
+            EditType::PathNodesDeletion(_) |
+            EditType::PathNodesSnap(_) |
+            EditType::PathNodeStandby(_) |
+            EditType::PathNodeAccel(_) |
+            EditType::PathNodeDecel(_) |
+            EditType::PathNodeMaxSpeed(_) |
+            EditType::PathNodeMinSpeed(_) => ("path_edited", Payload::Path),
+            EditType::Anchor(id) => ("anchored", Payload::Entity(*id)),
+            EditType::Disanchor(id) => ("disanchored", Payload::Entity(*id)),
+            EditType::Property(_) => ("property_set", Payload::Property(property_key)),
+            edit_type if edit_type.texture_edit() => ("texture_edited", Payload::Texture),
+            _ => return None
+        };
+
+        value.into()
+    }
+
+    /// Pushes a line describing the operation `op` performed on `identifiers` to `buffer`,
+    /// with `history` if the operation was undone or redone. `f` pushes the additional fields.
+    #[inline]
+    fn push_line<F>(
+        buffer: &mut String,
+        op: &str,
+        identifiers: impl Iterator<Item = Id>,
+        history: Option<&str>,
+        f: F
+    ) where
+        F: FnOnce(&mut String)
+    {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+
+        _ = write!(buffer, "{{\"time\":{time},\"op\":\"{op}\",\"ids\":[");
+
+        for (i, id) in identifiers.enumerate()
+        {
+            if i != 0
+            {
+                buffer.push(',');
+            }
+
+            _ = write!(buffer, "{}", id.value());
+        }
+
+        buffer.push(']');
+
+        if let Some(history) = history
+        {
+            _ = write!(buffer, ",\"history\":\"{history}\"");
+        }
+
+        f(buffer);
+        buffer.push_str("}\n");
+    }
+
+    /// Pushes the fields of `payload` of the entity with [`Id`] `id` to `buffer`.
+    #[inline]
+    fn push_payload(buffer: &mut String, payload: &Payload, id: Id, manager: &EntitiesManager)
+    {
+        match payload
+        {
+            Payload::Brush =>
+            {
+                buffer.push_str(",\"vertexes\":");
+                push_vec_list(buffer, manager.brush(id).vertexes());
+            },
+            Payload::Thing =>
+            {
+                let thing = manager.thing(id);
+                _ = write!(buffer, ",\"thing\":{},\"pos\":", thing.thing().value());
+                push_vec(buffer, thing.pos());
+                _ = write!(buffer, ",\"angle\":{}", thing.angle());
+            },
+            Payload::Texture =>
+            {
+                buffer.push_str(",\"texture\":");
+
+                match manager.brush(id).texture_settings()
+                {
+                    Some(settings) => push_str(buffer, settings.name()),
+                    None => buffer.push_str("null")
+                };
+            },
+            Payload::Path =>
+            {
+                buffer.push_str(",\"nodes\":");
+
+                match manager.moving(id).path()
+                {
+                    Some(path) =>
+                    {
+                        push_vec_list(buffer, (0..path.len()).map(|i| path.node_at_index_pos(i)));
+                    },
+                    None => buffer.push_str("null")
+                };
+            },
+            Payload::Property(key) =>
+            {
+                let key = return_if_none!(key);
+                let properties = if manager.is_thing(id)
+                {
+                    manager.thing(id).properties()
+                }
+                else
+                {
+                    manager.brush(id).properties_as_ref()
+                };
+
+                buffer.push_str(",\"key\":");
+                push_str(buffer, key);
+                buffer.push_str(",\"value\":");
+
+                match properties.get(key)
+                {
+                    Value::String(value) => push_str(buffer, value),
+                    Value::F32(value) if !value.is_finite() => buffer.push_str("null"),
+                    Value::F64(value) if !value.is_finite() => buffer.push_str("null"),
+                    value => buffer.push_str(&value.to_string())
+                };
+            },
+            Payload::None | Payload::Delta(_) | Payload::Entity(_) => ()
+        };
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Pushes `vec` to `buffer` as a JSON array.
+#[inline]
+fn push_vec(buffer: &mut String, vec: Vec2) { _ = write!(buffer, "[{},{}]", vec.x, vec.y); }
+
+//=======================================================================//
+
+/// Pushes the elements of `iter` to `buffer` as a JSON array of arrays.
+#[inline]
+fn push_vec_list(buffer: &mut String, iter: impl Iterator<Item = Vec2>)
+{
+    buffer.push('[');
+
+    for (i, vec) in iter.enumerate()
+    {
+        if i != 0
+        {
+            buffer.push(',');
+        }
+
+        push_vec(buffer, vec);
+    }
+
+    buffer.push(']');
+}
+
+//=======================================================================//
+
+/// Pushes `value` to `buffer` as a JSON string.
+#[inline]
+fn push_str(buffer: &mut String, value: &str)
+{
+    buffer.push('"');
+
+    for c in value.chars()
+    {
+        match c
+        {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            c if c.is_control() => buffer.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => buffer.push(c)
+        };
+    }
+
+    buffer.push('"');
+}
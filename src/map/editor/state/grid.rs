@@ -3,12 +3,18 @@
 //
 //=======================================================================//
 
+use std::ops::RangeInclusive;
+
 use bevy::prelude::{Transform, Vec2, Window};
 
 use super::manager::EntitiesManager;
 use crate::{
     map::drawer::{color::Color, EditDrawer},
-    utils::{hull::Hull, math::AroundEqual, misc::Camera}
+    utils::{
+        hull::Hull,
+        math::{points::rotate_point_around_origin, AroundEqual},
+        misc::Camera
+    }
 };
 
 //=======================================================================//
@@ -26,7 +32,9 @@ pub(in crate::map) struct Grid
     pub visible: bool,
     /// When true, the position of the grid squares is shifted by half of its size, both
     /// horizontally and vertically.
-    pub shifted: bool
+    pub shifted: bool,
+    /// The rotation of the grid around the origin, in degrees.
+    angle:       f32
 }
 
 impl Default for Grid
@@ -38,25 +46,34 @@ impl Default for Grid
         Self {
             size:    64,
             visible: true,
-            shifted: false
+            shifted: false,
+            angle:   0f32
         }
     }
 }
 
 impl Grid
 {
+    /// The range of the rotation of the grid.
+    pub(in crate::map::editor::state) const ANGLE_RANGE: RangeInclusive<f32> = 0f32..=359.99;
+
     //==============================================================
     // New
 
-    /// Returns a new [`Grid`].
+    /// Returns a new [`Grid`] rotated by `angle` degrees.
     #[inline]
-    pub(in crate::map::editor::state) const fn new(size: i16, visible: bool, shifted: bool)
-        -> Self
+    pub(in crate::map::editor::state) const fn new(
+        size: i16,
+        visible: bool,
+        shifted: bool,
+        angle: f32
+    ) -> Self
     {
         Self {
             size,
             visible,
-            shifted
+            shifted,
+            angle
         }
     }
 
@@ -73,10 +90,47 @@ impl Grid
     #[must_use]
     pub(in crate::map::editor::state) fn size_f32(self) -> f32 { f32::from(self.size) }
 
+    /// Returns the rotation of the grid, in degrees.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn angle(self) -> f32 { self.angle }
+
+    /// Whether the grid is rotated.
+    #[allow(clippy::float_cmp)]
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn rotated(self) -> bool { self.angle != 0f32 }
+
+    /// Returns `point` transformed from world space to the space of the rotated grid.
+    #[inline]
+    #[must_use]
+    fn to_grid_space(self, point: Vec2) -> Vec2
+    {
+        if !self.rotated()
+        {
+            return point;
+        }
+
+        rotate_point_around_origin(point, -self.angle.to_radians())
+    }
+
+    /// Returns `point` transformed from the space of the rotated grid to world space.
+    #[inline]
+    #[must_use]
+    fn to_world_space(self, point: Vec2) -> Vec2
+    {
+        if !self.rotated()
+        {
+            return point;
+        }
+
+        rotate_point_around_origin(point, self.angle.to_radians())
+    }
+
     //==============================================================
     // Square
 
-    /// Returns the square that contains `pos`.
+    /// Returns the square that contains `pos`, ignoring the rotation of the grid.
     #[allow(clippy::cast_possible_truncation)]
     #[inline]
     #[must_use]
@@ -138,6 +192,15 @@ impl Grid
         Hull::new(top, bottom, left, right)
     }
 
+    /// Returns the vertex of the grid closest to `pos`.
+    #[inline]
+    #[must_use]
+    pub fn nearest_vertex(self, pos: Vec2) -> Vec2
+    {
+        let pos = self.to_grid_space(pos);
+        self.to_world_space(self.square(pos).nearest_corner_to_point(pos))
+    }
+
     //==============================================================
     // Snap
 
@@ -176,6 +239,26 @@ impl Grid
         }
     }
 
+    /// Sets the rotation of the grid to `angle` degrees.
+    #[allow(clippy::float_cmp)]
+    #[inline]
+    pub(in crate::map::editor::state) fn set_angle(
+        &mut self,
+        angle: f32,
+        manager: &mut EntitiesManager
+    )
+    {
+        let angle = angle.rem_euclid(360f32);
+
+        if angle == self.angle
+        {
+            return;
+        }
+
+        self.angle = angle;
+        manager.schedule_outline_update();
+    }
+
     //==============================================================
     // Snap
 
@@ -184,13 +267,14 @@ impl Grid
     #[must_use]
     pub fn snap_point(self, point: Vec2) -> Option<Vec2>
     {
+        let point = self.to_grid_space(point);
         let center = self.square(point).center();
         let snapped = Vec2::new(
             self.snap_value_from_center(point.x, center.x),
             self.snap_value_from_center(point.y, center.y)
         );
 
-        (snapped != point).then_some(snapped)
+        (snapped != point).then(|| self.to_world_space(snapped))
     }
 
     /// Snaps `value` to the grid, in a way that moves it further away from `center`.
@@ -262,19 +346,26 @@ impl Grid
     #[must_use]
     pub fn snap_point_from_center(self, point: Vec2, center: Vec2) -> Option<Vec2>
     {
+        let (point, center) = (self.to_grid_space(point), self.to_grid_space(center));
         let snapped = Vec2::new(
             self.snap_value_from_center(point.x, center.x),
             self.snap_value_from_center(point.y, center.y)
         );
 
-        (snapped != point).then_some(snapped)
+        (snapped != point).then(|| self.to_world_space(snapped))
     }
 
-    /// Snaps `hull` to the grid.
+    /// Snaps `hull` to the grid. Since the sides of `hull` cannot lie on the lines of a rotated
+    /// grid, `hull` is returned unchanged if the grid is rotated.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn snap_hull(self, hull: &Hull) -> Hull
     {
+        if self.rotated()
+        {
+            return *hull;
+        }
+
         // Transform the hull to match the grid for better pivot setting.
         let center = hull.center();
         let (mut top, mut bottom, mut left, mut right) =
@@ -321,17 +412,138 @@ impl Grid
         window: &Window,
         camera: &Transform
     ) -> (impl ExactSizeIterator<Item = (Vec2, Vec2, Color)>, Axis)
+    {
+        // The lines are generated in grid space to cover the rotated viewport, and then
+        // transformed back to world space.
+        let viewport = camera.viewport_ui_constricted(window);
+        let (top, bottom, left, right) = if self.rotated()
+        {
+            Hull::from_points(viewport.vertexes().map(|vx| self.to_grid_space(vx)))
+                .unwrap()
+                .decompose()
+        }
+        else
+        {
+            viewport.decompose()
+        };
+
+        let lines = GridLines::new(top, bottom, left, right, self)
+            .map(move |(a, b, color)| (self.to_world_space(a), self.to_world_space(b), color));
+
+        (lines, Axis {
+            x: (bottom..top).contains(&0f32).then(|| {
+                (
+                    self.to_world_space(Vec2::new(left, 0f32)),
+                    self.to_world_space(Vec2::new(right, 0f32))
+                )
+            }),
+            y: (left..right).contains(&0f32).then(|| {
+                (
+                    self.to_world_space(Vec2::new(0f32, top)),
+                    self.to_world_space(Vec2::new(0f32, bottom))
+                )
+            })
+        })
+    }
+}
+
+//=======================================================================//
+
+/// A reference grid drawn on top of the map, unrelated to the snapping of the entities.
+#[derive(Clone, Copy, Debug)]
+pub(in crate::map) struct MeasurementGrid
+{
+    /// The distance between the lines.
+    spacing:     f32,
+    /// Whether the grid should be drawn on screen.
+    pub visible: bool
+}
+
+impl Default for MeasurementGrid
+{
+    #[inline]
+    #[must_use]
+    fn default() -> Self
+    {
+        Self {
+            spacing: 96f32,
+            visible: false
+        }
+    }
+}
+
+impl MeasurementGrid
+{
+    /// The range of the spacing.
+    pub(in crate::map::editor::state) const SPACING_RANGE: RangeInclusive<f32> = 1f32..=4096f32;
+    /// The maximum amount of lines drawn along each axis.
+    const MAX_LINES: f32 = 512f32;
+
+    /// Returns a mutable reference to the spacing.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn spacing_mut(&mut self) -> &mut f32 { &mut self.spacing }
+
+    /// Draws the grid.
+    #[inline]
+    pub(in crate::map::editor::state) fn draw(
+        self,
+        window: &Window,
+        drawer: &mut EditDrawer,
+        camera: &Transform
+    )
+    {
+        if !self.visible
+        {
+            return;
+        }
+
+        drawer.measurement_grid(self, window, camera);
+    }
+
+    /// Returns an iterator to the visible lines of the grid. No lines are returned if they would
+    /// be too dense to be distinguished.
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub(in crate::map) fn lines(
+        self,
+        window: &Window,
+        camera: &Transform
+    ) -> impl Iterator<Item = (Vec2, Vec2, Color)>
     {
         let (top, bottom, left, right) = camera.viewport_ui_constricted(window).decompose();
+        let spacing = self.spacing.clamp(*Self::SPACING_RANGE.start(), *Self::SPACING_RANGE.end());
+        let too_dense = (right - left).max(top - bottom) / spacing > Self::MAX_LINES;
+
+        let first_x = (left / spacing).ceil() * spacing;
+        let first_y = (bottom / spacing).ceil() * spacing;
+        let x_lines = if too_dense { 0 } else { Self::lines_amount(first_x, right, spacing) };
+        let y_lines = if too_dense { 0 } else { Self::lines_amount(first_y, top, spacing) };
+
+        (0..x_lines)
+            .map(move |i| {
+                let x = first_x + i as f32 * spacing;
+                (Vec2::new(x, bottom), Vec2::new(x, top), Color::MeasurementGridLines)
+            })
+            .chain((0..y_lines).map(move |i| {
+                let y = first_y + i as f32 * spacing;
+                (Vec2::new(left, y), Vec2::new(right, y), Color::MeasurementGridLines)
+            }))
+    }
 
-        (GridLines::new(top, bottom, left, right, self), Axis {
-            x: (bottom..top)
-                .contains(&0f32)
-                .then(|| (Vec2::new(left, 0f32), Vec2::new(right, 0f32))),
-            y: (left..right)
-                .contains(&0f32)
-                .then(|| (Vec2::new(0f32, top), Vec2::new(0f32, bottom)))
-        })
+    /// Returns the amount of lines with distance `spacing` between `first` and `last`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[inline]
+    #[must_use]
+    fn lines_amount(first: f32, last: f32, spacing: f32) -> usize
+    {
+        if first > last
+        {
+            return 0;
+        }
+
+        ((last - first) / spacing) as usize + 1
     }
 }
 
@@ -6,7 +6,7 @@
 use bevy::prelude::{ButtonInput, KeyCode};
 use hill_vacuum_shared::return_if_none;
 
-use crate::config::controls::{bind::Bind, BindsKeyCodes};
+use crate::config::controls::{bind::Bind, chord::SubToolChord, BindsKeyCodes, KeyRepeat};
 
 //=======================================================================//
 // TYPES
@@ -93,13 +93,17 @@ where
 
 //=======================================================================//
 
-/// The state of the button associated to a [`Bind`].
+/// The state of the button associated to a [`Bind`], which repeats its press while held down.
 pub struct InputState
 {
     /// The [`Bind`].
-    bind:  Bind,
+    bind:     Bind,
     /// The associated button press state.
-    state: State
+    state:    State,
+    /// The time the button has been held down.
+    held:     f32,
+    /// Whether the press was repeated in the current frame.
+    repeated: bool
 }
 
 impl InputState
@@ -111,7 +115,9 @@ impl InputState
     {
         Self {
             bind,
-            state: State::default()
+            state: State::default(),
+            held: 0f32,
+            repeated: false
         }
     }
 
@@ -120,27 +126,91 @@ impl InputState
     #[must_use]
     pub const fn just_pressed(&self) -> bool { matches!(self.state, State::JustPressed) }
 
+    /// Whether the button has just been pressed or its press has been repeated because it was
+    /// held down.
+    #[inline]
+    #[must_use]
+    pub const fn just_pressed_or_repeated(&self) -> bool { self.just_pressed() || self.repeated }
+
     /// Updates the state of the button associated to the bind.
     #[inline]
-    pub fn update(&mut self, source: &ButtonInput<KeyCode>, binds: &BindsKeyCodes)
+    pub fn update(
+        &mut self,
+        source: &ButtonInput<KeyCode>,
+        binds: &BindsKeyCodes,
+        key_repeat: &KeyRepeat,
+        delta_time: f32
+    )
     {
+        self.repeated = false;
         let key = return_if_none!(binds.get(self.bind));
 
         if source.just_pressed(key)
         {
             self.state = State::JustPressed;
+            self.held = 0f32;
         }
         else if source.pressed(key)
         {
+            let prev = self.held;
             self.state = State::Pressed;
+            self.held += delta_time;
+            self.repeated = key_repeat.repeats(prev, self.held);
         }
         else
         {
             self.state = State::NotPressed;
+            self.held = 0f32;
         }
     }
 
     /// Forcefully sets the press state of the button to not pressed.
     #[inline]
-    pub fn clear(&mut self) { self.state = State::default(); }
+    pub fn clear(&mut self)
+    {
+        self.state = State::default();
+        self.held = 0f32;
+        self.repeated = false;
+    }
+}
+
+//=======================================================================//
+
+/// The state of the key chord associated to a [`SubToolChord`].
+pub struct ChordState
+{
+    /// The [`SubToolChord`].
+    subtool:      SubToolChord,
+    /// Whether the chord has just been pressed.
+    just_pressed: bool
+}
+
+impl ChordState
+{
+    /// Returns a new [`ChordState`].
+    #[inline]
+    #[must_use]
+    pub const fn new(subtool: SubToolChord) -> Self
+    {
+        Self {
+            subtool,
+            just_pressed: false
+        }
+    }
+
+    /// Whether the chord has just been pressed.
+    #[inline]
+    #[must_use]
+    pub const fn just_pressed(&self) -> bool { self.just_pressed }
+
+    /// Updates the state of the chord.
+    #[inline]
+    pub fn update(&mut self, source: &ButtonInput<KeyCode>, binds: &BindsKeyCodes)
+    {
+        self.just_pressed = self.subtool.just_pressed(source, binds);
+    }
+
+    /// Forcefully sets the press state of the chord to not pressed.
+    #[inline]
+    pub fn clear(&mut self) { self.just_pressed = false; }
 }
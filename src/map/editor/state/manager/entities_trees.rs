@@ -6,6 +6,7 @@
 use std::cell::{Ref, RefCell};
 
 use bevy::prelude::{Transform, Vec2, Window};
+use hill_vacuum_shared::return_if_none;
 
 use crate::{
     map::{
@@ -27,9 +28,10 @@ use crate::{
 //
 //=======================================================================//
 
-/// Generates the function returning the visible `entities`.
+/// Generates the function returning the visible `entities`, filtered by their on screen size if
+/// `cull` is specified.
 macro_rules! visible_iters {
-    ($($entities:ident),+) => { paste::paste! { $(
+    ($(($entities:ident $(, $cull:ident)?)),+) => { paste::paste! { $(
         #[inline]
         pub fn [< visible_ $entities >](
             &self,
@@ -40,6 +42,7 @@ macro_rules! visible_iters {
             self.[< visible_ $entities >].borrow_mut().update(camera, window, |ids, viewport| {
                 self.[< $entities _tree >]
                     .entities_intersect_range(ids, &viewport);
+                $(self.$cull(ids, camera);)?
             });
 
             Ref::map(self.[< visible_ $entities >].borrow(), |v| &v.ids)
@@ -88,12 +91,23 @@ pub(in crate::map::editor::state::manager) struct Trees
     /// The visible [`ThingInstance`].
     visible_things:   RefCell<VisibleQuadTreeIds>,
     /// The [`ThingInstance`] in a certain range.
-    things_in_range:  RefCell<QuadTreeIds>
+    things_in_range:  RefCell<QuadTreeIds>,
+    /// The minimum on screen size, in pixels, of the visible brushes and [`ThingInstance`]s, if
+    /// detail culling is enabled.
+    cull_size:        Option<f32>,
+    /// Whether the updates of the trees are deferred until [`Trees::finish_bulk`].
+    bulk:             bool
 }
 
 impl Trees
 {
-    visible_iters!(brushes, paths, sprites, anchors, things);
+    visible_iters!(
+        (brushes, cull_details),
+        (paths),
+        (sprites),
+        (anchors),
+        (things, cull_details)
+    );
 
     /// Returns a new [`Trees`].
     #[inline]
@@ -117,14 +131,111 @@ impl Trees
             things_tree:      QuadTree::new(),
             things_at_pos:    QuadTreeIdsNearPos::new().into(),
             visible_things:   VisibleQuadTreeIds::new().into(),
-            things_in_range:  QuadTreeIds::new().into()
+            things_in_range:  QuadTreeIds::new().into(),
+            cull_size:        None,
+            bulk:             false
         }
     }
 
+    /// Sets the minimum on screen size, in pixels, of the visible brushes and [`ThingInstance`]s.
+    /// Disables detail culling if `cull_size` is [`None`].
+    #[inline]
+    pub fn set_cull_size(&mut self, cull_size: Option<f32>)
+    {
+        if self.cull_size == cull_size
+        {
+            return;
+        }
+
+        self.cull_size = cull_size;
+        self.visible_brushes.borrow_mut().set_dirty();
+        self.visible_things.borrow_mut().set_dirty();
+    }
+
+    /// Defers the updates of the trees until [`Trees::finish_bulk`] is called, so that a large
+    /// amount of entities can be inserted without updating the trees each time. The trees must
+    /// not be queried in the meantime.
+    #[inline]
+    pub fn start_bulk(&mut self) { self.bulk = true; }
+
+    /// Ends the deferral of the updates and rebuilds the trees from scratch with `brushes`, the
+    /// anchors [`Hull`]s `anchors`, and `things`.
+    #[inline]
+    pub fn finish_bulk<'a>(
+        &mut self,
+        brushes: impl Iterator<Item = &'a Brush>,
+        anchors: impl Iterator<Item = (&'a Id, &'a Hull)>,
+        things: impl Iterator<Item = &'a ThingInstance>
+    )
+    {
+        if !self.bulk
+        {
+            return;
+        }
+
+        self.bulk = false;
+        self.brushes_tree = QuadTree::new();
+        self.paths_tree = QuadTree::new();
+        self.anchors_tree = QuadTree::new();
+        self.sprites_tree = QuadTree::new();
+        self.things_tree = QuadTree::new();
+
+        for brush in brushes
+        {
+            self.brushes_tree.insert_entity(brush);
+
+            if brush.has_path()
+            {
+                self.paths_tree.insert_hull(brush.id(), &brush.path_hull().unwrap());
+            }
+
+            if brush.has_sprite()
+            {
+                self.sprites_tree
+                    .insert_hull(brush.id(), &brush.sprite_and_anchor_hull().unwrap());
+            }
+        }
+
+        for (id, hull) in anchors
+        {
+            self.anchors_tree.insert_hull(*id, hull);
+        }
+
+        for thing in things
+        {
+            self.things_tree.insert_hull(thing.id(), &thing.hull());
+
+            if thing.has_path()
+            {
+                self.paths_tree.insert_hull(thing.id(), &thing.path_hull().unwrap());
+            }
+        }
+
+        self.set_brushes_dirty();
+        self.set_paths_dirty();
+        self.set_sprites_dirty();
+        self.set_things_dirty();
+        self.set_anchors_dirty();
+    }
+
+    /// Removes from `ids` the entities whose [`Hull`] is too small on screen to be drawn, if
+    /// detail culling is enabled.
+    #[inline]
+    fn cull_details(&self, ids: &mut QuadTreeIds, camera: &Transform)
+    {
+        let min_size = return_if_none!(self.cull_size) * camera.scale();
+        ids.retain(|_, hull| hull.width().max(hull.height()) >= min_size);
+    }
+
     /// Inserts the anchor [`Hull`] of the brush with [`Id`] `owner_id`.
     #[inline]
     pub fn insert_anchor_hull(&mut self, owner_id: Id, hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.anchors_tree.insert_hull(owner_id, hull);
         self.set_anchors_dirty();
     }
@@ -133,6 +244,11 @@ impl Trees
     #[inline]
     pub fn remove_anchor_hull(&mut self, owner_id: Id, hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.anchors_tree.remove_hull(owner_id, hull);
         self.set_anchors_dirty();
     }
@@ -141,6 +257,11 @@ impl Trees
     #[inline]
     pub fn insert_brush_hull(&mut self, brush: &Brush)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.brushes_tree.insert_entity(brush);
         self.set_brushes_dirty();
     }
@@ -149,6 +270,11 @@ impl Trees
     #[inline]
     pub fn remove_brush_hull(&mut self, brush: &Brush)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.brushes_tree.remove_entity(brush);
         self.set_brushes_dirty();
     }
@@ -157,6 +283,11 @@ impl Trees
     #[inline]
     pub fn replace_brush_hull(&mut self, identifier: Id, current_hull: &Hull, previous_hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.brushes_tree
             .replace_hull(identifier, current_hull, previous_hull);
         self.set_brushes_dirty();
@@ -166,6 +297,11 @@ impl Trees
     #[inline]
     pub fn insert_path_hull<P: EntityId + Moving>(&mut self, entity: &P)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.paths_tree.insert_hull(entity.id(), &entity.path_hull().unwrap());
         self.set_paths_dirty();
     }
@@ -174,6 +310,11 @@ impl Trees
     #[inline]
     pub fn remove_path_hull<P: EntityId + ?Sized>(&mut self, entity: &P, hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.paths_tree.remove_hull(entity.id(), hull);
         self.set_paths_dirty();
     }
@@ -187,6 +328,11 @@ impl Trees
         previous_hull: &Hull
     )
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.paths_tree.replace_hull(entity.id(), current_hull, previous_hull);
         self.set_paths_dirty();
     }
@@ -195,6 +341,11 @@ impl Trees
     #[inline]
     pub fn insert_sprite_hull(&mut self, brush: &Brush)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.sprites_tree
             .insert_hull(brush.id(), &brush.sprite_and_anchor_hull().unwrap());
         self.set_sprites_dirty();
@@ -204,6 +355,11 @@ impl Trees
     #[inline]
     pub fn remove_sprite_hull(&mut self, brush: &Brush, hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.sprites_tree.remove_hull(brush.id(), hull);
         self.set_sprites_dirty();
     }
@@ -212,6 +368,11 @@ impl Trees
     #[inline]
     pub fn replace_sprite_hull(&mut self, brush: &Brush, current_hull: &Hull, previous_hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.sprites_tree
             .replace_hull(brush.id(), current_hull, previous_hull);
         self.set_sprites_dirty();
@@ -221,6 +382,11 @@ impl Trees
     #[inline]
     pub fn insert_thing_hull(&mut self, thing: &ThingInstance)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.things_tree.insert_hull(thing.id(), &thing.hull());
         self.set_things_dirty();
     }
@@ -229,6 +395,11 @@ impl Trees
     #[inline]
     pub fn remove_thing_hull(&mut self, thing: &ThingInstance)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.things_tree.remove_hull(thing.id(), &thing.hull());
         self.set_things_dirty();
     }
@@ -237,6 +408,11 @@ impl Trees
     #[inline]
     pub fn replace_thing_hull(&mut self, thing: &ThingInstance, previous_hull: &Hull)
     {
+        if self.bulk
+        {
+            return;
+        }
+
         self.things_tree
             .replace_hull(thing.id(), &thing.hull(), previous_hull);
         self.set_things_dirty();
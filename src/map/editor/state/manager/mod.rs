@@ -16,7 +16,8 @@ use std::{
 };
 
 use bevy::prelude::{Transform, Vec2, Window};
-use hill_vacuum_shared::{continue_if_none, return_if_none, NextValue};
+use bevy_egui::egui;
+use hill_vacuum_shared::{continue_if_none, return_if_none, NextValue, TEXTURE_HEIGHT_RANGE};
 
 use self::{
     entities_trees::Trees,
@@ -39,14 +40,17 @@ use super::{
     editor_state::{InputsPresses, ToolsSettings},
     edits_history::EditsHistory,
     grid::Grid,
+    markers::Markers,
     ui::Ui
 };
 use crate::{
     map::{
         brush::{
-            convex_polygon::{ConvexPolygon, TextureSetResult},
+            convex_polygon::{ConvexPolygon, ScaleInfo, TextureSetResult},
             Brush,
-            BrushData
+            BrushData,
+            RotateResult,
+            ScaleResult
         },
         containers::{hv_hash_map, hv_hash_set, Ids},
         drawer::{
@@ -62,20 +66,40 @@ use crate::{
             ToolUpdateBundle
         },
         hv_vec,
-        path::{EditPath, MovementSimulator, Moving},
-        properties::{DefaultProperties, Properties, PropertiesRefactor},
-        thing::{catalog::ThingsCatalog, ThingInstance, ThingInstanceData, ThingInterface},
+        navmesh::NavMeshRegion,
+        path::{EditPath, MovementSimulator, Moving, NodesSnapResult},
+        properties::{
+            DefaultProperties,
+            Properties,
+            PropertiesRefactor,
+            Value,
+            MATERIAL_PROPERTY,
+            SPAWN_ORDER_PROPERTY
+        },
+        thing::{
+            catalog::ThingsCatalog,
+            ThingId,
+            ThingInstance,
+            ThingInstanceData,
+            ThingInterface
+        },
         AssertedInsertRemove,
         HvHashMap,
         HvVec,
         MapHeader,
-        OutOfBounds
+        OutOfBounds,
+        DEFAULT_MAP_BOUND,
+        MAP_BOUND_RANGE
     },
     utils::{
         hull::{EntityHull, Hull},
         identifiers::{EntityCenter, EntityId, Id, IdGenerator},
-        math::AroundEqual,
-        misc::{Blinker, ReplaceValues}
+        math::{
+            points::{rotate_point_around_origin, rotate_point_quarter},
+            AroundEqual
+        },
+        misc::{Blinker, ReplaceValues},
+        tooltips::to_egui_coordinates
     },
     Path
 };
@@ -195,6 +219,11 @@ impl AuxiliaryIds
     #[must_use]
     fn contains(&self, id: Id) -> bool { self.0.contains(&id) }
 
+    /// Whether `self` contains no elements.
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool { self.0.is_empty() }
+
     /// Returns an iterator to the contained elements.
     #[inline]
     pub fn iter(&self) -> hashbrown::hash_set::Iter<Id> { self.0.iter() }
@@ -226,12 +255,14 @@ impl AuxiliaryIds
 #[must_use]
 struct ErrorHighlight
 {
-    /// The latest occured error.
+    /// The latest occured error, retained after the blinking ends so that it can still be pinned.
     error:   Option<Id>,
     /// The timer that makes to entity who caused the error blink on screen.
     blinker: Blinker,
     /// The amount of times left the entity error will be blinked.
-    blinks:  u8
+    blinks:  u8,
+    /// Whether the error is pinned, that is highlighted until it is unpinned.
+    pinned:  bool
 }
 
 impl ErrorHighlight
@@ -248,7 +279,8 @@ impl ErrorHighlight
         Self {
             error:   None,
             blinker: Blinker::new(f32::INFINITY),
-            blinks:  0
+            blinks:  0,
+            pinned:  false
         }
     }
 
@@ -258,7 +290,7 @@ impl ErrorHighlight
     {
         let error = error.into();
 
-        if error == self.error
+        if error == self.error && (self.pinned || self.blinks != 0)
         {
             return;
         }
@@ -266,6 +298,26 @@ impl ErrorHighlight
         self.error = error;
         self.blinker = Blinker::new(Self::BLINK_INTERVAL);
         self.blinks = Self::ERROR_BLINKS;
+        self.pinned = false;
+    }
+
+    /// Toggles the pinned state of the error, if any. Once unpinned the error resumes blinking.
+    #[inline]
+    fn toggle_pin(&mut self)
+    {
+        if self.error.is_none()
+        {
+            self.pinned = false;
+            return;
+        }
+
+        self.pinned = !self.pinned;
+
+        if !self.pinned
+        {
+            self.blinker = Blinker::new(Self::BLINK_INTERVAL);
+            self.blinks = Self::ERROR_BLINKS;
+        }
     }
 
     /// Check whether the stored error concerns the entity `error` and removes it if that is the
@@ -276,6 +328,7 @@ impl ErrorHighlight
         if self.error == error.into()
         {
             self.error = None;
+            self.pinned = false;
         }
     }
 
@@ -283,6 +336,11 @@ impl ErrorHighlight
     #[inline]
     fn draw(&mut self, bundle: &mut DrawBundle) -> Option<Id>
     {
+        if self.pinned
+        {
+            return self.error;
+        }
+
         if self.blinks == 0
         {
             return None;
@@ -297,7 +355,6 @@ impl ErrorHighlight
 
             if self.blinks == 0
             {
-                self.error = None;
                 return None;
             }
         }
@@ -388,6 +445,8 @@ struct Innards
     brushes: HvHashMap<Id, Brush>,
     /// All the [`Thing`]s on the map.
     things: HvHashMap<Id, ThingInstance>,
+    /// The total amount of vertexes of the brushes on the map.
+    vertexes_amount: usize,
     /// The currently selected brushes.
     selected_brushes: Ids,
     /// The currently selected [`Thing`]s.
@@ -442,6 +501,7 @@ impl Innards
         Self {
             brushes: hv_hash_map![],
             things: hv_hash_map![],
+            vertexes_amount: 0,
             selected_brushes: hv_hash_set![capacity; 10],
             selected_things: hv_hash_set![capacity; 10],
             moving: hv_hash_set![capacity; 10],
@@ -466,8 +526,8 @@ impl Innards
         }
     }
 
-    /// Reads the brushes and [`Thing`]s from `file`.
-    /// Returns an error if it occurred.
+    /// Reads the brushes and [`Thing`]s from `file`, discarding the ones out of `bound`.
+    /// Returns the amount of discarded entities, or an error if it occurred.
     #[inline]
     pub fn load(
         &mut self,
@@ -476,8 +536,9 @@ impl Innards
         things_catalog: &ThingsCatalog,
         drawing_resources: &DrawingResources,
         default_properties: &mut AllDefaultProperties,
-        quad_trees: &mut Trees
-    ) -> Result<(), &'static str>
+        quad_trees: &mut Trees,
+        bound: f32
+    ) -> Result<usize, &'static str>
     {
         /// Tests the validity of `value`.
         macro_rules! test {
@@ -539,6 +600,7 @@ impl Innards
         let mut max_id = Id::ZERO;
         let mut brushes = hv_vec![];
         let mut with_anchors = hv_vec![];
+        let mut out_of_bounds = 0;
 
         let file_brushes_default_properties = test!(
             ciborium::from_reader::<DefaultProperties, _>(&mut *file),
@@ -549,12 +611,23 @@ impl Innards
             "Error reading default things properties"
         );
 
-        let b_refactor = mismatching_properties(
-            default_properties.brushes,
-            default_properties.map_brushes,
-            file_brushes_default_properties,
-            "brushes"
-        );
+        // Maps saved before the material property was built in are upgraded without asking.
+        let b_refactor =
+            if file_brushes_default_properties.lacks_only_material(default_properties.brushes)
+            {
+                *default_properties.map_brushes = default_properties.brushes.clone();
+                DefaultProperties::built_in_refactor(default_properties.brushes, MATERIAL_PROPERTY)
+                    .into()
+            }
+            else
+            {
+                mismatching_properties(
+                    default_properties.brushes,
+                    default_properties.map_brushes,
+                    file_brushes_default_properties,
+                    "brushes"
+                )
+            };
 
         for _ in 0..header.brushes
         {
@@ -567,15 +640,17 @@ impl Innards
                     .texture_or_error(brush.texture_settings().unwrap().name())
                     .name();
 
-                if !brush.check_texture_change(drawing_resources, texture)
+                if !brush.check_texture_change(drawing_resources, texture, bound)
                 {
+                    out_of_bounds += 1;
                     continue;
                 }
 
                 _ = brush.set_texture(drawing_resources, texture);
             }
-            else if brush.out_of_bounds()
+            else if brush.out_of_bounds(bound)
             {
+                out_of_bounds += 1;
                 continue;
             }
 
@@ -604,12 +679,27 @@ impl Innards
         }
 
         let mut things = hv_vec![];
-        let t_refactor = mismatching_properties(
-            default_properties.things,
-            default_properties.map_things,
-            file_things_default_properties,
-            "things"
-        );
+
+        // Maps saved before the spawn order property was built in are upgraded without asking.
+        let t_refactor =
+            if file_things_default_properties.lacks_only_spawn_order(default_properties.things)
+            {
+                *default_properties.map_things = default_properties.things.clone();
+                DefaultProperties::built_in_refactor(
+                    default_properties.things,
+                    SPAWN_ORDER_PROPERTY
+                )
+                .into()
+            }
+            else
+            {
+                mismatching_properties(
+                    default_properties.things,
+                    default_properties.map_things,
+                    file_things_default_properties,
+                    "things"
+                )
+            };
 
         for _ in 0..header.things
         {
@@ -619,13 +709,20 @@ impl Innards
             );
             let thing = things_catalog.thing_or_error(thing_i.thing());
 
-            if !thing_i.check_thing_change(thing)
+            if !thing_i.check_thing_change(thing, bound)
             {
+                out_of_bounds += 1;
                 continue;
             }
 
             _ = thing_i.set_thing(thing);
 
+            if thing_i.out_of_bounds(bound)
+            {
+                out_of_bounds += 1;
+                continue;
+            }
+
             max_id = max_id.max(thing_i.id());
 
             things.push(thing_i);
@@ -639,6 +736,8 @@ impl Innards
             }
         }
 
+        quad_trees.start_bulk();
+
         for brush in brushes
         {
             self.insert_brush(quad_trees, brush, false);
@@ -654,11 +753,13 @@ impl Innards
             self.insert_thing(thing, quad_trees, false);
         }
 
+        self.finish_bulk(quad_trees);
+
         self.id_generator.reset(max_id);
         _ = self.id_generator.new_id();
         self.refactored_properties = b_refactor.is_some() || t_refactor.is_some();
 
-        Ok(())
+        Ok(out_of_bounds)
     }
 
     //==============================================================
@@ -1095,6 +1196,18 @@ impl Innards
         self.select_cluster(edits_history, auxiliary.iter());
     }
 
+    /// Rebuilds the quad trees deferred by [`Trees::start_bulk`] with all the entities on the
+    /// map.
+    #[inline]
+    fn finish_bulk(&self, quad_trees: &mut Trees)
+    {
+        quad_trees.finish_bulk(
+            self.brushes.values(),
+            self.brushes_with_anchors.iter(),
+            self.things.values()
+        );
+    }
+
     /// Adds a brush to the map.
     /// # Panics
     /// Panics if the brush has anchored brushes but the [`Hull`] describing the anchors
@@ -1105,6 +1218,7 @@ impl Innards
         let id = brush.id();
         quad_trees.insert_brush_hull(&brush);
         self.outline_update = true;
+        self.vertexes_amount += brush.vertexes().len();
 
         if brush.has_selected_vertexes()
         {
@@ -1204,6 +1318,7 @@ impl Innards
 
         let brush = self.brushes.remove(&identifier).unwrap();
         quad_trees.remove_brush_hull(&brush);
+        self.vertexes_amount -= brush.vertexes().len();
 
         if brush.has_selected_vertexes()
         {
@@ -1630,7 +1745,18 @@ pub(in crate::map::editor::state) struct EntitiesManager
     /// The auxiliary container used to avoid using unsafe code in certain procedures.
     auxiliary:       AuxiliaryIds,
     /// Vector to help in the despawn of the selected brushes.
-    brushes_despawn: HvVec<Id>
+    brushes_despawn: HvVec<Id>,
+    /// The editor-only notes of the entities.
+    notes:           HvHashMap<Id, String>,
+    /// The editor-only markers.
+    markers:         Markers,
+    /// Whether the entities spawned by drawing, pasting, and painting props are left selected.
+    select_spawned:  bool,
+    /// The amount of selected entities from which the destructive operations on the selection
+    /// must be confirmed. Zero disables the confirmation.
+    bulk_confirm:    usize,
+    /// The size of half of the square the entities of the map can occupy.
+    map_bound:       f32
 }
 
 impl EntitiesManager
@@ -1644,12 +1770,18 @@ impl EntitiesManager
             innards:         Innards::new(),
             quad_trees:      Trees::new(),
             auxiliary:       AuxiliaryIds::new(),
-            brushes_despawn: hv_vec![]
+            brushes_despawn: hv_vec![],
+            notes:           hv_hash_map![],
+            markers:         Markers::default(),
+            select_spawned:  true,
+            bulk_confirm:    0,
+            map_bound:       DEFAULT_MAP_BOUND
         }
     }
 
-    /// Returns a new [`EntitiesManager`] along with the [`MapHeader`] read from `file` if the read
-    /// process was successful.
+    /// Returns a new [`EntitiesManager`] loading the content of `file` if the read process was
+    /// successful, along with the amount of entities that were not loaded because out of the
+    /// map bounds.
     #[inline]
     pub fn from_file(
         header: &MapHeader,
@@ -1657,9 +1789,10 @@ impl EntitiesManager
         drawing_resources: &DrawingResources,
         things_catalog: &ThingsCatalog,
         default_properties: &mut AllDefaultProperties
-    ) -> Result<Self, &'static str>
+    ) -> Result<(Self, usize), &'static str>
     {
         let mut manager = Self::new();
+        manager.map_bound = header.bound.clamp(*MAP_BOUND_RANGE.start(), *MAP_BOUND_RANGE.end());
 
         match manager.innards.load(
             header,
@@ -1667,10 +1800,11 @@ impl EntitiesManager
             things_catalog,
             drawing_resources,
             default_properties,
-            &mut manager.quad_trees
+            &mut manager.quad_trees,
+            manager.map_bound
         )
         {
-            Ok(()) => Ok(manager),
+            Ok(out_of_bounds) => Ok((manager, out_of_bounds)),
             Err(err) => Err(err)
         }
     }
@@ -1687,6 +1821,46 @@ impl EntitiesManager
     #[inline]
     pub fn reset_refactored_properties(&mut self) { self.innards.refactored_properties = false; }
 
+    /// Returns the size of half of the square the entities of the map can occupy.
+    #[inline(always)]
+    #[must_use]
+    pub const fn map_bound(&self) -> f32 { self.map_bound }
+
+    /// Sets the size of half of the square the entities can occupy to `bound`.
+    /// Returns an error if some entities would end up out of bounds.
+    #[inline]
+    pub fn set_map_bound(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        bound: f32
+    ) -> Result<(), &'static str>
+    {
+        let bound = bound.clamp(*MAP_BOUND_RANGE.start(), *MAP_BOUND_RANGE.end());
+        let area = Hull::new(bound, -bound, -bound, bound);
+
+        if self.entities_hull().is_some_and(|hull| !area.contains_hull(&hull))
+        {
+            return Err("Some entities would be out of the map bounds");
+        }
+
+        if bound.around_equal_narrow(&self.map_bound)
+        {
+            return Ok(());
+        }
+
+        edits_history.map_bound(self.replace_map_bound(bound));
+        Ok(())
+    }
+
+    /// Sets the size of half of the square the entities can occupy to `bound` without checking
+    /// whether the entities fit, and returns the previous one.
+    #[inline]
+    #[must_use]
+    pub fn replace_map_bound(&mut self, bound: f32) -> f32
+    {
+        std::mem::replace(&mut self.map_bound, bound)
+    }
+
     /// Whether an entity with [`Id`] `identifier` exists.
     #[inline]
     #[must_use]
@@ -1710,6 +1884,124 @@ impl EntitiesManager
     #[inline]
     pub fn schedule_outline_update(&mut self) { self.innards.outline_update = true; }
 
+    //==============================================================
+    // Notes
+
+    /// Returns the note of the entity with [`Id`] `identifier`, if any.
+    #[inline]
+    #[must_use]
+    pub fn note(&self, identifier: Id) -> Option<&str>
+    {
+        self.notes.get(&identifier).map(String::as_str)
+    }
+
+    /// Sets the note of the entity with [`Id`] `identifier`, and returns the previous one. An
+    /// empty `note` removes it.
+    #[inline]
+    pub fn set_note(&mut self, identifier: Id, note: String) -> String
+    {
+        let prev = self.notes.remove(&identifier).unwrap_or_default();
+
+        if !note.is_empty()
+        {
+            _ = self.notes.insert(identifier, note);
+        }
+
+        prev
+    }
+
+    /// Returns an iterator to the [`Id`]s and notes of the existing entities.
+    /// The notes of despawned entities are retained in case the despawn is undone.
+    #[inline]
+    pub fn notes(&self) -> impl Iterator<Item = (Id, &str)>
+    {
+        self.notes
+            .iter()
+            .filter_map(|(id, note)| self.entity_exists(*id).then_some((*id, note.as_str())))
+    }
+
+    /// Draws an icon on the center of the annotated entities along with the first line of their
+    /// note.
+    #[inline]
+    pub fn draw_notes(&self, bundle: &mut DrawBundle)
+    {
+        /// The offset of the notes from the center of their entities, in screen pixels.
+        const NOTE_OFFSET: egui::Vec2 = egui::Vec2::new(8f32, 8f32);
+
+        let color = bundle.drawer.color_resources().egui_color(Color::Note);
+
+        for (id, note) in self.notes()
+        {
+            let center = self.entity(id).hull().center();
+            bundle.drawer.square_highlight(center, Color::Note);
+
+            egui::Area::new(egui::Id::new(("note", id)))
+                .fixed_pos(to_egui_coordinates(center, bundle.window, bundle.camera) + NOTE_OFFSET)
+                .order(egui::Order::Background)
+                .interactable(false)
+                .show(bundle.egui_context, |ui| {
+                    ui.label(
+                        egui::RichText::new(note.lines().next().unwrap_or_default()).color(color)
+                    );
+                });
+        }
+    }
+
+    /// Loads the notes stored in the file at `path`, if it exists.
+    #[inline]
+    pub fn import_notes(&mut self, path: &std::path::Path) -> Result<(), &'static str>
+    {
+        if !path.exists()
+        {
+            return Ok(());
+        }
+
+        let file = File::open(path).map_err(|_| "Could not open the notes file")?;
+
+        let mut notes = match ciborium::from_reader::<Vec<(Id, String)>, _>(BufReader::new(file))
+        {
+            Ok(notes) => notes,
+            Err(_) => return Err("Error reading the notes file")
+        };
+
+        notes.retain(|(id, _)| self.entity_exists(*id));
+        self.notes.clear();
+        self.notes.extend(notes);
+
+        Ok(())
+    }
+
+    /// Returns the serialized notes of the existing entities, or [`None`] if there are none.
+    #[inline]
+    pub fn export_notes(&self) -> Result<Option<Vec<u8>>, &'static str>
+    {
+        let notes = self.notes().collect::<Vec<_>>();
+
+        if notes.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let mut data = Vec::new();
+
+        match ciborium::ser::into_writer(&notes, &mut data)
+        {
+            Ok(()) => Ok(Some(data)),
+            Err(_) => Err("Error saving notes")
+        }
+    }
+
+    //==============================================================
+    // Markers
+
+    /// Returns a reference to the editor-only markers.
+    #[inline]
+    pub const fn markers(&self) -> &Markers { &self.markers }
+
+    /// Returns a mutable reference to the editor-only markers.
+    #[inline]
+    pub fn markers_mut(&mut self) -> &mut Markers { &mut self.markers }
+
     /// Updates certain tool and UI properties.
     #[inline]
     pub fn update_tool_and_overall_values(
@@ -1836,19 +2128,21 @@ impl EntitiesManager
     #[must_use]
     pub fn is_selected(&self, identifier: Id) -> bool { self.innards.is_selected(identifier) }
 
-    /// Selects the entity with [`Id`] `identifier`.
+    /// Selects the entity with [`Id`] `identifier`. If Ctrl is pressed and enabled in `settings`,
+    /// the attached brushes are selected as well.
     #[inline]
     pub fn select_entity(
         &mut self,
         identifier: Id,
         inputs: &InputsPresses,
-        edits_history: &mut EditsHistory
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings
     )
     {
         let thing = self.insert_entity_selection(identifier);
         edits_history.entity_selection(identifier);
 
-        if thing || !inputs.ctrl_pressed()
+        if thing || !settings.select_attachments || !inputs.ctrl_pressed()
         {
             return;
         }
@@ -1857,19 +2151,21 @@ impl EntitiesManager
             .select_anchored_brushes(edits_history, &mut self.auxiliary, Some(identifier));
     }
 
-    /// Deselects the entity with [`Id`] `identifier`.
+    /// Deselects the entity with [`Id`] `identifier`. If Ctrl is pressed and enabled in `settings`,
+    /// the attached brushes are deselected as well.
     #[inline]
     pub fn deselect_entity(
         &mut self,
         identifier: Id,
         inputs: &InputsPresses,
-        edits_history: &mut EditsHistory
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings
     )
     {
         let thing = self.remove_entity_selection(identifier);
         edits_history.entity_deselection(identifier);
 
-        if thing || !inputs.ctrl_pressed()
+        if thing || !settings.select_attachments || !inputs.ctrl_pressed()
         {
             return;
         }
@@ -1877,6 +2173,13 @@ impl EntitiesManager
         self.deselect_anchored_brushes(edits_history, Some(identifier));
     }
 
+    /// Returns the [`Id`]s of the selected brushes and [`ThingInstance`]s.
+    #[inline]
+    pub fn selected_entities_ids(&self) -> impl Iterator<Item = &Id>
+    {
+        self.innards.selected_entities_ids()
+    }
+
     /// Updates the value related to entity selection for the entity identifier. Returns true if
     /// entity is a [`ThingInstance`].
     #[inline]
@@ -1912,107 +2215,727 @@ impl EntitiesManager
         edits_history.entity_deselection_cluster(self.auxiliary.iter());
     }
 
-    /// Selects all entities.
+    /// Deselects all selected brushes, leaving the selected things selected.
     #[inline]
-    pub fn select_all_entities(&mut self, edits_history: &mut EditsHistory)
+    pub fn deselect_selected_brushes(&mut self, edits_history: &mut EditsHistory)
     {
-        self.innards.select_all_entities(edits_history, &mut self.auxiliary);
+        self.auxiliary.replace_values(&self.innards.selected_brushes);
+
+        if !self.auxiliary.is_empty()
+        {
+            self.innards.deselect_cluster(edits_history, self.auxiliary.iter());
+        }
     }
 
-    /// Despawns the selected entities.
+    /// Deselects all selected things, leaving the selected brushes selected.
     #[inline]
-    pub fn despawn_selected_entities(&mut self, edits_history: &mut EditsHistory)
+    pub fn deselect_selected_things(&mut self, edits_history: &mut EditsHistory)
     {
-        self.despawn_selected_brushes(edits_history);
-
-        self.auxiliary.replace_values(self.innards.selected_things.iter());
+        self.auxiliary.replace_values(&self.innards.selected_things);
 
-        for id in &self.auxiliary
+        if !self.auxiliary.is_empty()
         {
-            self.innards.despawn_thing(&mut self.quad_trees, edits_history, *id);
+            self.innards.deselect_cluster(edits_history, self.auxiliary.iter());
         }
     }
 
-    //==============================================================
-    // Brushes
-
-    /// Returns the amount of brushes.
+    /// Exclusively selects the existing entities with [`Id`]s contained in `identifiers`.
+    /// Returns the amount of [`Id`]s of entities that do not exist.
     #[inline]
     #[must_use]
-    pub fn brushes_amount(&self) -> usize { self.innards.brushes.len() }
+    pub fn exclusively_select_entities<'a>(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        identifiers: impl IntoIterator<Item = &'a Id>
+    ) -> usize
+    {
+        self.deselect_selected_entities(edits_history);
 
-    /// Returns the amount of selected brushes.
-    #[inline]
-    #[must_use]
-    pub fn selected_brushes_amount(&self) -> usize { self.innards.selected_brushes_amount() }
+        let mut missing = 0;
+        self.auxiliary.replace_values(identifiers.into_iter().filter(|id| {
+            let exists = self.innards.entity_exists(**id);
+            missing += usize::from(!exists);
+            exists
+        }));
 
-    /// Whether there are any currently selected brushes.
-    #[inline]
-    #[must_use]
-    pub fn any_selected_brushes(&self) -> bool { self.selected_brushes_amount() != 0 }
+        for id in &self.auxiliary
+        {
+            self.innards.insert_entity_selection(*id);
+        }
 
-    /// Returns a reference to the brush with [`Id`] identifier.
+        edits_history.entity_selection_cluster(self.auxiliary.iter());
+        self.schedule_outline_update();
+        missing
+    }
+
+    /// Sets whether the entities spawned by drawing, pasting, and painting props are left
+    /// selected.
     #[inline]
-    pub fn brush(&self, identifier: Id) -> &Brush { self.innards.brush(identifier) }
+    pub fn set_select_spawned(&mut self, value: bool) { self.select_spawned = value; }
 
-    /// Returns an iterator to the [`Id`]s of the selected brushes.
+    /// Sets the amount of selected entities from which the destructive operations on the selection
+    /// must be confirmed. Zero disables the confirmation.
     #[inline]
-    pub fn selected_brushes_ids(&self) -> impl ExactSizeIterator<Item = &Id> + Clone
-    {
-        self.innards.selected_brushes.iter()
-    }
+    pub fn set_bulk_confirm_threshold(&mut self, value: usize) { self.bulk_confirm = value; }
 
-    /// Returns a [`BrushesIter`] created from `ids`.
+    /// Asks the user to confirm `operation` if the amount of selected entities reaches the bulk
+    /// confirmation threshold, listing the affected entities. Returns whether the operation
+    /// should be performed.
     #[inline]
-    const fn brushes_iter<'a>(&'a self, ids: Ref<'a, QuadTreeIds>) -> BrushesIter<'a>
+    #[must_use]
+    pub fn confirm_bulk_operation(&self, operation: &str) -> bool
     {
-        BrushesIter::new(self, ids)
+        let brushes = self.selected_brushes_amount();
+        let things = self.selected_things_amount();
+
+        if self.bulk_confirm == 0 || brushes + things < self.bulk_confirm
+        {
+            return true;
+        }
+
+        let description = format!(
+            "{operation} will affect:\n{brushes} brushes\n{things} things\n{} entities with a \
+             path\n\nDo you wish to proceed?",
+            self.selected_moving_amount()
+        );
+
+        matches!(
+            rfd::MessageDialog::new()
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .set_title("WARNING")
+                .set_description(description)
+                .show(),
+            rfd::MessageDialogResult::Yes
+        )
     }
 
-    /// Returns a [`SelectedBrushesIter`] created from `ids`
+    /// Deselects the spawned entities with [`Id`]s contained in `identifiers` if they must not be
+    /// left selected.
     #[inline]
-    const fn selected_brushes_iter<'a>(
-        &'a self,
-        ids: Ref<'a, QuadTreeIds>
-    ) -> SelectedBrushesIter<'a>
+    pub fn deselect_spawned_entities<'a>(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        identifiers: impl IntoIterator<Item = &'a Id>
+    )
     {
-        SelectedBrushesIter::new(self, ids)
-    }
+        if self.select_spawned
+        {
+            return;
+        }
 
-    /// Returns a [`Brushes`] instance.
-    #[inline]
-    pub const fn brushes(&self) -> Brushes { self.innards.brushes() }
+        self.auxiliary.replace_values(identifiers.into_iter().filter(|id| {
+            self.innards.entity_exists(**id) && self.innards.is_selected(**id)
+        }));
 
-    /// Returns an array of references to `N` brushes.
-    #[inline]
-    pub fn many_brushes<const N: usize>(&self, identifiers: [Id; N]) -> [&Brush; N]
-    {
-        std::array::from_fn(|i| self.brush(identifiers[i]))
+        if self.auxiliary.is_empty()
+        {
+            return;
+        }
+
+        for id in &self.auxiliary
+        {
+            self.innards.remove_entity_selection(*id);
+        }
+
+        edits_history.entity_deselection_cluster(self.auxiliary.iter());
+        self.schedule_outline_update();
     }
 
-    /// Returns a [`BrushMut`] wrapping the brush with [`Id`] `identifier`.
+    /// Selects all entities.
     #[inline]
-    pub fn brush_mut(&mut self, identifier: Id) -> BrushMut<'_>
+    pub fn select_all_entities(&mut self, edits_history: &mut EditsHistory)
     {
-        BrushMut::new(&mut self.innards, &mut self.quad_trees, identifier)
+        self.innards.select_all_entities(edits_history, &mut self.auxiliary);
     }
 
-    /// Returns an iterator to the non selected brushes.
+    /// Selects all the brushes that have the same texture as the brush with [`Id`] `identifier`,
+    /// if `texture` is true, and the same values of the properties with keys contained in
+    /// `properties`.
     #[inline]
-    pub fn non_selected_brushes(&mut self) -> impl Iterator<Item = &Brush>
+    pub fn select_similar_brushes(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        identifier: Id,
+        texture: bool,
+        properties: &[&str]
+    )
     {
-        self.innards
-            .brushes
-            .values()
-            .filter(|brush| !self.is_selected(brush.id()))
+        let reference = self.innards.brush(identifier);
+        let texture = texture.then(|| reference.texture_settings().map(TextureInterface::name));
+
+        self.auxiliary
+            .replace_values(self.innards.brushes.iter().filter_map(|(id, brush)| {
+                (!self.innards.is_selected(*id) &&
+                    texture.map_or(true, |name| {
+                        brush.texture_settings().map(TextureInterface::name) == name
+                    }) &&
+                    properties.iter().all(|k| {
+                        brush.properties_as_ref().get(k) == reference.properties_as_ref().get(k)
+                    }))
+                .then_some(id)
+            }));
+
+        self.innards.select_cluster(edits_history, self.auxiliary.iter());
+        self.schedule_outline_update();
     }
 
-    /// Returns an iterator to the selected brushes.
+    /// Exclusively selects the things whose position is inside one of the selected brushes, which
+    /// act as the region to pick from. Returns the amount of selected things.
     #[inline]
-    pub fn selected_brushes(&self) -> impl Iterator<Item = &Brush>
+    pub fn select_things_in_selected_brushes(&mut self, edits_history: &mut EditsHistory) -> usize
     {
-        self.selected_brushes_ids().map(|id| self.brush(*id))
-    }
+        let things = hv_vec![collect; self.things().filter_map(|thing| {
+            self.selected_brushes()
+                .any(|brush| brush.contains_point(thing.pos()))
+                .then_some(thing.id())
+        })];
+
+        self.deselect_selected_entities(edits_history);
+        self.innards.select_cluster(edits_history, things.iter());
+
+        things.len()
+    }
+
+    /// Exclusively selects the entities whose hull is closer than `margin` to the map bounds or
+    /// exceeds them. Returns the amount of selected entities.
+    #[inline]
+    pub fn select_entities_near_map_bound(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        margin: f32
+    ) -> usize
+    {
+        let bound = self.map_bound - margin;
+        let near_bound = |hull: Hull| {
+            hull.top() > bound ||
+                hull.bottom() < -bound ||
+                hull.left() < -bound ||
+                hull.right() > bound
+        };
+
+        let ids = hv_vec![collect; self
+            .brushes()
+            .iter()
+            .filter_map(|brush| near_bound(brush.hull()).then_some(brush.id()))
+            .chain(
+                self.things().filter_map(|thing| near_bound(thing.hull()).then_some(thing.id()))
+            )];
+
+        self.deselect_selected_entities(edits_history);
+        self.innards.select_cluster(edits_history, ids.iter());
+        self.schedule_outline_update();
+
+        ids.len()
+    }
+
+    /// Exclusively selects the existing entities whose [`Id`]s are contained in `identifiers`.
+    #[inline]
+    pub fn select_entities_from_ids(&mut self, edits_history: &mut EditsHistory, identifiers: &[Id])
+    {
+        let ids =
+            hv_vec![collect; identifiers.iter().copied().filter(|id| self.entity_exists(*id))];
+
+        self.deselect_selected_entities(edits_history);
+        self.innards.select_cluster(edits_history, ids.iter());
+        self.schedule_outline_update();
+    }
+
+    /// Sets the property `key` of the selected things that declare it to `value`, converted to
+    /// the type of the property. Returns whether any thing was edited.
+    #[inline]
+    pub fn set_selected_things_property(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        key: &str,
+        value: &Value
+    ) -> bool
+    {
+        let mut edited = false;
+
+        edits_history.property(
+            key,
+            self.selected_things_mut().filter_map(|mut thing| {
+                if !thing.properties().contains(key)
+                {
+                    return None;
+                }
+
+                let value = thing.properties().get(key).parse(value)?;
+                let prev = thing.set_property(key, &value)?;
+                edited = true;
+                (thing.id(), prev).into()
+            })
+        );
+
+        edited
+    }
+
+    /// Assigns consecutive spawn orders starting from `first` to the selected things, sorted by
+    /// position from top to bottom and then from left to right. Returns the amount of things
+    /// whose spawn order changed, or an error if the spawn order property of the things is not a
+    /// `u32` because it was redefined in the things properties.
+    #[inline]
+    pub fn assign_selected_things_spawn_order(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        first: u32
+    ) -> Result<usize, &'static str>
+    {
+        if self.selected_things().any(|thing| {
+            thing.properties().contains(SPAWN_ORDER_PROPERTY) &&
+                !thing
+                    .properties()
+                    .get(SPAWN_ORDER_PROPERTY)
+                    .eq_discriminant(&Value::U32(0))
+        })
+        {
+            return Err("The spawn order property of the things is not a u32");
+        }
+
+        let mut things =
+            hv_vec![collect; self.selected_things().map(|thing| (thing.id(), thing.center()))];
+        things.sort_by(|(id_a, a), (id_b, b)| {
+            b.y.total_cmp(&a.y)
+                .then(a.x.total_cmp(&b.x))
+                .then(id_a.value().cmp(&id_b.value()))
+        });
+
+        let mut edits = hv_vec![];
+
+        for (order, (id, _)) in (first..).zip(things)
+        {
+            let mut thing = self.thing_mut(id);
+
+            if !thing.properties().contains(SPAWN_ORDER_PROPERTY)
+            {
+                continue;
+            }
+
+            edits.extend(
+                thing
+                    .set_property(SPAWN_ORDER_PROPERTY, &Value::U32(order))
+                    .map(|prev| (id, prev))
+            );
+        }
+
+        let edited = edits.len();
+
+        if edited != 0
+        {
+            edits_history.property(SPAWN_ORDER_PROPERTY, edits.into_iter());
+            self.schedule_overall_things_property_update(SPAWN_ORDER_PROPERTY);
+        }
+
+        Ok(edited)
+    }
+
+    /// Despawns the selected entities.
+    #[inline]
+    pub fn despawn_selected_entities(&mut self, edits_history: &mut EditsHistory)
+    {
+        self.despawn_selected_brushes(edits_history);
+
+        self.auxiliary.replace_values(self.innards.selected_things.iter());
+
+        for id in &self.auxiliary
+        {
+            self.innards.despawn_thing(&mut self.quad_trees, edits_history, *id);
+        }
+    }
+
+    //==============================================================
+    // Brushes
+
+    /// Returns the amount of brushes.
+    #[inline]
+    #[must_use]
+    pub fn brushes_amount(&self) -> usize { self.innards.brushes.len() }
+
+    /// Returns the amount of selected brushes.
+    #[inline]
+    #[must_use]
+    pub fn selected_brushes_amount(&self) -> usize { self.innards.selected_brushes_amount() }
+
+    /// Whether there are any currently selected brushes.
+    #[inline]
+    #[must_use]
+    pub fn any_selected_brushes(&self) -> bool { self.selected_brushes_amount() != 0 }
+
+    /// Returns a reference to the brush with [`Id`] identifier.
+    #[inline]
+    pub fn brush(&self, identifier: Id) -> &Brush { self.innards.brush(identifier) }
+
+    /// Returns an iterator to the [`Id`]s of the selected brushes.
+    #[inline]
+    pub fn selected_brushes_ids(&self) -> impl ExactSizeIterator<Item = &Id> + Clone
+    {
+        self.innards.selected_brushes.iter()
+    }
+
+    /// Returns a [`BrushesIter`] created from `ids`.
+    #[inline]
+    const fn brushes_iter<'a>(&'a self, ids: Ref<'a, QuadTreeIds>) -> BrushesIter<'a>
+    {
+        BrushesIter::new(self, ids)
+    }
+
+    /// Returns a [`SelectedBrushesIter`] created from `ids`
+    #[inline]
+    const fn selected_brushes_iter<'a>(
+        &'a self,
+        ids: Ref<'a, QuadTreeIds>
+    ) -> SelectedBrushesIter<'a>
+    {
+        SelectedBrushesIter::new(self, ids)
+    }
+
+    /// Returns a [`Brushes`] instance.
+    #[inline]
+    pub const fn brushes(&self) -> Brushes { self.innards.brushes() }
+
+    /// Returns an array of references to `N` brushes.
+    #[inline]
+    pub fn many_brushes<const N: usize>(&self, identifiers: [Id; N]) -> [&Brush; N]
+    {
+        std::array::from_fn(|i| self.brush(identifiers[i]))
+    }
+
+    /// Returns a [`BrushMut`] wrapping the brush with [`Id`] `identifier`.
+    #[inline]
+    pub fn brush_mut(&mut self, identifier: Id) -> BrushMut<'_>
+    {
+        BrushMut::new(&mut self.innards, &mut self.quad_trees, identifier)
+    }
+
+    /// Reverses the order of the vertexes of the selected brushes which are not stored in
+    /// counter-clockwise order.
+    #[inline]
+    pub fn fix_selected_brushes_winding(&mut self, edits_history: &mut EditsHistory)
+    {
+        edits_history.polygon_edit_cluster(
+            self.selected_brushes_mut()
+                .filter_map(|mut brush| brush.fix_winding().map(|prev| (brush.id(), prev)))
+        );
+    }
+
+    /// Moves each selected brush so that the top left corner of its bounding box lies on the
+    /// closest grid vertex, without altering its shape.
+    /// If `fit` is true the brushes are instead scaled so that all the corners of their bounding
+    /// boxes lie on grid vertexes. Bounding boxes cannot be fit to a rotated grid.
+    #[inline]
+    pub fn snap_selected_brushes_hulls(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: Grid,
+        fit: bool
+    )
+    {
+        if fit
+        {
+            self.fit_selected_brushes_hulls(drawing_resources, edits_history, grid);
+            return;
+        }
+
+        let moves = hv_vec![collect; self.selected_brushes().filter_map(|brush| {
+            let top_left = brush.hull().top_left();
+            grid.snap_point(top_left).map(|snapped| (brush.id(), snapped - top_left))
+        })];
+
+        if moves.is_empty()
+        {
+            return;
+        }
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            moves.iter().find_map(|(id, delta)| {
+                (!manager.brush(*id).check_move(*delta, true, bound)).then_some(*id)
+            })
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        for (id, delta) in moves
+        {
+            self.brush_mut(id).move_by_delta(drawing_resources, delta, true);
+            edits_history.brush_move(id, delta, true);
+        }
+    }
+
+    /// Scales each selected brush so that all the corners of its bounding box lie on the grid
+    /// vertexes around it.
+    #[inline]
+    fn fit_selected_brushes_hulls(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: Grid
+    )
+    {
+        let infos = hv_vec![collect; self.selected_brushes().filter_map(|brush| {
+            let hull = brush.hull();
+            ScaleInfo::new(&hull, &grid.snap_hull(&hull)).map(|info| (brush.id(), info))
+        })];
+
+        if infos.is_empty()
+        {
+            return;
+        }
+
+        let mut payloads = hv_vec![capacity; infos.len()];
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            infos.iter().find_map(|(id, info)| {
+                match manager.brush_mut(*id).check_scale(drawing_resources, info, true, bound)
+                {
+                    ScaleResult::Invalid => (*id).into(),
+                    ScaleResult::Valid(payload) =>
+                    {
+                        payloads.push(payload);
+                        None
+                    }
+                }
+            })
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        edits_history.polygon_edit_cluster(
+            payloads.iter().map(|payload| (payload.id(), self.brush(payload.id()).polygon()))
+        );
+
+        for payload in payloads
+        {
+            self.brush_mut(payload.id())
+                .set_scale_coordinates(drawing_resources, payload);
+        }
+    }
+
+    /// Sets the texture offset of the selected textured brush so that the texel with coordinates
+    /// `texel` lies on its selected vertex. The operation requires exactly one selected textured
+    /// brush, with a non sprite texture and exactly one selected vertex. Texture scroll and
+    /// parallax are not taken into account. If the texture is trimmed `texel` must lie inside the
+    /// trim region.
+    /// Returns whether the offset was changed.
+    #[inline]
+    pub fn pin_selected_texture_to_vertex(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        texel: Vec2
+    ) -> bool
+    {
+        if self.selected_textured_amount() != 1
+        {
+            return false;
+        }
+
+        let id = *self.selected_textured_ids().next_value();
+        let brush = self.brush(id);
+
+        if brush.selected_vertexes_amount() != 1
+        {
+            return false;
+        }
+
+        let settings = brush.texture_settings().unwrap();
+
+        if settings.sprite()
+        {
+            return false;
+        }
+
+        // The offset of a trimmed texture is relative to the top left corner of its region.
+        let texel = match settings
+            .trim()
+            .and_then(|name| drawing_resources.texture(settings.name())?.trim(name))
+        {
+            Some(trim) =>
+            {
+                let (pos, size) = (trim.pos().as_vec2(), trim.size().as_vec2());
+
+                if texel.cmplt(pos).any() || texel.cmpge(pos + size).any()
+                {
+                    return false;
+                }
+
+                texel - pos
+            },
+            None => texel
+        };
+
+        let vx = rotate_point_around_origin(
+            brush.selected_vertexes().unwrap().next_value(),
+            settings.angle().to_radians()
+        );
+        let offset = Vec2::new(
+            texel.x * settings.scale_x() - vx.x,
+            texel.y * settings.scale_y() + vx.y
+        );
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            let mut brush = manager.brush_mut(id);
+
+            (!(brush.check_texture_offset_x(drawing_resources, offset.x, bound) &&
+                brush.check_texture_offset_y(drawing_resources, offset.y, bound)))
+            .then_some(id)
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        let mut brush = self.brush_mut(id);
+
+        if let Some(prev) = brush.set_texture_offset_x(drawing_resources, offset.x)
+        {
+            edits_history.texture_offset_x(id, prev);
+        }
+
+        if let Some(prev) = brush.set_texture_offset_y(drawing_resources, offset.y)
+        {
+            edits_history.texture_offset_y(id, prev);
+        }
+
+        self.innards.overall_texture_update = true;
+        true
+    }
+
+    /// Sets the texture offset of one of the two selected brushes so that its texture continues
+    /// seamlessly from the one of the other brush, `reference` or the one with the lowest [`Id`],
+    /// across the edge they share. The texture settings are compared at the start of the shared
+    /// edge, so the continuity is exact along the whole edge only if the brushes have the same
+    /// texture angle and scale. Texture scroll and parallax are not taken into account.
+    /// # Errors
+    /// Returns an error if the selected brushes are not two textured brushes sharing an edge, one
+    /// of them has a sprite, or the resulting offset is not valid.
+    #[inline]
+    pub fn align_selected_textures_across_seam(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        reference: Option<Id>
+    ) -> Result<(), &'static str>
+    {
+        if self.selected_brushes_amount() != 2
+        {
+            return Err("Exactly two brushes must be selected");
+        }
+
+        let mut ids = hv_vec![collect; self.selected_brushes().map(EntityId::id)];
+        ids.sort_by(|a, b| a.value().cmp(&b.value()));
+
+        let (ref_id, id) = if reference == Some(ids[1])
+        {
+            (ids[1], ids[0])
+        }
+        else
+        {
+            (ids[0], ids[1])
+        };
+
+        let (reference, target) = (self.brush(ref_id), self.brush(id));
+        let (ref_settings, settings) =
+            match (reference.texture_settings(), target.texture_settings())
+            {
+                (Some(ref_settings), Some(settings)) => (ref_settings, settings),
+                _ => return Err("Both brushes must have a texture")
+            };
+
+        if ref_settings.sprite() || settings.sprite()
+        {
+            return Err("Textures rendered as sprites cannot be aligned");
+        }
+
+        let seam = match NavMeshRegion::portal(
+            &hv_vec![collect; reference.vertexes()],
+            &hv_vec![collect; target.vertexes()]
+        )
+        {
+            Some([start, _]) => start,
+            None => return Err("The brushes do not share an edge")
+        };
+
+        let vx = rotate_point_around_origin(seam, ref_settings.angle().to_radians());
+        let texel = Vec2::new(
+            (vx.x + ref_settings.offset_x()) / ref_settings.scale_x(),
+            (-vx.y + ref_settings.offset_y()) / ref_settings.scale_y()
+        );
+        let vx = rotate_point_around_origin(seam, settings.angle().to_radians());
+        let offset = Vec2::new(
+            texel.x * settings.scale_x() - vx.x,
+            texel.y * settings.scale_y() + vx.y
+        );
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            let mut brush = manager.brush_mut(id);
+
+            (!(brush.check_texture_offset_x(drawing_resources, offset.x, bound) &&
+                brush.check_texture_offset_y(drawing_resources, offset.y, bound)))
+            .then_some(id)
+        });
+
+        if !valid
+        {
+            return Err("The aligned texture offset is not valid");
+        }
+
+        let mut brush = self.brush_mut(id);
+
+        if let Some(prev) = brush.set_texture_offset_x(drawing_resources, offset.x)
+        {
+            edits_history.texture_offset_x(id, prev);
+        }
+
+        if let Some(prev) = brush.set_texture_offset_y(drawing_resources, offset.y)
+        {
+            edits_history.texture_offset_y(id, prev);
+        }
+
+        self.innards.overall_texture_update = true;
+        Ok(())
+    }
+
+    /// Returns an iterator to the non selected brushes.
+    #[inline]
+    pub fn non_selected_brushes(&mut self) -> impl Iterator<Item = &Brush>
+    {
+        self.innards
+            .brushes
+            .values()
+            .filter(|brush| !self.is_selected(brush.id()))
+    }
+
+    /// Returns an iterator to the selected brushes.
+    #[inline]
+    pub fn selected_brushes(&self) -> impl Iterator<Item = &Brush>
+    {
+        self.selected_brushes_ids().map(|id| self.brush(*id))
+    }
+
+    /// Returns the [`Id`]s of the textured brushes attached to the selected brushes which are not
+    /// selected.
+    #[inline]
+    pub fn unselected_textured_attachments(&self) -> HvVec<Id>
+    {
+        hv_vec![collect; self
+            .selected_brushes()
+            .filter_map(Brush::anchors_iter)
+            .flatten()
+            .filter(|id| !self.is_selected(**id) && self.brush(**id).has_texture())
+            .copied()]
+    }
 
     /// Returns an iterator to [`BrushMut`] wrapping the selected brushes.
     #[inline]
@@ -2030,6 +2953,25 @@ impl EntitiesManager
         self.brushes_iter(self.quad_trees.brushes_at_pos(cursor_pos, camera_scale))
     }
 
+    /// Sets the minimum on screen size, in pixels, the brushes and [`ThingInstance`]s must have to
+    /// be returned by the visible entities queries. Detail culling is disabled if `min_size` is
+    /// [`None`]. Does not affect the queries used to pick entities.
+    #[inline]
+    pub fn set_detail_culling(&mut self, min_size: Option<f32>)
+    {
+        self.quad_trees.set_cull_size(min_size);
+    }
+
+    /// Enables the bulk mode, in which the spawned entities are not inserted in the quad trees
+    /// until [`Self::finish_bulk`] is called. Meant to speed up the spawn of a large amount of
+    /// entities. The entities queries must not be used while it is enabled.
+    #[inline]
+    pub fn start_bulk(&mut self) { self.quad_trees.start_bulk(); }
+
+    /// Disables the bulk mode and rebuilds the quad trees once with all the entities.
+    #[inline]
+    pub fn finish_bulk(&mut self) { self.innards.finish_bulk(&mut self.quad_trees); }
+
     /// Returns an iterator to the visible brushes.
     #[inline]
     pub fn visible_brushes(&self, window: &Window, camera: &Transform) -> BrushesIter<'_>
@@ -2100,7 +3042,7 @@ impl EntitiesManager
                 self.innards.select_cluster(edits_history, self.auxiliary.iter());
 
                 $(
-                    if inputs.$ctrl_pressed()
+                    if settings.select_attachments && inputs.$ctrl_pressed()
                     {
                         self.innards.select_anchored_brushes(
                             edits_history,
@@ -2119,16 +3061,56 @@ impl EntitiesManager
         {
             TargetSwitch::Entity =>
             {
-                select!(brushes_in_range, ctrl_pressed);
-                select!(things_in_range);
+                select!(brushes_in_range, ctrl_pressed);
+                select!(things_in_range);
+            },
+            TargetSwitch::Both =>
+            {
+                select!(brushes_in_range, ctrl_pressed);
+                select!(things_in_range);
+                select!(sprites_in_range, ctrl_pressed);
+            },
+            TargetSwitch::Texture => select!(sprites_in_range, ctrl_pressed)
+        };
+    }
+
+    /// Deselects all selected entities that are fully contained in `range`.
+    #[inline]
+    pub fn deselect_entities_in_range(
+        &mut self,
+        range: &Hull,
+        edits_history: &mut EditsHistory,
+        settings: &ToolsSettings
+    )
+    {
+        /// Executes the ranged deselection.
+        macro_rules! deselect {
+            ($func:ident) => {{
+                let in_range = self.quad_trees.$func(range);
+
+                self.auxiliary
+                    .replace_values(in_range.iter().filter_map(|(id, hull)| {
+                        (self.innards.is_selected(*id) && range.contains_hull(hull)).then_some(id)
+                    }));
+
+                self.innards.deselect_cluster(edits_history, self.auxiliary.iter());
+            }};
+        }
+
+        match settings.target_switch()
+        {
+            TargetSwitch::Entity =>
+            {
+                deselect!(brushes_in_range);
+                deselect!(things_in_range);
             },
             TargetSwitch::Both =>
             {
-                select!(brushes_in_range, ctrl_pressed);
-                select!(things_in_range);
-                select!(sprites_in_range, ctrl_pressed);
+                deselect!(brushes_in_range);
+                deselect!(things_in_range);
+                deselect!(sprites_in_range);
             },
-            TargetSwitch::Texture => select!(sprites_in_range, ctrl_pressed)
+            TargetSwitch::Texture => deselect!(sprites_in_range)
         };
     }
 
@@ -2209,7 +3191,7 @@ impl EntitiesManager
             }
         };
 
-        if !inputs.ctrl_pressed()
+        if !settings.select_attachments || !inputs.ctrl_pressed()
         {
             return;
         }
@@ -2287,6 +3269,16 @@ impl EntitiesManager
         Hull::from_hulls_iter(self.selected_brushes_ids().map(|id| self.brush(*id).hull()))
     }
 
+    /// Returns the sum of the areas and the sum of the perimeters of the selected brushes.
+    #[inline]
+    #[must_use]
+    pub fn selected_brushes_area_perimeter(&self) -> (f32, f32)
+    {
+        self.selected_brushes().fold((0f32, 0f32), |(area, perimeter), brush| {
+            (area + brush.area(), perimeter + brush.perimeter())
+        })
+    }
+
     /// Returns the [`Hull`] describing the rectangle encompassing all selected textured
     /// brushes, if any.
     #[inline]
@@ -2398,6 +3390,72 @@ impl EntitiesManager
         self.innards.insert_brush(&mut self.quad_trees, brush, selected);
     }
 
+    /// Returns the [`Hull`] encompassing all the entities, if any.
+    #[inline]
+    #[must_use]
+    pub fn entities_hull(&self) -> Option<Hull>
+    {
+        Hull::from_hulls_iter(
+            self.brushes()
+                .iter()
+                .map(Brush::global_hull)
+                .chain(self.things().map(EntityHull::hull))
+        )
+    }
+
+    /// Exclusively selects four newly spawned brushes `thickness` thick framing `hull`, with
+    /// texture `texture` if not [`None`].
+    /// Returns an error if the brushes would be out of the map bounds.
+    #[inline]
+    pub fn spawn_boundary_brushes(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        hull: &Hull,
+        thickness: f32,
+        texture: Option<&str>,
+        default_properties: &DefaultProperties
+    ) -> Result<(), &'static str>
+    {
+        let outer = Hull::new(
+            hull.top() + thickness,
+            hull.bottom() - thickness,
+            hull.left() - thickness,
+            hull.right() + thickness
+        );
+
+        if outer.out_of_bounds(self.map_bound)
+        {
+            return Err("The boundary brushes would be out of bounds");
+        }
+
+        self.deselect_selected_entities(edits_history);
+
+        for wall in [
+            Hull::new(outer.top(), hull.top(), outer.left(), outer.right()),
+            Hull::new(hull.bottom(), outer.bottom(), outer.left(), outer.right()),
+            Hull::new(hull.top(), hull.bottom(), outer.left(), hull.left()),
+            Hull::new(hull.top(), hull.bottom(), hull.right(), outer.right())
+        ]
+        {
+            _ = self.spawn_brush(
+                ConvexPolygon::from(hv_vec![collect; wall.rectangle()]),
+                edits_history,
+                default_properties.instance()
+            );
+        }
+
+        let texture = return_if_none!(texture, Ok(()));
+
+        if let TextureResult::ValidRefreshOutline =
+            self.set_selected_brushes_texture(drawing_resources, edits_history, texture)
+        {
+            self.schedule_outline_update();
+        }
+
+        Ok(())
+    }
+
     /// Spawns the entities created from `data`.
     #[inline]
     #[must_use]
@@ -2551,10 +3609,13 @@ impl EntitiesManager
         delta: Vec2
     ) -> bool
     {
+        let bound = self.map_bound;
         let valid = self.test_operation_validity(|manager| {
             manager
                 .selected_entities()
-                .find_map(|entity| (entity.hull() + delta).out_of_bounds().then_some(entity.id()))
+                .find_map(|entity| {
+                    (entity.hull() + delta).out_of_bounds(bound).then_some(entity.id())
+                })
         });
 
         if !valid
@@ -2641,6 +3702,62 @@ impl EntitiesManager
             .replace_selected_path(&mut self.quad_trees, identifier, edits_history, path);
     }
 
+    /// Splits the [`Path`] of the only selected moving entity at its only selected [`Node`]. The
+    /// entity keeps the nodes up to the selected one, and a copy of it is spawned with the nodes
+    /// from the selected one onward.
+    #[inline]
+    pub fn split_selected_path(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory
+    ) -> Result<(), &'static str>
+    {
+        if self.selected_moving_amount() != 1
+        {
+            return Err("Exactly one entity with a path must be selected");
+        }
+
+        let id = *self.innards.selected_moving.iter().next().unwrap();
+        let path = self.moving(id).path().unwrap();
+
+        let index = match path.selected_nodes()
+        {
+            Some(idxs) if idxs.len() == 1 => usize::from(idxs[0]),
+            _ => return Err("Exactly one node must be selected")
+        };
+
+        let [first, second] = match path.split(index)
+        {
+            Some(paths) => paths,
+            None => return Err("The path cannot be split into two valid paths at the selected node")
+        };
+
+        let mut item = self.innards.entity(id).copy_to_clipboard();
+
+        if let ClipboardData::Brush(data, _) = &mut item
+        {
+            let anchors = data.anchors().map(|ids| hv_vec![collect; ids.iter().copied()]);
+
+            for anchor in anchors.into_iter().flatten()
+            {
+                data.remove_anchor(anchor);
+            }
+        }
+
+        self.replace_selected_path(id, edits_history, first);
+        let copy = self.innards.spawn_pasted_entity(
+            drawing_resources,
+            edits_history,
+            &mut self.quad_trees,
+            item,
+            Vec2::ZERO
+        );
+        self.replace_selected_path(copy, edits_history, second);
+        self.schedule_outline_update();
+
+        Ok(())
+    }
+
     /// Removes the [`Motor`]s from the selected brushes.
     #[inline]
     pub fn remove_selected_paths(&mut self, edits_history: &mut EditsHistory)
@@ -2724,6 +3841,164 @@ impl EntitiesManager
         self.innards.disanchor(&mut self.quad_trees, owner_id, anchor_id);
     }
 
+    /// Anchors all the selected brushes to the selected one with [`Id`] `owner`, or to the selected
+    /// brush with the lowest [`Id`] if `owner` is [`None`]. The brushes that are anchored to
+    /// another brush, have anchors, or have a [`Path`] are skipped and their [`Id`]s returned.
+    /// # Errors
+    /// Returns an error if the owner is anchored to another brush.
+    #[inline]
+    pub fn anchor_selected_brushes(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        owner: Option<Id>
+    ) -> Result<HvVec<Id>, &'static str>
+    {
+        let mut ids = hv_vec![collect; self.selected_brushes().map(EntityId::id)];
+        ids.sort_by(|a, b| a.value().cmp(&b.value()));
+
+        let owner = match owner
+        {
+            Some(owner) => owner,
+            None => return_if_none!(ids.first().copied(), Ok(hv_vec![]))
+        };
+
+        if self.brush(owner).anchored().is_some()
+        {
+            return Err("The owner brush is attached to another brush");
+        }
+
+        let mut skipped = hv_vec![];
+
+        for id in ids.into_iter().filter(|id| *id != owner)
+        {
+            let brush = self.brush(id);
+
+            match brush.anchored()
+            {
+                Some(anchor_owner) if anchor_owner == owner => continue,
+                Some(_) =>
+                {
+                    skipped.push(id);
+                    continue;
+                },
+                None => ()
+            };
+
+            if !brush.anchorable()
+            {
+                skipped.push(id);
+                continue;
+            }
+
+            self.anchor(owner, id);
+            edits_history.anchor(owner, id);
+        }
+
+        Ok(skipped)
+    }
+
+    /// Disanchors all the brushes anchored to the selected brushes, selecting them if they are not
+    /// already. Returns the amount of disanchored brushes.
+    #[inline]
+    pub fn disanchor_selected_brushes_anchors(&mut self, edits_history: &mut EditsHistory) -> usize
+    {
+        let pairs = hv_vec![collect; self
+            .selected_brushes()
+            .filter_map(|brush| {
+                let owner = brush.id();
+                brush.anchors_iter().map(|ids| ids.map(move |id| (owner, *id)))
+            })
+            .flatten()];
+
+        let unselected = hv_vec![collect; pairs
+            .iter()
+            .filter_map(|(_, id)| (!self.is_selected(*id)).then_some(*id))];
+        self.innards.select_cluster(edits_history, unselected.iter());
+
+        for (owner, anchor) in &pairs
+        {
+            self.disanchor(*owner, *anchor);
+            edits_history.disanchor(*owner, *anchor);
+        }
+
+        pairs.len()
+    }
+
+    /// Snaps all the [`Path`] nodes of the selected entities to the grid.
+    /// The paths that would become invalid are left unchanged and the [`Id`]s of their owners are
+    /// returned.
+    #[inline]
+    pub fn snap_selected_paths_nodes(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        grid: Grid
+    ) -> HvVec<Id>
+    {
+        let mut invalid = hv_vec![];
+
+        for mut moving in self.selected_movings_mut()
+        {
+            match moving.snap_path_nodes(grid)
+            {
+                NodesSnapResult::None => (),
+                NodesSnapResult::Invalid => invalid.push(moving.id()),
+                NodesSnapResult::Valid(snap) => edits_history.path_nodes_snap(moving.id(), snap)
+            };
+        }
+
+        invalid
+    }
+
+    /// Snaps the vertexes of the selected brushes, the positions of the selected things, and the
+    /// [`Path`] nodes of the selected entities to the grid.
+    /// The entities that would become invalid are left unchanged and their [`Id`]s are returned.
+    #[inline]
+    pub fn snap_selected_entities(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: Grid
+    ) -> HvVec<Id>
+    {
+        let mut invalid = hv_vec![];
+
+        for mut brush in self.selected_brushes_mut()
+        {
+            if brush.vertexes().all(|vx| grid.snap_point(vx).is_none())
+            {
+                continue;
+            }
+
+            match brush.snap_vertexes(drawing_resources, grid)
+            {
+                Some(snap) => edits_history.vertexes_snap(brush.id(), snap),
+                None => invalid.push(brush.id())
+            };
+        }
+
+        let bound = self.map_bound;
+
+        for mut thing in self.selected_things_mut()
+        {
+            if grid.snap_point(thing.center()).is_none()
+            {
+                continue;
+            }
+
+            match thing.snap(grid, bound)
+            {
+                Some(delta) => edits_history.thing_move(thing.id(), delta),
+                None => invalid.push(thing.id())
+            };
+        }
+
+        invalid.extend(self.snap_selected_paths_nodes(edits_history, grid));
+        invalid.sort_by(|a, b| a.value().cmp(&b.value()));
+        invalid.dedup();
+        self.schedule_outline_update();
+        invalid
+    }
+
     /// Sets the texture of the brush with [`Id`] identifier.
     /// Returns the name of the replaced texture, if any.
     #[inline]
@@ -2765,6 +4040,171 @@ impl EntitiesManager
         }
     }
 
+    /// Restores the aspect ratio of the textures scales of the selected brushes, which were
+    /// respectively `prev_scales` before one of the two axis was changed, by scaling the other axis
+    /// proportionally.
+    #[inline]
+    pub fn keep_selected_textures_scale_aspect(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        prev_scales: &[(Id, f32, f32)]
+    )
+    {
+        if prev_scales.iter().all(|(id, x, y)| {
+            let texture = self.brush(*id).texture_settings().unwrap();
+            texture.scale_x() == *x && texture.scale_y() == *y
+        })
+        {
+            return;
+        }
+
+        let mut x_edits = hv_vec![];
+        let mut y_edits = hv_vec![];
+        let bound = self.map_bound;
+
+        for mut brush in self.selected_textured_brushes_mut()
+        {
+            let id = brush.id();
+            let (_, prev_x, prev_y) =
+                continue_if_none!(prev_scales.iter().find(|(identifier, ..)| *identifier == id));
+            let (x, y) = {
+                let texture = brush.texture_settings().unwrap();
+                (texture.scale_x(), texture.scale_y())
+            };
+
+            if x != *prev_x && y == *prev_y
+            {
+                let value = prev_y * x / prev_x;
+
+                if brush.check_texture_scale_y(drawing_resources, value, bound)
+                {
+                    y_edits.extend(
+                        brush.set_texture_scale_y(drawing_resources, value).map(|prev| (id, prev))
+                    );
+                }
+            }
+            else if y != *prev_y && x == *prev_x
+            {
+                let value = prev_x * y / prev_y;
+
+                if brush.check_texture_scale_x(drawing_resources, value, bound)
+                {
+                    x_edits.extend(
+                        brush.set_texture_scale_x(drawing_resources, value).map(|prev| (id, prev))
+                    );
+                }
+            }
+        }
+
+        edits_history.texture_scale_x_cluster(x_edits.into_iter());
+        edits_history.texture_scale_y_cluster(y_edits.into_iter());
+        self.schedule_outline_update();
+    }
+
+    /// Returns the [`Id`]s and texture names of the brushes whose texture does not exist in
+    /// `drawing_resources`, sorted by [`Id`].
+    #[inline]
+    pub fn brushes_with_missing_texture<'a>(
+        &'a self,
+        drawing_resources: &DrawingResources
+    ) -> HvVec<(Id, &'a str)>
+    {
+        let mut missing = hv_vec![collect; self.innards.brushes.values().filter_map(|brush| {
+            brush
+                .texture_name()
+                .filter(|name| drawing_resources.texture(name).is_none())
+                .map(|name| (brush.id(), name))
+        })];
+
+        missing.sort_by(|(a, _), (b, _)| a.value().cmp(&b.value()));
+        missing
+    }
+
+    /// Returns the [`Id`]s of the entities whose [`Path`] makes them travel out of the map bounds,
+    /// sorted by [`Id`].
+    #[inline]
+    pub fn entities_with_path_out_of_bounds(&self) -> HvVec<Id>
+    {
+        let mut ids = hv_vec![collect; self.innards.moving.iter().copied().filter(|id| {
+            let moving = self.moving(*id);
+            moving.path_hull_out_of_bounds(moving.center(), self.map_bound)
+        })];
+
+        ids.sort_by(|a, b| a.value().cmp(&b.value()));
+        ids
+    }
+
+    /// Returns the [`Id`]s of the things placed within `tolerance` from another thing with the
+    /// same [`ThingId`], sorted by [`Id`].
+    #[inline]
+    pub fn overlapping_things(&self, tolerance: f32) -> HvVec<Id>
+    {
+        let things =
+            hv_vec![collect; self.things().map(|thing| (thing.id(), thing.thing(), thing.pos()))];
+        let mut overlapping = hv_hash_set![];
+
+        for (i, (a_id, a_thing, a_pos)) in things.iter().enumerate()
+        {
+            for (b_id, b_thing, b_pos) in things.iter().skip(i + 1)
+            {
+                if a_thing == b_thing && a_pos.distance(*b_pos) <= tolerance
+                {
+                    _ = overlapping.insert(*a_id);
+                    _ = overlapping.insert(*b_id);
+                }
+            }
+        }
+
+        let mut ids = hv_vec![collect; overlapping];
+        ids.sort_by(|a, b| a.value().cmp(&b.value()));
+        ids
+    }
+
+    /// Replaces the textures of the brushes using any texture of each group in `groups` but the
+    /// first with the first one, then exclusively selects the affected brushes.
+    /// Returns the amount of brushes whose texture was replaced.
+    #[inline]
+    pub fn merge_duplicate_textures(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        groups: &[HvVec<String>]
+    ) -> usize
+    {
+        let mut replaced = hv_vec![];
+
+        for (texture, duplicates) in groups.iter().filter_map(|group| group.split_first())
+        {
+            let ids = hv_vec![collect; self.brushes().iter().filter_map(|brush| {
+                brush
+                    .texture_settings()
+                    .is_some_and(|tex| duplicates.iter().any(|name| name == tex.name()))
+                    .then_some(brush.id())
+            })];
+
+            if ids.is_empty()
+            {
+                continue;
+            }
+
+            self.deselect_selected_entities(edits_history);
+            self.innards.select_cluster(edits_history, ids.iter());
+
+            if let TextureResult::ValidRefreshOutline =
+                self.set_selected_brushes_texture(drawing_resources, edits_history, texture)
+            {
+                self.schedule_outline_update();
+            }
+
+            replaced.extend(ids);
+        }
+
+        self.deselect_selected_entities(edits_history);
+        self.innards.select_cluster(edits_history, replaced.iter());
+        replaced.len()
+    }
+
     /// Sets the texture of the selected brushes and returns a [`TextureResult`] describing the
     /// result of the procedure.
     #[inline]
@@ -2775,9 +4215,11 @@ impl EntitiesManager
         texture: &str
     ) -> TextureResult
     {
+        let bound = self.map_bound;
         let valid = self.test_operation_validity(|manager| {
             manager.selected_brushes_with_sprite_mut().find_map(|mut brush| {
-                (!brush.check_texture_change(drawing_resources, texture)).then_some(brush.id)
+                (!brush.check_texture_change(drawing_resources, texture, bound))
+                    .then_some(brush.id)
             })
         });
 
@@ -2839,6 +4281,34 @@ impl EntitiesManager
         TextureResult::Valid
     }
 
+    /// Sets the texture of the selected brushes and renders it as a sprite. The operation is
+    /// performed only if all the resulting sprites are within the map bounds.
+    #[inline]
+    pub fn set_selected_brushes_texture_sprite(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        texture: &str
+    ) -> TextureResult
+    {
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            manager.selected_brushes_mut().find_map(|mut brush| {
+                (!brush.check_texture_sprite_change(drawing_resources, texture, bound))
+                    .then_some(brush.id())
+            })
+        });
+
+        if !valid
+        {
+            return TextureResult::Invalid;
+        }
+
+        _ = self.set_selected_brushes_texture(drawing_resources, edits_history, texture);
+        self.set_sprite(drawing_resources, edits_history, true);
+        TextureResult::ValidRefreshOutline
+    }
+
     /// Removes the textures from the selected brushes.
     #[inline]
     pub fn remove_selected_textures(&mut self, edits_history: &mut EditsHistory)
@@ -2854,6 +4324,24 @@ impl EntitiesManager
         self.innards.selected_textured.clear();
     }
 
+    /// Constrains the textures of the selected brushes to the trim sheet region named `trim`, or
+    /// removes the constraint if [`None`].
+    #[inline]
+    pub fn set_selected_textures_trim(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        trim: Option<&str>
+    )
+    {
+        edits_history.texture_trim_cluster(self.selected_textured_brushes_mut().filter_map(
+            |mut brush| {
+                brush
+                    .set_texture_trim(trim.map(str::to_owned))
+                    .map(|prev| (brush.id(), prev))
+            }
+        ));
+    }
+
     /// Sets whether the texture of the selected brushes should be rendered as a sprite or not.
     #[inline]
     pub fn set_sprite(
@@ -2865,9 +4353,11 @@ impl EntitiesManager
     {
         if value
         {
+            let bound = self.map_bound;
             let valid = self.test_operation_validity(|manager| {
                 manager.selected_textured_brushes_mut().find_map(|mut brush| {
-                    (!brush.check_texture_sprite(drawing_resources, value)).then_some(brush.id())
+                    (!brush.check_texture_sprite(drawing_resources, value, bound))
+                        .then_some(brush.id())
                 })
             });
 
@@ -2938,6 +4428,8 @@ impl EntitiesManager
     {
         self.auxiliary.replace_values(&self.innards.textured);
 
+        let bound = self.map_bound;
+
         for id in &self.auxiliary
         {
             let mut brush = self.innards.brush_mut(&mut self.quad_trees, *id);
@@ -2952,7 +4444,7 @@ impl EntitiesManager
                 continue_if_none!(drawing_resources.texture(settings.name())).name()
             };
 
-            if !brush.check_texture_change(drawing_resources, name)
+            if !brush.check_texture_change(drawing_resources, name, bound)
             {
                 _ = brush.set_texture(drawing_resources, "error");
             }
@@ -2983,6 +4475,11 @@ impl EntitiesManager
     #[must_use]
     pub fn things_amount(&self) -> usize { self.innards.things.len() }
 
+    /// Returns the total amount of vertexes of the brushes.
+    #[inline]
+    #[must_use]
+    pub const fn vertexes_amount(&self) -> usize { self.innards.vertexes_amount }
+
     /// Returns an iterator to all [`ThingInstance`]s in the map.
     #[inline]
     pub fn things(&self) -> impl Iterator<Item = &ThingInstance> { self.innards.things.values() }
@@ -3018,6 +4515,438 @@ impl EntitiesManager
         SelectedThingsMut::new(&mut self.innards, &mut self.quad_trees, &self.auxiliary)
     }
 
+    /// Rotates the selected entities by 90 degrees, clockwise if `clockwise` is true, around the
+    /// center of their [`Hull`] rounded to the closest integer coordinates. The coordinates are
+    /// swapped rather than computed through trigonometry, so integer positions remain integers.
+    /// The angles of the [`ThingInstance`]s are rotated as well.
+    /// Returns whether the rotation was valid.
+    #[inline]
+    pub fn rotate_selected_entities_quarter(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        clockwise: bool,
+        rotate_texture: bool
+    ) -> bool
+    {
+        let pivot = return_if_none!(self.selected_entities_hull(), false).center().round();
+        let mut payloads = hv_vec![];
+        let mut deltas = hv_vec![];
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            let invalid = manager.selected_brushes_mut().find_map(|mut brush| {
+                match brush.check_quarter_rotate(
+                    drawing_resources,
+                    pivot,
+                    clockwise,
+                    rotate_texture,
+                    bound
+                )
+                {
+                    RotateResult::Invalid => brush.id().into(),
+                    RotateResult::Valid(payload) =>
+                    {
+                        payloads.push(payload);
+                        None
+                    }
+                }
+            });
+
+            if invalid.is_some()
+            {
+                return invalid;
+            }
+
+            manager.selected_things().find_map(|thing| {
+                let pos = thing.pos();
+                let delta = rotate_point_quarter(pos, pivot, clockwise) - pos;
+
+                if !thing.check_move(delta, bound)
+                {
+                    return thing.id().into();
+                }
+
+                deltas.push((thing.id(), delta));
+                None
+            })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        edits_history.polygon_edit_cluster(
+            self.selected_brushes().map(|brush| (brush.id(), brush.polygon()))
+        );
+
+        for payload in payloads
+        {
+            self.brush_mut(payload.id())
+                .set_rotation_coordinates(drawing_resources, payload);
+        }
+
+        let angle_delta = if clockwise { -90f32 } else { 90f32 };
+
+        for (id, delta) in deltas
+        {
+            let mut thing = self.thing_mut(id);
+
+            if delta != Vec2::ZERO
+            {
+                thing.move_by_delta(delta);
+                edits_history.thing_move(id, delta);
+            }
+
+            let angle = thing.angle() + angle_delta;
+
+            if let Some(prev) = thing.set_angle(angle)
+            {
+                edits_history.thing_angle(id, prev);
+            }
+        }
+
+        self.schedule_overall_things_info_update();
+        true
+    }
+
+    /// Offsets the angle of each selected thing by a pseudo-random value between `-range` and
+    /// `range` degrees generated from `seed`. The things are processed in [`Id`] order so that the
+    /// same seed always produces the same angles.
+    #[inline]
+    pub fn randomize_selected_things_angles(
+        &mut self,
+        edits_history: &mut EditsHistory,
+        range: f32,
+        seed: u64
+    )
+    {
+        /// Advances `state` and returns a pseudo-random value between 0 and 1 (splitmix64).
+        #[allow(clippy::cast_precision_loss)]
+        #[inline]
+        #[must_use]
+        fn next_random(state: &mut u64) -> f32
+        {
+            *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut value = *state;
+            value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            value ^= value >> 31;
+            (value >> 40) as f32 / (1u64 << 24) as f32
+        }
+
+        let mut ids = hv_vec![collect; self.selected_things_ids().copied()];
+        ids.sort_by(|a, b| a.value().cmp(&b.value()));
+        let mut state = seed;
+
+        edits_history.thing_angle_cluster(ids.into_iter().filter_map(|id| {
+            let offset = (next_random(&mut state) * 2f32 - 1f32) * range;
+            let mut thing = self.thing_mut(id);
+            let angle = thing.angle() + offset;
+            thing.set_angle(angle).map(|prev| (id, prev))
+        }));
+
+        self.schedule_overall_things_info_update();
+    }
+
+    /// Rotates the textures of the selected brushes by 90 degrees, clockwise if `clockwise` is
+    /// true. Returns whether the rotation was valid.
+    #[inline]
+    pub fn rotate_selected_textures_quarter(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        clockwise: bool
+    ) -> bool
+    {
+        if self.selected_textured_amount() == 0
+        {
+            return false;
+        }
+
+        let angle = if clockwise { -90f32 } else { 90f32 };
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            manager.selected_brushes_with_sprite_mut().find_map(|mut brush| {
+                let prev_angle = brush.texture_settings().unwrap().angle();
+
+                (!brush.check_texture_angle(drawing_resources, prev_angle - angle, bound))
+                    .then_some(brush.id())
+            })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        for mut brush in self.selected_textured_brushes_mut()
+        {
+            let prev_angle = brush.texture_settings().unwrap().angle();
+            _ = brush.set_texture_angle(drawing_resources, prev_angle - angle);
+        }
+
+        edits_history.texture_angle_delta(self.selected_textured_ids().copied(), angle);
+        true
+    }
+
+    /// Restores the default angle, scale, and offset of the textures of the selected brushes,
+    /// keeping the textures assigned. Returns whether the reset was valid.
+    #[inline]
+    pub fn reset_selected_textures_settings(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory
+    ) -> bool
+    {
+        if self.selected_textured_amount() == 0
+        {
+            return false;
+        }
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            manager.selected_textured_brushes_mut().find_map(|mut brush| {
+                (!(brush.check_texture_angle(drawing_resources, 0f32, bound) &&
+                    brush.check_texture_scale_x(drawing_resources, 1f32, bound) &&
+                    brush.check_texture_scale_y(drawing_resources, 1f32, bound) &&
+                    brush.check_texture_offset_x(drawing_resources, 0f32, bound) &&
+                    brush.check_texture_offset_y(drawing_resources, 0f32, bound)))
+                .then_some(brush.id())
+            })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        let mut angle_edits = hv_vec![];
+        let mut scale_x_edits = hv_vec![];
+        let mut scale_y_edits = hv_vec![];
+        let mut offset_x_edits = hv_vec![];
+        let mut offset_y_edits = hv_vec![];
+
+        for mut brush in self.selected_textured_brushes_mut()
+        {
+            let id = brush.id();
+
+            angle_edits.extend(
+                brush.set_texture_angle(drawing_resources, 0f32).map(|prev| (id, prev))
+            );
+            scale_x_edits.extend(
+                brush.set_texture_scale_x(drawing_resources, 1f32).map(|prev| (id, prev))
+            );
+            scale_y_edits.extend(
+                brush.set_texture_scale_y(drawing_resources, 1f32).map(|prev| (id, prev))
+            );
+            offset_x_edits.extend(
+                brush.set_texture_offset_x(drawing_resources, 0f32).map(|prev| (id, prev))
+            );
+            offset_y_edits.extend(
+                brush.set_texture_offset_y(drawing_resources, 0f32).map(|prev| (id, prev))
+            );
+        }
+
+        edits_history.texture_angle_cluster(angle_edits.into_iter());
+        edits_history.texture_scale_x_cluster(scale_x_edits.into_iter());
+        edits_history.texture_scale_y_cluster(scale_y_edits.into_iter());
+        edits_history.texture_offset_x_cluster(offset_x_edits.into_iter());
+        edits_history.texture_offset_y_cluster(offset_y_edits.into_iter());
+        self.innards.overall_texture_update = true;
+        self.schedule_outline_update();
+        true
+    }
+
+    /// Sets the offsets of the textures of the selected brushes so that the boundaries of their
+    /// tiles lie on the lines of `grid`. The offsets are moved to the closest aligned values, and
+    /// then wrapped within the size of the tiles. Rotated textures, sprites, and rotated grids are
+    /// not supported. Returns whether any offset changed.
+    #[allow(clippy::float_cmp)]
+    #[inline]
+    pub fn align_selected_textures_to_grid(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: Grid
+    ) -> bool
+    {
+        /// Returns `offset` moved to the closest value placing the tile boundaries on the grid
+        /// lines, wrapped within `tile`.
+        #[allow(clippy::float_cmp)]
+        #[inline]
+        #[must_use]
+        fn aligned(offset: f32, grid_size: f32, phase: f32, tile: f32) -> f32
+        {
+            let offset = phase + ((offset - phase) / grid_size).round() * grid_size;
+
+            if tile == 0f32
+            {
+                return offset;
+            }
+
+            offset.rem_euclid(tile)
+        }
+
+        if grid.angle() != 0f32
+        {
+            return false;
+        }
+
+        let grid_size = grid.size_f32();
+        let phase = if grid.shifted { grid_size / 2f32 } else { 0f32 };
+
+        let offsets = hv_vec![collect; self.selected_textured_brushes().filter_map(|brush| {
+            let settings = brush.texture_settings().unwrap();
+
+            if settings.angle() != 0f32 || settings.sprite()
+            {
+                return None;
+            }
+
+            let texture = drawing_resources.texture_or_error(settings.name());
+            let size = settings
+                .trim()
+                .and_then(|name| texture.trim(name))
+                .map_or(texture.size(), |trim| trim.size())
+                .as_vec2();
+
+            (
+                brush.id(),
+                aligned(settings.offset_x(), grid_size, phase, (size.x * settings.scale_x()).abs()),
+                aligned(settings.offset_y(), grid_size, phase, (size.y * settings.scale_y()).abs())
+            )
+                .into()
+        })];
+
+        if offsets.is_empty()
+        {
+            return false;
+        }
+
+        let bound = self.map_bound;
+        let valid = self.test_operation_validity(|manager| {
+            offsets.iter().find_map(|(id, x, y)| {
+                let mut brush = manager.brush_mut(*id);
+                (!(brush.check_texture_offset_x(drawing_resources, *x, bound) &&
+                    brush.check_texture_offset_y(drawing_resources, *y, bound)))
+                .then_some(*id)
+            })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        let mut offset_x_edits = hv_vec![];
+        let mut offset_y_edits = hv_vec![];
+
+        for (id, x, y) in offsets
+        {
+            let mut brush = self.brush_mut(id);
+            offset_x_edits
+                .extend(brush.set_texture_offset_x(drawing_resources, x).map(|prev| (id, prev)));
+            offset_y_edits
+                .extend(brush.set_texture_offset_y(drawing_resources, y).map(|prev| (id, prev)));
+        }
+
+        if offset_x_edits.is_empty() && offset_y_edits.is_empty()
+        {
+            return false;
+        }
+
+        edits_history.texture_offset_x_cluster(offset_x_edits.into_iter());
+        edits_history.texture_offset_y_cluster(offset_y_edits.into_iter());
+        self.innards.overall_texture_update = true;
+        self.schedule_outline_update();
+        true
+    }
+
+    /// Splits each selected brush into `pieces` strips of equal size, separated by vertical lines
+    /// if `vertical` is true, horizontal ones otherwise. The strips inherit the texture and
+    /// properties of the split brush. Returns whether any brush was split.
+    #[inline]
+    pub fn split_selected_brushes(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        pieces: u8,
+        vertical: bool
+    ) -> bool
+    {
+        let splits = hv_vec![collect; self.selected_brushes().filter_map(|brush| {
+            brush
+                .split(drawing_resources, pieces, vertical)
+                .map(|polygons| (brush.id(), polygons, brush.properties()))
+        })];
+
+        if splits.is_empty()
+        {
+            return false;
+        }
+
+        for (id, polygons, properties) in splits
+        {
+            self.spawn_brushes(polygons.into_iter(), edits_history, properties);
+            self.despawn_selected_brush(id, edits_history);
+        }
+
+        true
+    }
+
+    /// Moves the selected [`ThingInstance`]s and the textures of the selected brushes to the top
+    /// of the draw heights if `front` is true, otherwise to the bottom, retaining their relative
+    /// order.
+    #[inline]
+    pub fn set_selected_draw_order(&mut self, edits_history: &mut EditsHistory, front: bool)
+    {
+        // Stable sort, the ties retain their original order and remain ties.
+        let mut heights = self
+            .selected_things()
+            .map(ThingInstance::draw_height)
+            .chain(
+                self.selected_textured_brushes()
+                    .map(|brush| brush.texture_settings().unwrap().height())
+            )
+            .collect::<Vec<_>>();
+        heights.sort();
+        heights.dedup();
+
+        let len = heights.len();
+        let new_height = |height: i8| {
+            let index = heights.binary_search(&height).unwrap();
+
+            if front
+            {
+                *TEXTURE_HEIGHT_RANGE.end() - i8::try_from(len - 1 - index).unwrap()
+            }
+            else
+            {
+                *TEXTURE_HEIGHT_RANGE.start() + i8::try_from(index).unwrap()
+            }
+        };
+
+        edits_history.thing_draw_height_cluster(self.selected_things_mut().filter_map(
+            |mut thing| {
+                let height = new_height(thing.draw_height());
+                thing.set_draw_height(height).map(|prev| (thing.id(), prev))
+            }
+        ));
+
+        edits_history.texture_height_cluster(self.selected_textured_brushes_mut().filter_map(
+            |mut brush| {
+                let height = new_height(brush.texture_settings().unwrap().height());
+                brush.set_texture_height(height).map(|prev| (brush.id(), prev))
+            }
+        ));
+
+        self.schedule_overall_things_info_update();
+        self.innards.overall_texture_update = true;
+    }
+
     /// Spawns a new [`ThingInstance`] with id [`identifier`].
     #[inline]
     pub fn spawn_thing_from_parts(&mut self, identifier: Id, data: ThingInstanceData)
@@ -3029,6 +4958,29 @@ impl EntitiesManager
         );
     }
 
+    /// Spawns a selected [`ThingInstance`] of the [`Thing`] with [`ThingId`] `thing` centered at
+    /// `pos`. Returns its [`Id`].
+    #[inline]
+    pub fn spawn_thing(
+        &mut self,
+        things_catalog: &ThingsCatalog,
+        edits_history: &mut EditsHistory,
+        thing: ThingId,
+        pos: Vec2,
+        default_properties: &DefaultProperties
+    ) -> Id
+    {
+        let id = self.innards.new_id();
+
+        self.innards.spawn_thing(
+            things_catalog.thing_instance(id, thing, pos, default_properties),
+            &mut self.quad_trees,
+            edits_history
+        );
+
+        id
+    }
+
     /// Spawns a selected [`ThingInstance`] from the selected [`Thing`]. Returns its [`Id`].
     #[inline]
     pub fn spawn_selected_thing(
@@ -3117,13 +5069,14 @@ impl EntitiesManager
     {
         self.auxiliary.replace_values(self.innards.things.keys());
         let error = things_catalog.error();
+        let bound = self.map_bound;
 
         for id in &self.auxiliary
         {
             let mut instance = self.innards.thing_mut(&mut self.quad_trees, *id);
             let thing = continue_if_none!(things_catalog.thing(instance.thing()));
 
-            if !instance.check_thing_change(thing)
+            if !instance.check_thing_change(thing, bound)
             {
                 _ = instance.set_thing(error);
             }
@@ -3242,6 +5195,24 @@ impl EntitiesManager
         Animators::new(drawing_resources, self.innards.textured.iter().map(|id| self.brush(*id)))
     }
 
+    /// Returns the [`Id`] of the entity highlighted because of the latest error, if any.
+    #[inline]
+    #[must_use]
+    pub const fn error_highlight(&self) -> Option<Id> { self.innards.error_highlight.error }
+
+    /// Returns the [`Hull`] of the entity highlighted because of the latest error, if any.
+    #[inline]
+    #[must_use]
+    pub fn error_highlight_hull(&self) -> Option<Hull>
+    {
+        self.error_highlight().map(|id| self.entity(id).hull())
+    }
+
+    /// Toggles whether the entity highlighted because of the latest error, if any, should stay
+    /// highlighted until the toggle is called again.
+    #[inline]
+    pub fn toggle_error_highlight_pin(&mut self) { self.innards.error_highlight.toggle_pin(); }
+
     /// Draws the UI error highlight.
     #[inline]
     pub fn draw_error_highlight(&mut self, bundle: &mut DrawBundle)
@@ -3261,6 +5232,34 @@ impl EntitiesManager
             .draw_wih_solid_color(&mut bundle.drawer, Color::ErrorHighlight);
     }
 
+    /// Draws the outline of the visible non selected brushes with the same texture as one of the
+    /// selected brushes.
+    #[inline]
+    pub fn draw_same_texture_highlight(&self, bundle: &mut DrawBundle)
+    {
+        let names = hv_hash_set![collect; self
+            .selected_textured_brushes()
+            .filter_map(|brush| brush.texture_settings().map(TextureInterface::name))];
+
+        if names.is_empty()
+        {
+            return;
+        }
+
+        for brush in self.visible_brushes(bundle.window, bundle.camera).iter()
+        {
+            if self.is_selected(brush.id()) ||
+                !brush
+                    .texture_settings()
+                    .is_some_and(|texture| names.contains(&texture.name()))
+            {
+                continue;
+            }
+
+            bundle.drawer.sides(brush.vertexes(), Color::SameTexture);
+        }
+    }
+
     #[cfg(feature = "debug")]
     /// Draws the quad tree debug lines.
     #[inline]
@@ -3318,7 +5317,9 @@ pub(in crate::map) struct BrushMut<'a>
     /// struct was created.
     sprite_hull:       Option<Hull>,
     /// The amount of selected vertexes of the brush at the moment the struct was created.
-    selected_vertexes: bool
+    selected_vertexes: bool,
+    /// The amount of vertexes of the brush at the moment the struct was created.
+    vertexes:          usize
 }
 
 impl<'a> Deref for BrushMut<'a>
@@ -3375,6 +5376,8 @@ impl<'a> Drop for BrushMut<'a>
         }
 
         let new_hull = brush.hull();
+        self.manager.vertexes_amount -= self.vertexes;
+        self.manager.vertexes_amount += brush.vertexes().len();
 
         if !self.hull.around_equal_narrow(&new_hull)
         {
@@ -3435,6 +5438,7 @@ impl<'a> BrushMut<'a>
         let path_hull = brush.path_hull();
         let sprite_hull = brush.sprite_and_anchor_hull();
         let selected_vertexes = brush.has_selected_vertexes();
+        let vertexes = brush.vertexes().len();
 
         Self {
             manager,
@@ -3444,7 +5448,8 @@ impl<'a> BrushMut<'a>
             center,
             path_hull,
             sprite_hull,
-            selected_vertexes
+            selected_vertexes,
+            vertexes
         }
     }
 }
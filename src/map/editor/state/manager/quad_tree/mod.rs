@@ -356,7 +356,7 @@ impl QuadTreeIds
 
     /// Retains only the elements specified by the predicate.
     #[inline]
-    fn retain<F>(&mut self, f: F)
+    pub fn retain<F>(&mut self, f: F)
     where
         F: FnMut(&Id, &mut Hull) -> bool
     {
@@ -0,0 +1,167 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{fs::File, io::BufReader, path::Path};
+
+use bevy::prelude::Vec2;
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    map::{
+        containers::{hv_vec, HvVec},
+        drawer::color::Color,
+        editor::DrawBundle
+    },
+    utils::tooltips::to_egui_coordinates
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The offset of the labels from the position of their markers, in screen pixels.
+const LABEL_OFFSET: egui::Vec2 = egui::Vec2::new(8f32, -8f32);
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// An editor-only labeled pin placed on the map. It is not an entity and it is never exported.
+#[must_use]
+#[derive(Clone, Serialize, Deserialize)]
+pub(in crate::map::editor::state) struct Marker
+{
+    /// The position.
+    pub pos:   Vec2,
+    /// The text shown beside the marker.
+    pub label: String
+}
+
+//=======================================================================//
+
+/// The markers of the map, stored in a file beside the map file.
+#[must_use]
+#[derive(Default)]
+pub(in crate::map::editor::state) struct Markers
+{
+    /// The markers.
+    markers: HvVec<Marker>,
+    /// Whether the markers were changed since the last save.
+    changed: bool
+}
+
+impl Markers
+{
+    /// Whether there are no markers.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.markers.is_empty() }
+
+    /// Whether the markers were added, edited, or removed since the last save.
+    #[inline]
+    #[must_use]
+    pub const fn changed(&self) -> bool { self.changed }
+
+    /// Marks the markers as changed.
+    #[inline]
+    pub fn set_changed(&mut self) { self.changed = true; }
+
+    /// Marks the markers as saved.
+    #[inline]
+    pub fn reset_changed(&mut self) { self.changed = false; }
+
+    /// Returns an iterator to the markers.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Marker> { self.markers.iter() }
+
+    /// Returns an iterator to mutable references to the markers. The markers must be marked as
+    /// changed through [`Markers::set_changed`] if they are edited.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut Marker>
+    {
+        self.markers.iter_mut()
+    }
+
+    /// Places a new marker at `pos`.
+    #[inline]
+    pub fn push(&mut self, pos: Vec2)
+    {
+        let label = format!("Marker {}", self.markers.len() + 1);
+        self.markers.push(Marker { pos, label });
+        self.changed = true;
+    }
+
+    /// Removes the marker at `index`.
+    #[inline]
+    pub fn remove(&mut self, index: usize)
+    {
+        _ = self.markers.remove(index);
+        self.changed = true;
+    }
+
+    /// Loads the markers stored in the file at `path`, if it exists.
+    #[inline]
+    pub fn import(&mut self, path: &Path) -> Result<(), &'static str>
+    {
+        self.changed = false;
+
+        if !path.exists()
+        {
+            return Ok(());
+        }
+
+        let file = File::open(path).map_err(|_| "Could not open the markers file")?;
+
+        match ciborium::from_reader::<Vec<Marker>, _>(BufReader::new(file))
+        {
+            Ok(markers) => self.markers = hv_vec![collect; markers],
+            Err(_) => return Err("Error reading the markers file")
+        };
+
+        Ok(())
+    }
+
+    /// Returns the serialized markers, or [`None`] if there are none.
+    #[inline]
+    pub fn export(&self) -> Result<Option<Vec<u8>>, &'static str>
+    {
+        if self.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let mut data = Vec::new();
+
+        match ciborium::ser::into_writer(&*self.markers, &mut data)
+        {
+            Ok(()) => Ok(Some(data)),
+            Err(_) => Err("Error saving markers")
+        }
+    }
+
+    /// Draws the markers along with their labels.
+    #[inline]
+    pub fn draw(&self, bundle: &mut DrawBundle)
+    {
+        let color = bundle.drawer.color_resources().egui_color(Color::Marker);
+
+        for (i, marker) in self.markers.iter().enumerate()
+        {
+            bundle.drawer.square_highlight(marker.pos, Color::Marker);
+
+            egui::Area::new(egui::Id::new(("marker", i)))
+                .fixed_pos(
+                    to_egui_coordinates(marker.pos, bundle.window, bundle.camera) + LABEL_OFFSET
+                )
+                .order(egui::Order::Background)
+                .show(bundle.egui_context, |ui| {
+                    ui.label(egui::RichText::new(&marker.label).color(color));
+                });
+        }
+    }
+}
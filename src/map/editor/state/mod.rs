@@ -1,11 +1,15 @@
 pub(in crate::map) mod clipboard;
+mod batch;
+mod comparison;
 pub(in crate::map) mod core;
 pub(in crate::map) mod editor_state;
 mod edits_history;
 pub(in crate::map) mod grid;
 mod input_press;
 pub(in crate::map) mod manager;
+mod markers;
 pub(in crate::map) mod ui;
+mod view_history;
 
 //=======================================================================//
 // MACROS
@@ -0,0 +1,239 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    error_message,
+    map::{
+        editor::{
+            state::{edits_history::EditsHistory, manager::EntitiesManager},
+            AllDefaultProperties,
+            StateUpdateBundle
+        },
+        map_range,
+        MAP_BOUND_RANGE
+    },
+    utils::{hull::Hull, math::AroundEqual, misc::Toggle}
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to spawn the four brushes framing the map and to set the size of the map bounds.
+pub(in crate::map::editor::state::ui) struct BoundaryWindow
+{
+    /// The window data.
+    window:    Window,
+    /// The thickness of the brushes.
+    thickness: f32,
+    /// The name of the texture to assign to the brushes, empty for none.
+    texture:   String,
+    /// Whether the framed area is typed in rather than computed from the map extents.
+    custom:    bool,
+    /// The top, bottom, left, and right coordinates of the custom framed area.
+    area:      [f32; 4],
+    /// The size of half of the square the entities can occupy to be set.
+    bound:     f32,
+    /// The bound of the map when `bound` was last synchronized with it.
+    map_bound: f32
+}
+
+impl Default for BoundaryWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:    Window::default(),
+            thickness: 64f32,
+            texture:   String::new(),
+            custom:    false,
+            area:      [1024f32, -1024f32, -1024f32, 1024f32],
+            bound:     0f32,
+            map_bound: 0f32
+        }
+    }
+}
+
+impl Toggle for BoundaryWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for BoundaryWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(boundary: &mut BoundaryWindow) { boundary.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Boundary(id, close as fn(&mut Self)))
+    }
+}
+
+impl BoundaryWindow
+{
+    /// Shows the boundary brushes window. The brushes can only be spawned if `available` is true.
+    /// Returns whether any of its fields has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    ) -> bool
+    {
+        let StateUpdateBundle {
+            egui_context,
+            drawing_resources,
+            default_properties: AllDefaultProperties { map_brushes, .. },
+            ..
+        } = bundle;
+
+        // Resynchronize if the map was replaced or its bound changed through undo or redo.
+        if !self.map_bound.around_equal_narrow(&manager.map_bound())
+        {
+            self.map_bound = manager.map_bound();
+            self.bound = self.map_bound;
+        }
+
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let bound = self.map_bound;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Boundary brushes")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("boundary").num_columns(2).show(ui, |ui| {
+                        ui.label("Thickness");
+                        focused = ui
+                            .add(
+                                egui::DragValue::new(&mut self.thickness)
+                                    .clamp_range(1f32..=1024f32)
+                            )
+                            .has_focus();
+                        ui.end_row();
+
+                        ui.label("Texture");
+                        focused |= ui.text_edit_singleline(&mut self.texture).has_focus();
+                        ui.end_row();
+
+                        ui.label("Custom area");
+                        ui.checkbox(&mut self.custom, "");
+                        ui.end_row();
+
+                        if !self.custom
+                        {
+                            return;
+                        }
+
+                        for (label, value) in
+                            ["Top", "Bottom", "Left", "Right"].into_iter().zip(&mut self.area)
+                        {
+                            ui.label(label);
+                            focused |= ui
+                                .add(egui::DragValue::new(value).clamp_range(map_range(bound)))
+                                .has_focus();
+                            ui.end_row();
+                        }
+                    });
+
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Map bound");
+                        focused |= ui
+                            .add(egui::DragValue::new(&mut self.bound).clamp_range(MAP_BOUND_RANGE))
+                            .has_focus();
+
+                        if !ui.add_enabled(available, egui::Button::new("Set")).clicked()
+                        {
+                            return;
+                        }
+
+                        if let Err(err) = manager.set_map_bound(edits_history, self.bound)
+                        {
+                            error_message(err);
+                        }
+                    });
+
+                    ui.label(format!("Current map bound: {}", manager.map_bound()));
+                    ui.separator();
+
+                    if !ui.add_enabled(available, egui::Button::new("Create")).clicked()
+                    {
+                        return focused;
+                    }
+
+                    let hull = if self.custom
+                    {
+                        let [top, bottom, left, right] = self.area;
+
+                        if top <= bottom || right <= left
+                        {
+                            error_message("The custom area is not valid");
+                            return focused;
+                        }
+
+                        Hull::new(top, bottom, left, right)
+                    }
+                    else
+                    {
+                        match manager.entities_hull()
+                        {
+                            Some(hull) => hull,
+                            None =>
+                            {
+                                error_message("The map is empty");
+                                return focused;
+                            }
+                        }
+                    };
+
+                    let texture = (!self.texture.is_empty()).then_some(self.texture.as_str());
+
+                    if texture.is_some_and(|name| drawing_resources.texture(name).is_none())
+                    {
+                        error_message("The texture does not exist");
+                        return focused;
+                    }
+
+                    if let Err(err) = manager.spawn_boundary_brushes(
+                        drawing_resources,
+                        edits_history,
+                        &hull,
+                        self.thickness,
+                        texture,
+                        map_brushes
+                    )
+                    {
+                        error_message(err);
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,325 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        brush::mover::Mover,
+        drawer::{drawing_resources::DrawingResources, texture::TextureInterface},
+        editor::StateUpdateBundle,
+        exported_path::NodesCoordinates,
+        thing::catalog::ThingsCatalog
+    },
+    utils::{hull::Hull, misc::Toggle},
+    Exporter
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The summary of the data generated by the [`Exporter`] from the map being edited.
+#[must_use]
+struct ExportPreview
+{
+    /// The amount of brushes.
+    brushes:      usize,
+    /// The amount of brushes that are part of the level geometry.
+    polygons:     usize,
+    /// The amount of decals.
+    decals:       usize,
+    /// The amount of things.
+    things:       usize,
+    /// The amount of paths.
+    paths:        usize,
+    /// The area covered by the exported entities.
+    bounds:       Option<Hull>,
+    /// The content hash.
+    hash:         u64,
+    /// The size of the binary export, or the error that occurred while generating it.
+    binary_size:  Result<usize, String>,
+    /// The issues found in the exported data.
+    warnings:     Vec<String>,
+    /// The descriptions of the exported brushes.
+    brushes_tree: Vec<String>,
+    /// The descriptions of the exported things.
+    things_tree:  Vec<String>
+}
+
+impl ExportPreview
+{
+    /// Returns a new [`ExportPreview`] describing the data of `exporter`.
+    #[inline]
+    fn new(
+        exporter: &Exporter,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog
+    ) -> Self
+    {
+        let mut warnings = Vec::new();
+        let mut brushes_tree = Vec::with_capacity(exporter.0.len());
+        let mut things_tree = Vec::with_capacity(exporter.1.len());
+
+        let mut brushes = exporter.0.values().collect::<Vec<_>>();
+        brushes.sort_by_key(|brush| brush.id.value());
+
+        for brush in brushes
+        {
+            let id = brush.id.value();
+
+            let texture = match &brush.texture
+            {
+                Some(texture) =>
+                {
+                    if drawing_resources.texture(texture.name()).is_none()
+                    {
+                        warnings.push(format!(
+                            "Brush {id}: texture \"{}\" is not loaded",
+                            texture.name()
+                        ));
+                    }
+
+                    texture.name()
+                },
+                None => "none"
+            };
+
+            match &brush.mover
+            {
+                Mover::Anchored(owner) if !exporter.0.contains_key(owner) =>
+                {
+                    warnings.push(format!(
+                        "Brush {id}: anchored to missing brush {}",
+                        owner.value()
+                    ));
+                },
+                Mover::Anchors(anchors) =>
+                {
+                    for anchor in anchors.iter().filter(|anchor| !exporter.0.contains_key(*anchor))
+                    {
+                        warnings.push(format!(
+                            "Brush {id}: missing anchored brush {}",
+                            anchor.value()
+                        ));
+                    }
+                },
+                _ => ()
+            };
+
+            brushes_tree.push(format!(
+                "{id}: {} vertexes, texture {texture}, collision {}, {} properties",
+                brush.vertexes.len(),
+                brush.collision,
+                brush.properties.len()
+            ));
+        }
+
+        let mut things = exporter.1.values().collect::<Vec<_>>();
+        things.sort_by_key(|thing| thing.id.value());
+
+        for thing in things
+        {
+            let id = thing.id.value();
+            let thing_id = thing.thing_id.value();
+
+            if things_catalog.thing(thing.thing_id).is_none()
+            {
+                warnings.push(format!("Thing {id}: thing {thing_id} is not in the catalog"));
+            }
+
+            things_tree.push(format!(
+                "{id}: thing {thing_id}, position ({}, {}), {} properties",
+                thing.pos.x,
+                thing.pos.y,
+                thing.properties.len()
+            ));
+        }
+
+        let mut binary = Vec::new();
+        let binary_size = match exporter.write_binary(&mut binary)
+        {
+            Ok(()) => Ok(binary.len()),
+            Err(err) =>
+            {
+                warnings.push(format!("Binary export failed: {err}"));
+                Err(err.to_string())
+            }
+        };
+
+        Self {
+            brushes:      exporter.0.len(),
+            polygons:     exporter.polygons().count(),
+            decals:       exporter.decals().len(),
+            things:       exporter.1.len(),
+            paths:        exporter.paths(NodesCoordinates::default()).len(),
+            bounds:       exporter.bounds(),
+            hash:         exporter.2.value,
+            binary_size,
+            warnings,
+            brushes_tree,
+            things_tree
+        }
+    }
+
+    /// Shows the summary.
+    #[inline]
+    fn show(&self, ui: &mut egui::Ui)
+    {
+        egui::Grid::new("export_summary").num_columns(2).striped(true).show(ui, |ui| {
+            for (label, value) in [
+                ("Brushes", self.brushes),
+                ("Polygons", self.polygons),
+                ("Decals", self.decals),
+                ("Things", self.things),
+                ("Paths", self.paths)
+            ]
+            {
+                ui.label(label);
+                ui.label(value.to_string());
+                ui.end_row();
+            }
+
+            ui.label("Bounds");
+            ui.label(self.bounds.map_or_else(
+                || "none".to_owned(),
+                |hull| {
+                    format!(
+                        "top {} bottom {} left {} right {}",
+                        hull.top(),
+                        hull.bottom(),
+                        hull.left(),
+                        hull.right()
+                    )
+                }
+            ));
+            ui.end_row();
+
+            ui.label("Content hash");
+            ui.label(format!("{:016x}", self.hash));
+            ui.end_row();
+
+            ui.label("Binary size");
+            ui.label(match &self.binary_size
+            {
+                Ok(size) => format!("{size} bytes"),
+                Err(err) => err.clone()
+            });
+            ui.end_row();
+        });
+
+        ui.separator();
+
+        if self.warnings.is_empty()
+        {
+            ui.label("No warnings");
+        }
+        else
+        {
+            egui::CollapsingHeader::new(format!("Warnings ({})", self.warnings.len()))
+                .default_open(true)
+                .show(ui, |ui| {
+                    for warning in &self.warnings
+                    {
+                        ui.colored_label(egui::Color32::YELLOW, warning);
+                    }
+                });
+        }
+
+        for (label, tree) in [("Brushes", &self.brushes_tree), ("Things", &self.things_tree)]
+        {
+            egui::CollapsingHeader::new(label).show(ui, |ui| {
+                for line in tree
+                {
+                    ui.label(line);
+                }
+            });
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The window showing a preview of the exported map data.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct ExportPreviewWindow
+{
+    /// The window data.
+    window:  Window,
+    /// The summary of the last export preview, if any.
+    preview: Option<ExportPreview>
+}
+
+impl Toggle for ExportPreviewWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for ExportPreviewWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(export_preview: &mut ExportPreviewWindow) { export_preview.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::ExportPreview(id, close as fn(&mut Self)))
+    }
+}
+
+impl ExportPreviewWindow
+{
+    /// Generates the preview of the data of `exporter` and opens the window.
+    #[inline]
+    pub fn set(
+        &mut self,
+        exporter: &Exporter,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog
+    )
+    {
+        self.preview = ExportPreview::new(exporter, drawing_resources, things_catalog).into();
+
+        self.window.open();
+    }
+
+    /// Shows the export preview window. Returns whether the data should be generated again.
+    #[inline]
+    #[must_use]
+    pub fn show(&mut self, bundle: &mut StateUpdateBundle) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        self.window
+            .show(
+                bundle.egui_context,
+                egui::Window::new("Export preview")
+                    .vscroll(true)
+                    .collapsible(true)
+                    .resizable(true),
+                |ui| {
+                    let refresh = ui.button("Refresh").clicked();
+                    ui.separator();
+
+                    if let Some(preview) = &self.preview
+                    {
+                        preview.show(ui);
+                    }
+
+                    refresh
+                }
+            )
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,144 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::editor::{
+        state::{edits_history::EditsHistory, grid::Grid, manager::EntitiesManager},
+        StateUpdateBundle
+    },
+    utils::{
+        hull::EntityHull,
+        identifiers::Id,
+        misc::{Camera, Toggle}
+    }
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to locate an entity from its [`Id`].
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct GoToWindow
+{
+    /// The window data.
+    window:    Window,
+    /// The text of the [`Id`] to look for.
+    id:        String,
+    /// Whether the last search did not find any entity.
+    not_found: bool
+}
+
+impl Toggle for GoToWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for GoToWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(go_to: &mut GoToWindow) { go_to.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::GoTo(id, close as fn(&mut Self)))
+    }
+}
+
+impl GoToWindow
+{
+    /// Shows the go to entity window. If the entity with the typed [`Id`] exists the camera is
+    /// framed on it, and, if `available` is true, it becomes the only selected entity.
+    /// Returns whether the [`Id`] text field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: Grid,
+        available: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            window,
+            camera,
+            egui_context,
+            ..
+        } = bundle;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Go to entity")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut go = false;
+
+                    let focused = ui
+                        .horizontal(|ui| {
+                            ui.label("Id");
+                            let response = ui.text_edit_singleline(&mut self.id);
+                            go = response.lost_focus() &&
+                                ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            go |= ui.button("Go").clicked();
+                            response.has_focus()
+                        })
+                        .inner;
+
+                    if go
+                    {
+                        let id = self
+                            .id
+                            .trim()
+                            .parse::<usize>()
+                            .ok()
+                            .map(Id::new)
+                            .filter(|id| manager.entity_exists(*id));
+                        self.not_found = id.is_none();
+
+                        if let Some(id) = id
+                        {
+                            if available
+                            {
+                                manager.deselect_selected_entities(edits_history);
+                                _ = manager.insert_entity_selection(id);
+                                edits_history.entity_selection(id);
+                            }
+
+                            camera.scale_viewport_ui_constricted_to_hull(
+                                window,
+                                &manager.entity(id).hull(),
+                                grid.size_f32()
+                            );
+                        }
+                    }
+
+                    if self.not_found
+                    {
+                        ui.label("No entity with such id");
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
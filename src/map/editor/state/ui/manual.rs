@@ -258,7 +258,18 @@ impl Manual
                      Entity tool."
                 ),
                 ("Ctrl + Z", "Undo."),
-                ("Ctrl + Y", "Redo.")
+                ("Ctrl + Y", "Redo."),
+                (
+                    "Ctrl + Shift + 1-9",
+                    "Stores the selected entities in the selection memory slot associated with \
+                     the pressed digit. The slots are not saved in the map file."
+                ),
+                (
+                    "Ctrl + 1-9",
+                    "Exclusively selects the entities stored in the selection memory slot \
+                     associated with the pressed digit, when using the Entity tool. The stored \
+                     entities that no longer exist are reported."
+                )
             );
 
             manual_section!(
@@ -372,7 +383,7 @@ impl Manual
                     "If there is a non-selected entity beneath the cursor, it will be exclusively \
                      selected. If there is no entity, all entities will be deselected upon mouse \
                      button release.\nPressing Ctrl on a brush causes all anchored brushes to be \
-                     selected as well."
+                     selected as well, unless disabled in the tool settings."
                 ),
                 (
                     "Left mouse + Shift",
@@ -550,6 +561,18 @@ impl Manual
                     "Deletes all selected sides, unless there is at least one brush that would \
                      become a point or line if such sides were deleted."
                 ),
+                (
+                    "Enter",
+                    "If only one side is selected, rotates its brush around the center so that the \
+                     side becomes horizontal or vertical, whichever is closer, unless the rotation \
+                     moves the brush out of bounds."
+                ),
+                (
+                    "Ctrl + Enter",
+                    "If only one side is selected, replaces it with an arc bulging outward with \
+                     the radius and amount of segments specified in the tool panel, unless the \
+                     brush would become concave or go out of bounds."
+                ),
                 (
                     SideXtrusion,
                     "Side xtrusion subtool. Selecting it and then left clicking on the selected \
@@ -707,6 +730,11 @@ impl Manual
                     "Intersection Tool key",
                     "Generates the intersection brush of the selected brushes. If not all \
                      selected brushes overlap over a common area they will be erased from the map."
+                ),
+                (
+                    "Alt + Intersection Tool key",
+                    "Generates a new brush from the intersection of the two selected brushes, \
+                     keeping the originals."
                 )
             );
 
@@ -840,7 +868,9 @@ impl Manual
                 (
                     "Left mouse + cursor drag",
                     "Creates a drag selection that determines the area onto which the viewport \
-                     will be zoomed. Zoom is actuated once the Left mouse button is released."
+                     will be zoomed. Zoom is actuated once the Left mouse button is released.\nThe \
+                     drag selection is not snapped to the grid, so the viewport frames exactly the \
+                     drawn area."
                 )
             );
         }
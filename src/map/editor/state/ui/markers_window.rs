@@ -0,0 +1,169 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        editor::{
+            state::{grid::Grid, manager::EntitiesManager},
+            StateUpdateBundle
+        },
+        map_range
+    },
+    utils::{
+        hull::Hull,
+        misc::{Camera, Toggle}
+    }
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to place, edit, and list the editor-only markers.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct MarkersWindow
+{
+    /// The window data.
+    window: Window
+}
+
+impl Toggle for MarkersWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for MarkersWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(markers: &mut MarkersWindow) { markers.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Markers(id, close as fn(&mut Self)))
+    }
+}
+
+impl MarkersWindow
+{
+    /// Shows the markers window. Returns whether a text or value field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        grid: Grid
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            window,
+            camera,
+            egui_context,
+            ..
+        } = bundle;
+
+        let bound = manager.map_bound();
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Markers")
+                    .vscroll(true)
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    if ui.button("Add at view center").clicked()
+                    {
+                        manager.markers_mut().push(camera.pos());
+                    }
+
+                    ui.separator();
+
+                    let mut jump = None;
+                    let mut remove = None;
+                    let mut changed = false;
+
+                    egui::Grid::new("markers").num_columns(5).show(ui, |ui| {
+                        for (i, marker) in manager.markers_mut().iter_mut().enumerate()
+                        {
+                            for response in [
+                                ui.text_edit_singleline(&mut marker.label),
+                                ui.add(
+                                    egui::DragValue::new(&mut marker.pos.x)
+                                        .clamp_range(map_range(bound))
+                                        .prefix("X: ")
+                                ),
+                                ui.add(
+                                    egui::DragValue::new(&mut marker.pos.y)
+                                        .clamp_range(map_range(bound))
+                                        .prefix("Y: ")
+                                )
+                            ]
+                            {
+                                focused |= response.has_focus();
+                                changed |= response.changed();
+                            }
+
+                            if ui.button("Go to").clicked()
+                            {
+                                jump = marker.pos.into();
+                            }
+
+                            if ui.button("Delete").clicked()
+                            {
+                                remove = i.into();
+                            }
+
+                            ui.end_row();
+                        }
+                    });
+
+                    if changed
+                    {
+                        manager.markers_mut().set_changed();
+                    }
+
+                    if let Some(i) = remove
+                    {
+                        manager.markers_mut().remove(i);
+                    }
+
+                    if let Some(pos) = jump
+                    {
+                        let half_size = grid.size_f32();
+
+                        camera.scale_viewport_ui_constricted_to_hull(
+                            window,
+                            &Hull::new(
+                                pos.y + half_size,
+                                pos.y - half_size,
+                                pos.x - half_size,
+                                pos.x + half_size
+                            ),
+                            grid.size_f32()
+                        );
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,218 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+use bevy_egui::egui;
+use hill_vacuum_shared::return_if_none;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        containers::hv_vec,
+        editor::{state::manager::EntitiesManager, StateUpdateBundle}
+    },
+    utils::{
+        hull::{EntityHull, Hull},
+        misc::{Camera, Toggle}
+    }
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The size of the area where the overview is drawn.
+const MINI_MAP_SIZE: f32 = 220f32;
+/// The padding around the overview.
+const MINI_MAP_PADDING: f32 = 4f32;
+/// The color of the background of the overview.
+const BACKGROUND_COLOR: egui::Color32 = egui::Color32::from_gray(24);
+/// The color of the outlines of the brushes.
+const BRUSH_COLOR: egui::Color32 = egui::Color32::from_gray(150);
+/// The color of the things.
+const THING_COLOR: egui::Color32 = egui::Color32::from_rgb(90, 160, 230);
+/// The color of the rectangle representing the viewport.
+const VIEWPORT_COLOR: egui::Color32 = egui::Color32::YELLOW;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The transformation from map coordinates to mini-map coordinates.
+#[must_use]
+#[derive(Clone, Copy)]
+struct MiniMapTransform
+{
+    /// The center of the area of the map represented in the mini-map.
+    map_center:    Vec2,
+    /// The center of the mini-map on screen.
+    screen_center: egui::Pos2,
+    /// The ratio between the mini-map and map lengths.
+    scale:         f32
+}
+
+impl MiniMapTransform
+{
+    /// Returns a new [`MiniMapTransform`] that fits `hull` inside `rect`.
+    #[inline]
+    fn new(hull: &Hull, rect: egui::Rect) -> Self
+    {
+        let area = rect.shrink(MINI_MAP_PADDING);
+
+        Self {
+            map_center:    hull.center(),
+            screen_center: rect.center(),
+            scale:         (area.width() / hull.width().max(1f32))
+                .min(area.height() / hull.height().max(1f32))
+        }
+    }
+
+    /// Converts the map position `pos` to a mini-map position.
+    #[inline]
+    #[must_use]
+    fn to_screen(self, pos: Vec2) -> egui::Pos2
+    {
+        let pos = (pos - self.map_center) * self.scale;
+        egui::pos2(self.screen_center.x + pos.x, self.screen_center.y - pos.y)
+    }
+
+    /// Converts the mini-map position `pos` to a map position.
+    #[inline]
+    #[must_use]
+    fn to_map(self, pos: egui::Pos2) -> Vec2
+    {
+        self.map_center +
+            Vec2::new(pos.x - self.screen_center.x, self.screen_center.y - pos.y) / self.scale
+    }
+
+    /// Converts `hull` to a mini-map rectangle.
+    #[inline]
+    #[must_use]
+    fn rect(self, hull: &Hull) -> egui::Rect
+    {
+        egui::Rect::from_two_pos(
+            self.to_screen(hull.top_left()),
+            self.to_screen(hull.bottom_right())
+        )
+    }
+}
+
+//=======================================================================//
+
+/// The window showing a scaled down overview of the map, which can be used to move the camera.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct MiniMapWindow
+{
+    /// The window data.
+    window:            Window,
+    /// Whether the viewport rectangle is being dragged.
+    dragging_viewport: bool
+}
+
+impl Toggle for MiniMapWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for MiniMapWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(mini_map: &mut MiniMapWindow) { mini_map.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::MiniMap(id, close as fn(&mut Self)))
+    }
+}
+
+impl MiniMapWindow
+{
+    /// Shows the mini-map window. Clicking the overview centers the viewport on the clicked
+    /// position, dragging the viewport rectangle pans the camera.
+    #[inline]
+    pub fn show(&mut self, bundle: &mut StateUpdateBundle, manager: &EntitiesManager)
+    {
+        if !self.window.is_open()
+        {
+            return;
+        }
+
+        let StateUpdateBundle {
+            window,
+            camera,
+            egui_context,
+            ..
+        } = bundle;
+
+        let viewport = camera.viewport_ui_constricted(window);
+        let brushes = hv_vec![collect; manager.brushes().iter().map(EntityHull::hull)];
+        let things = hv_vec![collect; manager.things().map(EntityHull::hull)];
+        let hull = Hull::from_hulls_iter(brushes.iter().chain(&things).copied())
+            .map_or(viewport, |hull| hull.merged(&viewport));
+
+        _ = self.window.show(
+            egui_context,
+            egui::Window::new("Mini-map")
+                .collapsible(true)
+                .resizable(false),
+            |ui| {
+                let (response, painter) = ui.allocate_painter(
+                    egui::vec2(MINI_MAP_SIZE, MINI_MAP_SIZE),
+                    egui::Sense::click_and_drag()
+                );
+                let transform = MiniMapTransform::new(&hull, response.rect);
+                let viewport_rect = transform.rect(&viewport);
+
+                painter.rect_filled(response.rect, 0f32, BACKGROUND_COLOR);
+
+                for hull in &brushes
+                {
+                    painter.rect_stroke(
+                        transform.rect(hull),
+                        0f32,
+                        egui::Stroke::new(1f32, BRUSH_COLOR)
+                    );
+                }
+
+                for hull in &things
+                {
+                    painter.rect_filled(transform.rect(hull).expand(0.5f32), 0f32, THING_COLOR);
+                }
+
+                painter.rect_stroke(viewport_rect, 0f32, egui::Stroke::new(1f32, VIEWPORT_COLOR));
+
+                if response.drag_started()
+                {
+                    self.dragging_viewport = response
+                        .interact_pointer_pos()
+                        .is_some_and(|pos| viewport_rect.contains(pos));
+                }
+
+                if response.dragged() && self.dragging_viewport
+                {
+                    let delta = response.drag_delta();
+                    camera.translate(Vec2::new(delta.x, -delta.y) / transform.scale);
+                    return;
+                }
+
+                if !response.clicked() && !response.dragged()
+                {
+                    return;
+                }
+
+                let pos = return_if_none!(response.interact_pointer_pos());
+                let offset = camera.pos() - viewport.center();
+                camera.set_pos(transform.to_map(pos) + offset);
+            }
+        );
+    }
+}
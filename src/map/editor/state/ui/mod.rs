@@ -1,10 +1,24 @@
+mod boundary_window;
 pub(in crate::map::editor) mod checkbox;
+mod export_preview_window;
+mod go_to_window;
 mod manual;
+mod mini_map_window;
 mod minus_plus_buttons;
+mod markers_window;
+mod notes_window;
 pub(in crate::map::editor::state) mod overall_value_field;
 mod properties_window;
+mod randomize_angles_window;
+mod rectangle_window;
+mod render_image_window;
+mod select_similar_window;
 mod settings_window;
+mod split_window;
+mod stamp_window;
+mod tag_things_window;
 mod texture_editor;
+mod things_legend_window;
 mod tooltip;
 mod window;
 
@@ -14,37 +28,53 @@ mod window;
 //=======================================================================//
 
 use arrayvec::ArrayVec;
-use bevy::prelude::{AssetServer, Transform, Vec2};
+use bevy::prelude::{AssetServer, Transform, UVec2, Vec2};
 use bevy_egui::{egui, EguiUserTextures};
 use hill_vacuum_shared::{return_if_none, NextValue};
 
 use self::{
+    boundary_window::BoundaryWindow,
+    export_preview_window::ExportPreviewWindow,
+    go_to_window::GoToWindow,
     manual::Manual,
+    mini_map_window::MiniMapWindow,
+    markers_window::MarkersWindow,
+    notes_window::NotesWindow,
     properties_window::PropertiesWindow,
+    randomize_angles_window::RandomizeAnglesWindow,
+    rectangle_window::RectangleWindow,
+    render_image_window::RenderImageWindow,
+    select_similar_window::SelectSimilarWindow,
     settings_window::SettingsWindow,
+    split_window::SplitWindow,
+    stamp_window::StampWindow,
+    tag_things_window::TagThingsWindow,
     texture_editor::TextureEditor,
+    things_legend_window::ThingsLegendWindow,
     tooltip::Tooltip
 };
 use super::{
     clipboard::Clipboard,
     core::{
-        tool::{ChangeConditions, EnabledTool, SubTool, Tool, ToolInterface},
+        tool::{ChangeConditions, ConfigName, EnabledTool, SubTool, Tool, ToolInterface},
         Core
     },
     editor_state::{InputsPresses, ToolsSettings},
     edits_history::EditsHistory,
-    grid::Grid,
+    grid::{Grid, MeasurementGrid},
     manager::EntitiesManager
 };
 use crate::{
-    config::{controls::bind::Bind, Config},
+    config::{controls::bind::Bind, ComplexityLimits, Config},
     embedded_assets::embedded_asset_path,
     map::{
         drawer::drawing_resources::DrawingResources,
         editor::{cursor_pos::Cursor, Placeholder, StateUpdateBundle},
-        properties::DefaultProperties
+        properties::DefaultProperties,
+        thing::catalog::ThingsCatalog
     },
     utils::misc::{Camera, FromToStr, Toggle},
+    Exporter,
     HardcodedActions
 };
 
@@ -209,6 +239,8 @@ pub(in crate::map::editor::state) enum Command
     None,
     /// Change the active tool.
     ChangeTool(Tool),
+    /// Enable the custom tool at the contained index.
+    ChangeCustomTool(usize),
     /// Open new map.
     New,
     /// Save current map.
@@ -217,8 +249,20 @@ pub(in crate::map::editor::state) enum Command
     SaveAs,
     /// Open map.
     Open,
+    /// Load a map to compare to the one being edited.
+    Compare,
+    /// Stop comparing the map being edited to another one.
+    CloseComparison,
+    /// Execute the commands listed in a text file.
+    RunBatch,
     /// Export map.
     Export,
+    /// Show a preview of the exported map data.
+    ExportPreview,
+    /// Save the selected entities to a standalone map file.
+    ExportSelection,
+    /// Render the map preview to an image file of the contained size.
+    RenderToImage(UVec2),
     /// Export the map's animations to a .anms file.
     ExportAnimations,
     /// Import an .anms file.
@@ -229,6 +273,10 @@ pub(in crate::map::editor::state) enum Command
     ImportProps,
     /// Select all entities.
     SelectAll,
+    /// Deselect the selected brushes, leaving the selected things selected.
+    DeselectBrushes,
+    /// Deselect the selected things, leaving the selected brushes selected.
+    DeselectThings,
     /// Copy the selected entities.
     Copy,
     /// Paste the copied entities.
@@ -257,14 +305,77 @@ pub(in crate::map::editor::state) enum Command
     ToggleMapPreview,
     /// Toggles the collision of the selected brushes.
     ToggleCollision,
+    /// Toggles the wireframe rendering of the brushes.
+    ToggleWireframe,
+    /// Toggles the drawing of the things' hulls.
+    ToggleThingsHulls,
+    /// Toggles the drawing of the sprites of the brushes.
+    ToggleSprites,
+    /// Toggles the culling of the entities too small on screen.
+    ToggleDetailCulling,
+    /// Toggles the highlight of the brushes with the same texture as the selected ones.
+    ToggleTextureHighlight,
     /// Reload the textures.
     ReloadTextures,
+    /// Toggles the prompt to reload the textures whose files change.
+    ToggleTexturesWatch,
     /// Reload the things.
     ReloadThings,
     /// Zoom on the selected entities.
     QuickZoom,
+    /// Move the camera to the previous view.
+    PreviousView,
+    /// Move the camera to the following view.
+    NextView,
+    /// Toggle whether the entity that caused the latest error stays highlighted.
+    ToggleErrorHighlightPin,
+    /// Zoom on the entity that caused the latest error.
+    ZoomOnError,
     /// Snap the vertexes of the selected brushes.
     QuickSnap,
+    /// Snap the bounding boxes of the selected brushes to the grid.
+    SnapBoundingBox,
+    /// Scale the selected brushes so that their bounding boxes fit the grid.
+    FitBoundingBox,
+    /// Fix the vertexes winding of the selected brushes.
+    FixWinding,
+    /// Assign consecutive spawn orders to the selected things.
+    AssignSpawnOrder,
+    /// Select the entities close to the map bounds.
+    SelectNearMapBound,
+    /// Replace the selected entities with copies of the selected prop.
+    ReplaceWithProp,
+    /// Update the selected linked prop with the selected entities and push the changes to its
+    /// instances.
+    UpdateLinkedProp,
+    /// Replace the textures with identical content with a single one.
+    MergeDuplicateTextures,
+    /// Disanchor all the brushes anchored to the selected brushes.
+    DisanchorAll,
+    /// Attach the selected brushes to the one beneath the cursor or the first selected one.
+    AttachSelected,
+    /// Align the texture of a selected brush to the one of the other across their shared edge.
+    AlignTextureSeam,
+    /// Snap all the path nodes of the selected entities.
+    SnapPathsNodes,
+    /// Split the path of the selected entity in two at the selected node.
+    SplitPath,
+    /// Snap the vertexes, positions, and path nodes of the selected entities.
+    SnapSelection,
+    /// Rotate the selected entities 90 degrees clockwise.
+    RotateClockwise,
+    /// Rotate the selected entities 90 degrees counter-clockwise.
+    RotateCounterClockwise,
+    /// Rotate the textures of the selected brushes by 90 degrees.
+    RotateTextures,
+    /// Reset the angle, scale, and offset of the textures of the selected brushes.
+    ResetTextureSettings,
+    /// Offset the textures of the selected brushes so that their tiles line up with the grid.
+    AlignTexturesToGrid,
+    /// Draw the selected things and textures above all others.
+    BringToFront,
+    /// Draw the selected things and textures below all others.
+    SendToBack,
     /// Quits the application
     Quit,
     #[cfg(feature = "debug")]
@@ -287,7 +398,28 @@ impl Command
                 Self::Duplicate |
                 Self::Undo |
                 Self::Redo |
-                Self::QuickSnap
+                Self::QuickSnap |
+                Self::SnapBoundingBox |
+                Self::FitBoundingBox |
+                Self::FixWinding |
+                Self::ReplaceWithProp |
+                Self::UpdateLinkedProp |
+                Self::AssignSpawnOrder |
+                Self::MergeDuplicateTextures |
+                Self::DisanchorAll |
+                Self::AttachSelected |
+                Self::AlignTextureSeam |
+                Self::SnapPathsNodes |
+                Self::SplitPath |
+                Self::SnapSelection |
+                Self::RotateClockwise |
+                Self::RotateCounterClockwise |
+                Self::RotateTextures |
+                Self::ResetTextureSettings |
+                Self::AlignTexturesToGrid |
+                Self::BringToFront |
+                Self::SendToBack |
+                Self::RunBatch
         )
     }
 }
@@ -306,6 +438,34 @@ enum WindowCloser
     Settings(egui::LayerId, fn(&mut SettingsWindow)),
     /// Properties window.
     Properties(egui::LayerId, fn(&mut PropertiesWindow)),
+    /// Select similar window.
+    SelectSimilar(egui::LayerId, fn(&mut SelectSimilarWindow)),
+    /// Notes window.
+    Notes(egui::LayerId, fn(&mut NotesWindow)),
+    /// Markers window.
+    Markers(egui::LayerId, fn(&mut MarkersWindow)),
+    /// Boundary brushes window.
+    Boundary(egui::LayerId, fn(&mut BoundaryWindow)),
+    /// Go to entity window.
+    GoTo(egui::LayerId, fn(&mut GoToWindow)),
+    /// Tag things window.
+    TagThings(egui::LayerId, fn(&mut TagThingsWindow)),
+    /// Export preview window.
+    ExportPreview(egui::LayerId, fn(&mut ExportPreviewWindow)),
+    /// Things legend window.
+    ThingsLegend(egui::LayerId, fn(&mut ThingsLegendWindow)),
+    /// Mini-map window.
+    MiniMap(egui::LayerId, fn(&mut MiniMapWindow)),
+    /// Stamp along side window.
+    Stamp(egui::LayerId, fn(&mut StampWindow)),
+    /// Spawn rectangle window.
+    Rectangle(egui::LayerId, fn(&mut RectangleWindow)),
+    /// Split brushes window.
+    Split(egui::LayerId, fn(&mut SplitWindow)),
+    /// Randomize angles window.
+    RandomizeAngles(egui::LayerId, fn(&mut RandomizeAnglesWindow)),
+    /// Render to image window.
+    RenderImage(egui::LayerId, fn(&mut RenderImageWindow)),
     /// Manual window.
     Manual(egui::LayerId, fn(&mut Manual))
 }
@@ -320,6 +480,20 @@ impl WindowCloser
         let (Self::TextureEditor(id, _) |
         Self::Settings(id, _) |
         Self::Properties(id, _) |
+        Self::SelectSimilar(id, _) |
+        Self::Notes(id, _) |
+        Self::Markers(id, _) |
+        Self::Boundary(id, _) |
+        Self::GoTo(id, _) |
+        Self::TagThings(id, _) |
+        Self::ExportPreview(id, _) |
+        Self::ThingsLegend(id, _) |
+        Self::MiniMap(id, _) |
+        Self::Stamp(id, _) |
+        Self::Rectangle(id, _) |
+        Self::Split(id, _) |
+        Self::RandomizeAngles(id, _) |
+        Self::RenderImage(id, _) |
         Self::Manual(id, _)) = self;
         id
     }
@@ -341,11 +515,25 @@ impl WindowCloser
             ui.texture_editor.window_closer(),
             ui.settings_window.window_closer(),
             ui.properties_window.window_closer(),
+            ui.select_similar_window.window_closer(),
+            ui.notes_window.window_closer(),
+            ui.markers_window.window_closer(),
+            ui.boundary_window.window_closer(),
+            ui.go_to_window.window_closer(),
+            ui.tag_things_window.window_closer(),
+            ui.export_preview_window.window_closer(),
+            ui.things_legend_window.window_closer(),
+            ui.mini_map_window.window_closer(),
+            ui.stamp_window.window_closer(),
+            ui.rectangle_window.window_closer(),
+            ui.split_window.window_closer(),
+            ui.randomize_angles_window.window_closer(),
+            ui.render_image_window.window_closer(),
             ui.manual.window_closer()
         ]
         .into_iter()
         .flatten()
-        .collect::<ArrayVec<_, 5>>();
+        .collect::<ArrayVec<_, 18>>();
 
         if windows.is_empty()
         {
@@ -382,6 +570,20 @@ impl WindowCloser
             Self::Settings(_, closer) => closer(&mut ui.settings_window),
             Self::TextureEditor(_, closer) => closer(&mut ui.texture_editor),
             Self::Properties(_, closer) => closer(&mut ui.properties_window),
+            Self::SelectSimilar(_, closer) => closer(&mut ui.select_similar_window),
+            Self::Notes(_, closer) => closer(&mut ui.notes_window),
+            Self::Markers(_, closer) => closer(&mut ui.markers_window),
+            Self::Boundary(_, closer) => closer(&mut ui.boundary_window),
+            Self::GoTo(_, closer) => closer(&mut ui.go_to_window),
+            Self::TagThings(_, closer) => closer(&mut ui.tag_things_window),
+            Self::ExportPreview(_, closer) => closer(&mut ui.export_preview_window),
+            Self::ThingsLegend(_, closer) => closer(&mut ui.things_legend_window),
+            Self::MiniMap(_, closer) => closer(&mut ui.mini_map_window),
+            Self::Stamp(_, closer) => closer(&mut ui.stamp_window),
+            Self::Rectangle(_, closer) => closer(&mut ui.rectangle_window),
+            Self::Split(_, closer) => closer(&mut ui.split_window),
+            Self::RandomizeAngles(_, closer) => closer(&mut ui.randomize_angles_window),
+            Self::RenderImage(_, closer) => closer(&mut ui.render_image_window),
             Self::Manual(_, closer) => closer(&mut ui.manual)
         };
     }
@@ -434,7 +636,15 @@ impl ToolsButtons
         }
     }
 
-    /// Draws the tool's UI element.
+    /// Whether `tool` is hidden from the toolbar.
+    #[inline]
+    #[must_use]
+    fn hidden(bundle: &StateUpdateBundle, tool: impl ConfigName) -> bool
+    {
+        bundle.config.hidden_tools.contains(tool.config_name())
+    }
+
+    /// Draws the tool's UI element, unless it is hidden from the toolbar.
     #[inline]
     #[must_use]
     pub fn draw<T, E>(
@@ -446,9 +656,14 @@ impl ToolsButtons
         enabled: &E
     ) -> bool
     where
-        T: ToolInterface,
+        T: ToolInterface + ConfigName,
         E: EnabledTool<Item = T>
     {
+        if Self::hidden(bundle, tool)
+        {
+            return false;
+        }
+
         let response = ui.add_enabled(
             tool.change_conditions_met(change_conditions),
             egui::ImageButton::new(egui::Image::new((
@@ -494,19 +709,47 @@ pub(in crate::map::editor::state) struct Interaction
 pub(in crate::map::editor::state) struct Ui
 {
     /// The buttons to enable the tools.
-    tools_buttons:        ToolsButtons,
+    tools_buttons:           ToolsButtons,
     /// The id of the left panel
-    left_panel_layer_id:  egui::LayerId,
+    left_panel_layer_id:     egui::LayerId,
     /// The id of the right panel.
-    right_panel_layer_id: egui::LayerId,
+    right_panel_layer_id:    egui::LayerId,
     /// The settings window.
-    settings_window:      SettingsWindow,
+    settings_window:         SettingsWindow,
     /// The parameters window.
-    properties_window:    PropertiesWindow,
+    properties_window:       PropertiesWindow,
+    /// The select similar window.
+    select_similar_window:   SelectSimilarWindow,
+    /// The notes window.
+    notes_window:            NotesWindow,
+    /// The markers window.
+    markers_window:          MarkersWindow,
+    /// The boundary brushes window.
+    boundary_window:         BoundaryWindow,
+    /// The go to entity window.
+    go_to_window:            GoToWindow,
+    /// The tag things window.
+    tag_things_window:       TagThingsWindow,
+    /// The export preview window.
+    export_preview_window:   ExportPreviewWindow,
+    /// The things legend window.
+    things_legend_window:    ThingsLegendWindow,
+    /// The mini-map window.
+    mini_map_window:         MiniMapWindow,
+    /// The stamp along side window.
+    stamp_window:            StampWindow,
+    /// The spawn rectangle window.
+    rectangle_window:        RectangleWindow,
+    /// The split brushes window.
+    split_window:            SplitWindow,
+    /// The randomize angles window.
+    randomize_angles_window: RandomizeAnglesWindow,
+    /// The render to image window.
+    render_image_window:     RenderImageWindow,
     /// The texture editor.
-    texture_editor:       TextureEditor,
+    texture_editor:          TextureEditor,
     /// The manual.
-    manual:               Manual
+    manual:                  Manual
 }
 
 impl Placeholder for Ui
@@ -515,16 +758,30 @@ impl Placeholder for Ui
     unsafe fn placeholder() -> Self
     {
         Self {
-            tools_buttons:        ToolsButtons {
+            tools_buttons:           ToolsButtons {
                 icons:   [egui::TextureId::default(); Tool::SIZE + SubTool::SIZE],
                 tooltip: Tooltip::new()
             },
-            left_panel_layer_id:  egui::LayerId::background(),
-            right_panel_layer_id: egui::LayerId::background(),
-            settings_window:      SettingsWindow::default(),
-            properties_window:    PropertiesWindow::placeholder(),
-            texture_editor:       TextureEditor::default(),
-            manual:               Manual::default()
+            left_panel_layer_id:     egui::LayerId::background(),
+            right_panel_layer_id:    egui::LayerId::background(),
+            settings_window:         SettingsWindow::default(),
+            properties_window:       PropertiesWindow::placeholder(),
+            select_similar_window:   SelectSimilarWindow::default(),
+            notes_window:            NotesWindow::default(),
+            markers_window:          MarkersWindow::default(),
+            boundary_window:         BoundaryWindow::default(),
+            go_to_window:            GoToWindow::default(),
+            tag_things_window:       TagThingsWindow::default(),
+            export_preview_window:   ExportPreviewWindow::default(),
+            things_legend_window:    ThingsLegendWindow::default(),
+            mini_map_window:         MiniMapWindow::default(),
+            stamp_window:            StampWindow::default(),
+            rectangle_window:        RectangleWindow::default(),
+            split_window:            SplitWindow::default(),
+            randomize_angles_window: RandomizeAnglesWindow::default(),
+            render_image_window:     RenderImageWindow::default(),
+            texture_editor:          TextureEditor::default(),
+            manual:                  Manual::default()
         }
     }
 }
@@ -542,16 +799,30 @@ impl Ui
     ) -> Self
     {
         Self {
-            tools_buttons:        ToolsButtons::new(asset_server, user_textures),
-            left_panel_layer_id:  egui::LayerId::background(),
-            right_panel_layer_id: egui::LayerId::background(),
-            settings_window:      SettingsWindow::default(),
-            properties_window:    PropertiesWindow::new(
+            tools_buttons:           ToolsButtons::new(asset_server, user_textures),
+            left_panel_layer_id:     egui::LayerId::background(),
+            right_panel_layer_id:    egui::LayerId::background(),
+            settings_window:         SettingsWindow::default(),
+            properties_window:       PropertiesWindow::new(
                 brushes_default_properties,
                 things_default_properties
             ),
-            texture_editor:       TextureEditor::default(),
-            manual:               Manual::default()
+            select_similar_window:   SelectSimilarWindow::default(),
+            notes_window:            NotesWindow::default(),
+            markers_window:          MarkersWindow::default(),
+            boundary_window:         BoundaryWindow::default(),
+            go_to_window:            GoToWindow::default(),
+            tag_things_window:       TagThingsWindow::default(),
+            export_preview_window:   ExportPreviewWindow::default(),
+            things_legend_window:    ThingsLegendWindow::default(),
+            mini_map_window:         MiniMapWindow::default(),
+            stamp_window:            StampWindow::default(),
+            rectangle_window:        RectangleWindow::default(),
+            split_window:            SplitWindow::default(),
+            randomize_angles_window: RandomizeAnglesWindow::default(),
+            render_image_window:     RenderImageWindow::default(),
+            texture_editor:          TextureEditor::default(),
+            manual:                  Manual::default()
         }
     }
 
@@ -565,9 +836,11 @@ impl Ui
         inputs: &mut InputsPresses,
         edits_history: &mut EditsHistory,
         clipboard: &mut Clipboard,
-        grid: Grid,
+        grid: &mut Grid,
+        measurement_grid: &mut MeasurementGrid,
         settings: &mut ToolsSettings,
-        tool_change_conditions: &ChangeConditions
+        tool_change_conditions: &ChangeConditions,
+        comparing: bool
     ) -> Interaction
     {
         bundle.egui_context.memory(|mem| {
@@ -575,7 +848,7 @@ impl Ui
         });
 
         // Top bar.
-        let mut command = self.menu_bar(bundle, manager, core);
+        let mut command = self.menu_bar(bundle, manager, core, *grid, comparing);
 
         // Manual menu.
         self.manual.show(bundle, &self.tools_buttons);
@@ -591,10 +864,60 @@ impl Ui
                 .show(bundle, manager, edits_history, clipboard, inputs, settings)
         };
 
-        focused |= self.settings_window.show(bundle, inputs) |
+        focused |= self.settings_window.show(bundle, inputs, manager.map_bound()) |
             self.properties_window
                 .show(bundle, manager, edits_history, clipboard, inputs);
 
+        self.select_similar_window.show(bundle, manager, edits_history, core.entity_tool());
+        focused |= self.notes_window.show(bundle, manager, edits_history, *grid) |
+            self.markers_window.show(bundle, manager, *grid) |
+            self.boundary_window.show(bundle, manager, edits_history, core.entity_tool()) |
+            self.go_to_window.show(bundle, manager, edits_history, *grid, core.entity_tool());
+
+        let (tag_focused, tagged) =
+            self.tag_things_window.show(bundle, manager, edits_history, core.entity_tool());
+        focused |= tag_focused;
+
+        if let Some(k) = tagged
+        {
+            self.properties_window.update_overall_things_property(manager, &k);
+        }
+
+        if self.export_preview_window.show(bundle)
+        {
+            command = Command::ExportPreview;
+        }
+
+        if self.things_legend_window.show(bundle, manager) &&
+            !core.is_tool_enabled(Tool::Thing) &&
+            Tool::Thing.change_conditions_met(tool_change_conditions)
+        {
+            command = Command::ChangeTool(Tool::Thing);
+        }
+
+        self.mini_map_window.show(bundle, manager);
+        focused |= self.stamp_window.show(
+            bundle,
+            manager,
+            edits_history,
+            clipboard,
+            core.is_tool_enabled(Tool::Side)
+        );
+        focused |= self.rectangle_window.show(bundle, manager, edits_history, core.entity_tool());
+        focused |= self.split_window.show(bundle, manager, edits_history, core.entity_tool());
+        focused |=
+            self.randomize_angles_window
+                .show(bundle, manager, edits_history, core.entity_tool());
+
+        let (render_focused, render) =
+            self.render_image_window.show(bundle.egui_context, core.map_preview());
+        focused |= render_focused;
+
+        if let Some(size) = render
+        {
+            command = Command::RenderToImage(size);
+        }
+
         // Panels.
         let us_context = unsafe { std::ptr::from_mut(bundle.egui_context).as_mut().unwrap() };
 
@@ -612,7 +935,7 @@ impl Ui
                         bundle,
                         manager,
                         edits_history,
-                        grid,
+                        *grid,
                         &mut self.tools_buttons,
                         tool_change_conditions
                     );
@@ -633,13 +956,26 @@ impl Ui
                 }
 
                 // Cursor info.
-                Self::cursor_info(bundle.cursor, ui);
+                Self::cursor_info(
+                    bundle.cursor,
+                    Bind::ToggleCursorSnap.keycode_str(&bundle.config.binds),
+                    ui
+                );
 
                 // Grid info.
-                Self::grid_info(grid, ui);
+                focused |= Self::grid_info(grid, manager, ui);
+
+                // Measurement grid.
+                focused |= Self::measurement_grid_info(measurement_grid, ui);
 
                 // Camera info.
-                Self::camera_info(bundle.camera, ui);
+                focused |= Self::camera_info(bundle.camera, ui);
+
+                // Map complexity info.
+                Self::complexity_info(manager, &bundle.config.limits, ui);
+
+                // Selection measurements.
+                Self::selection_measurements_info(manager, ui);
 
                 // Extra tool info.
                 focused |= core.tool_ui(manager, inputs, edits_history, clipboard, ui, settings);
@@ -675,6 +1011,18 @@ impl Ui
         egui_context.move_to_top(self.right_panel_layer_id);
     }
 
+    /// Shows the preview of the data generated by `exporter`.
+    #[inline]
+    pub fn set_export_preview(
+        &mut self,
+        exporter: &Exporter,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog
+    )
+    {
+        self.export_preview_window.set(exporter, drawing_resources, things_catalog);
+    }
+
     /// Updates the overall texture.
     #[inline]
     pub fn update_overall_texture(
@@ -742,7 +1090,9 @@ impl Ui
         &mut self,
         bundle: &mut StateUpdateBundle,
         manager: &EntitiesManager,
-        core: &mut Core
+        core: &mut Core,
+        grid: Grid,
+        comparing: bool
     ) -> Command
     {
         let mut command = Command::None;
@@ -764,7 +1114,22 @@ impl Ui
                 let reload = !core.map_preview();
                 let export = exporter.is_some();
                 let quick_snap = manager.any_selected_brushes();
+                let fit_bounding_box = quick_snap && !grid.rotated();
                 let quick_zoom = manager.any_selected_entities();
+                let draw_order = quick_zoom;
+                let export_selection = quick_zoom;
+                let entity_tool = core.entity_tool();
+                let replace_with_prop = entity_tool && quick_zoom;
+                let disanchor_all = entity_tool && quick_snap;
+                let attach_selected = entity_tool && manager.selected_brushes_amount() > 1;
+                let align_texture_seam = entity_tool && manager.selected_brushes_amount() == 2;
+                let snap_paths_nodes = entity_tool && manager.selected_moving_amount() != 0;
+                let split_path = core.path_tool() && manager.selected_moving_amount() == 1;
+                let snap_selection = entity_tool && manager.any_selected_entities();
+                let rotate_quarter = entity_tool && quick_zoom;
+                let assign_spawn_order = entity_tool && manager.any_selected_things();
+                let reset_textures = entity_tool && manager.selected_textured_amount() != 0;
+                let error_highlight = manager.error_highlight().is_some();
 
                 /// Draws a menu button.
                 macro_rules! menu_button {
@@ -835,6 +1200,15 @@ impl Ui
                     ("Open", {
                         command = Command::Open;
                     }, HardcodedActions::Open.key_combo()),
+                    ("Compare", {
+                        command = Command::Compare;
+                    }),
+                    ("Close comparison", comparing, {
+                        command = Command::CloseComparison;
+                    }),
+                    ("Run commands file", {
+                        command = Command::RunBatch;
+                    }),
                     ("Save", {
                         command = Command::Save;
                     }, HardcodedActions::Save.key_combo()),
@@ -844,6 +1218,15 @@ impl Ui
                     ("Export", export, {
                         command = Command::Export;
                     }, HardcodedActions::Export.key_combo()),
+                    ("Export preview", {
+                        command = Command::ExportPreview;
+                    }),
+                    ("Export selection", export_selection, {
+                        command = Command::ExportSelection;
+                    }),
+                    ("Render to image", {
+                        self.render_image_window.toggle();
+                    }),
                     ("Import animations", {
                         command = Command::ImportAnimations;
                     }),
@@ -867,6 +1250,12 @@ impl Ui
                     ("Select all", select_all, {
                         command = Command::SelectAll;
                     }, HardcodedActions::SelectAll.key_combo()),
+                    ("Deselect brushes", entity_tool, {
+                        command = Command::DeselectBrushes;
+                    }, HardcodedActions::DeselectBrushes.key_combo()),
+                    ("Deselect things", entity_tool, {
+                        command = Command::DeselectThings;
+                    }, HardcodedActions::DeselectThings.key_combo()),
                     ("Copy", copy_paste, {
                         command = Command::Copy;
                     }, HardcodedActions::Copy.key_combo()),
@@ -888,12 +1277,105 @@ impl Ui
                     ("Quick snap", quick_snap, {
                         command = Command::QuickSnap;
                     }, format!("Alt+{}", Tool::Snap.keycode_str(binds))),
+                    ("Snap bounding box", quick_snap, {
+                        command = Command::SnapBoundingBox;
+                    }),
+                    ("Fit bounding box", fit_bounding_box, {
+                        command = Command::FitBoundingBox;
+                    }),
+                    ("Fix winding", quick_snap, {
+                        command = Command::FixWinding;
+                    }),
+                    ("Assign spawn order", assign_spawn_order, {
+                        command = Command::AssignSpawnOrder;
+                    }),
+                    ("Select near map bounds", entity_tool, {
+                        command = Command::SelectNearMapBound;
+                    }),
+                    ("Replace with prop", replace_with_prop, {
+                        command = Command::ReplaceWithProp;
+                    }),
+                    ("Update linked prop", replace_with_prop, {
+                        command = Command::UpdateLinkedProp;
+                    }),
+                    ("Merge duplicate textures", entity_tool, {
+                        command = Command::MergeDuplicateTextures;
+                    }),
+                    ("Detach all attachments", disanchor_all, {
+                        command = Command::DisanchorAll;
+                    }),
+                    ("Attach selected to owner", attach_selected, {
+                        command = Command::AttachSelected;
+                    }),
+                    ("Align texture across seam", align_texture_seam, {
+                        command = Command::AlignTextureSeam;
+                    }),
+                    ("Snap all path nodes", snap_paths_nodes, {
+                        command = Command::SnapPathsNodes;
+                    }),
+                    ("Split path at node", split_path, {
+                        command = Command::SplitPath;
+                    }),
+                    ("Snap selection to grid", snap_selection, {
+                        command = Command::SnapSelection;
+                    }),
+                    ("Rotate 90° clockwise", rotate_quarter, {
+                        command = Command::RotateClockwise;
+                    }, Bind::RotateClockwise.keycode_str(binds)),
+                    ("Rotate 90° counter-clockwise", rotate_quarter, {
+                        command = Command::RotateCounterClockwise;
+                    }, Bind::RotateCounterClockwise.keycode_str(binds)),
+                    ("Rotate textures 90°", rotate_quarter, {
+                        command = Command::RotateTextures;
+                    }),
+                    ("Reset texture settings", reset_textures, {
+                        command = Command::ResetTextureSettings;
+                    }),
+                    ("Align textures to grid", reset_textures, {
+                        command = Command::AlignTexturesToGrid;
+                    }),
+                    ("Bring to front", draw_order, {
+                        command = Command::BringToFront;
+                    }),
+                    ("Send to back", draw_order, {
+                        command = Command::SendToBack;
+                    }),
                     ("Texture editor", {
                         self.texture_editor.toggle();
                     }, binds.get(Bind::TextureEditor).map_or("", FromToStr::to_str)),
                     ("Properties", {
                         self.properties_window.toggle();
-                    }, binds.get(Bind::PropertiesEditor).map_or("", FromToStr::to_str))
+                    }, binds.get(Bind::PropertiesEditor).map_or("", FromToStr::to_str)),
+                    ("Select similar", {
+                        self.select_similar_window.toggle();
+                    }),
+                    ("Notes", {
+                        self.notes_window.toggle();
+                    }),
+                    ("Markers", {
+                        self.markers_window.toggle();
+                    }),
+                    ("Boundary brushes", {
+                        self.boundary_window.toggle();
+                    }),
+                    ("Spawn rectangle", {
+                        self.rectangle_window.toggle();
+                    }),
+                    ("Split brushes", {
+                        self.split_window.toggle();
+                    }),
+                    ("Randomize angles", {
+                        self.randomize_angles_window.toggle();
+                    }),
+                    ("Go to entity", {
+                        self.go_to_window.toggle();
+                    }),
+                    ("Tag things", {
+                        self.tag_things_window.toggle();
+                    }),
+                    ("Stamp along side", {
+                        self.stamp_window.toggle();
+                    })
                 );
 
                 submenu!(
@@ -908,14 +1390,73 @@ impl Ui
                     ("Quick zoom", quick_zoom, {
                         command = Command::QuickZoom;
                     }, format!("Alt+{}", Tool::Zoom.keycode_str(binds))),
+                    ("Previous view", {
+                        command = Command::PreviousView;
+                    }),
+                    ("Next view", {
+                        command = Command::NextView;
+                    }),
+                    ("Zoom on error", error_highlight, {
+                        command = Command::ZoomOnError;
+                    }),
+                    ("Toggle error highlight pin", error_highlight, {
+                        command = Command::ToggleErrorHighlightPin;
+                    }),
                     ("Fullscreen", {
                         window.mode.toggle();
                     }, HardcodedActions::Fullscreen.key_combo()),
                     ("Toggle map preview", {
                         command = Command::ToggleMapPreview;
+                    }),
+                    ("Toggle wireframe", {
+                        command = Command::ToggleWireframe;
+                    }),
+                    ("Toggle things hulls", {
+                        command = Command::ToggleThingsHulls;
+                    }),
+                    ("Toggle sprites", {
+                        command = Command::ToggleSprites;
+                    }, Bind::ToggleSprites.keycode_str(binds)),
+                    ("Things legend", {
+                        self.things_legend_window.toggle();
+                    }),
+                    ("Mini-map", {
+                        self.mini_map_window.toggle();
+                    }),
+                    ("Toggle detail culling", {
+                        command = Command::ToggleDetailCulling;
+                    }),
+                    ("Toggle same texture highlight", {
+                        command = Command::ToggleTextureHighlight;
                     })
                 );
 
+                let active_custom_tool = core.custom_tool();
+                let mut custom_tools = core.custom_tools(manager).peekable();
+
+                if custom_tools.peek().is_some()
+                {
+                    egui::menu::menu_button(ui, "Tools", |ui| {
+                        ui.set_min_width(200f32);
+                        let spacing = ui.spacing_mut();
+                        spacing.button_padding = [6f32; 2].into();
+                        spacing.item_spacing = [2f32; 2].into();
+                        ui.visuals_mut().menu_rounding = 0f32.into();
+
+                        for (index, name, enabled) in custom_tools
+                        {
+                            let button = egui::Button::new(name)
+                                .selected(active_custom_tool == Some(index));
+
+                            if ui.add_enabled(enabled, button).clicked()
+                            {
+                                command = Command::ChangeCustomTool(index);
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                }
+
                 submenu!(
                     ui,
                     "Options",
@@ -946,6 +1487,9 @@ impl Ui
                     ("Reload textures", reload, {
                         command = Command::ReloadTextures;
                     }),
+                    ("Toggle textures watch", {
+                        command = Command::ToggleTexturesWatch;
+                    }),
                     ("Reload things", reload, {
                         command = Command::ReloadThings;
                     }),
@@ -988,57 +1532,52 @@ impl Ui
         ui.add_space(ICONS_PADDING.y);
 
         let mut tool_to_enable = None;
-        let mut tool_image_buttons_row = |ui: &mut egui::Ui, range| {
-            ui.spacing_mut().item_spacing = ICONS_PADDING;
-            ui.add_space(row_padding);
+        let tools = Tool::iter()
+            .filter(|tool| !ToolsButtons::hidden(bundle, *tool))
+            .collect::<ArrayVec<_, { Tool::SIZE }>>();
 
-            for i in range
-            {
-                let tool = Into::<Tool>::into(i);
+        for row in tools.chunks(ICONS_PER_ROW)
+        {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing = ICONS_PADDING;
+                ui.add_space(row_padding);
 
-                if self
-                    .tools_buttons
-                    .draw(ui, bundle, tool, tool_change_conditions, core)
+                for tool in row
                 {
-                    tool_to_enable = tool.into();
+                    if self
+                        .tools_buttons
+                        .draw(ui, bundle, *tool, tool_change_conditions, core)
+                    {
+                        tool_to_enable = (*tool).into();
+                    }
                 }
-            }
-        };
-
-        for i in 0..Tool::SIZE / ICONS_PER_ROW
-        {
-            ui.horizontal(|ui| {
-                let i = i * 3;
-                tool_image_buttons_row(ui, i..i + 3);
             });
         }
 
-        ui.horizontal(|ui| {
-            tool_image_buttons_row(ui, (Tool::SIZE / ICONS_PER_ROW) * ICONS_PER_ROW..Tool::SIZE);
-        });
-
         tool_to_enable
     }
 
-    /// The info concerning the cursor.
+    /// The info concerning the cursor, including the key toggling the grid snap, `snap_key`.
     #[inline]
-    fn cursor_info(cursor: &Cursor, ui: &mut egui::Ui)
+    fn cursor_info(cursor: &Cursor, snap_key: &str, ui: &mut egui::Ui)
     {
         ui.separator();
 
         let pos = cursor.world_snapped();
 
         ui.label(egui::RichText::new(format!(
-            "CURSOR\nX: {:.2}\nY: {:.2}\nSnapped: {}",
+            "CURSOR\nX: {:.2}\nY: {:.2}\nSnapped: {} ({snap_key})",
             pos.x,
             pos.y,
             cursor.snap()
         )));
     }
 
-    /// The info concerning the grid.
+    /// The info concerning the grid, and the field to set its rotation.
+    /// Returns whether the rotation field has focus.
     #[inline]
-    fn grid_info(grid: Grid, ui: &mut egui::Ui)
+    #[must_use]
+    fn grid_info(grid: &mut Grid, manager: &mut EntitiesManager, ui: &mut egui::Ui) -> bool
     {
         ui.separator();
 
@@ -1047,11 +1586,55 @@ impl Ui
             grid.size(),
             grid.shifted
         )));
+
+        ui.horizontal(|ui| {
+            ui.label("Angle");
+
+            let mut angle = grid.angle();
+            let response = ui.add(
+                egui::DragValue::new(&mut angle)
+                    .speed(1)
+                    .max_decimals(2)
+                    .clamp_range(Grid::ANGLE_RANGE)
+                    .suffix("°")
+            );
+
+            if response.changed()
+            {
+                grid.set_angle(angle, manager);
+            }
+
+            response.has_focus()
+        })
+        .inner
     }
 
-    /// The info concerning the camera.
+    /// The toggle and spacing of the measurement grid.
+    /// Returns whether the spacing field has focus.
     #[inline]
-    fn camera_info(camera: &Transform, ui: &mut egui::Ui)
+    #[must_use]
+    fn measurement_grid_info(measurement_grid: &mut MeasurementGrid, ui: &mut egui::Ui) -> bool
+    {
+        ui.separator();
+
+        ui.checkbox(&mut measurement_grid.visible, "MEASUREMENT GRID");
+        ui.horizontal(|ui| {
+            ui.label("Spacing");
+            ui.add(
+                egui::DragValue::new(measurement_grid.spacing_mut())
+                    .speed(1)
+                    .clamp_range(MeasurementGrid::SPACING_RANGE)
+            )
+            .has_focus()
+        })
+        .inner
+    }
+
+    /// The info concerning the camera, and the field to set the zoom percentage.
+    /// Returns whether the zoom field has focus.
+    #[inline]
+    #[must_use]
+    fn camera_info(camera: &mut Transform, ui: &mut egui::Ui) -> bool
     {
         ui.separator();
 
@@ -1064,6 +1647,72 @@ impl Ui
             pos.y,
             camera.scale()
         )));
+
+        ui.horizontal(|ui| {
+            ui.label("Zoom %");
+
+            let mut percentage = camera.zoom_percentage();
+            let response = ui.add(
+                egui::DragValue::new(&mut percentage)
+                    .speed(1)
+                    .max_decimals(1)
+                    .clamp_range(20f32..=800f32)
+            );
+
+            if response.changed()
+            {
+                camera.set_zoom_percentage(percentage);
+            }
+
+            response.has_focus()
+        })
+        .inner
+    }
+
+    /// The info concerning the complexity of the map, highlighted if any limit is exceeded.
+    #[inline]
+    fn complexity_info(manager: &EntitiesManager, limits: &ComplexityLimits, ui: &mut egui::Ui)
+    {
+        ui.separator();
+
+        let brushes = manager.brushes_amount();
+        let vertexes = manager.vertexes_amount();
+        let things = manager.things_amount();
+        let text = egui::RichText::new(format!(
+            "MAP\nBrushes: {brushes}\nVertexes: {vertexes}\nThings: {things}"
+        ));
+
+        match limits.exceeded(brushes, vertexes, things)
+        {
+            Some(exceeded) =>
+            {
+                ui.label(text.color(egui::Color32::RED))
+                    .on_hover_text(format!("Limits exceeded: {exceeded}"));
+            },
+            None =>
+            {
+                ui.label(text);
+            }
+        };
+    }
+
+    /// The combined area and perimeter of the selected brushes, if any.
+    #[inline]
+    fn selection_measurements_info(manager: &EntitiesManager, ui: &mut egui::Ui)
+    {
+        if !manager.any_selected_brushes()
+        {
+            return;
+        }
+
+        ui.separator();
+
+        let (area, perimeter) = manager.selected_brushes_area_perimeter();
+
+        ui.label(egui::RichText::new(format!(
+            "SELECTION\nBrushes: {}\nArea: {area:.2}\nPerimeter: {perimeter:.2}",
+            manager.selected_brushes_amount()
+        )));
     }
 }
 
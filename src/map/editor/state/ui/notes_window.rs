@@ -0,0 +1,180 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use hill_vacuum_shared::return_if_none;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::editor::{
+        state::{edits_history::EditsHistory, grid::Grid, manager::EntitiesManager},
+        StateUpdateBundle
+    },
+    utils::{
+        hull::EntityHull,
+        identifiers::Id,
+        misc::{Camera, Toggle}
+    }
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to edit the editor-only notes of the entities and to list the annotated ones.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct NotesWindow
+{
+    /// The window data.
+    window: Window,
+    /// The [`Id`] of the entity whose note is being edited, if any.
+    target: Option<Id>,
+    /// The text of the note being edited.
+    note:   String
+}
+
+impl Toggle for NotesWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for NotesWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(notes: &mut NotesWindow) { notes.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Notes(id, close as fn(&mut Self)))
+    }
+}
+
+impl NotesWindow
+{
+    /// Shows the notes window. The note of the selected entity can be edited if there is exactly
+    /// one, and it is stored when the text field loses focus. Returns whether the note text field
+    /// has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: Grid
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            Self::store_note(self.target.take(), &self.note, manager, edits_history);
+            return false;
+        }
+
+        let selected = (manager.selected_brushes_amount() + manager.selected_things_amount() == 1)
+            .then(|| *manager.selected_entities_ids().next().unwrap());
+
+        if selected != self.target
+        {
+            Self::store_note(self.target, &self.note, manager, edits_history);
+            self.target = selected;
+            self.note = selected
+                .and_then(|id| manager.note(id))
+                .unwrap_or_default()
+                .to_string();
+        }
+
+        let StateUpdateBundle {
+            window,
+            camera,
+            egui_context,
+            ..
+        } = bundle;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Notes")
+                    .vscroll(true)
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    match self.target
+                    {
+                        Some(id) =>
+                        {
+                            ui.label("Selected entity note");
+                            let response = ui.text_edit_multiline(&mut self.note);
+                            focused = response.has_focus();
+
+                            if response.lost_focus()
+                            {
+                                Self::store_note(id.into(), &self.note, manager, edits_history);
+                            }
+                        },
+                        None =>
+                        {
+                            ui.label("Select a single entity to edit its note");
+                        }
+                    };
+
+                    ui.separator();
+
+                    let mut jump = None;
+
+                    for (id, note) in manager.notes()
+                    {
+                        ui.horizontal(|ui| {
+                            if ui.button("Go to").clicked()
+                            {
+                                jump = id.into();
+                            }
+
+                            ui.label(note.lines().next().unwrap_or_default());
+                        });
+                    }
+
+                    if let Some(id) = jump
+                    {
+                        camera.scale_viewport_ui_constricted_to_hull(
+                            window,
+                            &manager.entity(id).hull(),
+                            grid.size_f32()
+                        );
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+
+    /// Stores `note` as the note of the entity with [`Id`] `target`, if it exists and its note
+    /// changed.
+    #[inline]
+    fn store_note(
+        target: Option<Id>,
+        note: &str,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    )
+    {
+        let id = return_if_none!(target);
+
+        if !manager.entity_exists(id) || manager.note(id).unwrap_or_default() == note
+        {
+            return;
+        }
+
+        let prev = manager.set_note(id, note.to_owned());
+        edits_history.note(id, prev);
+    }
+}
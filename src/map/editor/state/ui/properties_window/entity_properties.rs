@@ -0,0 +1,247 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use hill_vacuum_shared::continue_if_none;
+
+use crate::{
+    map::{
+        containers::{hv_vec, HvVec},
+        editor::state::{edits_history::EditsHistory, manager::EntitiesManager},
+        properties::{Properties, Value}
+    },
+    utils::identifiers::{EntityId, Id}
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A property of the selected entity.
+struct EntityProperty
+{
+    /// The key.
+    key:   String,
+    /// The [`Value`].
+    value: Value,
+    /// The text being edited.
+    text:  String
+}
+
+//=======================================================================//
+
+/// The UI elements to edit the [`Properties`] of a single selected entity.
+#[must_use]
+#[derive(Default)]
+pub(in crate::map::editor::state::ui::properties_window) struct EntityProperties
+{
+    /// The [`Id`] of the entity and whether it is a brush, if exactly one entity is selected.
+    target:     Option<(Id, bool)>,
+    /// The properties of the entity, sorted by key.
+    properties: HvVec<EntityProperty>,
+    /// The key of the property whose text is being edited, if any.
+    focused:    Option<String>
+}
+
+impl EntityProperties
+{
+    /// Updates the target entity and its properties.
+    #[inline]
+    fn update(&mut self, manager: &EntitiesManager)
+    {
+        /// Returns the [`Id`] and [`Properties`] of the single selected entity, if any.
+        #[inline]
+        fn target(manager: &EntitiesManager) -> Option<(Id, bool, &Properties)>
+        {
+            match (manager.selected_brushes_amount(), manager.selected_things_amount())
+            {
+                (1, 0) =>
+                {
+                    let brush = manager.selected_brushes().next().unwrap();
+                    (brush.id(), true, brush.properties_as_ref()).into()
+                },
+                (0, 1) =>
+                {
+                    let thing = manager.selected_things().next().unwrap();
+                    (thing.id(), false, thing.properties()).into()
+                },
+                _ => None
+            }
+        }
+
+        let (id, brush, properties) = match target(manager)
+        {
+            Some(target) => target,
+            None =>
+            {
+                self.target = None;
+                self.properties.clear();
+                self.focused = None;
+                return;
+            }
+        };
+
+        if self.target != Some((id, brush))
+        {
+            self.target = (id, brush).into();
+            self.focused = None;
+        }
+
+        let mut new_properties = hv_vec![collect; properties.iter().map(|(k, v)| EntityProperty {
+            key:   k.clone(),
+            value: v.clone(),
+            text:  v.to_string()
+        })];
+        new_properties.sort_by(|a, b| a.key.cmp(&b.key));
+
+        if let Some(key) = &self.focused
+        {
+            if let Some((new, old)) = new_properties
+                .iter_mut()
+                .find(|p| p.key == *key)
+                .zip(self.properties.iter_mut().find(|p| p.key == *key))
+            {
+                std::mem::swap(&mut new.text, &mut old.text);
+            }
+        }
+
+        self.properties = new_properties;
+    }
+
+    /// Shows the properties of the single selected entity, if any. Returns whether a text field
+    /// has focus and the key of the property that was edited, if any.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory
+    ) -> (bool, Option<String>)
+    {
+        self.update(manager);
+
+        let (id, brush) = match self.target
+        {
+            Some(target) => target,
+            None => return (false, None)
+        };
+
+        let mut focused = false;
+        let mut edited = None;
+
+        egui::CollapsingHeader::new("Selected entity").show(ui, |ui| {
+            egui::Grid::new("entity_properties")
+                .num_columns(2)
+                .spacing([0f32, 4f32])
+                .striped(true)
+                .show(ui, |ui| {
+                    for property in &mut self.properties
+                    {
+                        ui.label(&property.key);
+
+                        let new_value = if let Value::Bool(value) = &property.value
+                        {
+                            let mut value = *value;
+                            ui.checkbox(&mut value, "").clicked().then_some(Value::Bool(value))
+                        }
+                        else
+                        {
+                            let response = ui.text_edit_singleline(&mut property.text);
+
+                            if response.has_focus()
+                            {
+                                focused = true;
+                                self.focused = property.key.clone().into();
+                            }
+
+                            if response.lost_focus()
+                            {
+                                let value = parse_text(&property.value, &property.text);
+
+                                // Restore the text of the current value if the input is invalid.
+                                if value.is_none()
+                                {
+                                    property.text = property.value.to_string();
+                                }
+
+                                value
+                            }
+                            else
+                            {
+                                None
+                            }
+                        };
+
+                        ui.end_row();
+
+                        let new_value = continue_if_none!(new_value);
+                        let prev = if brush
+                        {
+                            manager.brush_mut(id).set_property(&property.key, &new_value)
+                        }
+                        else
+                        {
+                            manager.thing_mut(id).set_property(&property.key, &new_value)
+                        };
+
+                        if let Some(prev) = prev
+                        {
+                            edits_history.property(&property.key, Some((id, prev)).into_iter());
+                            property.text = new_value.to_string();
+                            property.value = new_value;
+                            edited = property.key.clone().into();
+                        }
+                    }
+                });
+        });
+
+        if !focused
+        {
+            self.focused = None;
+        }
+
+        (focused, edited)
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the [`Value`] of the same type of `value` represented by `text`, if any.
+#[inline]
+#[must_use]
+fn parse_text(value: &Value, text: &str) -> Option<Value>
+{
+    value.parse(&Value::String(text.to_owned()))
+}
+
+//=======================================================================//
+// TESTS
+//
+//=======================================================================//
+
+#[cfg(test)]
+mod tests
+{
+    use super::parse_text;
+    use crate::map::properties::Value;
+
+    #[test]
+    fn typed_values()
+    {
+        assert!(matches!(parse_text(&Value::U32(1), "5"), Some(Value::U32(5))));
+        assert!(matches!(parse_text(&Value::Bool(false), "true"), Some(Value::Bool(true))));
+        assert!(matches!(parse_text(&Value::F32(0f32), "2.5"), Some(Value::F32(v)) if v == 2.5));
+        assert!(matches!(
+            parse_text(&Value::String(String::new()), "a"),
+            Some(Value::String(s)) if s == "a"
+        ));
+        assert!(parse_text(&Value::I8(0), "300").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+mod entity_properties;
 mod overall_properties;
 
 //=======================================================================//
@@ -8,7 +9,7 @@ mod overall_properties;
 use bevy_egui::egui;
 use hill_vacuum_shared::{continue_if_none, TEXTURE_HEIGHT_RANGE};
 
-use self::overall_properties::UiOverallProperties;
+use self::{entity_properties::EntityProperties, overall_properties::UiOverallProperties};
 use super::{window::Window, WindowCloser, WindowCloserInfo};
 use crate::{
     config::controls::bind::Bind,
@@ -79,7 +80,9 @@ struct Innards
     /// The filler rows of the brushes grid.
     brushes_filler:             usize,
     /// The filler rows of the [`ThingInstance`]s grid.
-    things_filler:              usize
+    things_filler:              usize,
+    /// The properties of the single selected entity.
+    entity_properties:          EntityProperties
 }
 
 impl Innards
@@ -136,7 +139,7 @@ impl Innards
             };
         });
 
-        egui::Grid::new("properties")
+        let focused = egui::Grid::new("properties")
             .num_columns(2)
             .spacing([0f32, 4f32])
             .striped(true)
@@ -154,7 +157,25 @@ impl Innards
                     inputs
                 )
             })
-            .inner
+            .inner;
+
+        let (entity_focused, edited) = self.entity_properties.show(ui, manager, edits_history);
+
+        if let Some(k) = edited
+        {
+            if manager.any_selected_brushes()
+            {
+                self.overall_brushes_properties
+                    .overwrite(&k, manager.selected_brushes().map(Brush::properties_as_ref));
+            }
+            else
+            {
+                self.overall_things_properties
+                    .overwrite(&k, manager.selected_things().map(ThingInstance::properties));
+            }
+        }
+
+        focused | entity_focused
     }
 
     /// The grid of the properties.
@@ -380,7 +401,8 @@ impl Placeholder for PropertiesWindow
                 overall_things_properties:  UiOverallProperties::placeholder(),
                 max_rows:                   0,
                 brushes_filler:             0,
-                things_filler:              0
+                things_filler:              0,
+                entity_properties:          EntityProperties::default()
             }
         }
     }
@@ -410,7 +432,8 @@ impl PropertiesWindow
                 overall_things_properties: UiOverallProperties::from(things_default_properties),
                 max_rows,
                 brushes_filler: max_rows - b_len,
-                things_filler: max_rows - t_len
+                things_filler: max_rows - t_len,
+                entity_properties: EntityProperties::default()
             }
         }
     }
@@ -0,0 +1,139 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::editor::{
+        state::{edits_history::EditsHistory, manager::EntitiesManager},
+        StateUpdateBundle
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to offset the angles of the selected things by reproducible pseudo-random values.
+pub(in crate::map::editor::state::ui) struct RandomizeAnglesWindow
+{
+    /// The window data.
+    window: Window,
+    /// The maximum offset from the current angle, in degrees.
+    range:  f32,
+    /// The seed of the pseudo-random values.
+    seed:   u64
+}
+
+impl Default for RandomizeAnglesWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window: Window::default(),
+            range:  45f32,
+            seed:   0
+        }
+    }
+}
+
+impl Toggle for RandomizeAnglesWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for RandomizeAnglesWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(randomize: &mut RandomizeAnglesWindow) { randomize.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::RandomizeAngles(id, close as fn(&mut Self)))
+    }
+}
+
+impl RandomizeAnglesWindow
+{
+    /// Shows the randomize angles window. The angles can only be randomized if `available` is
+    /// true. Returns whether a value field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        self.window
+            .show(
+                bundle.egui_context,
+                egui::Window::new("Randomize angles")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("randomize_angles").num_columns(2).show(ui, |ui| {
+                        ui.label("Range");
+                        focused |= ui
+                            .add(
+                                egui::DragValue::new(&mut self.range)
+                                    .clamp_range(0f32..=180f32)
+                                    .prefix("±")
+                                    .suffix("°")
+                            )
+                            .has_focus();
+                        ui.end_row();
+
+                        ui.label("Seed");
+                        ui.horizontal(|ui| {
+                            focused |= ui.add(egui::DragValue::new(&mut self.seed)).has_focus();
+
+                            if ui.button("New").clicked()
+                            {
+                                self.seed = self.seed.wrapping_add(1);
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                    ui.separator();
+
+                    if ui
+                        .add_enabled(
+                            available && manager.selected_things_amount() != 0,
+                            egui::Button::new("Randomize")
+                        )
+                        .clicked()
+                    {
+                        manager.randomize_selected_things_angles(
+                            edits_history,
+                            self.range,
+                            self.seed
+                        );
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,204 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    error_message,
+    map::{
+        brush::convex_polygon::ConvexPolygon,
+        containers::hv_vec,
+        editor::{
+            state::{edits_history::EditsHistory, manager::EntitiesManager},
+            AllDefaultProperties,
+            StateUpdateBundle
+        },
+        map_range,
+        OutOfBounds
+    },
+    utils::{math::points::rotate_point, misc::Toggle}
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to spawn a rectangular brush from typed coordinates and size.
+pub(in crate::map::editor::state::ui) struct RectangleWindow
+{
+    /// The window data.
+    window:   Window,
+    /// The typed position.
+    pos:      Vec2,
+    /// Whether `pos` is the center of the rectangle rather than its bottom left corner.
+    centered: bool,
+    /// The width and height of the rectangle.
+    size:     Vec2,
+    /// The rotation of the rectangle around its center, in degrees.
+    angle:    f32
+}
+
+impl Default for RectangleWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:   Window::default(),
+            pos:      Vec2::ZERO,
+            centered: true,
+            size:     Vec2::splat(64f32),
+            angle:    0f32
+        }
+    }
+}
+
+impl Toggle for RectangleWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for RectangleWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(rectangle: &mut RectangleWindow) { rectangle.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Rectangle(id, close as fn(&mut Self)))
+    }
+}
+
+impl RectangleWindow
+{
+    /// Returns the vertexes of the rectangle described by the typed values, in counter-clockwise
+    /// order.
+    #[inline]
+    #[must_use]
+    fn vertexes(&self) -> [Vec2; 4]
+    {
+        let half_size = self.size / 2f32;
+        let center = if self.centered { self.pos } else { self.pos + half_size };
+        let angle = self.angle.to_radians();
+
+        [
+            Vec2::new(-half_size.x, -half_size.y),
+            Vec2::new(half_size.x, -half_size.y),
+            half_size,
+            Vec2::new(-half_size.x, half_size.y)
+        ]
+        .map(|vx| rotate_point(center + vx, center, angle))
+    }
+
+    /// Shows the spawn rectangle window. The brush can only be spawned if `available` is true.
+    /// Returns whether a value field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            egui_context,
+            default_properties: AllDefaultProperties { map_brushes, .. },
+            ..
+        } = bundle;
+
+        let bound = manager.map_bound();
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Spawn rectangle")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("rectangle").num_columns(2).show(ui, |ui| {
+                        ui.label("Position");
+                        ui.horizontal(|ui| {
+                            for value in [&mut self.pos.x, &mut self.pos.y]
+                            {
+                                focused |= ui
+                                    .add(egui::DragValue::new(value).clamp_range(map_range(bound)))
+                                    .has_focus();
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Pivot");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.centered, true, "Center");
+                            ui.radio_value(&mut self.centered, false, "Bottom left");
+                        });
+                        ui.end_row();
+
+                        ui.label("Size");
+                        ui.horizontal(|ui| {
+                            for value in [&mut self.size.x, &mut self.size.y]
+                            {
+                                focused |= ui
+                                    .add(egui::DragValue::new(value).clamp_range(1f32..=f32::MAX))
+                                    .has_focus();
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Rotation");
+                        focused |= ui
+                            .add(
+                                egui::DragValue::new(&mut self.angle)
+                                    .clamp_range(-360f32..=360f32)
+                                    .suffix("°")
+                            )
+                            .has_focus();
+                        ui.end_row();
+                    });
+
+                    ui.separator();
+
+                    if !ui.add_enabled(available, egui::Button::new("Spawn")).clicked()
+                    {
+                        return focused;
+                    }
+
+                    let vxs = self.vertexes();
+
+                    if vxs.iter().any(|vx| vx.out_of_bounds(bound))
+                    {
+                        error_message("The rectangle would be out of bounds");
+                        return focused;
+                    }
+
+                    manager.deselect_selected_entities(edits_history);
+                    _ = manager.spawn_brush(
+                        ConvexPolygon::from(hv_vec![collect; vxs]),
+                        edits_history,
+                        map_brushes.instance()
+                    );
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,119 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::UVec2;
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::utils::misc::Toggle;
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum width and height of the rendered image.
+const MAX_SIZE: u32 = 8192;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to render the map preview to an image file.
+pub(in crate::map::editor::state::ui) struct RenderImageWindow
+{
+    /// The window data.
+    window: Window,
+    /// The width and height of the image.
+    size:   UVec2
+}
+
+impl Default for RenderImageWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window: Window::default(),
+            size:   UVec2::splat(2048)
+        }
+    }
+}
+
+impl Toggle for RenderImageWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for RenderImageWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(render: &mut RenderImageWindow) { render.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::RenderImage(id, close as fn(&mut Self)))
+    }
+}
+
+impl RenderImageWindow
+{
+    /// Shows the render to image window. The image can only be rendered if `available` is true.
+    /// Returns whether a value field has focus and the size of the image to render, if the render
+    /// was requested.
+    #[inline]
+    pub fn show(&mut self, egui_context: &egui::Context, available: bool) -> (bool, Option<UVec2>)
+    {
+        if !self.window.is_open()
+        {
+            return (false, None);
+        }
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Render to image")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("render_image").num_columns(2).show(ui, |ui| {
+                        let UVec2 { x, y } = &mut self.size;
+
+                        for (label, value) in [("Width", x), ("Height", y)]
+                        {
+                            ui.label(label);
+                            focused |= ui
+                                .add(egui::DragValue::new(value).clamp_range(1..=MAX_SIZE))
+                                .has_focus();
+                            ui.end_row();
+                        }
+                    });
+
+                    ui.separator();
+
+                    if !available
+                    {
+                        ui.label("Enable the map preview to render it");
+                    }
+
+                    let render = ui
+                        .add_enabled(available, egui::Button::new("Render"))
+                        .clicked()
+                        .then_some(self.size);
+
+                    (focused, render)
+                }
+            )
+            .unwrap_or((false, None))
+    }
+}
@@ -0,0 +1,134 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        containers::{hv_vec, HvHashSet},
+        editor::{
+            state::{edits_history::EditsHistory, manager::EntitiesManager},
+            AllDefaultProperties,
+            StateUpdateBundle
+        },
+        AssertedInsertRemove
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to select the brushes similar to the selected one.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct SelectSimilarWindow
+{
+    /// The window data.
+    window:     Window,
+    /// Whether the texture must match.
+    texture:    bool,
+    /// The keys of the properties whose values must match.
+    properties: HvHashSet<String>
+}
+
+impl Toggle for SelectSimilarWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for SelectSimilarWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(select_similar: &mut SelectSimilarWindow) { select_similar.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::SelectSimilar(id, close as fn(&mut Self)))
+    }
+}
+
+impl SelectSimilarWindow
+{
+    /// Shows the select similar window. The selection can only be executed if `available` is true
+    /// and there is exactly one selected brush, which is used as reference.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    )
+    {
+        let StateUpdateBundle {
+            egui_context,
+            default_properties: AllDefaultProperties { map_brushes, .. },
+            ..
+        } = bundle;
+
+        if !self.window.is_open()
+        {
+            return;
+        }
+
+        self.window.show(
+            egui_context,
+            egui::Window::new("Select similar")
+                .vscroll(true)
+                .collapsible(true)
+                .resizable(false),
+            |ui| {
+                ui.label("Matching attributes");
+                ui.checkbox(&mut self.texture, "Texture");
+
+                for (k, _) in map_brushes.iter()
+                {
+                    let mut checked = self.properties.contains(k);
+
+                    if !ui.checkbox(&mut checked, k.as_str()).changed()
+                    {
+                        continue;
+                    }
+
+                    if checked
+                    {
+                        self.properties.asserted_insert(k.clone());
+                    }
+                    else
+                    {
+                        self.properties.asserted_remove(k);
+                    }
+                }
+
+                ui.separator();
+
+                let enabled = available && manager.selected_brushes_amount() == 1;
+
+                if ui.add_enabled(enabled, egui::Button::new("Select")).clicked()
+                {
+                    let reference = *manager.selected_brushes_ids().next().unwrap();
+                    let properties = hv_vec![collect; map_brushes
+                        .iter()
+                        .filter_map(|(k, _)| self.properties.contains(k).then_some(k.as_str()))];
+
+                    manager.select_similar_brushes(
+                        edits_history,
+                        reference,
+                        self.texture,
+                        &properties
+                    );
+                }
+            }
+        );
+    }
+}
@@ -5,15 +5,31 @@
 
 use bevy::prelude::KeyCode;
 use bevy_egui::egui;
-use hill_vacuum_shared::return_if_no_match;
+use hill_vacuum_shared::{return_if_no_match, FILE_EXTENSION};
 use is_executable::IsExecutable;
 
 use super::{window::Window, WindowCloserInfo};
 use crate::{
-    config::{controls::bind::Bind, Config},
-    map::editor::{
-        state::{editor_state::InputsPresses, ui::WindowCloser},
-        StateUpdateBundle
+    config::{
+        controls::{bind::Bind, KeyRepeat},
+        BulkConfirmation,
+        CanonicalSave,
+        Config,
+        DetailCulling,
+        OverlapTolerance,
+        RecentTextures,
+        SaveExport,
+        SnapThreshold
+    },
+    map::{
+        editor::{
+            state::{
+                editor_state::{InputsPresses, HV_FILTER_NAME},
+                ui::WindowCloser
+            },
+            StateUpdateBundle
+        },
+        map_range
     },
     utils::misc::{Blinker, Toggle}
 };
@@ -117,10 +133,15 @@ impl WindowCloserInfo for SettingsWindow
 
 impl SettingsWindow
 {
-    /// Shows the settings window.
+    /// Shows the settings window. The edges of the play area are kept within `map_bound`.
     #[inline]
     #[must_use]
-    pub fn show(&mut self, bundle: &mut StateUpdateBundle, inputs: &mut InputsPresses) -> bool
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        inputs: &mut InputsPresses,
+        map_bound: f32
+    ) -> bool
     {
         let StateUpdateBundle {
             delta_time,
@@ -129,8 +150,20 @@ impl SettingsWindow
             config:
                 Config {
                     binds,
+                    key_repeat,
+                    detail_culling,
+                    limits,
+                    spawn_selection,
+                    play_area,
+                    canonical_save,
+                    save_export,
+                    recent_textures,
+                    bulk_confirm,
+                    snap_threshold,
+                    thing_overlap,
                     colors,
                     exporter,
+                    template,
                     ..
                 },
             ..
@@ -236,6 +269,236 @@ impl SettingsWindow
                         ui.label("");
                         ui.end_row();
 
+                        // Key repeat.
+                        ui.label("KEY REPEAT");
+                        ui.end_row();
+
+                        ui.label("Delay");
+                        ui.add(
+                            egui::DragValue::new(key_repeat.delay_mut())
+                                .speed(0.01)
+                                .clamp_range(KeyRepeat::DELAY_RANGE)
+                        );
+                        ui.end_row();
+
+                        ui.label("Interval");
+                        ui.add(
+                            egui::DragValue::new(key_repeat.interval_mut())
+                                .speed(0.005)
+                                .clamp_range(KeyRepeat::INTERVAL_RANGE)
+                        );
+                        ui.end_row();
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            key_repeat.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Detail culling.
+                        ui.label("DETAIL CULLING");
+                        ui.end_row();
+
+                        ui.label("Min size");
+                        ui.add(
+                            egui::DragValue::new(detail_culling.min_size_mut())
+                                .speed(0.25)
+                                .clamp_range(DetailCulling::MIN_SIZE_RANGE)
+                        );
+                        ui.end_row();
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            detail_culling.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Complexity limits.
+                        ui.label("COMPLEXITY LIMITS (0 = none)");
+                        ui.end_row();
+
+                        for (label, limit) in [
+                            ("Brushes", &mut limits.brushes),
+                            ("Vertexes", &mut limits.vertexes),
+                            ("Things", &mut limits.things)
+                        ]
+                        {
+                            ui.label(label);
+                            ui.add(egui::DragValue::new(limit).speed(1));
+                            ui.end_row();
+                        }
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            limits.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Spawn selection.
+                        ui.label("SPAWNED ENTITIES");
+                        ui.end_row();
+
+                        ui.label("Select");
+                        ui.checkbox(spawn_selection.enabled_mut(), "");
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Play area.
+                        ui.label("PLAY AREA");
+                        ui.end_row();
+
+                        ui.label("Visible");
+                        ui.checkbox(&mut play_area.visible, "");
+                        ui.end_row();
+
+                        for (label, bound) in [
+                            ("Left", &mut play_area.left),
+                            ("Right", &mut play_area.right),
+                            ("Bottom", &mut play_area.bottom),
+                            ("Top", &mut play_area.top)
+                        ]
+                        {
+                            ui.label(label);
+                            ui.add(egui::DragValue::new(bound).clamp_range(map_range(map_bound)));
+                            ui.end_row();
+                        }
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            play_area.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Canonical save.
+                        ui.label("CANONICAL SAVE");
+                        ui.end_row();
+
+                        ui.label("Enabled");
+                        ui.checkbox(&mut canonical_save.enabled, "");
+                        ui.end_row();
+
+                        ui.label("Decimals");
+                        ui.add(
+                            egui::DragValue::new(canonical_save.decimals_mut())
+                                .clamp_range(CanonicalSave::DECIMALS_RANGE)
+                        );
+                        ui.end_row();
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            canonical_save.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Save export.
+                        ui.label("SAVE EXPORT");
+                        ui.end_row();
+
+                        ui.label("Format");
+                        egui::ComboBox::from_id_source("save_export")
+                            .selected_text(save_export.label())
+                            .show_ui(ui, |ui| {
+                                for format in SaveExport::ALL
+                                {
+                                    ui.selectable_value(save_export, format, format.label());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Recent textures.
+                        ui.label("RECENT TEXTURES");
+                        ui.end_row();
+
+                        ui.label("Amount");
+                        ui.add(
+                            egui::DragValue::new(recent_textures.amount_mut())
+                                .clamp_range(RecentTextures::AMOUNT_RANGE)
+                        );
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Bulk operations confirmation.
+                        ui.label("BULK CONFIRMATION");
+                        ui.end_row();
+
+                        ui.label("Threshold");
+                        ui.add(
+                            egui::DragValue::new(bulk_confirm.threshold_mut())
+                                .clamp_range(BulkConfirmation::THRESHOLD_RANGE)
+                        );
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Snap threshold.
+                        ui.label("SNAP");
+                        ui.end_row();
+
+                        ui.label("Threshold (pixels)");
+                        ui.add(
+                            egui::DragValue::new(snap_threshold.pixels_mut())
+                                .speed(0.25)
+                                .clamp_range(SnapThreshold::PIXELS_RANGE)
+                        );
+                        ui.end_row();
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            snap_threshold.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Overlapping things.
+                        ui.label("OVERLAPPING THINGS");
+                        ui.end_row();
+
+                        ui.label("Tolerance");
+                        ui.add(
+                            egui::DragValue::new(thing_overlap.distance_mut())
+                                .speed(0.1)
+                                .clamp_range(OverlapTolerance::DISTANCE_RANGE)
+                        )
+                        .on_hover_text(
+                            "Distance within which things of the same type are considered \
+                             overlapping when the map is saved"
+                        );
+                        ui.end_row();
+
+                        if ui.button("Reset to default").clicked()
+                        {
+                            thing_overlap.reset();
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
                         // Colors.
                         ui.label("COLORS");
                         ui.end_row();
@@ -275,6 +538,40 @@ impl SettingsWindow
 
                         ui.label(label);
                         ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // New map template.
+                        ui.label("NEW MAP TEMPLATE");
+                        ui.end_row();
+
+                        if ui.button("Pick template").clicked()
+                        {
+                            if let Some(file) = rfd::FileDialog::new()
+                                .set_title("Pick template")
+                                .add_filter(HV_FILTER_NAME, &[FILE_EXTENSION])
+                                .set_directory(std::env::current_dir().unwrap())
+                                .pick_file()
+                            {
+                                *template = file.into();
+                            }
+                        }
+
+                        let label = match template
+                        {
+                            Some(path) => path.file_stem().unwrap().to_str().unwrap(),
+                            None => ""
+                        };
+
+                        ui.label(label);
+                        ui.end_row();
+
+                        if ui.button("Clear template").clicked()
+                        {
+                            *template = None;
+                        }
+                        ui.end_row();
                     });
             }
         );
@@ -0,0 +1,143 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    error_message,
+    map::editor::{
+        state::{edits_history::EditsHistory, manager::EntitiesManager},
+        StateUpdateBundle
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to split the selected brushes into strips of equal size.
+pub(in crate::map::editor::state::ui) struct SplitWindow
+{
+    /// The window data.
+    window:   Window,
+    /// The amount of strips.
+    pieces:   u8,
+    /// Whether the strips are columns rather than rows.
+    vertical: bool
+}
+
+impl Default for SplitWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:   Window::default(),
+            pieces:   2,
+            vertical: true
+        }
+    }
+}
+
+impl Toggle for SplitWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for SplitWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(split: &mut SplitWindow) { split.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Split(id, close as fn(&mut Self)))
+    }
+}
+
+impl SplitWindow
+{
+    /// Shows the split brushes window. The brushes can only be split if `available` is true.
+    /// Returns whether the pieces value field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            egui_context,
+            drawing_resources,
+            ..
+        } = bundle;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Split brushes")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("split").num_columns(2).show(ui, |ui| {
+                        ui.label("Pieces");
+                        focused = ui
+                            .add(egui::DragValue::new(&mut self.pieces).clamp_range(2..=64))
+                            .has_focus();
+                        ui.end_row();
+
+                        ui.label("Layout");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.vertical, true, "Columns");
+                            ui.radio_value(&mut self.vertical, false, "Rows");
+                        });
+                        ui.end_row();
+                    });
+
+                    ui.separator();
+
+                    if !ui
+                        .add_enabled(
+                            available && manager.selected_brushes_amount() != 0,
+                            egui::Button::new("Split")
+                        )
+                        .clicked()
+                    {
+                        return focused;
+                    }
+
+                    if !manager.split_selected_brushes(
+                        drawing_resources,
+                        edits_history,
+                        self.pieces,
+                        self.vertical
+                    )
+                    {
+                        error_message("The selected brushes could not be split");
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
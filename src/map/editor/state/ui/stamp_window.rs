@@ -0,0 +1,179 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    error_message,
+    map::editor::{
+        state::{clipboard::Clipboard, edits_history::EditsHistory, manager::EntitiesManager},
+        StateUpdateBundle
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the vertexes of the side selected with the side tool, if only one is selected.
+#[inline]
+#[must_use]
+fn selected_side(manager: &EntitiesManager) -> Option<[Vec2; 2]>
+{
+    let mut vxs = manager
+        .selected_brushes()
+        .filter_map(|brush| brush.selected_sides_vertexes())
+        .flatten();
+    let side = [vxs.next()?, vxs.next()?];
+    vxs.next().is_none().then_some(side)
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to spawn copies of the selected prop, or of the copied entities, along the side
+/// selected with the side tool.
+pub(in crate::map::editor::state::ui) struct StampWindow
+{
+    /// The window data.
+    window:  Window,
+    /// The distance between the copies.
+    spacing: f32,
+    /// Whether the copies are rotated to follow the direction of the side.
+    align:   bool,
+    /// Whether the selected slotted prop is stamped rather than the copied entities.
+    slotted: bool
+}
+
+impl Default for StampWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:  Window::default(),
+            spacing: 64f32,
+            align:   true,
+            slotted: true
+        }
+    }
+}
+
+impl Toggle for StampWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for StampWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(stamp: &mut StampWindow) { stamp.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Stamp(id, close as fn(&mut Self)))
+    }
+}
+
+impl StampWindow
+{
+    /// Shows the stamp along side window. The copies can only be spawned if `available` is true
+    /// and a single side is selected. Returns whether the spacing value field has focus.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        clipboard: &mut Clipboard,
+        available: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            egui_context,
+            drawing_resources,
+            ..
+        } = bundle;
+
+        let side = available.then(|| selected_side(manager)).flatten();
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new("Stamp along side")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    let mut focused = false;
+
+                    egui::Grid::new("stamp").num_columns(2).show(ui, |ui| {
+                        ui.label("Spacing");
+                        focused = ui
+                            .add(
+                                egui::DragValue::new(&mut self.spacing)
+                                    .clamp_range(1f32..=4096f32)
+                            )
+                            .has_focus();
+                        ui.end_row();
+
+                        ui.label("Align to side");
+                        ui.checkbox(&mut self.align, "");
+                        ui.end_row();
+
+                        ui.label("Template");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.slotted, true, "Selected prop");
+                            ui.radio_value(&mut self.slotted, false, "Copied entities");
+                        });
+                        ui.end_row();
+                    });
+
+                    ui.separator();
+
+                    if side.is_none()
+                    {
+                        ui.label("Select a single side with the side tool");
+                    }
+
+                    if !ui.add_enabled(side.is_some(), egui::Button::new("Stamp")).clicked()
+                    {
+                        return focused;
+                    }
+
+                    if let Err(err) = clipboard.stamp_along_side(
+                        drawing_resources,
+                        manager,
+                        edits_history,
+                        side.unwrap(),
+                        self.spacing,
+                        self.align,
+                        self.slotted
+                    )
+                    {
+                        error_message(err);
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false)
+    }
+}
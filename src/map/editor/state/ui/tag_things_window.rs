@@ -0,0 +1,166 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        editor::{
+            state::{edits_history::EditsHistory, manager::EntitiesManager},
+            AllDefaultProperties,
+            StateUpdateBundle
+        },
+        properties::Value
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window to set a property of all the things within a region to the same value.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct TagThingsWindow
+{
+    /// The window data.
+    window: Window,
+    /// The key of the property to set.
+    key:    String,
+    /// The text of the value to assign.
+    value:  String,
+    /// The outcome of the last operation.
+    status: String
+}
+
+impl Toggle for TagThingsWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for TagThingsWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(tag_things: &mut TagThingsWindow) { tag_things.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::TagThings(id, close as fn(&mut Self)))
+    }
+}
+
+impl TagThingsWindow
+{
+    /// Shows the tag things window. The things can be picked from the region described by the
+    /// selected brushes, or through a range selection, and their property is set in a single
+    /// edit. Nothing can be executed if `available` is false.
+    /// Returns whether the value text field has focus and the key of the edited property, if any.
+    #[inline]
+    pub fn show(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        available: bool
+    ) -> (bool, Option<String>)
+    {
+        let StateUpdateBundle {
+            egui_context,
+            default_properties: AllDefaultProperties { map_things, .. },
+            ..
+        } = bundle;
+
+        if !self.window.is_open()
+        {
+            return (false, None);
+        }
+
+        if !self.key.is_empty() && map_things.iter().all(|(k, _)| *k != self.key)
+        {
+            self.key.clear();
+        }
+
+        let mut edited = None;
+
+        let focused = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new("Tag things")
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    ui.label("Region");
+
+                    let enabled = available && manager.any_selected_brushes();
+                    let button = egui::Button::new("Select things in selected brushes");
+
+                    if ui.add_enabled(enabled, button).clicked()
+                    {
+                        let amount = manager.select_things_in_selected_brushes(edits_history);
+                        self.status = format!("{amount} things selected");
+                    }
+
+                    ui.separator();
+
+                    ui.label(format!("Selected things: {}", manager.selected_things_amount()));
+
+                    egui::ComboBox::from_label("Property")
+                        .selected_text(self.key.as_str())
+                        .show_ui(ui, |ui| {
+                            for (k, _) in map_things.iter()
+                            {
+                                if ui.selectable_label(*k == self.key, k.as_str()).clicked()
+                                {
+                                    self.key.clone_from(k);
+                                }
+                            }
+                        });
+
+                    let focused = ui
+                        .horizontal(|ui| {
+                            ui.label("Value");
+                            ui.text_edit_singleline(&mut self.value).has_focus()
+                        })
+                        .inner;
+
+                    let enabled =
+                        available && !self.key.is_empty() && manager.any_selected_things();
+
+                    if ui.add_enabled(enabled, egui::Button::new("Apply")).clicked()
+                    {
+                        let value = Value::String(self.value.clone());
+
+                        if manager.set_selected_things_property(edits_history, &self.key, &value)
+                        {
+                            self.status.clear();
+                            edited = self.key.clone().into();
+                        }
+                        else
+                        {
+                            self.status = "No thing was edited".to_owned();
+                        }
+                    }
+
+                    if !self.status.is_empty()
+                    {
+                        ui.label(self.status.as_str());
+                    }
+
+                    focused
+                }
+            )
+            .unwrap_or(false);
+
+        (focused, edited)
+    }
+}
@@ -178,11 +178,13 @@ impl InstancesEditor
             ($xy:ident) => {
                 paste::paste! {
                     |value| {
+                        let bound = manager.map_bound();
                         let valid = manager.test_operation_validity(|manager| {
                             manager.selected_brushes_with_sprite_mut().find_map(|mut brush| {
                                 (!brush.[< check_atlas_animation_ $xy _partition >](
                                     drawing_resources,
-                                    value
+                                    value,
+                                    bound
                                 )).then_some(brush.id())
                             })
                         });
@@ -340,10 +342,12 @@ impl InstancesEditor
                 macro_rules! anim_change {
                     ($new:expr, $f:expr) => {{
                         let new = &$new;
+                        let bound = manager.map_bound();
                         let valid = manager.test_operation_validity(|manager| {
                             manager.selected_brushes_with_sprite_mut().find_map(|mut brush| {
-                                (!brush.check_texture_animation_change(drawing_resources, new))
-                                    .then_some(brush.id())
+                                (!brush
+                                    .check_texture_animation_change(drawing_resources, new, bound))
+                                .then_some(brush.id())
                             })
                         });
 
@@ -733,10 +733,13 @@ impl AnimationEditor
         manager: &mut EntitiesManager
     ) -> bool
     {
+        let bound = manager.map_bound();
+
         manager.test_operation_validity(|manager| {
             return_if_none!(manager.selected_brushes_with_texture_sprite_mut(texture), None)
                 .find_map(|mut brush| {
-                    (!brush.check_texture_within_bounds(drawing_resources)).then_some(brush.id())
+                    (!brush.check_texture_within_bounds(drawing_resources, bound))
+                        .then_some(brush.id())
                 })
         })
     }
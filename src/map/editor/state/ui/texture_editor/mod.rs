@@ -5,6 +5,7 @@ mod animation_editor;
 //
 //=======================================================================//
 
+use bevy::prelude::Vec2;
 use bevy_egui::egui;
 use hill_vacuum_shared::{return_if_none, TEXTURE_HEIGHT_RANGE};
 
@@ -19,9 +20,16 @@ use super::{
 use crate::{
     config::controls::bind::Bind,
     map::{
+        containers::{hv_vec, HvVec},
         drawer::{
             drawing_resources::{DrawingResources, TextureMaterials},
-            texture::{OverallTextureSettings, Texture, UiOverallTextureSettings}
+            texture::{
+                OverallTextureSettings,
+                Texture,
+                TextureInterface,
+                UiOverallTextureSettings,
+                TEXTURE_BRIGHTNESS_RANGE
+            }
         },
         editor::{
             state::{
@@ -92,9 +100,10 @@ macro_rules! plus_minus_textedit {
         ) -> bool
         {
             $(
+                let bound = manager.map_bound();
                 let valid = manager.test_operation_validity(|manager| {
                     manager.selected_textured_brushes_mut().find_map(|mut brush| {
-                        (!brush.[< check_texture_ $value >]($drawing_resources, value)).then_some(brush.id())
+                        (!brush.[< check_texture_ $value >]($drawing_resources, value, bound)).then_some(brush.id())
                     })
                 });
 
@@ -318,7 +327,8 @@ struct Bundle<'a>
     edits_history:     &'a mut EditsHistory,
     clipboard:         &'a mut Clipboard,
     inputs:            &'a InputsPresses,
-    settings:          &'a mut ToolsSettings
+    settings:          &'a mut ToolsSettings,
+    recent_amount:     usize
 }
 
 //=======================================================================//
@@ -330,7 +340,12 @@ struct Innards
     /// The overall texture.
     overall_texture:  UiOverallTextureSettings,
     /// The editor of the texture animation.
-    animation_editor: AnimationEditor
+    animation_editor: AnimationEditor,
+    /// The texel to pin to the selected vertex, if any.
+    pinned_texel:     Option<Vec2>,
+    /// The names of the textures most recently assigned to the selected brushes, from the most
+    /// recent.
+    recent_textures:  HvVec<String>
 }
 
 impl Innards
@@ -377,27 +392,116 @@ impl Innards
         })
     );
 
-    toggle!((scroll, "Scroll"), (parallax, "Parallax"));
+    toggle!(
+        (scroll, "Scroll"),
+        (parallax, "Parallax"),
+        (scale_aspect_lock, "Lock aspect")
+    );
 
-    /// Assigns a texture to the selected brushes, if possible.
+    /// Assigns a texture to the selected brushes, if possible, rendering it as a sprite if
+    /// `sprite` is true. If successful `texture` is stored as the most recent of the
+    /// `recent_amount` recently assigned textures.
     #[inline]
     fn assign_texture(
         drawing_resources: &DrawingResources,
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
-        texture: &str
+        recent_textures: &mut HvVec<String>,
+        recent_amount: usize,
+        texture: &str,
+        sprite: bool
     ) -> bool
     {
-        match manager.set_selected_brushes_texture(drawing_resources, edits_history, texture)
+        let result = if sprite
+        {
+            manager.set_selected_brushes_texture_sprite(drawing_resources, edits_history, texture)
+        }
+        else
+        {
+            manager.set_selected_brushes_texture(drawing_resources, edits_history, texture)
+        };
+
+        match result
+        {
+            TextureResult::Invalid => return false,
+            TextureResult::Valid => (),
+            TextureResult::ValidRefreshOutline => manager.schedule_outline_update()
+        };
+
+        if let Some(index) = recent_textures.iter().position(|name| name == texture)
         {
-            TextureResult::Invalid => false,
-            TextureResult::Valid => true,
-            TextureResult::ValidRefreshOutline =>
+            _ = recent_textures.remove(index);
+        }
+
+        recent_textures.insert(0, texture.to_owned());
+        recent_textures.truncate(recent_amount);
+        true
+    }
+
+    /// Draws the bar of the recently assigned textures. Clicking one of them assigns it to the
+    /// selected brushes, as a sprite if Shift is pressed.
+    #[inline]
+    fn recent_textures(&mut self, ui: &mut egui::Ui, bundle: &mut Bundle)
+    {
+        /// The side of the previews of the textures.
+        const RECENT_TEXTURE_PREVIEW_SIDE: f32 = 48f32;
+
+        let Bundle {
+            drawing_resources,
+            manager,
+            edits_history,
+            inputs,
+            recent_amount,
+            ..
+        } = bundle;
+
+        self.recent_textures.retain_mut(|name| drawing_resources.texture(name).is_some());
+        self.recent_textures.truncate(*recent_amount);
+
+        if self.recent_textures.is_empty()
+        {
+            return;
+        }
+
+        let mut clicked_texture = None;
+
+        ui.horizontal(|ui| {
+            ui.label("Recent");
+
+            for name in &self.recent_textures
             {
-                manager.schedule_outline_update();
-                true
+                let texture = drawing_resources.egui_texture(name);
+
+                ui.vertical(|ui| {
+                    ui.set_width(RECENT_TEXTURE_PREVIEW_SIDE);
+
+                    if format_texture_preview!(
+                        ImageButton,
+                        ui,
+                        texture.0,
+                        texture.1,
+                        RECENT_TEXTURE_PREVIEW_SIDE
+                    )
+                    .on_hover_text(name.as_str())
+                    .clicked()
+                    {
+                        clicked_texture = name.clone().into();
+                    }
+                });
             }
-        }
+        });
+
+        ui.separator();
+
+        _ = Self::assign_texture(
+            drawing_resources,
+            manager,
+            edits_history,
+            &mut self.recent_textures,
+            *recent_amount,
+            return_if_none!(clicked_texture).as_str(),
+            inputs.shift_pressed()
+        );
     }
 
     /// The name of the texture being edited, if any.
@@ -410,19 +514,54 @@ impl Innards
             .or_else(|| self.overall_texture.name.uniform_value())
     }
 
-    /// Draws the selected texture.
+    /// Draws the selected texture. Clicking on it picks the texel to be pinned to the selected
+    /// vertex of the selected textured brush.
     #[inline]
     fn selected_texture(&mut self, ui: &mut egui::Ui, bundle: &mut Bundle)
     {
         /// The side of the texture preview.
         const TEXTURE_PREVIEW_FRAME_SIDE: f32 = 224f32;
+        /// The radius of the marker of the pinned texel.
+        const PIN_MARKER_RADIUS: f32 = 3f32;
 
         ui.set_width(TEXTURE_PREVIEW_FRAME_SIDE);
         let texture = bundle
             .drawing_resources
             .egui_texture(return_if_none!(self.selected_texture_name()));
-        format_texture_preview!(Image, ui, texture.0, texture.1, TEXTURE_PREVIEW_FRAME_SIDE);
+        let size = texture.1.as_vec2();
+        let rect =
+            format_texture_preview!(Image, ui, texture.0, texture.1, TEXTURE_PREVIEW_FRAME_SIDE)
+                .rect;
         ui.vertical_centered(|ui| ui.label(texture.2));
+
+        let response = ui.interact(rect, ui.id().with("texture_pin"), egui::Sense::click());
+
+        if let Some(pos) = response.interact_pointer_pos().filter(|_| response.clicked())
+        {
+            let pos = (pos - rect.min) / rect.size();
+            self.pinned_texel =
+                Vec2::new((pos.x * size.x).floor(), (pos.y * size.y).floor()).into();
+        }
+
+        let texel = return_if_none!(self.pinned_texel);
+        ui.painter().circle_stroke(
+            rect.min + egui::vec2(texel.x / size.x, texel.y / size.y) * rect.size(),
+            PIN_MARKER_RADIUS,
+            egui::Stroke::new(1f32, egui::Color32::RED)
+        );
+
+        ui.horizontal(|ui| {
+            ui.label(format!("Pin {}, {}", texel.x, texel.y));
+
+            if ui.button("Pin to vertex").clicked()
+            {
+                _ = bundle.manager.pin_selected_texture_to_vertex(
+                    bundle.drawing_resources,
+                    bundle.edits_history,
+                    texel
+                );
+            }
+        });
     }
 
     /// Draws the UI elements of the texture editor.
@@ -437,7 +576,7 @@ impl Innards
         let mut has_focus = false;
 
         egui_extras::StripBuilder::new(ui)
-            .sizes(egui_extras::Size::exact(SETTING_HEIGHT), 10)
+            .sizes(egui_extras::Size::exact(SETTING_HEIGHT), 13)
             .vertical(|mut strip| {
                 let plus_minus_field_width =
                     available_width / 2f32 - 11.5 - (FIELD_NAME_WIDTH + MINUS_PLUS_TOTAL_WIDTH);
@@ -446,13 +585,34 @@ impl Innards
                     has_focus = self.set_texture(strip, bundle, available_width);
                 });
 
-                for func in [Self::set_offset, Self::set_scale]
+                strip.strip(|strip| {
+                    has_focus |= self.set_offset(strip, bundle, plus_minus_field_width);
+                });
+
+                let prev_scales = bundle.settings.scale_aspect_lock_enabled.then(|| {
+                    hv_vec![collect; bundle.manager.selected_textured_brushes().map(|brush| {
+                        let texture = brush.texture_settings().unwrap();
+                        (brush.id(), texture.scale_x(), texture.scale_y())
+                    })]
+                });
+
+                strip.strip(|strip| {
+                    has_focus |= self.set_scale(strip, bundle, plus_minus_field_width);
+                });
+
+                if let Some(prev_scales) = prev_scales
                 {
-                    strip.strip(|strip| {
-                        has_focus |= func(self, strip, bundle, plus_minus_field_width);
-                    });
+                    bundle.manager.keep_selected_textures_scale_aspect(
+                        bundle.drawing_resources,
+                        bundle.edits_history,
+                        &prev_scales
+                    );
                 }
 
+                strip.strip(|strip| {
+                    self.toggle_scale_aspect_lock(strip, bundle.settings);
+                });
+
                 strip.strip(|strip| {
                     has_focus |= self.set_scroll(strip, bundle, plus_minus_field_width);
                 });
@@ -468,6 +628,14 @@ impl Innards
                     has_focus |= self.set_height(strip, bundle, plus_minus_field_width);
                 });
 
+                strip.strip(|strip| {
+                    has_focus |= self.set_brightness(strip, bundle, plus_minus_field_width);
+                });
+
+                strip.strip(|strip| {
+                    self.set_trim(strip, bundle);
+                });
+
                 strip.strip(|strip| {
                     self.set_sprite(strip, bundle);
                 });
@@ -565,6 +733,13 @@ impl Innards
 
         ui.separator();
 
+        if !self
+            .animation_editor
+            .can_add_textures_to_atlas(&self.overall_texture.animation)
+        {
+            self.recent_textures(ui, bundle);
+        }
+
         egui::ScrollArea::vertical().show(ui, |ui| {
             /// Draws the button to be clicked to pick a texture.
             #[inline]
@@ -602,6 +777,8 @@ impl Innards
                 drawing_resources,
                 manager,
                 edits_history,
+                inputs,
+                recent_amount,
                 ..
             } = bundle;
 
@@ -668,7 +845,10 @@ impl Innards
                         drawing_resources,
                         manager,
                         edits_history,
-                        texture.name()
+                        &mut self.recent_textures,
+                        *recent_amount,
+                        texture.name(),
+                        inputs.shift_pressed()
                     );
                 }
                 else if response.secondary_clicked()
@@ -725,6 +905,7 @@ impl Innards
                             drawing_resources,
                             manager,
                             edits_history,
+                            recent_amount,
                             ..
                         } = bundle;
 
@@ -734,7 +915,10 @@ impl Innards
                                 drawing_resources,
                                 manager,
                                 edits_history,
-                                texture.name()
+                                &mut self.recent_textures,
+                                *recent_amount,
+                                texture.name(),
+                                false
                             )
                             .then(|| value.clone());
                         }
@@ -762,6 +946,113 @@ impl Innards
         has_focus
     }
 
+    /// Sets the brightness multiplier of the selected textures.
+    #[inline]
+    fn set_brightness(
+        &mut self,
+        strip: egui_extras::StripBuilder,
+        bundle: &mut Bundle,
+        field_width: f32
+    ) -> bool
+    {
+        /// The step of the brightness change.
+        const STEP: f32 = 0.1;
+
+        let mut has_focus = false;
+
+        strip
+            .size(egui_extras::Size::exact(FIELD_NAME_WIDTH))
+            .size(egui_extras::Size::exact(field_width))
+            .size(egui_extras::Size::exact(MINUS_PLUS_TOTAL_WIDTH))
+            .horizontal(|mut strip| {
+                strip.cell(|ui| {
+                    ui.label("Brightness");
+                });
+
+                has_focus = plus_minus_textedit!(
+                    self,
+                    bundle,
+                    f32,
+                    brightness,
+                    STEP,
+                    strip,
+                    |brightness: f32, _| {
+                        brightness.clamp(
+                            *TEXTURE_BRIGHTNESS_RANGE.start(),
+                            *TEXTURE_BRIGHTNESS_RANGE.end()
+                        )
+                    }
+                );
+            });
+
+        has_focus
+    }
+
+    /// Sets the trim sheet region of the selected textures.
+    #[inline]
+    fn set_trim(&mut self, strip: egui_extras::StripBuilder, bundle: &mut Bundle)
+    {
+        let trims = self
+            .overall_texture
+            .name
+            .uniform_value()
+            .and_then(|name| bundle.drawing_resources.texture(name))
+            .map_or_else(Vec::new, |texture| {
+                texture
+                    .trims()
+                    .iter()
+                    .map(|trim| trim.name().to_owned())
+                    .collect::<Vec<_>>()
+            });
+
+        let mut selected = bundle
+            .manager
+            .selected_textured_brushes()
+            .map(|brush| brush.texture_settings().unwrap().trim());
+        let current = selected.next().and_then(|first| {
+            selected
+                .all(|trim| trim == first)
+                .then(|| first.unwrap_or("None").to_owned())
+        });
+
+        strip
+            .size(egui_extras::Size::exact(FIELD_NAME_WIDTH))
+            .size(egui_extras::Size::remainder())
+            .horizontal(|mut strip| {
+                strip.cell(|ui| {
+                    ui.label("Trim");
+                });
+
+                strip.cell(|ui| {
+                    let mut new_trim = None;
+
+                    ui.add_enabled_ui(!trims.is_empty(), |ui| {
+                        egui::ComboBox::from_id_source("texture_trim")
+                            .selected_text(current.as_deref().unwrap_or(""))
+                            .show_ui(ui, |ui| {
+                                if ui.selectable_label(false, "None").clicked()
+                                {
+                                    new_trim = Some(None);
+                                }
+
+                                for trim in &trims
+                                {
+                                    let checked = current.as_ref() == Some(trim);
+
+                                    if ui.selectable_label(checked, trim).clicked()
+                                    {
+                                        new_trim = Some(Some(trim.as_str()));
+                                    }
+                                }
+                            });
+                    });
+
+                    let new_trim = return_if_none!(new_trim);
+                    bundle.manager.set_selected_textures_trim(bundle.edits_history, new_trim);
+                });
+            });
+    }
+
     /// Sets the sprite value of the selected textures.
     #[inline]
     fn set_sprite(&mut self, strip: egui_extras::StripBuilder, bundle: &mut Bundle)
@@ -913,6 +1204,7 @@ impl TextureEditor
         let StateUpdateBundle {
             egui_context,
             drawing_resources,
+            config,
             ..
         } = bundle;
 
@@ -922,7 +1214,8 @@ impl TextureEditor
             edits_history,
             clipboard,
             inputs,
-            settings
+            settings,
+            recent_amount: config.recent_textures.amount()
         };
 
         self.window
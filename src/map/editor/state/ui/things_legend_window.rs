@@ -0,0 +1,145 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::math::UVec2;
+use bevy_egui::egui;
+use hill_vacuum_shared::return_if_none;
+
+use super::{window::Window, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{
+        containers::hv_hash_map,
+        editor::{
+            state::{format_texture_preview, manager::EntitiesManager},
+            StateUpdateBundle
+        },
+        thing::{ThingId, ThingInterface},
+        HvHashMap
+    },
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The size of the frame of the things' previews.
+const PREVIEW_SIZE: f32 = 48f32;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The window listing the things of the catalog along with the amount of placed instances.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct ThingsLegendWindow
+{
+    /// The window data.
+    window: Window
+}
+
+impl Toggle for ThingsLegendWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle(); }
+}
+
+impl WindowCloserInfo for ThingsLegendWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the window close.
+        #[inline]
+        fn close(things_legend: &mut ThingsLegendWindow) { things_legend.window.close(); }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::ThingsLegend(id, close as fn(&mut Self)))
+    }
+}
+
+impl ThingsLegendWindow
+{
+    /// Shows the things legend window. Clicking an entry selects the associated thing in the
+    /// catalog. Returns whether an entry was clicked.
+    #[inline]
+    #[must_use]
+    pub fn show(&mut self, bundle: &mut StateUpdateBundle, manager: &EntitiesManager) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let StateUpdateBundle {
+            egui_context,
+            things_catalog,
+            drawing_resources,
+            ..
+        } = bundle;
+
+        let mut placed = hv_hash_map![];
+
+        for thing in manager.things()
+        {
+            *placed.entry(thing.thing()).or_insert(0usize) += 1;
+        }
+
+        let clicked = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new("Things legend")
+                    .vscroll(true)
+                    .collapsible(true)
+                    .resizable(true),
+                |ui| Self::legend(ui, things_catalog.legend(drawing_resources), &placed)
+            )
+            .flatten();
+
+        things_catalog.set_selected_thing_index(return_if_none!(clicked, false));
+        true
+    }
+
+    /// Shows the legend entries. Returns the index of the clicked thing, if any.
+    #[inline]
+    fn legend<'a>(
+        ui: &mut egui::Ui,
+        things: impl ExactSizeIterator<Item = (usize, ThingId, egui::TextureId, UVec2, &'a str)>,
+        placed: &HvHashMap<ThingId, usize>
+    ) -> Option<usize>
+    {
+        if things.len() == 0
+        {
+            ui.label("No things in the catalog");
+            return None;
+        }
+
+        let mut clicked = None;
+
+        egui::Grid::new("things_legend").num_columns(3).striped(true).show(ui, |ui| {
+            for (index, id, texture, size, name) in things
+            {
+                if format_texture_preview!(ImageButton, ui, texture, size, PREVIEW_SIZE).clicked()
+                {
+                    clicked = index.into();
+                }
+
+                if ui.selectable_label(false, name).clicked()
+                {
+                    clicked = index.into();
+                }
+
+                ui.label(format!("{} placed", placed.get(&id).copied().unwrap_or_default()));
+                ui.end_row();
+            }
+        });
+
+        clicked
+    }
+}
@@ -0,0 +1,196 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::{Transform, Vec2};
+use hill_vacuum_shared::return_if_none;
+
+use crate::{
+    map::containers::{hv_vec, HvVec},
+    utils::{math::AroundEqual, misc::Camera}
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum amount of stored views.
+const MAX_VIEWS: usize = 32;
+/// The time, in seconds, the camera must stay still for the view to be stored.
+const SETTLE_TIME: f32 = 0.5;
+/// The minimum distance, in screen pixels, the camera must be moved for the view to be
+/// considered a new one.
+const MIN_DISTANCE: f32 = 64f32;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A position and scale of the camera.
+#[must_use]
+#[derive(Clone, Copy, PartialEq)]
+struct View
+{
+    /// The position of the camera.
+    pos:   Vec2,
+    /// The scale of the camera.
+    scale: f32
+}
+
+impl View
+{
+    /// Returns a new [`View`] describing the state of `camera`.
+    #[inline]
+    fn new(camera: &Transform) -> Self
+    {
+        Self {
+            pos:   camera.pos(),
+            scale: camera.scale()
+        }
+    }
+
+    /// Whether `self` and `other` are so close they should be considered the same view.
+    #[inline]
+    #[must_use]
+    fn similar(&self, other: &Self) -> bool
+    {
+        self.scale.around_equal_narrow(&other.scale) && self.pos.distance(other.pos) < MIN_DISTANCE * self.scale
+    }
+
+    /// Sets the state of `camera` to the one of `self`.
+    #[inline]
+    fn apply(&self, camera: &mut Transform)
+    {
+        camera.set_zoom_percentage(100f32 / self.scale);
+        camera.set_pos(self.pos);
+    }
+}
+
+//=======================================================================//
+
+/// The history of the views of the map, which can be stepped through back and forth without
+/// affecting the edits history.
+#[must_use]
+pub(in crate::map::editor::state) struct ViewHistory
+{
+    /// The stored views.
+    views:      HvVec<View>,
+    /// The index of the current view in `views`.
+    index:      usize,
+    /// The state of the camera in the previous frame.
+    last:       Option<View>,
+    /// The time the camera has been still since it was last moved, if it has not been stored yet.
+    still_time: Option<f32>
+}
+
+impl Default for ViewHistory
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            views:      hv_vec![],
+            index:      0,
+            last:       None,
+            still_time: None
+        }
+    }
+}
+
+impl ViewHistory
+{
+    /// Whether there is a previous view to go back to.
+    #[inline]
+    #[must_use]
+    pub const fn has_previous(&self) -> bool { self.index != 0 }
+
+    /// Whether there is a following view to go forward to.
+    #[inline]
+    #[must_use]
+    pub fn has_next(&self) -> bool { self.index + 1 < self.views.len() }
+
+    /// Updates the history, storing the state of `camera` if it has been still for long enough
+    /// after being moved.
+    #[inline]
+    pub fn update(&mut self, camera: &Transform, delta_time: f32)
+    {
+        let view = View::new(camera);
+
+        if self.views.is_empty()
+        {
+            self.views.push(view);
+            self.last = view.into();
+            return;
+        }
+
+        if self.last != Some(view)
+        {
+            self.last = view.into();
+            self.still_time = 0f32.into();
+            return;
+        }
+
+        let still_time = return_if_none!(self.still_time.as_mut());
+        *still_time += delta_time;
+
+        if *still_time < SETTLE_TIME
+        {
+            return;
+        }
+
+        self.still_time = None;
+
+        if view.similar(&self.views[self.index])
+        {
+            return;
+        }
+
+        self.views.truncate(self.index + 1);
+        self.views.push(view);
+
+        if self.views.len() > MAX_VIEWS
+        {
+            _ = self.views.remove(0);
+        }
+
+        self.index = self.views.len() - 1;
+    }
+
+    /// Moves `camera` to the previous view, if any.
+    #[inline]
+    pub fn previous(&mut self, camera: &mut Transform)
+    {
+        if !self.has_previous()
+        {
+            return;
+        }
+
+        self.index -= 1;
+        self.apply(camera);
+    }
+
+    /// Moves `camera` to the following view, if any.
+    #[inline]
+    pub fn next(&mut self, camera: &mut Transform)
+    {
+        if !self.has_next()
+        {
+            return;
+        }
+
+        self.index += 1;
+        self.apply(camera);
+    }
+
+    /// Sets the state of `camera` to the current view.
+    #[inline]
+    fn apply(&mut self, camera: &mut Transform)
+    {
+        self.views[self.index].apply(camera);
+        self.last = View::new(camera).into();
+        self.still_time = None;
+    }
+}
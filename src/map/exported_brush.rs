@@ -0,0 +1,159 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::{mover::Mover, BrushViewer},
+        containers::{hv_vec, HvHashMap, HvVec}
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The coordinate space of the exported brush vertexes.
+#[must_use]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VertexesCoordinates
+{
+    /// Absolute world coordinates.
+    #[default]
+    Absolute,
+    /// Relative to the area-weighted centroid of the brush, so that brushes with the same shape
+    /// have the same vertexes and only differ by their origin.
+    Local
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The vertexes of the polygon of a brush.
+#[must_use]
+pub struct ExportedBrush
+{
+    /// The [`Id`] of the brush.
+    pub id:          Id,
+    /// The area-weighted centroid of the brush, which is the origin of `vertexes` if they are
+    /// expressed in [`VertexesCoordinates::Local`].
+    pub origin:      Vec2,
+    /// The coordinate space of `vertexes`.
+    pub coordinates: VertexesCoordinates,
+    /// The vertexes, in the same order as the ones of the brush.
+    pub vertexes:    HvVec<Vec2>
+}
+
+impl ExportedBrush
+{
+    /// Returns the [`ExportedBrush`]es of `brushes`, with the vertexes expressed in
+    /// `coordinates`.
+    #[inline]
+    pub(in crate::map) fn brushes<'a, I>(
+        brushes: I,
+        coordinates: VertexesCoordinates
+    ) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        hv_vec![collect; brushes.map(|brush| Self::new(brush, coordinates))]
+    }
+
+    /// Returns a new [`ExportedBrush`] generated from `brush`.
+    #[inline]
+    fn new(brush: &BrushViewer, coordinates: VertexesCoordinates) -> Self
+    {
+        let origin = brush.centroid();
+        let offset = match coordinates
+        {
+            VertexesCoordinates::Absolute => Vec2::ZERO,
+            VertexesCoordinates::Local => origin
+        };
+
+        Self {
+            id: brush.id,
+            origin,
+            coordinates,
+            vertexes: hv_vec![collect; brush.vertexes.iter().map(|vx| *vx - offset)]
+        }
+    }
+}
+
+//=======================================================================//
+
+/// A brush of an [`ExportedBrushGroup`].
+#[must_use]
+pub struct GroupMember
+{
+    /// The [`Id`] of the brush.
+    pub id:       Id,
+    /// The vertexes, relative to the origin of the group and in the same order as the ones of
+    /// the brush.
+    pub vertexes: HvVec<Vec2>
+}
+
+//=======================================================================//
+
+/// A brush exported together with the brushes attached to it, so that the group can be moved as a
+/// whole by moving its origin.
+#[must_use]
+pub struct ExportedBrushGroup
+{
+    /// The [`Id`] of the brush owning the group.
+    pub owner:   Id,
+    /// The area-weighted centroid of the owner, which is the origin of the vertexes of the
+    /// members.
+    pub origin:  Vec2,
+    /// The brushes of the group, the owner being the first one.
+    pub members: HvVec<GroupMember>
+}
+
+impl ExportedBrushGroup
+{
+    /// Returns the [`ExportedBrushGroup`]s of `brushes`. Every brush with attached brushes is
+    /// exported with them in a single group, while the brushes without attachments are exported
+    /// as single member groups. The groups are sorted by the id of their owner, and the attached
+    /// brushes by their id.
+    #[inline]
+    pub(in crate::map) fn groups(brushes: &HvHashMap<Id, BrushViewer>) -> HvVec<Self>
+    {
+        let mut groups = hv_vec![collect; brushes
+            .values()
+            .filter(|brush| brush.mover.is_anchored().is_none())
+            .map(|brush| {
+                let origin = brush.centroid();
+                let member = |brush: &BrushViewer| GroupMember {
+                    id:       brush.id,
+                    vertexes: hv_vec![collect; brush.vertexes.iter().map(|vx| *vx - origin)]
+                };
+
+                let mut members = hv_vec![member(brush)];
+                let attachments = match &brush.mover
+                {
+                    Mover::Anchors(ids) => Some(ids),
+                    Mover::Motor(motor) if motor.has_anchors() => Some(motor.anchored_brushes()),
+                    _ => None
+                };
+
+                if let Some(ids) = attachments
+                {
+                    let mut attached =
+                        ids.iter().filter_map(|id| brushes.get(id)).collect::<Vec<_>>();
+                    attached.sort_by_key(|brush| brush.id.value());
+                    members.extend(attached.into_iter().map(member));
+                }
+
+                Self { owner: brush.id, origin, members }
+            })];
+
+        groups.sort_by(|a, b| a.owner.value().cmp(&b.owner.value()));
+        groups
+    }
+}
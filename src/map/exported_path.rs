@@ -0,0 +1,100 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        path::Path,
+        thing::ThingViewer
+    },
+    utils::{identifiers::Id, math::points::vxs_center}
+};
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The coordinate space of the positions of the exported path nodes.
+#[must_use]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NodesCoordinates
+{
+    /// Relative to the center of the entity owning the path.
+    #[default]
+    Relative,
+    /// Absolute world coordinates, that is the center of the entity plus the relative position.
+    Absolute
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The positions of the nodes of the path of an entity.
+#[must_use]
+pub struct ExportedPath
+{
+    /// The [`Id`] of the entity owning the path.
+    pub id:          Id,
+    /// The center of the entity owning the path.
+    pub center:      Vec2,
+    /// The coordinate space of `positions`.
+    pub coordinates: NodesCoordinates,
+    /// The positions of the nodes, in the same order as [`Path::nodes`].
+    pub positions:   HvVec<Vec2>
+}
+
+impl ExportedPath
+{
+    /// Returns the [`ExportedPath`]s of the entities in `brushes` and `things` that have a path,
+    /// with the node positions expressed in `coordinates`.
+    #[inline]
+    pub(in crate::map) fn paths<'a, B, T>(
+        brushes: B,
+        things: T,
+        coordinates: NodesCoordinates
+    ) -> HvVec<Self>
+    where
+        B: Iterator<Item = &'a BrushViewer>,
+        T: Iterator<Item = &'a ThingViewer>
+    {
+        let brushes = brushes.filter_map(|brush| {
+            brush
+                .mover
+                .path()
+                .map(|path| (brush.id, vxs_center(brush.vertexes.iter().copied()), path))
+        });
+        let things = things
+            .filter_map(|thing| thing.path.as_ref().map(|path| (thing.id, thing.pos, path)));
+
+        hv_vec![collect; brushes
+            .chain(things)
+            .map(|(id, center, path)| Self::new(id, center, path, coordinates))]
+    }
+
+    /// Returns a new [`ExportedPath`] generated from `path`, owned by the entity with [`Id`] `id`
+    /// and center `center`.
+    #[inline]
+    fn new(id: Id, center: Vec2, path: &Path, coordinates: NodesCoordinates) -> Self
+    {
+        let origin = match coordinates
+        {
+            NodesCoordinates::Relative => Vec2::ZERO,
+            NodesCoordinates::Absolute => center
+        };
+
+        Self {
+            id,
+            center,
+            coordinates,
+            positions: hv_vec![collect; path.nodes().iter().map(|node| node.world_pos(origin))]
+        }
+    }
+}
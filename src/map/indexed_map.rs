@@ -26,6 +26,7 @@ where
     /// The ordered vector of values
     vec: HvVec<T>,
     /// The keys with the indexes of the associated values contained in `vec`.
+    #[serde(serialize_with = "serialize_by_index", bound(serialize = "K: Serialize"))]
     map: HvHashMap<K, usize>
 }
 
@@ -134,6 +135,10 @@ where
         })
     }
 
+    /// Returns an iterator to the references of the contained values.
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &T> { self.vec.iter() }
+
     /// Returns an iterator to the mutable references of the contained values.
     #[inline]
     pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> { self.vec.iter_mut() }
@@ -141,4 +146,26 @@ where
     /// Returns a [`Chunks`] iterator with `chunk_size` to the contained values.
     #[inline]
     pub fn chunks(&self, chunk_size: usize) -> Chunks<T> { self.vec.chunks(chunk_size) }
+
+    /// Consumes `self` and returns the contained values.
+    #[inline]
+    pub fn into_values(self) -> HvVec<T> { self.vec }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Serializes the keys and indexes of `map` sorted by index, so that the serialized bytes do not
+/// depend on the iteration order of the map.
+#[inline]
+fn serialize_by_index<K, S>(map: &HvHashMap<K, usize>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    S: serde::Serializer
+{
+    let mut pairs = map.iter().collect::<Vec<_>>();
+    pairs.sort_unstable_by_key(|(_, index)| **index);
+    serializer.collect_seq(pairs)
 }
@@ -0,0 +1,140 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        properties::Value
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The color of the lights that do not specify a valid one.
+const DEFAULT_COLOR: [f32; 3] = [1f32; 3];
+/// The intensity of the lights that do not specify a valid one.
+const DEFAULT_INTENSITY: f32 = 1f32;
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the RGB color, with channels ranging from 0 to 1, described by the hexadecimal
+/// `#RRGGBB` or `RRGGBB` string contained in `value`, if any.
+#[inline]
+#[must_use]
+fn parse_color(value: &Value) -> Option<[f32; 3]>
+{
+    let hex = match value
+    {
+        Value::String(string) => string.trim().trim_start_matches('#'),
+        _ => return None
+    };
+
+    if hex.len() != 6
+    {
+        return None;
+    }
+
+    let mut color = [0f32; 3];
+
+    for (i, channel) in color.iter_mut().enumerate()
+    {
+        *channel = f32::from(u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?) / 255f32;
+    }
+
+    Some(color)
+}
+
+//=======================================================================//
+
+/// Returns the intensity described by the numeric `value`, if any.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+#[inline]
+#[must_use]
+fn parse_intensity(value: &Value) -> Option<f32>
+{
+    let intensity = match value
+    {
+        Value::U8(value) => f32::from(*value),
+        Value::U16(value) => f32::from(*value),
+        Value::U32(value) => *value as f32,
+        Value::U64(value) => *value as f32,
+        Value::U128(value) => *value as f32,
+        Value::I8(value) => f32::from(*value),
+        Value::I16(value) => f32::from(*value),
+        Value::I32(value) => *value as f32,
+        Value::I64(value) => *value as f32,
+        Value::I128(value) => *value as f32,
+        Value::F32(value) => *value,
+        Value::F64(value) => *value as f32,
+        Value::Bool(_) | Value::String(_) => return None
+    };
+
+    (intensity.is_finite() && intensity >= 0f32).then_some(intensity)
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// An area light generated from an emissive brush.
+#[must_use]
+pub struct AreaLight
+{
+    /// The [`Id`] of the brush.
+    pub id:        Id,
+    /// The vertexes of the polygon, in counter-clockwise order.
+    pub vertexes:  HvVec<Vec2>,
+    /// The RGB color, with channels ranging from 0 to 1.
+    pub color:     [f32; 3],
+    /// The intensity.
+    pub intensity: f32
+}
+
+impl AreaLight
+{
+    /// Returns the [`AreaLight`]s generated from the brushes in `brushes` that have the property
+    /// `emissive` set to true.
+    /// The color is read from the `color` property, which must be a string in the `#RRGGBB`
+    /// format, and the intensity from the numeric `intensity` property. If either is missing or
+    /// invalid white and 1 are used respectively.
+    #[inline]
+    pub(in crate::map) fn lights<'a, I>(
+        brushes: I,
+        emissive: &str,
+        color: &str,
+        intensity: &str
+    ) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        hv_vec![collect; brushes
+            .filter(|brush| matches!(brush.properties.get(emissive), Some(Value::Bool(true))))
+            .map(|brush| Self {
+                id:        brush.id,
+                vertexes:  brush.vertexes.clone(),
+                color:     brush
+                    .properties
+                    .get(color)
+                    .and_then(parse_color)
+                    .unwrap_or(DEFAULT_COLOR),
+                intensity: brush
+                    .properties
+                    .get(intensity)
+                    .and_then(parse_intensity)
+                    .unwrap_or(DEFAULT_INTENSITY)
+            })]
+    }
+}
@@ -0,0 +1,158 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::{UVec2, Vec2};
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        drawer::texture::{TextureInterface, TextureSettings}
+    },
+    utils::{hull::Hull, identifiers::Id, math::points::rotate_point_around_origin}
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The empty space left on each side of a brush inside its atlas cell, as a fraction of the size
+/// of the cell.
+const CELL_PADDING: f32 = 1f32 / 16f32;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The triangulated surface of a brush with a lightmap UV layout.
+#[must_use]
+pub struct LightmapMesh
+{
+    /// The [`Id`] of the brush the mesh was generated from.
+    pub id:        Id,
+    /// The positions of the vertexes.
+    pub positions: HvVec<Vec2>,
+    /// The texture UV coordinates of the vertexes, as authored in the editor. [`None`] if the
+    /// brush has no texture, its texture is rendered as a sprite, or its size is unknown.
+    pub uvs:       Option<HvVec<Vec2>>,
+    /// The lightmap UV coordinates of the vertexes, within the atlas cell assigned to the brush.
+    pub uv2s:      HvVec<Vec2>,
+    /// The indexes of the vertexes of the triangles, in counter-clockwise order.
+    pub indexes:   HvVec<u32>
+}
+
+impl LightmapMesh
+{
+    /// Returns the [`LightmapMesh`]es of `brushes`.
+    /// Each brush is assigned a cell of a square grid covering the `[0, 1]` UV range, and its
+    /// lightmap UVs are a planar projection scaled to fit the cell preserving the aspect ratio.
+    /// `texture_size` must return the size of the texture with the requested name, if known.
+    /// `trim_region` must return the top left corner and the size, in pixels, of the trim region
+    /// with the requested name of the texture with the requested name, if known.
+    #[inline]
+    pub(in crate::map) fn meshes<'a, I, F, T>(
+        brushes: I,
+        texture_size: F,
+        trim_region: T
+    ) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>,
+        F: Fn(&str) -> Option<UVec2>,
+        T: Fn(&str, &str) -> Option<(UVec2, UVec2)>
+    {
+        let brushes = hv_vec![collect; brushes];
+
+        if brushes.is_empty()
+        {
+            return hv_vec![];
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        #[allow(clippy::cast_precision_loss)]
+        let columns = (brushes.len() as f32).sqrt().ceil() as usize;
+        #[allow(clippy::cast_precision_loss)]
+        let cell_size = 1f32 / columns as f32;
+
+        hv_vec![collect; brushes.iter().enumerate().map(|(i, brush)| {
+            #[allow(clippy::cast_precision_loss)]
+            let cell = Vec2::new((i % columns) as f32, (i / columns) as f32) * cell_size;
+
+            Self {
+                id:        brush.id,
+                positions: hv_vec![collect; brush.vertexes.iter().copied()],
+                uvs:       brush.texture.as_ref().and_then(|settings| {
+                    Self::texture_uvs(&brush.vertexes, settings, &texture_size, &trim_region)
+                }),
+                uv2s:      Self::lightmap_uvs(&brush.vertexes, cell, cell_size),
+                indexes:   Self::triangles(brush.vertexes.len())
+            }
+        })]
+    }
+
+    /// Returns the texture UV coordinates of `vertexes`, ignoring scroll and parallax.
+    /// If the texture is used as a trim sheet the coordinates are computed relative to the trim
+    /// region and then mapped inside it, as in the editor.
+    #[inline]
+    fn texture_uvs<F, T>(
+        vertexes: &HvVec<Vec2>,
+        settings: &TextureSettings,
+        texture_size: &F,
+        trim_region: &T
+    ) -> Option<HvVec<Vec2>>
+    where
+        F: Fn(&str) -> Option<UVec2>,
+        T: Fn(&str, &str) -> Option<(UVec2, UVec2)>
+    {
+        if settings.sprite()
+        {
+            return None;
+        }
+
+        let size = texture_size(settings.name())?.as_vec2();
+        let trim = settings.trim().and_then(|trim| trim_region(settings.name(), trim));
+        let region_size = trim.map_or(size, |(_, region_size)| region_size.as_vec2());
+        let scaled_size = region_size * Vec2::new(settings.scale_x(), settings.scale_y());
+        let angle = settings.angle().to_radians();
+
+        hv_vec![collect; vertexes.iter().map(|vx| {
+            let vx = if angle == 0f32 { *vx } else { rotate_point_around_origin(*vx, angle) };
+            let uv =
+                Vec2::new(vx.x + settings.offset_x(), -vx.y + settings.offset_y()) / scaled_size;
+
+            match trim
+            {
+                Some((pos, _)) => (pos.as_vec2() + uv * region_size) / size,
+                None => uv
+            }
+        })]
+        .into()
+    }
+
+    /// Returns the lightmap UV coordinates of `vertexes` fit inside the cell with top left corner
+    /// `cell` and side `cell_size`.
+    #[inline]
+    fn lightmap_uvs(vertexes: &HvVec<Vec2>, cell: Vec2, cell_size: f32) -> HvVec<Vec2>
+    {
+        let hull = Hull::from_points(vertexes.iter().copied()).unwrap();
+        let padding = cell_size * CELL_PADDING;
+        let scale = (cell_size - padding * 2f32) / hull.width().max(hull.height());
+        let origin = cell + padding;
+
+        hv_vec![collect; vertexes
+            .iter()
+            .map(|vx| origin + Vec2::new(vx.x - hull.left(), hull.top() - vx.y) * scale)]
+    }
+
+    /// Returns the indexes of the triangles of a convex polygon with `len` vertexes.
+    #[inline]
+    fn triangles(len: usize) -> HvVec<u32>
+    {
+        let len = u32::try_from(len).unwrap();
+        hv_vec![collect; (1..len - 1).flat_map(|i| [0, i, i + 1])]
+    }
+}
@@ -0,0 +1,272 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    path::PathBuf,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Mutex
+    }
+};
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::{RenderAssetUsages, RenderAssets},
+        render_resource::{
+            BufferDescriptor,
+            BufferUsages,
+            CommandEncoderDescriptor,
+            Extent3d,
+            ImageCopyBuffer,
+            ImageDataLayout,
+            Maintain,
+            MapMode,
+            TextureDescriptor,
+            TextureDimension,
+            TextureFormat,
+            TextureUsages
+        },
+        renderer::{RenderDevice, RenderQueue},
+        texture::GpuImage,
+        Extract,
+        ExtractSchedule,
+        Render,
+        RenderApp,
+        RenderSet
+    }
+};
+use hill_vacuum_shared::{return_if_err, return_if_none};
+
+use crate::error_message;
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The format of the rendered images.
+const FORMAT: TextureFormat = TextureFormat::Bgra8UnormSrgb;
+/// The bytes of a pixel of the rendered images.
+const PIXEL_SIZE: usize = 4;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The marker of the camera that renders the map to an image file.
+#[derive(Component)]
+pub(in crate::map) struct MapRenderCamera;
+
+//=======================================================================//
+
+/// The image rendered by the [`MapRenderCamera`] that should be read back from the GPU in the
+/// current frame, and the path of the file it should be saved to.
+#[must_use]
+#[derive(Resource, Clone, Default)]
+pub(in crate::map) struct MapRenderRequest(Option<(Handle<Image>, PathBuf)>);
+
+impl MapRenderRequest
+{
+    /// Requests the render `image` to be saved to the file at `path`.
+    #[inline]
+    pub fn set(&mut self, image: Handle<Image>, path: PathBuf) { self.0 = (image, path).into(); }
+
+    /// Clears the request.
+    #[inline]
+    pub fn clear(&mut self) { self.0 = None; }
+}
+
+//=======================================================================//
+
+/// The outcomes of the saves of the rendered images, received from the render world.
+#[derive(Resource)]
+struct MapRenderResults(Mutex<Receiver<Result<(), &'static str>>>);
+
+//=======================================================================//
+
+/// The sender of the outcomes of the saves of the rendered images to the main world.
+#[derive(Resource)]
+struct MapRenderSender(Sender<Result<(), &'static str>>);
+
+//=======================================================================//
+
+/// The plugin that reads back the images rendered by the [`MapRenderCamera`] and saves them to
+/// file.
+pub(in crate::map) struct MapRenderPlugin;
+
+impl Plugin for MapRenderPlugin
+{
+    #[inline]
+    fn build(&self, app: &mut App)
+    {
+        let (sender, receiver) = channel();
+
+        app.init_resource::<MapRenderRequest>()
+            .insert_resource(MapRenderResults(Mutex::new(receiver)))
+            .add_systems(Update, report_map_render_errors);
+
+        return_if_err!(app.get_sub_app_mut(RenderApp))
+            .init_resource::<MapRenderRequest>()
+            .insert_resource(MapRenderSender(sender))
+            .add_systems(ExtractSchedule, extract_map_render_request)
+            .add_systems(
+                Render,
+                save_map_render.after(RenderSet::Render).before(RenderSet::Cleanup)
+            );
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns a new [`Image`] the [`MapRenderCamera`] can render to and that can be read back from
+/// the GPU.
+#[inline]
+#[must_use]
+pub(in crate::map) fn image(size: UVec2) -> Image
+{
+    let size = Extent3d {
+        width:                 size.x,
+        height:                size.y,
+        depth_or_array_layers: 1
+    };
+
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: FORMAT,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING |
+                TextureUsages::COPY_DST |
+                TextureUsages::COPY_SRC |
+                TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[]
+        },
+        ..Default::default()
+    };
+    image.resize(size);
+
+    image
+}
+
+//=======================================================================//
+
+/// Shows the errors that occurred while saving the rendered images.
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+fn report_map_render_errors(results: Res<MapRenderResults>)
+{
+    for result in results.0.lock().unwrap().try_iter()
+    {
+        if let Err(err) = result
+        {
+            error_message(err);
+        }
+    }
+}
+
+//=======================================================================//
+
+/// Copies the [`MapRenderRequest`] to the render world.
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+fn extract_map_render_request(
+    mut render_request: ResMut<MapRenderRequest>,
+    request: Extract<Res<MapRenderRequest>>
+)
+{
+    render_request.clone_from(&request);
+}
+
+//=======================================================================//
+
+/// Saves the image requested by the [`MapRenderRequest`] once it has been rendered.
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+fn save_map_render(
+    request: Res<MapRenderRequest>,
+    images: Res<RenderAssets<Image>>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    sender: Res<MapRenderSender>
+)
+{
+    let (image, path) = return_if_none!(request.0.as_ref());
+
+    let result = images
+        .get(image)
+        .ok_or("The map render is not ready")
+        .and_then(|image| read_image(image, &render_device, &render_queue))
+        .and_then(|image| image.try_into_dynamic().map_err(|_| "Error converting the map render"))
+        .and_then(|image| image.to_rgba8().save(path).map_err(|_| "Error saving the map render"));
+
+    _ = sender.0.send(result);
+}
+
+//=======================================================================//
+
+/// Copies the content of `gpu_image` from the GPU.
+#[inline]
+fn read_image(
+    gpu_image: &GpuImage,
+    render_device: &RenderDevice,
+    render_queue: &RenderQueue
+) -> Result<Image, &'static str>
+{
+    let size = gpu_image.texture.size();
+    let row_size = size.width as usize * PIXEL_SIZE;
+    let padded_row_size = RenderDevice::align_copy_bytes_per_row(row_size);
+
+    let buffer = render_device.create_buffer(&BufferDescriptor {
+        label:              None,
+        size:               (padded_row_size * size.height as usize) as u64,
+        usage:              BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+        mapped_at_creation: false
+    });
+
+    let mut encoder = render_device.create_command_encoder(&CommandEncoderDescriptor::default());
+    encoder.copy_texture_to_buffer(
+        gpu_image.texture.as_image_copy(),
+        ImageCopyBuffer {
+            buffer: &buffer,
+            layout: ImageDataLayout {
+                offset:         0,
+                bytes_per_row:  u32::try_from(padded_row_size).ok(),
+                rows_per_image: None
+            }
+        },
+        size
+    );
+    render_queue.submit([encoder.finish()]);
+
+    // Wait for the copy to complete.
+    let slice = buffer.slice(..);
+    let (sender, receiver) = channel();
+    slice.map_async(MapMode::Read, move |result| _ = sender.send(result));
+    _ = render_device.poll(Maintain::Wait);
+
+    if !matches!(receiver.recv(), Ok(Ok(())))
+    {
+        return Err("Error reading the map render");
+    }
+
+    // Remove the row padding required by the copy.
+    let data = slice
+        .get_mapped_range()
+        .chunks(padded_row_size)
+        .flat_map(|row| &row[..row_size])
+        .copied()
+        .collect::<Vec<_>>();
+    buffer.unmap();
+
+    Ok(Image::new(size, TextureDimension::D2, data, FORMAT, RenderAssetUsages::default()))
+}
@@ -1,20 +1,40 @@
+mod binary;
 pub mod brush;
 mod camera;
+pub mod collider;
+pub mod collision_edge;
 pub mod containers;
+pub mod custom_tool;
+pub mod decal;
 pub mod drawer;
 mod editor;
+pub mod exported_brush;
+pub mod exported_path;
 mod indexed_map;
+pub mod light;
+pub mod lightmap;
+mod map_render;
+pub mod navmesh;
 pub mod path;
 pub mod properties;
+pub mod properties_editor;
+pub mod render_pass;
 mod selectable_vector;
 pub mod thing;
+mod tmx;
 
 //=======================================================================//
 // IMPORTS
 //
 //=======================================================================//
 
-use std::{fs::File, hash::Hash, io::BufReader, ops::RangeInclusive, path::PathBuf};
+use std::{
+    fs::File,
+    hash::Hash,
+    io::{BufReader, Read, Seek, SeekFrom, Write},
+    ops::RangeInclusive,
+    path::PathBuf
+};
 
 use bevy::{
     input::mouse::MouseWheel,
@@ -40,7 +60,10 @@ use serde::{Deserialize, Serialize};
 
 use self::{
     camera::init_camera_transform,
+    collider::ThingCollider,
+    collision_edge::CollisionEdge,
     containers::{hv_vec, HvHashMap, HvVec},
+    custom_tool::CustomTools,
     drawer::{
         color::Color,
         drawing_resources::DrawingResources,
@@ -51,7 +74,16 @@ use self::{
         Editor,
         Placeholder
     },
-    properties::{BrushProperties, ThingProperties}
+    decal::Decal,
+    exported_brush::{ExportedBrush, ExportedBrushGroup, VertexesCoordinates},
+    exported_path::{ExportedPath, NodesCoordinates},
+    light::AreaLight,
+    lightmap::LightmapMesh,
+    map_render::{MapRenderCamera, MapRenderPlugin, MapRenderRequest},
+    navmesh::{BrushesAdjacency, NavMeshRegion},
+    path::calc_swept_path_hull,
+    properties::{BrushProperties, DefaultProperties, ThingProperties},
+    render_pass::RenderPass
 };
 use crate::{
     config::Config,
@@ -77,16 +109,17 @@ use crate::{
 //
 //=======================================================================//
 
-/// The size of half of the map square.
-const MAP_HALF_SIZE: f32 = 16384f32;
+/// The size of half of the map square, the largest area the entities can ever occupy.
+/// It is four times the default map bound so that the maps whose worlds do not fit in the default
+/// area can raise their bound up to it. The quad trees of the entities and the cameras of the props
+/// are laid out around this square, so it cannot depend on the bound of the map being edited.
+const MAP_HALF_SIZE: f32 = 65536f32;
 /// The size of the map square.
 const MAP_SIZE: f32 = MAP_HALF_SIZE * 2f32;
-/// The range of the map dimensions.
-const MAP_RANGE: RangeInclusive<f32> = -MAP_HALF_SIZE..=MAP_HALF_SIZE;
-/// The [`Hull`] representing the map's area.
-const MAP_RECT: Hull = unsafe {
-    std::mem::transmute::<_, Hull>([MAP_HALF_SIZE, -MAP_HALF_SIZE, -MAP_HALF_SIZE, MAP_HALF_SIZE])
-};
+/// The default size of half of the square the entities of a map can occupy.
+const DEFAULT_MAP_BOUND: f32 = 16384f32;
+/// The range of the sizes of half of the square the entities of a map can occupy.
+const MAP_BOUND_RANGE: RangeInclusive<f32> = 1024f32..=MAP_HALF_SIZE;
 
 /// The general offset of the tooltips.
 const TOOLTIP_OFFSET: egui::Vec2 = egui::Vec2::new(0f32, -12.5);
@@ -99,38 +132,39 @@ const TOOLTIP_OFFSET: egui::Vec2 = egui::Vec2::new(0f32, -12.5);
 /// A trait to determine wherever an entity fits within the map's bounds.
 pub trait OutOfBounds
 {
-    /// Whether the entity fits within the map bounds.
+    /// Whether the entity does not fit within the map bounds, `bound` being the size of half of
+    /// the square the entities of the map can occupy.
     #[must_use]
-    fn out_of_bounds(&self) -> bool;
+    fn out_of_bounds(&self, bound: f32) -> bool;
 }
 
 impl OutOfBounds for Hull
 {
     #[inline]
-    fn out_of_bounds(&self) -> bool
+    fn out_of_bounds(&self, bound: f32) -> bool
     {
-        self.top() > MAP_RECT.top() ||
-            self.bottom() < MAP_RECT.bottom() ||
-            self.left() < MAP_RECT.left() ||
-            self.right() > MAP_RECT.right()
+        self.top() > bound || self.bottom() < -bound || self.left() < -bound || self.right() > bound
     }
 }
 
 impl OutOfBounds for Vec2
 {
     #[inline]
-    fn out_of_bounds(&self) -> bool { !MAP_RECT.contains_point(*self) }
+    fn out_of_bounds(&self, bound: f32) -> bool
+    {
+        self.x.out_of_bounds(bound) || self.y.out_of_bounds(bound)
+    }
 }
 
 impl OutOfBounds for f32
 {
     #[inline]
-    fn out_of_bounds(&self) -> bool { self.abs() > MAP_HALF_SIZE }
+    fn out_of_bounds(&self, bound: f32) -> bool { self.abs() > bound }
 }
 
 impl<T: EntityHull> OutOfBounds for T
 {
-    fn out_of_bounds(&self) -> bool { self.hull().out_of_bounds() }
+    fn out_of_bounds(&self, bound: f32) -> bool { self.hull().out_of_bounds(bound) }
 }
 
 //=======================================================================//
@@ -205,7 +239,12 @@ type MainCameraQuery<'world, 'state, 'a> = Query<
     'world,
     'state,
     &'a Transform,
-    (With<Camera>, Without<PropCamera>, Without<PaintToolPropCamera>)
+    (
+        With<Camera>,
+        Without<PropCamera>,
+        Without<PaintToolPropCamera>,
+        Without<MapRenderCamera>
+    )
 >;
 
 //=======================================================================//
@@ -215,7 +254,12 @@ type MainCameraQueryMut<'world, 'state, 'a> = Query<
     'world,
     'state,
     &'a mut Transform,
-    (With<Camera>, Without<PropCamera>, Without<PaintToolPropCamera>)
+    (
+        With<Camera>,
+        Without<PropCamera>,
+        Without<PaintToolPropCamera>,
+        Without<MapRenderCamera>
+    )
 >;
 
 //=======================================================================//
@@ -236,6 +280,12 @@ type PaintToolCameraQueryMut<'world, 'state, 'a> = Query<
 
 //=======================================================================//
 
+/// The query of the mutable camera used to render the map to an image file.
+type MapRenderCameraQueryMut<'world, 'state, 'a> =
+    Query<'world, 'state, (&'a mut Camera, &'a mut Transform), With<MapRenderCamera>>;
+
+//=======================================================================//
+
 /// The plugin that builds the map editor.
 pub struct MapEditorPlugin;
 
@@ -246,7 +296,7 @@ impl Plugin for MapEditorPlugin
     {
         app
             // UI
-            .add_plugins(EguiPlugin)
+            .add_plugins((EguiPlugin, MapRenderPlugin))
             .add_systems(PreUpdate, clean_egui_inputs.after(EguiSet::ProcessInput).before(EguiSet::BeginFrame))
             // Init resources
             .insert_non_send_resource(unsafe { Editor::placeholder() })
@@ -270,6 +320,7 @@ impl Plugin for MapEditorPlugin
                 Update,
                 (
                     update_state,
+                    update_map_render,
                     update_active_tool,
                     apply_state_transition::<EditorState>
                 )
@@ -302,19 +353,73 @@ struct MapHeader
     /// The amount of animations.
     pub animations: usize,
     /// The amount of props.
-    pub props:      usize
+    pub props:      usize,
+    /// The size of half of the square the entities can occupy.
+    #[serde(default = "default_map_bound")]
+    pub bound:      f32,
+    /// The rotation of the grid, in degrees.
+    #[serde(default)]
+    pub grid_angle: f32,
+    /// The [`ContentHash`] of the brushes and things, computed when the map is saved. Absent in
+    /// the files saved before it was stored.
+    #[serde(default)]
+    pub hash:       Option<ContentHash>
+}
+
+//=======================================================================//
+
+/// A hash of the serialized brushes and things of a map file, which can be used to detect whether
+/// the content of the map changed between two saves.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentHash
+{
+    /// The version of the hashing algorithm used to compute `value`. Hashes with different
+    /// versions are not comparable.
+    pub version: u8,
+    /// The hash.
+    pub value:   u64
+}
+
+impl ContentHash
+{
+    /// The current version of the hashing algorithm.
+    /// - 1: 64 bit FNV-1a computed over the serialized bytes of the brushes and things.
+    pub const VERSION: u8 = 1;
+    /// The FNV-1a 64 bit offset basis.
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    /// The FNV-1a 64 bit prime.
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    /// Returns the [`ContentHash`] of `bytes`.
+    #[inline]
+    fn new(bytes: &[u8]) -> Self
+    {
+        Self {
+            version: Self::VERSION,
+            value:   bytes.iter().fold(Self::FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(Self::FNV_PRIME)
+            })
+        }
+    }
 }
 
 //=======================================================================//
 
 /// The struct used to read a map file and generate the brushes and things to be used to generate
-/// another file format.
+/// another file format. The third field is the [`ContentHash`] of the brushes and things, the
+/// fourth is the scale applied through [`Exporter::scale`].
 /// ```
 /// let exporter = hill_vacuum::Exporter::new(&std::env::args().collect::<Vec<_>>()[0]);
 /// // Your code.
 /// ```
 #[must_use]
-pub struct Exporter(pub HvHashMap<Id, crate::Brush>, pub HvHashMap<Id, crate::ThingInstance>);
+pub struct Exporter(
+    pub HvHashMap<Id, crate::Brush>,
+    pub HvHashMap<Id, crate::ThingInstance>,
+    pub ContentHash,
+    pub f32
+);
 
 impl Exporter
 {
@@ -331,25 +436,48 @@ impl Exporter
             Err(_) => return Err("Could not open the file")
         };
 
-        let mut file = BufReader::new(file);
+        Self::from_reader(&mut BufReader::new(file))
+    }
 
-        let header = match ciborium::from_reader::<MapHeader, _>(&mut file)
+    /// Returns a new [`Exporter`] generated from the map data read from `file`, unless there was
+    /// an error. Useful to export map data that is not stored in a file.
+    /// # Errors
+    /// Returns an error if there was an issue reading the map data.
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn from_reader<R: Read + Seek>(file: &mut R) -> Result<Self, &'static str>
+    {
+        let header = match ciborium::from_reader::<MapHeader, _>(&mut *file)
         {
             Ok(header) => header,
             Err(_) => return Err("Error reading file header")
         };
 
-        let animations = match DrawingResources::file_animations(header.animations, &mut file)
+        for _ in 0..2
+        {
+            if ciborium::from_reader::<DefaultProperties, _>(&mut *file).is_err()
+            {
+                return Err("Error reading default properties");
+            }
+        }
+
+        let animations = match DrawingResources::file_animations(header.animations, file)
         {
             Ok(animations) => animations,
             Err(_) => return Err("Error reading default animations")
         };
 
+        let content_start = match file.stream_position()
+        {
+            Ok(pos) => pos,
+            Err(_) => return Err("Error reading file")
+        };
+
         let mut brushes = hv_vec![];
 
         for _ in 0..header.brushes
         {
-            let brush = match ciborium::from_reader::<crate::map::brush::Brush, _>(&mut file)
+            let brush = match ciborium::from_reader::<crate::map::brush::Brush, _>(&mut *file)
             {
                 Ok(brush) => brush,
                 Err(_) => return Err("Error reading Brush")
@@ -395,7 +523,7 @@ impl Exporter
         for _ in 0..header.things
         {
             let thing =
-                match ciborium::from_reader::<crate::map::thing::ThingInstance, _>(&mut file)
+                match ciborium::from_reader::<crate::map::thing::ThingInstance, _>(&mut *file)
                 {
                     Ok(thing) => ThingViewer::new(thing),
                     Err(_) => return Err("Error reading ThingInstance")
@@ -404,6 +532,20 @@ impl Exporter
             things.asserted_insert((thing.id, thing));
         }
 
+        let hash = match header.hash
+        {
+            Some(hash) => hash,
+            // Files saved before the hash was stored in the header.
+            None =>
+            {
+                match Self::content_hash(file, content_start)
+                {
+                    Ok(hash) => hash,
+                    Err(_) => return Err("Error hashing file content")
+                }
+            }
+        };
+
         let mut brushes_map = hv_hash_map![];
 
         for brush in brushes
@@ -411,7 +553,211 @@ impl Exporter
             brushes_map.asserted_insert((brush.id, brush));
         }
 
-        Ok(Self(brushes_map, things))
+        Ok(Self(brushes_map, things, hash, 1f32))
+    }
+
+    /// Multiplies all the coordinates, sizes, path distances, and speeds of the brushes and things
+    /// by `factor`, as well as the scales, offsets, and scroll speeds of their textures. Only the
+    /// exported data is affected, the map file is left untouched. The applied scale accumulates in
+    /// the fourth field and is written in the header of [`Exporter::write_binary`].
+    /// # Panics
+    /// Panics if `factor` is not a finite value higher than 0.
+    #[inline]
+    pub fn scale(&mut self, factor: f32)
+    {
+        assert!(factor.is_finite() && factor > 0f32, "Invalid export scale {factor}.");
+
+        for brush in self.0.values_mut()
+        {
+            brush.export_scale(factor);
+        }
+
+        for thing in self.1.values_mut()
+        {
+            thing.export_scale(factor);
+        }
+
+        self.3 *= factor;
+    }
+
+    /// Returns the [`Hull`] encompassing all the brushes and things of the map, including the area
+    /// covered by their paths, or [`None`] if the map is empty.
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> Option<Hull>
+    {
+        let brushes = self.0.values().filter_map(|brush| {
+            let hull = Hull::from_points(brush.vertexes.iter().copied())?;
+
+            match brush.mover.path()
+            {
+                Some(path) => calc_swept_path_hull(path, &hull).into(),
+                None => hull.into()
+            }
+        });
+        let things = self.1.values().map(|thing| {
+            match &thing.path
+            {
+                Some(path) => calc_swept_path_hull(path, &thing.hull),
+                None => thing.hull
+            }
+        });
+
+        Hull::from_hulls_iter(brushes.chain(things))
+    }
+
+    /// Returns the [`Decal`]s of the brushes whose texture is rendered as a sprite, which are
+    /// visual-only and can therefore be loaded separately from the level geometry.
+    #[inline]
+    pub fn decals(&self) -> HvVec<Decal> { Decal::decals(self.0.values()) }
+
+    /// Returns an iterator to the brushes whose polygons are part of the level geometry, that is
+    /// those whose texture is not rendered as a sprite and those that have collision enabled.
+    /// The textures of the latter should be retrieved through [`Exporter::decals`].
+    #[inline]
+    pub fn polygons(&self) -> impl Iterator<Item = &crate::Brush>
+    {
+        self.0.values().filter(|brush| {
+            brush.collision || !brush.texture.as_ref().is_some_and(TextureInterface::sprite)
+        })
+    }
+
+    /// Writes the brushes to `writer` in a compact little-endian binary layout, distinct from the
+    /// map file format, meant to be loaded at runtime. The layout is described in the README.
+    /// # Errors
+    /// Returns an error if writing to `writer` fails.
+    #[inline]
+    pub fn write_binary(&self, writer: &mut impl Write) -> std::io::Result<()>
+    {
+        binary::write_brushes(self.0.values(), self.3, self.2, false, writer)
+    }
+
+    /// Writes the brushes to `writer` in the same binary layout as [`Exporter::write_binary`],
+    /// with each record followed by the area-weighted centroid and the bounding box of the brush.
+    /// # Errors
+    /// Returns an error if writing to `writer` fails.
+    #[inline]
+    pub fn write_binary_with_bounds(&self, writer: &mut impl Write) -> std::io::Result<()>
+    {
+        binary::write_brushes(self.0.values(), self.3, self.2, true, writer)
+    }
+
+    /// Writes the brushes and things to `writer` as a Tiled TMX map with tiles of size
+    /// `tile_size`, which is usually the grid size the map was edited with. The brushes are
+    /// written as polygon objects and the things as point objects, both with their properties,
+    /// and the coordinates are converted to the Tiled conventions. The layout is described in the
+    /// README.
+    /// # Errors
+    /// Returns an error if writing to `writer` fails.
+    /// # Panics
+    /// Panics if either dimension of `tile_size` is 0.
+    #[inline]
+    pub fn write_tmx(&self, writer: &mut impl Write, tile_size: UVec2) -> std::io::Result<()>
+    {
+        tmx::write_tmx(self.0.values(), self.1.values(), self.bounds(), tile_size, writer)
+    }
+
+    /// Returns the [`ExportedBrush`]es of the brushes, with the vertexes expressed in
+    /// `coordinates`. [`VertexesCoordinates::Local`] is useful to instance brushes with the same
+    /// shape by placing their shared geometry at their origins.
+    #[inline]
+    pub fn brushes_vertexes(&self, coordinates: VertexesCoordinates) -> HvVec<ExportedBrush>
+    {
+        ExportedBrush::brushes(self.0.values(), coordinates)
+    }
+
+    /// Returns the brushes grouped into [`ExportedBrushGroup`]s, each one containing a brush and
+    /// the brushes attached to it, with the vertexes relative to the centroid of the owner.
+    #[inline]
+    pub fn brush_groups(&self) -> HvVec<ExportedBrushGroup> { ExportedBrushGroup::groups(&self.0) }
+
+    /// Returns the [`ExportedPath`]s of the brushes and things that have a path, with the
+    /// positions of the nodes expressed in `coordinates`.
+    #[inline]
+    pub fn paths(&self, coordinates: NodesCoordinates) -> HvVec<ExportedPath>
+    {
+        ExportedPath::paths(self.0.values(), self.1.values(), coordinates)
+    }
+
+    /// Returns the [`AreaLight`]s generated from the brushes with the boolean property `emissive`
+    /// set to true, with the color and intensity read from the `color` and `intensity`
+    /// properties.
+    #[inline]
+    pub fn area_lights(&self, emissive: &str, color: &str, intensity: &str) -> HvVec<AreaLight>
+    {
+        AreaLight::lights(self.0.values(), emissive, color, intensity)
+    }
+
+    /// Returns the [`LightmapMesh`]es of the brushes returned by [`Exporter::polygons`], each
+    /// one with a non overlapping lightmap UV layout packed into a single atlas.
+    /// `texture_size` must return the size of the texture with the requested name, if known, to
+    /// compute the texture UV coordinates. `trim_region` must return the top left corner and the
+    /// size, in pixels, of the trim region with the requested name of the texture with the
+    /// requested name, if known, as described in its `.trims` file.
+    #[inline]
+    pub fn lightmap_meshes<F, T>(&self, texture_size: F, trim_region: T) -> HvVec<LightmapMesh>
+    where
+        F: Fn(&str) -> Option<UVec2>,
+        T: Fn(&str, &str) -> Option<(UVec2, UVec2)>
+    {
+        LightmapMesh::meshes(self.polygons(), texture_size, trim_region)
+    }
+
+    /// Returns the [`ThingCollider`]s of the things with the boolean property `solid` set to true.
+    /// `shape` can return a custom footprint for a thing, relative to its position, otherwise the
+    /// rectangle of its [`Hull`] is used.
+    #[inline]
+    pub fn things_colliders<F>(&self, solid: &str, shape: F) -> HvVec<ThingCollider>
+    where
+        F: Fn(&crate::ThingInstance) -> Option<HvVec<Vec2>>
+    {
+        ThingCollider::colliders(self.1.values(), solid, shape)
+    }
+
+    /// Returns the solid [`CollisionEdge`]s of the brushes with collision enabled, each one with
+    /// its outward normal. The brushes with the boolean property `one_way` set to true only
+    /// return their upward facing edges, flagged as one-way. The edges are sorted by the id of
+    /// their brush.
+    #[inline]
+    pub fn collision_edges(&self, one_way: &str) -> HvVec<CollisionEdge>
+    {
+        CollisionEdge::edges(self.0.values(), one_way)
+    }
+
+    /// Returns the [`NavMeshRegion`]s generated from the brushes with the boolean property
+    /// `walkable` set to true, grouped by adjacency.
+    #[inline]
+    pub fn navmesh(&self, walkable: &str) -> HvVec<NavMeshRegion>
+    {
+        NavMeshRegion::regions(self.0.values(), walkable)
+    }
+
+    /// Returns the [`RenderPass`]es grouping the brushes and things by the height they are drawn
+    /// at in the editor, sorted from the lowest to the highest.
+    #[inline]
+    pub fn render_passes(&self) -> HvVec<RenderPass>
+    {
+        RenderPass::passes(self.0.values(), self.1.values())
+    }
+
+    /// Returns the [`BrushesAdjacency`]s of the brushes returned by [`Exporter::polygons`], that
+    /// is the pairs of brushes sharing a segment of their boundaries.
+    #[inline]
+    pub fn adjacency_graph(&self) -> HvVec<BrushesAdjacency>
+    {
+        BrushesAdjacency::adjacencies(self.polygons())
+    }
+
+    /// Returns the [`ContentHash`] of the bytes of `file` going from `start` to the current
+    /// position.
+    #[inline]
+    fn content_hash<R: Read + Seek>(file: &mut R, start: u64) -> std::io::Result<ContentHash>
+    {
+        let end = file.stream_position()?;
+        let mut bytes = vec![0; usize::try_from(end - start).unwrap()];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut bytes)?;
+        Ok(ContentHash::new(&bytes))
     }
 }
 
@@ -420,6 +766,16 @@ impl Exporter
 //
 //=======================================================================//
 
+/// Returns the range of the coordinates the entities of a map with bound `bound` can have.
+#[inline]
+#[must_use]
+pub(in crate::map) fn map_range(bound: f32) -> RangeInclusive<f32> { -bound..=bound }
+
+/// Returns the size of half of the map square of the files that do not specify it.
+#[inline]
+#[must_use]
+const fn default_map_bound() -> f32 { DEFAULT_MAP_BOUND }
+
 /// Initializes the editor.
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::cast_precision_loss)]
@@ -502,6 +858,18 @@ pub(in crate::map) fn initialize(
     camera!(PaintToolPropCamera);
     commands.spawn(prop_camera(&mut images, Vec2::new(0f32, y + MAP_SIZE)));
 
+    commands.spawn((
+        Camera2dBundle {
+            camera: Camera {
+                is_active: false,
+                target: RenderTarget::Image(images.add(map_render::image(UVec2::ONE))),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        MapRenderCamera
+    ));
+
     // Extract necessary values.
     let ctx = context.ctx.get_mut();
 
@@ -583,6 +951,7 @@ fn store_loaded_textures(
     config: Res<Config>,
     mut texture_loader: ResMut<TextureLoader>,
     hardcoded_things: Option<Res<HardcodedThings>>,
+    custom_tools: Option<ResMut<CustomTools>>,
     brush_properties: Option<ResMut<BrushProperties>>,
     thing_properties: Option<ResMut<ThingProperties>>,
     state: Res<State<EditorState>>,
@@ -602,6 +971,7 @@ fn store_loaded_textures(
             &config,
             &mut texture_loader,
             hardcoded_things,
+            custom_tools,
             brush_properties,
             thing_properties
         );
@@ -610,12 +980,14 @@ fn store_loaded_textures(
         return;
     }
 
+    let changed = texture_loader.take_changed_textures();
     editor.reload_textures(
         &mut prop_cameras,
         &mut images,
         &mut materials,
         &mut user_textures,
-        texture_loader.loaded_textures()
+        texture_loader.loaded_textures(),
+        changed.as_ref()
     );
 }
 
@@ -644,7 +1016,8 @@ fn update_state(
     mut editor: NonSendMut<Editor>,
     mut config: ResMut<Config>,
     mut next_editor_state: ResMut<NextState<EditorState>>,
-    mut next_tex_load: ResMut<NextState<TextureLoadingProgress>>
+    mut next_tex_load: ResMut<NextState<TextureLoadingProgress>>,
+    mut texture_loader: ResMut<TextureLoader>
 )
 {
     let mut window = return_if_err!(window.get_single_mut());
@@ -670,7 +1043,8 @@ fn update_state(
             &mut key_inputs,
             &mut config,
             &mut next_editor_state,
-            &mut next_tex_load
+            &mut next_tex_load,
+            &mut texture_loader
         )
     {
         return;
@@ -690,7 +1064,27 @@ fn update_state(
         &mut key_inputs,
         &mut config,
         &mut next_editor_state,
-        &mut next_tex_load
+        &mut next_tex_load,
+        &mut texture_loader
+    );
+}
+
+//=======================================================================//
+
+/// Updates the render of the map preview to an image file.
+#[inline]
+fn update_map_render(
+    mut images: ResMut<Assets<Image>>,
+    mut camera: MapRenderCameraQueryMut,
+    mut request: ResMut<MapRenderRequest>,
+    mut editor: NonSendMut<Editor>
+)
+{
+    let (mut camera, mut transform) = camera.single_mut();
+    editor.update_map_render(
+        &mut images,
+        (camera.as_mut(), transform.as_mut()),
+        &mut request
     );
 }
 
@@ -708,7 +1102,8 @@ fn update_active_tool(
     mut paint_tool_camera: PaintToolCameraQueryMut,
     time: Res<Time>,
     mut user_textures: ResMut<EguiUserTextures>,
-    mut editor: NonSendMut<Editor>
+    mut editor: NonSendMut<Editor>,
+    config: Res<Config>
 )
 {
     let mut paint_tool_camera = paint_tool_camera.single_mut();
@@ -720,7 +1115,8 @@ fn update_active_tool(
         &mut prop_cameras,
         (paint_tool_camera.0.as_mut(), paint_tool_camera.1.as_mut()),
         &time,
-        &mut user_textures
+        &mut user_textures,
+        config.snap_threshold
     );
 }
 
@@ -756,6 +1152,8 @@ fn draw(
         egui_context.ctx_mut(),
         &meshes_query,
         &config.colors,
+        config.play_area.hull(),
+        config.snap_threshold,
         #[cfg(feature = "debug")]
         &mut gizmos
     );
@@ -0,0 +1,218 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::prelude::Vec2;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        properties::Value
+    },
+    utils::{hull::Hull, identifiers::Id, iterators::PairIterator}
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum distance between two edges for them to be considered as lying on the same line.
+const PORTAL_EPSILON: f32 = 1f32 / 128f32;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// A walkable polygon of a [`NavMeshRegion`].
+#[must_use]
+pub struct NavMeshPolygon
+{
+    /// The [`Id`] of the brush the polygon was generated from.
+    pub id:       Id,
+    /// The vertexes, in counter-clockwise order.
+    pub vertexes: HvVec<Vec2>,
+    /// The indexes of the adjacent polygons of the region and the segments shared with them.
+    pub portals:  HvVec<(usize, [Vec2; 2])>
+}
+
+//=======================================================================//
+
+/// A group of walkable polygons connected to each other through shared edges.
+#[must_use]
+pub struct NavMeshRegion(pub HvVec<NavMeshPolygon>);
+
+impl NavMeshRegion
+{
+    /// Returns the [`NavMeshRegion`]s generated from the brushes in `brushes` that have the
+    /// property `walkable` set to true.
+    /// Brushes that do not share any edge segment are placed in different regions. Obstacles are
+    /// not subtracted from the walkable surface.
+    #[inline]
+    pub(in crate::map) fn regions<'a, I>(brushes: I, walkable: &str) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        let polygons = hv_vec![collect; brushes
+            .filter(|brush| matches!(brush.properties.get(walkable), Some(Value::Bool(true))))
+            .map(|brush| (brush, Hull::from_points(brush.vertexes.iter().copied()).unwrap()))];
+
+        let mut portals = (0..polygons.len()).map(|_| hv_vec![]).collect::<Vec<HvVec<_>>>();
+
+        for (i, (a, a_hull)) in polygons.iter().enumerate()
+        {
+            for (j, (b, b_hull)) in polygons.iter().enumerate().skip(i + 1)
+            {
+                if !a_hull.intersects(b_hull)
+                {
+                    continue;
+                }
+
+                if let Some(portal) = Self::portal(&a.vertexes, &b.vertexes)
+                {
+                    portals[i].push((j, portal));
+                    portals[j].push((i, [portal[1], portal[0]]));
+                }
+            }
+        }
+
+        let mut region_index = vec![None; polygons.len()];
+        let mut regions = hv_vec![];
+
+        for start in 0..polygons.len()
+        {
+            if region_index[start].is_some()
+            {
+                continue;
+            }
+
+            let mut members = hv_vec![start];
+            region_index[start] = Some(0);
+            let mut k = 0;
+
+            while k < members.len()
+            {
+                for (j, _) in &portals[members[k]]
+                {
+                    if region_index[*j].is_none()
+                    {
+                        region_index[*j] = Some(members.len());
+                        members.push(*j);
+                    }
+                }
+
+                k += 1;
+            }
+
+            regions.push(Self(hv_vec![collect; members.iter().map(|i| {
+                let brush = polygons[*i].0;
+
+                NavMeshPolygon {
+                    id:       brush.id,
+                    vertexes: hv_vec![collect; brush.vertexes.iter().copied()],
+                    portals:  hv_vec![collect; portals[*i]
+                        .iter()
+                        .map(|(j, portal)| (region_index[*j].unwrap(), *portal))]
+                }
+            })]));
+        }
+
+        regions
+    }
+
+    /// Returns the segment shared by the sides of the polygons with vertexes `a` and `b`, if any.
+    /// The segment is oriented as the side of `a` it lies on.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn portal(a: &HvVec<Vec2>, b: &HvVec<Vec2>) -> Option<[Vec2; 2]>
+    {
+        for [a_0, a_1] in a.pair_iter().unwrap()
+        {
+            let dir = *a_1 - *a_0;
+            let len = dir.length();
+
+            if len < PORTAL_EPSILON
+            {
+                continue;
+            }
+
+            let dir = dir / len;
+
+            for [b_0, b_1] in b.pair_iter().unwrap()
+            {
+                // The sides of two adjacent counter-clockwise polygons face opposite directions.
+                if dir.dot(*b_1 - *b_0) >= 0f32 ||
+                    dir.perp_dot(*b_0 - *a_0).abs() > PORTAL_EPSILON ||
+                    dir.perp_dot(*b_1 - *a_0).abs() > PORTAL_EPSILON
+                {
+                    continue;
+                }
+
+                let (t_0, t_1) = (dir.dot(*b_0 - *a_0), dir.dot(*b_1 - *a_0));
+                let start = t_0.min(t_1).max(0f32);
+                let end = t_0.max(t_1).min(len);
+
+                if end - start > PORTAL_EPSILON
+                {
+                    return Some([*a_0 + dir * start, *a_0 + dir * end]);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+//=======================================================================//
+
+/// Two brushes sharing a segment of their boundaries.
+#[must_use]
+pub struct BrushesAdjacency
+{
+    /// The [`Id`]s of the adjacent brushes, the lowest first.
+    pub brushes: [Id; 2],
+    /// The segment shared by the brushes, oriented as the side of the first brush it lies on.
+    pub portal:  [Vec2; 2]
+}
+
+impl BrushesAdjacency
+{
+    /// Returns the [`BrushesAdjacency`]s of all the pairs of brushes in `brushes` that share an
+    /// edge segment, sorted by the [`Id`]s of the brushes.
+    #[inline]
+    pub(in crate::map) fn adjacencies<'a, I>(brushes: I) -> HvVec<Self>
+    where
+        I: Iterator<Item = &'a BrushViewer>
+    {
+        let mut polygons = brushes
+            .map(|brush| (brush, Hull::from_points(brush.vertexes.iter().copied()).unwrap()))
+            .collect::<Vec<_>>();
+        polygons.sort_by_key(|(brush, _)| brush.id.value());
+
+        let mut adjacencies = hv_vec![];
+
+        for (i, (a, a_hull)) in polygons.iter().enumerate()
+        {
+            for (b, b_hull) in polygons.iter().skip(i + 1)
+            {
+                if !a_hull.intersects(b_hull)
+                {
+                    continue;
+                }
+
+                if let Some(portal) = NavMeshRegion::portal(&a.vertexes, &b.vertexes)
+                {
+                    adjacencies.push(Self {
+                        brushes: [a.id, b.id],
+                        portal
+                    });
+                }
+            }
+        }
+
+        adjacencies
+    }
+}
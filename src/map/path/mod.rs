@@ -14,7 +14,7 @@ use hill_vacuum_shared::{continue_if_none, return_if_none, NextValue};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use self::{
-    nodes::{Node, NodeWorld, NodesWorld, NodesWorldMut},
+    nodes::{Movement, Node, NodeWorld, NodesWorld, NodesWorldMut},
     overall_values::OverallMovement
 };
 use super::{
@@ -158,6 +158,28 @@ macro_rules! movement_values {
             edit.none_if_empty()
         }
 
+        #[inline]
+        pub(in crate::map) fn [< quantize_selected_nodes_ $value >](
+            &mut self,
+            step: f32,
+            clamp: fn(f32) -> f32
+        ) -> Option<MovementValueEdit>
+        {
+            let mut edit = MovementValueEdit::new();
+
+            for (i, node) in self
+                .nodes
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, n)| n.selectable_vector.selected)
+            {
+                let value = clamp(quantize(node.movement.$value(), step));
+                edit.insert(i, continue_if_none!(node.movement.[< set_ $value >](value)));
+            }
+
+            edit.none_if_empty()
+        }
+
         #[inline]
         pub(in crate::map) fn [< undo_ $value _edit>](&mut self, edit: &MovementValueEdit)
         {
@@ -205,6 +227,16 @@ macro_rules! common_edit_path {
             self.path_mut().[< set_selected_nodes_ $value >](value)
         }
 
+        #[inline]
+        fn [< quantize_selected_path_nodes_ $value >](
+            &mut self,
+            step: f32,
+            clamp: fn(f32) -> f32
+        ) -> Option<$t>
+        {
+            self.path_mut().[< quantize_selected_nodes_ $value >](step, clamp)
+        }
+
         #[inline]
         fn [< undo_path_nodes_ $value _edit >](&mut self, edit: &$t)
         {
@@ -342,6 +374,16 @@ macro_rules! common_edit_path {
             self.path_mut().snap_selected_nodes(grid, center)
         }
 
+        #[inline]
+        fn snap_path_nodes(
+            &mut self,
+            grid: crate::map::editor::state::grid::Grid
+        ) -> crate::map::path::NodesSnapResult
+        {
+            let center = self.center();
+            self.path_mut().snap_all_nodes(grid, center)
+        }
+
         common_edit_path!(
             (standby_time, crate::map::path::StandbyValueEdit),
             (max_speed, crate::map::path::MovementValueEdit),
@@ -389,14 +431,14 @@ pub(in crate::map) trait Moving: EntityId + EntityCenter
     /// Whether the [`Hull`] encompassing the nodes of the [`Path`] are out of bounds if the entity
     /// has center at `center`.
     #[inline]
-    fn path_hull_out_of_bounds(&self, center: Vec2) -> bool
+    fn path_hull_out_of_bounds(&self, center: Vec2, bound: f32) -> bool
     {
         if !self.has_path()
         {
             return false;
         }
 
-        calc_path_hull(self.path().unwrap(), center).out_of_bounds()
+        calc_path_hull(self.path().unwrap(), center).out_of_bounds(bound)
     }
 
     /// Returns the `OverallMovement` describing the movement settings of the selected nodes.
@@ -412,9 +454,9 @@ pub(in crate::map) trait Moving: EntityId + EntityCenter
     /// # Panics
     /// Panics if the entity has no [`Path`].
     #[inline]
-    fn check_selected_path_nodes_move(&self, delta: Vec2) -> IdNodesMoveResult
+    fn check_selected_path_nodes_move(&self, delta: Vec2, bound: f32) -> IdNodesMoveResult
     {
-        (self.path().unwrap().check_selected_nodes_move(delta), self.id()).into()
+        (self.path().unwrap().check_selected_nodes_move(delta, bound), self.id()).into()
     }
 
     /// Returns the nodes near `cursor_pos`.
@@ -667,12 +709,28 @@ pub(in crate::map) trait EditPath: EntityId + Moving
     #[must_use]
     fn snap_selected_path_nodes(&mut self, grid: Grid) -> Option<HvVec<(HvVec<u8>, Vec2)>>;
 
+    /// Snaps all the [`Path`]'s [`Node`]s to the grid. Returns a [`NodesSnapResult`] describing
+    /// the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn snap_path_nodes(&mut self, grid: Grid) -> NodesSnapResult;
+
     /// Sets the standy time of the selected [`Path`]'s [`Node`]s to `value`, returns a
     /// [`StandbyValueEdit`] describing the outcome.
     /// # Panics
     /// Panics if the entity has no [`Path`].
     fn set_selected_path_nodes_standby_time(&mut self, value: f32) -> Option<StandbyValueEdit>;
 
+    /// Rounds the standby time of the selected [`Path`]'s [`Node`]s to the closest multiple of
+    /// `step`, passed through `clamp`, and returns a [`StandbyValueEdit`] describing the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn quantize_selected_path_nodes_standby_time(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<StandbyValueEdit>;
+
     /// Undoes the [`Path`]'s [`Node`]s standby time edit.
     /// # Panics
     /// Panics if the entity has no [`Path`].
@@ -689,6 +747,16 @@ pub(in crate::map) trait EditPath: EntityId + Moving
     /// Panics if the entity has no [`Path`].
     fn set_selected_path_nodes_max_speed(&mut self, value: f32) -> Option<MovementValueEdit>;
 
+    /// Rounds the max speed of the selected [`Path`]'s [`Node`]s to the closest multiple of `step`,
+    /// passed through `clamp`, and returns a [`MovementValueEdit`] describing the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn quantize_selected_path_nodes_max_speed(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<MovementValueEdit>;
+
     /// Undoes the [`Path`]'s [`Node`]s max speed edit.
     /// # Panics
     /// Panics if the entity has no [`Path`].
@@ -705,6 +773,16 @@ pub(in crate::map) trait EditPath: EntityId + Moving
     /// Panics if the entity has no [`Path`].
     fn set_selected_path_nodes_min_speed(&mut self, value: f32) -> Option<MovementValueEdit>;
 
+    /// Rounds the min speed of the selected [`Path`]'s [`Node`]s to the closest multiple of `step`,
+    /// passed through `clamp`, and returns a [`MovementValueEdit`] describing the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn quantize_selected_path_nodes_min_speed(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<MovementValueEdit>;
+
     /// Undoes the [`Path`]'s [`Node`]s min speed edit.
     /// # Panics
     /// Panics if the entity has no [`Path`].
@@ -724,6 +802,17 @@ pub(in crate::map) trait EditPath: EntityId + Moving
         value: f32
     ) -> Option<MovementValueEdit>;
 
+    /// Rounds the accel travel percentage of the selected [`Path`]'s [`Node`]s to the closest
+    /// multiple of `step`, passed through `clamp`, and returns a [`MovementValueEdit`] describing
+    /// the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn quantize_selected_path_nodes_accel_travel_percentage(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<MovementValueEdit>;
+
     /// Undoes the [`Path`]'s [`Node`]s accel travel percentage edit.
     /// # Panics
     /// Panics if the entity has no [`Path`].
@@ -743,6 +832,17 @@ pub(in crate::map) trait EditPath: EntityId + Moving
         value: f32
     ) -> Option<MovementValueEdit>;
 
+    /// Rounds the decel travel percentage of the selected [`Path`]'s [`Node`]s to the closest
+    /// multiple of `step`, passed through `clamp`, and returns a [`MovementValueEdit`] describing
+    /// the outcome.
+    /// # Panics
+    /// Panics if the entity has no [`Path`].
+    fn quantize_selected_path_nodes_decel_travel_percentage(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<MovementValueEdit>;
+
     /// Undoes the [`Path`]'s [`Node`]s decel travel percentage edit.
     /// # Panics
     /// Panics if the entity has no [`Path`].
@@ -871,6 +971,20 @@ impl NodesMove
 
 //=======================================================================//
 
+/// The result of the snap of the [`Node`]s of a [`Path`] to the grid.
+#[must_use]
+pub(in crate::map) enum NodesSnapResult
+{
+    /// No nodes were snapped.
+    None,
+    /// Snapping the nodes generates an invalid [`Path`].
+    Invalid,
+    /// The snap is valid, contains the indexes of the snapped nodes grouped by their movement.
+    Valid(HvVec<(HvVec<u8>, Vec2)>)
+}
+
+//=======================================================================//
+
 /// The result of the [`Node`]s selection process.
 #[must_use]
 #[derive(Debug)]
@@ -1784,6 +1898,25 @@ impl Path
     #[must_use]
     pub(in crate::map) fn len(&self) -> usize { self.nodes.len() }
 
+    /// Multiplies the positions of the [`Node`]s and the travel speeds by `factor`.
+    #[inline]
+    pub(in crate::map) fn export_scale(&mut self, factor: f32)
+    {
+        for node in &mut self.nodes
+        {
+            node.selectable_vector.vec *= factor;
+            node.movement.scale_speeds(factor);
+        }
+
+        self.hull = Self::nodes_hull(&self.nodes);
+        self.buckets = Buckets::new();
+
+        for (i, node) in self.nodes.iter().enumerate()
+        {
+            self.buckets.insert(i, node.pos());
+        }
+    }
+
     /// Returns the indexes of the selected [`Node`]s.
     #[inline]
     pub(in crate::map) fn selected_nodes(&self) -> Option<HvVec<u8>>
@@ -1799,6 +1932,15 @@ impl Path
         .none_if_empty()
     }
 
+    /// Returns an iterator to the [`Movement`]s of the selected [`Node`]s.
+    #[inline]
+    pub(in crate::map) fn selected_nodes_movements(&self) -> impl Iterator<Item = &Movement>
+    {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.selectable_vector.selected.then_some(&node.movement))
+    }
+
     /// Returns the position of the [`Node`] at `index`.
     #[inline]
     #[must_use]
@@ -1831,14 +1973,32 @@ impl Path
         grid: Grid,
         center: Vec2
     ) -> Option<HvVec<(HvVec<u8>, Vec2)>>
+    {
+        match self.snap_nodes(grid, center, |node| node.selectable_vector.selected)
+        {
+            NodesSnapResult::None | NodesSnapResult::Invalid => None,
+            NodesSnapResult::Valid(moved_nodes) => moved_nodes.into()
+        }
+    }
+
+    /// Snaps all the [`Node`]s to the Grid.
+    /// Returns a [`NodesSnapResult`] describing the outcome.
+    #[inline]
+    pub(in crate::map) fn snap_all_nodes(&mut self, grid: Grid, center: Vec2) -> NodesSnapResult
+    {
+        self.snap_nodes(grid, center, |_| true)
+    }
+
+    /// Snaps the [`Node`]s for which `filter` returns true to the Grid.
+    /// Returns a [`NodesSnapResult`] describing the outcome.
+    #[inline]
+    fn snap_nodes<F>(&mut self, grid: Grid, center: Vec2, filter: F) -> NodesSnapResult
+    where
+        F: Fn(&Node) -> bool
     {
         let mut moved_nodes: HvVec<(HvVec<u8>, Vec2)> = hv_vec![];
 
-        'outer: for (i, node) in self
-            .nodes
-            .iter_mut()
-            .enumerate()
-            .filter(|(_, node)| node.selectable_vector.selected)
+        'outer: for (i, node) in self.nodes.iter_mut().enumerate().filter(|(_, node)| filter(node))
         {
             let node_world = node.world_pos(center);
             let delta = continue_if_none!(grid.snap_point(node_world)) - node_world;
@@ -1860,7 +2020,7 @@ impl Path
 
         if moved_nodes.is_empty()
         {
-            return None;
+            return NodesSnapResult::None;
         }
 
         if !self.nodes_valid()
@@ -1873,7 +2033,7 @@ impl Path
                 }
             }
 
-            return None;
+            return NodesSnapResult::Invalid;
         }
 
         for (idxs, delta) in &moved_nodes
@@ -1887,7 +2047,7 @@ impl Path
         }
 
         self.update_hull();
-        Some(moved_nodes)
+        NodesSnapResult::Valid(moved_nodes)
     }
 
     /// Returns the [`Node`]s near `cursor_pos`.
@@ -2310,6 +2470,43 @@ impl Path
         .none_if_empty()
     }
 
+    //==============================================================
+    // Split
+
+    /// Splits the [`Path`] at the [`Node`] at `index`, which must be neither the first nor the
+    /// last one. Returns the [`Path`] made of the nodes up to the one at `index` and the one made
+    /// of the nodes from the one at `index` onward, if both are valid.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn split(&self, index: usize) -> Option<[Self; 2]>
+    {
+        if index == 0 || index >= self.len() - 1
+        {
+            return None;
+        }
+
+        let mut first = self.nodes.clone();
+
+        for node in &mut first
+        {
+            node.selectable_vector.selected = false;
+        }
+
+        let second = hv_vec![collect; first[index..].iter().copied()];
+        first.truncate(index + 1);
+
+        // The consecutive nodes are already distinct, only the closing ones must be checked.
+        for nodes in [&first, &second]
+        {
+            if nodes[0].pos().around_equal_narrow(&nodes[nodes.len() - 1].pos())
+            {
+                return None;
+            }
+        }
+
+        Some([Self::from(first), Self::from(second)])
+    }
+
     //==============================================================
     // Move
 
@@ -2349,13 +2546,17 @@ impl Path
     /// Checks whether moving the selected [`Node`]s by `delta` generates a valid path.
     /// Returns a [`NodesMoveResult`] describing the outcome.
     #[inline]
-    pub(in crate::map) fn check_selected_nodes_move(&self, delta: Vec2) -> NodesMoveResult
+    pub(in crate::map) fn check_selected_nodes_move(
+        &self,
+        delta: Vec2,
+        bound: f32
+    ) -> NodesMoveResult
     {
         let moved = return_if_none!(self.selected_nodes(), NodesMoveResult::None);
 
         if moved
             .iter()
-            .any(|idx| (self.nodes[*idx as usize].pos() + delta).out_of_bounds())
+            .any(|idx| (self.nodes[*idx as usize].pos() + delta).out_of_bounds(bound))
         {
             return NodesMoveResult::Invalid;
         }
@@ -2484,6 +2685,30 @@ impl Path
         edit.none_if_empty()
     }
 
+    /// Rounds the standby time of the selected [`Node`]s to the closest multiple of `step`, passed
+    /// through `clamp`, and returns a [`StandbyValueEdit`] describing the outcome.
+    #[inline]
+    pub(in crate::map) fn quantize_selected_nodes_standby_time(
+        &mut self,
+        step: f32,
+        clamp: fn(f32) -> f32
+    ) -> Option<StandbyValueEdit>
+    {
+        let mut edit = StandbyValueEdit::new();
+
+        for (i, node) in self
+            .nodes
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, n)| n.selectable_vector.selected)
+        {
+            let value = clamp(quantize(node.movement.standby_time(), step));
+            edit.insert(i, continue_if_none!(node.movement.set_standby_time(value)));
+        }
+
+        edit.none_if_empty()
+    }
+
     /// Undoes a standby time edit.
     #[inline]
     pub(in crate::map) fn undo_standby_time_edit(&mut self, edit: &StandbyValueEdit)
@@ -3100,3 +3325,28 @@ pub(in crate::map) fn calc_path_hull(path: &Path, center: Vec2) -> Hull
         .merged(&Some(center).into_iter().into())
         .bumped(2f32)
 }
+
+//=======================================================================//
+
+/// Returns the [`Hull`] covered by `hull` while the owning entity travels along the [`Path`],
+/// that is the area swept by `hull` moved to each node, including its starting position.
+#[inline]
+#[must_use]
+pub(in crate::map) fn calc_swept_path_hull(path: &Path, hull: &Hull) -> Hull
+{
+    let path_hull = path.hull();
+
+    Hull::new(
+        hull.top() + path_hull.top().max(0f32),
+        hull.bottom() + path_hull.bottom().min(0f32),
+        hull.left() + path_hull.left().min(0f32),
+        hull.right() + path_hull.right().max(0f32)
+    )
+}
+
+//=======================================================================//
+
+/// Returns the multiple of `step` closest to `value`.
+#[inline]
+#[must_use]
+fn quantize(value: f32, step: f32) -> f32 { (value / step).round() * step }
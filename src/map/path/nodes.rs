@@ -131,6 +131,14 @@ impl Movement
     #[must_use]
     pub const fn standby_time(&self) -> f32 { self.standby_time }
 
+    /// Multiplies the maximum and minimum speeds by `factor`.
+    #[inline]
+    pub(in crate::map::path) fn scale_speeds(&mut self, factor: f32)
+    {
+        self.max_speed *= factor;
+        self.min_speed *= factor;
+    }
+
     /// Sets the maximum speed.
     #[inline]
     pub(in crate::map) fn set_max_speed(&mut self, value: f32) -> Option<Vec2>
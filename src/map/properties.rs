@@ -20,6 +20,18 @@ use crate::{
     ThingInstance
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The name of the built-in brush property describing the surface material, such as the one
+/// determining footstep sounds or friction, independently of the texture.
+pub const MATERIAL_PROPERTY: &str = "material";
+/// The name of the built-in thing property describing the order in which the things should be
+/// spawned, such as the wave they belong to.
+pub const SPAWN_ORDER_PROPERTY: &str = "spawn_order";
+
 //=======================================================================//
 // MACROS
 //
@@ -247,7 +259,7 @@ impl Value
         /// Implements the conversion of `string` to the [`Value`] variant of `self`, if possible.
         macro_rules! convert {
             ($(($value:ident, $t:ty)),+) => {
-                match value
+                match self
                 {
                     $(Self::$value(_) =>
                     {
@@ -427,6 +439,83 @@ impl DefaultProperties
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> { self.1 .0.iter() }
 
+    /// Returns a new [`DefaultProperties`] generated for the values contained in `values` with the
+    /// addition of the built-in property `key` with default `value`, unless already present.
+    #[inline]
+    fn with_built_in(
+        mut values: Vec<(&'static str, Value)>,
+        key: &'static str,
+        value: Value
+    ) -> Self
+    {
+        if values.iter().all(|(k, _)| *k != key)
+        {
+            values.push((key, value));
+        }
+
+        Self::new(values)
+    }
+
+    /// Returns a new [`DefaultProperties`] generated for the values contained in `values` with the
+    /// addition of the built-in material property, unless already present.
+    #[inline]
+    pub fn with_material(values: Vec<(&'static str, Value)>) -> Self
+    {
+        Self::with_built_in(values, MATERIAL_PROPERTY, Value::String(String::new()))
+    }
+
+    /// Returns a new [`DefaultProperties`] generated for the values contained in `values` with the
+    /// addition of the built-in spawn order property, unless already present.
+    #[inline]
+    pub fn with_spawn_order(values: Vec<(&'static str, Value)>) -> Self
+    {
+        Self::with_built_in(values, SPAWN_ORDER_PROPERTY, Value::U32(0))
+    }
+
+    /// Whether `self` is equal to `other` except for the lack of the built-in property `key`.
+    #[inline]
+    #[must_use]
+    fn lacks_only_built_in(&self, other: &Self, key: &str) -> bool
+    {
+        !self.1 .0.contains_key(key) &&
+            other.1 .0.contains_key(key) &&
+            other.len() == self.len() + 1 &&
+            self.0.iter().all(|(k, v0)| {
+                other.0.get(k).is_some_and(|v1| v0.eq_discriminant(v1) && v0 == v1)
+            })
+    }
+
+    /// Whether `self` is equal to `other` except for the lack of the built-in material property,
+    /// which means it was stored in a map file saved before the property was introduced.
+    #[inline]
+    #[must_use]
+    pub fn lacks_only_material(&self, other: &Self) -> bool
+    {
+        self.lacks_only_built_in(other, MATERIAL_PROPERTY)
+    }
+
+    /// Whether `self` is equal to `other` except for the lack of the built-in spawn order
+    /// property, which means it was stored in a map file saved before the property was
+    /// introduced.
+    #[inline]
+    #[must_use]
+    pub fn lacks_only_spawn_order(&self, other: &Self) -> bool
+    {
+        self.lacks_only_built_in(other, SPAWN_ORDER_PROPERTY)
+    }
+
+    /// Returns a [`PropertiesRefactor`] inserting the built-in property `key` of `new` in the
+    /// [`Properties`] stored in a map file saved before the property was introduced.
+    #[inline]
+    pub fn built_in_refactor(new: &Self, key: &'static str) -> PropertiesRefactor<'_>
+    {
+        PropertiesRefactor {
+            remove:             hv_vec![],
+            insert:             hv_vec![key],
+            default_properties: new
+        }
+    }
+
     /// Generates a [`PropertiesRefactor`] describing how the [`Properties`] created from `self`
     /// should be refactored to be compatible with `new`.
     #[inline]
@@ -480,7 +569,7 @@ pub(in crate::map) struct PropertiesRefactor<'a>
 
 /// Key-value pairs associated to an entity.
 #[must_use]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 
 pub(in crate::map) struct Properties(HvHashMap<String, Value>);
 
@@ -490,6 +579,22 @@ impl Default for Properties
     fn default() -> Self { Self(HvHashMap::default()) }
 }
 
+impl Serialize for Properties
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        // Sorted so that the serialized bytes do not depend on the iteration order of the map.
+        // Serialized as a sequence of pairs, like the wrapped map, so that the derived
+        // deserialization can read it back.
+        let mut pairs = self.0.iter().collect::<Vec<_>>();
+        pairs.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        serializer.collect_seq(pairs)
+    }
+}
+
 impl Properties
 {
     /// Returns the amount of contained values.
@@ -501,6 +606,15 @@ impl Properties
     #[inline]
     pub fn get(&self, k: &str) -> &Value { self.0.get(k).unwrap() }
 
+    /// Whether `self` contains a [`Value`] associated with `k`.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, k: &str) -> bool { self.0.contains_key(k) }
+
+    /// Returns an iterator to the key-value pairs.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> { self.0.iter() }
+
     /// Consumes `self` and returns the underlying hashmap of values.
     #[inline]
     pub fn take(self) -> HvHashMap<String, Value> { self.0 }
@@ -529,3 +643,28 @@ impl Properties
         }
     }
 }
+
+//=======================================================================//
+// TESTS
+//
+//=======================================================================//
+
+#[cfg(test)]
+mod tests
+{
+    use super::Value;
+
+    #[test]
+    fn parse()
+    {
+        assert!(matches!(
+            Value::U32(0).parse(&Value::String("5".to_string())),
+            Some(Value::U32(5))
+        ));
+        assert!(matches!(
+            Value::Bool(false).parse(&Value::String("true".to_string())),
+            Some(Value::Bool(true))
+        ));
+        assert!(Value::U32(0).parse(&Value::String("five".to_string())).is_none());
+    }
+}
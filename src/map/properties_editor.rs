@@ -0,0 +1,376 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::PathBuf
+};
+
+use crate::{
+    map::{
+        brush::Brush,
+        containers::{hv_vec, HvVec},
+        drawer::drawing_resources::DrawingResources,
+        properties::{DefaultProperties, Properties, Value},
+        thing::ThingInstance,
+        ContentHash,
+        MapHeader
+    },
+    utils::identifiers::{EntityId, Id}
+};
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Checks whether the [`Value`] associated with `key` in `properties` can be replaced by `value`.
+/// # Errors
+/// Returns an error if `properties` does not contain `key` or the contained [`Value`] is of a
+/// different type than `value`.
+#[inline]
+fn check_property(properties: &Properties, key: &str, value: &Value) -> Result<(), &'static str>
+{
+    if !properties.contains(key)
+    {
+        return Err("Unknown property");
+    }
+
+    if !properties.get(key).eq_discriminant(value)
+    {
+        return Err("Mismatching property type");
+    }
+
+    Ok(())
+}
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The struct used to read a map file, edit the properties of its brushes and things without the
+/// editor, and write it back. The data not concerning the brushes and things, such as animations
+/// and props, is stored unchanged.
+/// ```no_run
+/// let mut map = hill_vacuum::PropertiesEditor::new("map.hv").unwrap();
+/// map.set_brushes_property("version", &hill_vacuum::Value::U8(2)).unwrap();
+/// map.save("map.hv").unwrap();
+/// ```
+#[must_use]
+pub struct PropertiesEditor
+{
+    /// The header of the file.
+    header:                     MapHeader,
+    /// The default properties of the brushes.
+    brushes_default_properties: DefaultProperties,
+    /// The default properties of the things.
+    things_default_properties:  DefaultProperties,
+    /// The serialized animations.
+    animations:                 Vec<u8>,
+    /// The brushes.
+    brushes:                    HvVec<Brush>,
+    /// The things.
+    things:                     HvVec<ThingInstance>,
+    /// The serialized props.
+    props:                      Vec<u8>
+}
+
+impl PropertiesEditor
+{
+    /// Returns a new [`PropertiesEditor`] generated from the requested `path`, unless there was an
+    /// error.
+    /// # Errors
+    /// Returns an error if there was an issue reading the requested file.
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, &'static str>
+    {
+        let file = match File::open(Into::<PathBuf>::into(path))
+        {
+            Ok(file) => file,
+            Err(_) => return Err("Could not open the file")
+        };
+
+        Self::from_reader(&mut BufReader::new(file))
+    }
+
+    /// Returns a new [`PropertiesEditor`] generated from the map data read from `file`, unless
+    /// there was an error.
+    /// # Errors
+    /// Returns an error if there was an issue reading the map data.
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn from_reader<R: Read + Seek>(file: &mut R) -> Result<Self, &'static str>
+    {
+        /// Returns the current position of `file`.
+        macro_rules! position {
+            () => {
+                match file.stream_position()
+                {
+                    Ok(pos) => pos,
+                    Err(_) => return Err("Error reading file")
+                }
+            };
+        }
+
+        let header = match ciborium::from_reader::<MapHeader, _>(&mut *file)
+        {
+            Ok(header) => header,
+            Err(_) => return Err("Error reading file header")
+        };
+
+        let mut default_properties = hv_vec![];
+
+        for _ in 0..2
+        {
+            match ciborium::from_reader::<DefaultProperties, _>(&mut *file)
+            {
+                Ok(properties) => default_properties.push(properties),
+                Err(_) => return Err("Error reading default properties")
+            };
+        }
+
+        let things_default_properties = default_properties.pop().unwrap();
+        let brushes_default_properties = default_properties.pop().unwrap();
+
+        // The animations are only copied.
+        let animations_start = position!();
+        _ = DrawingResources::file_animations(header.animations, file)?;
+        let mut animations = vec![0; usize::try_from(position!() - animations_start).unwrap()];
+
+        if file.seek(SeekFrom::Start(animations_start)).is_err() ||
+            file.read_exact(&mut animations).is_err()
+        {
+            return Err("Error reading animations");
+        }
+
+        let mut brushes = hv_vec![];
+
+        for _ in 0..header.brushes
+        {
+            match ciborium::from_reader::<Brush, _>(&mut *file)
+            {
+                Ok(brush) => brushes.push(brush),
+                Err(_) => return Err("Error reading Brush")
+            };
+        }
+
+        let mut things = hv_vec![];
+
+        for _ in 0..header.things
+        {
+            match ciborium::from_reader::<ThingInstance, _>(&mut *file)
+            {
+                Ok(thing) => things.push(thing),
+                Err(_) => return Err("Error reading ThingInstance")
+            };
+        }
+
+        // The props are only copied.
+        let mut props = Vec::new();
+
+        if file.read_to_end(&mut props).is_err()
+        {
+            return Err("Error reading props");
+        }
+
+        Ok(Self {
+            header,
+            brushes_default_properties,
+            things_default_properties,
+            animations,
+            brushes,
+            things,
+            props
+        })
+    }
+
+    /// Returns an iterator to the [`Id`]s of the brushes.
+    #[inline]
+    pub fn brushes_ids(&self) -> impl Iterator<Item = Id> + '_
+    {
+        self.brushes.iter().map(EntityId::id)
+    }
+
+    /// Returns an iterator to the [`Id`]s of the things.
+    #[inline]
+    pub fn things_ids(&self) -> impl Iterator<Item = Id> + '_
+    {
+        self.things.iter().map(EntityId::id)
+    }
+
+    /// Returns an iterator to the key-value pairs of the properties of the brush with [`Id`]
+    /// `identifier`, if it exists.
+    #[inline]
+    pub fn brush_properties(
+        &self,
+        identifier: Id
+    ) -> Option<impl Iterator<Item = (&String, &Value)>>
+    {
+        self.brushes
+            .iter()
+            .find(|brush| brush.id() == identifier)
+            .map(|brush| brush.properties_as_ref().iter())
+    }
+
+    /// Returns an iterator to the key-value pairs of the properties of the thing with [`Id`]
+    /// `identifier`, if it exists.
+    #[inline]
+    pub fn thing_properties(
+        &self,
+        identifier: Id
+    ) -> Option<impl Iterator<Item = (&String, &Value)>>
+    {
+        self.things
+            .iter()
+            .find(|thing| thing.id() == identifier)
+            .map(|thing| thing.properties().iter())
+    }
+
+    /// Sets the property `key` of the brush with [`Id`] `identifier` to `value`. Returns the
+    /// previous value if different.
+    /// # Errors
+    /// Returns an error if the brush does not exist, it does not have the property `key`, or the
+    /// property is of a different type than `value`.
+    #[inline]
+    pub fn set_brush_property(
+        &mut self,
+        identifier: Id,
+        key: &str,
+        value: &Value
+    ) -> Result<Option<Value>, &'static str>
+    {
+        let brush = match self.brushes.iter_mut().find(|brush| brush.id() == identifier)
+        {
+            Some(brush) => brush,
+            None => return Err("Unknown brush")
+        };
+
+        check_property(brush.properties_as_ref(), key, value)?;
+        Ok(brush.set_property(key, value))
+    }
+
+    /// Sets the property `key` of the thing with [`Id`] `identifier` to `value`. Returns the
+    /// previous value if different.
+    /// # Errors
+    /// Returns an error if the thing does not exist, it does not have the property `key`, or the
+    /// property is of a different type than `value`.
+    #[inline]
+    pub fn set_thing_property(
+        &mut self,
+        identifier: Id,
+        key: &str,
+        value: &Value
+    ) -> Result<Option<Value>, &'static str>
+    {
+        let thing = match self.things.iter_mut().find(|thing| thing.id() == identifier)
+        {
+            Some(thing) => thing,
+            None => return Err("Unknown thing")
+        };
+
+        check_property(thing.properties(), key, value)?;
+        Ok(thing.set_property(key, value))
+    }
+
+    /// Sets the property `key` of all the brushes to `value`. Returns the amount of brushes whose
+    /// property changed.
+    /// # Errors
+    /// Returns an error if the brushes do not have the property `key` or the property is of a
+    /// different type than `value`.
+    #[inline]
+    pub fn set_brushes_property(&mut self, key: &str, value: &Value) -> Result<usize, &'static str>
+    {
+        check_property(&self.brushes_default_properties.instance(), key, value)?;
+
+        Ok(self
+            .brushes
+            .iter_mut()
+            .filter_map(|brush| brush.set_property(key, value))
+            .count())
+    }
+
+    /// Sets the property `key` of all the things to `value`. Returns the amount of things whose
+    /// property changed.
+    /// # Errors
+    /// Returns an error if the things do not have the property `key` or the property is of a
+    /// different type than `value`.
+    #[inline]
+    pub fn set_things_property(&mut self, key: &str, value: &Value) -> Result<usize, &'static str>
+    {
+        check_property(&self.things_default_properties.instance(), key, value)?;
+
+        Ok(self
+            .things
+            .iter_mut()
+            .filter_map(|thing| thing.set_property(key, value))
+            .count())
+    }
+
+    /// Writes the map to `writer` in the map file format.
+    /// # Errors
+    /// Returns an error if writing to `writer` fails.
+    #[inline]
+    pub fn write(&self, writer: &mut impl Write) -> Result<(), &'static str>
+    {
+        /// Tests whether `test` is an error and returns an [`Err`] wrapping the error message `err`.
+        macro_rules! test {
+            ($test:expr, $err:literal) => {
+                if $test.is_err()
+                {
+                    return Err($err);
+                }
+            };
+        }
+
+        // The properties may have changed, so the hash stored in the header is recomputed.
+        let mut content = Vec::new();
+
+        for brush in &self.brushes
+        {
+            test!(ciborium::ser::into_writer(brush, &mut content), "Error saving brushes");
+        }
+
+        for thing in &self.things
+        {
+            test!(ciborium::ser::into_writer(thing, &mut content), "Error saving things");
+        }
+
+        let header = MapHeader {
+            hash: ContentHash::new(&content).into(),
+            ..self.header
+        };
+
+        test!(ciborium::ser::into_writer(&header, &mut *writer), "Error saving file header");
+        test!(
+            ciborium::ser::into_writer(&self.brushes_default_properties, &mut *writer),
+            "Error saving brushes default properties"
+        );
+        test!(
+            ciborium::ser::into_writer(&self.things_default_properties, &mut *writer),
+            "Error saving things default properties"
+        );
+        test!(writer.write_all(&self.animations), "Error saving animations");
+        test!(writer.write_all(&content), "Error saving brushes and things");
+
+        test!(writer.write_all(&self.props), "Error saving props");
+        test!(writer.flush(), "Error saving file");
+        Ok(())
+    }
+
+    /// Saves the map to the file at `path`.
+    /// # Errors
+    /// Returns an error if the file could not be created or written.
+    #[inline]
+    pub fn save(&self, path: impl Into<PathBuf>) -> Result<(), &'static str>
+    {
+        match File::create(Into::<PathBuf>::into(path))
+        {
+            Ok(file) => self.write(&mut BufWriter::new(file)),
+            Err(_) => Err("Could not create the file")
+        }
+    }
+}
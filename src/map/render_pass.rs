@@ -0,0 +1,89 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use hill_vacuum_shared::draw_height_to_world;
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::{hv_vec, HvVec},
+        drawer::texture::TextureInterface,
+        thing::ThingViewer
+    },
+    utils::identifiers::Id
+};
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The brushes and things drawn at the same height, in the order they are drawn by the editor.
+#[must_use]
+pub struct RenderPass
+{
+    /// The draw height of the entities, as [`ThingViewer::draw_height`].
+    pub height:  f32,
+    /// The [`Id`]s of the brushes, sorted in ascending order.
+    pub brushes: HvVec<Id>,
+    /// The [`Id`]s of the things, sorted in ascending order.
+    pub things:  HvVec<Id>
+}
+
+impl RenderPass
+{
+    /// Returns the [`RenderPass`]es of `brushes` and `things`, sorted from the lowest to the
+    /// highest draw height. Untextured brushes are drawn at the same height of the textured
+    /// brushes with draw height 0.
+    #[allow(clippy::float_cmp)]
+    #[inline]
+    pub(in crate::map) fn passes<'a, B, T>(brushes: B, things: T) -> HvVec<Self>
+    where
+        B: Iterator<Item = &'a BrushViewer>,
+        T: Iterator<Item = &'a ThingViewer>
+    {
+        let mut entities = brushes
+            .map(|brush| {
+                let height = brush
+                    .texture
+                    .as_ref()
+                    .map_or_else(|| draw_height_to_world(0), TextureInterface::height_f32);
+                (height, brush.id, true)
+            })
+            .chain(things.map(|thing| (thing.draw_height, thing.id, false)))
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.value().cmp(&b.1.value())));
+
+        let mut passes: HvVec<Self> = hv_vec![];
+
+        for (height, id, brush) in entities
+        {
+            let pass = match passes.last_mut()
+            {
+                Some(pass) if pass.height == height => pass,
+                _ =>
+                {
+                    passes.push(Self {
+                        height,
+                        brushes: hv_vec![],
+                        things: hv_vec![]
+                    });
+                    passes.last_mut().unwrap()
+                }
+            };
+
+            if brush
+            {
+                pass.brushes.push(id);
+            }
+            else
+            {
+                pass.things.push(id);
+            }
+        }
+
+        passes
+    }
+}
@@ -15,6 +15,7 @@ use hill_vacuum_shared::{continue_if_err, continue_if_none};
 
 use super::{Thing, ThingId, ThingInstance};
 use crate::{
+    error_message,
     map::{
         containers::{hv_hash_map, hv_vec},
         drawer::drawing_resources::DrawingResources,
@@ -110,9 +111,27 @@ impl ThingsCatalog
         /// The directory where ini defined things are located.
         const THINGS_DIR: &str = "assets/things/";
 
+        std::fs::create_dir_all(THINGS_DIR).ok();
+
+        let configs = Self::ini_configs(Path::new(THINGS_DIR)).unwrap_or_else(|_| {
+            error_message("Error reading the things folder");
+            hv_vec![]
+        });
+
+        let mut things = Self::merged_things(hardcoded_things.values().cloned(), configs);
+        things.sort_by(|a, b| a.name.cmp(&b.name));
+        IndexedMap::new(things, |thing| thing.id)
+    }
+
+    /// Returns the ini files contained in `path` and its subfolders.
+    /// # Errors
+    /// Returns an error if the content of a folder could not be read.
+    #[inline]
+    fn ini_configs(path: &Path) -> std::io::Result<HvVec<Ini>>
+    {
         /// Gathers all ini files.
         #[inline]
-        fn recurse(path: &Path, inis: &mut HvVec<Ini>)
+        fn recurse(path: &Path, inis: &mut HvVec<Ini>) -> std::io::Result<()>
         {
             if path.is_file()
             {
@@ -123,24 +142,53 @@ impl ThingsCatalog
                     inis.push(ini);
                 }
 
-                return;
+                return Ok(());
             }
 
-            for entry in std::fs::read_dir(path).unwrap()
+            for entry in std::fs::read_dir(path)?
             {
-                recurse(&entry.unwrap().path(), inis);
+                recurse(&entry?.path(), inis)?;
             }
+
+            Ok(())
         }
 
-        std::fs::create_dir_all(THINGS_DIR).ok();
         let mut configs = hv_vec![];
-        recurse(Path::new(THINGS_DIR), &mut configs);
+        recurse(path, &mut configs)?;
+        Ok(configs)
+    }
 
-        let mut things = hv_vec![collect; hardcoded_things.values().cloned()];
+    /// Returns `things` combined with the ones defined in `configs`. A [`Thing`] overwrites the
+    /// previous one with the same [`ThingId`], if any.
+    #[inline]
+    fn merged_things(things: impl IntoIterator<Item = Thing>, configs: HvVec<Ini>) -> HvVec<Thing>
+    {
+        /// Pushes `new_thing` in `things`, overwriting the [`Thing`] with the same [`ThingId`].
+        #[inline]
+        fn insert(things: &mut HvVec<Thing>, new_thing: Thing)
+        {
+            for thing in &mut *things
+            {
+                if thing.id == new_thing.id
+                {
+                    *thing = new_thing;
+                    return;
+                }
+            }
+
+            things.push(new_thing);
+        }
+
+        let mut merged = hv_vec![];
+
+        for thing in things
+        {
+            insert(&mut merged, thing);
+        }
 
         for ini in configs
         {
-            'outer: for (name, values) in ini.get_map_ref()
+            for (name, values) in ini.get_map_ref()
             {
                 /// Returns the value associated to `key` of type `t`, it it exists.
                 /// Otherwise the thing loading is aborted.
@@ -160,30 +208,40 @@ impl ThingsCatalog
                     continue;
                 }
 
-                let new_thing = continue_if_none!(Thing::new(
+                let mut new_thing = continue_if_none!(Thing::new(
                     name,
                     id,
                     value!("width", f32),
                     value!("height", f32),
                     continue_if_none!(values.get("preview")).as_ref().unwrap()
                 ));
-                let id = new_thing.id;
 
-                for thing in &mut things
+                if let Some(tint) = values.get("tint").and_then(|v| Self::parse_tint(v.as_ref()?))
                 {
-                    if thing.id == id
-                    {
-                        *thing = new_thing;
-                        continue 'outer;
-                    }
+                    new_thing = new_thing.with_tint(tint);
                 }
 
-                things.push(new_thing);
+                insert(&mut merged, new_thing);
             }
         }
 
-        things.sort_by(|a, b| a.name.cmp(&b.name));
-        IndexedMap::new(things, |thing| thing.id)
+        merged
+    }
+
+    /// Parses the rgb tint color defined in `value` as three comma separated values, if valid.
+    #[inline]
+    #[must_use]
+    fn parse_tint(value: &str) -> Option<[f32; 3]>
+    {
+        let mut rgb = [0f32; 3];
+        let mut values = value.split(',');
+
+        for c in &mut rgb
+        {
+            *c = values.next()?.trim().parse::<f32>().ok()?;
+        }
+
+        values.next().is_none().then_some(rgb)
     }
 
     //==============================================================
@@ -216,6 +274,11 @@ impl ThingsCatalog
     #[must_use]
     pub fn texture(&self, thing: ThingId) -> &str { &self.thing_or_error(thing).preview }
 
+    /// Returns the editor tint color of the [`Thing`] with the associated [`ThingId`], if any.
+    #[inline]
+    #[must_use]
+    pub fn tint(&self, thing: ThingId) -> Option<[f32; 3]> { self.thing_or_error(thing).tint }
+
     /// Returns a reference to the [`Thing`] selected in the UI gallery.
     /// # Panics
     /// Panics if no [`Thing`] is selected.
@@ -293,6 +356,20 @@ impl ThingsCatalog
                 })
             })
     }
+
+    /// Returns an iterator to the indexes, [`ThingId`]s, texture ids, texture sizes, and names of
+    /// the [`Thing`]s.
+    #[inline]
+    pub fn legend<'a>(
+        &'a self,
+        drawing_resources: &'a DrawingResources
+    ) -> impl ExactSizeIterator<Item = (usize, ThingId, egui::TextureId, UVec2, &'a str)>
+    {
+        self.things.values().enumerate().map(|(index, thing)| {
+            let texture = drawing_resources.egui_texture(&thing.preview);
+            (index, thing.id, texture.0, texture.1, thing.name.as_str())
+        })
+    }
 }
 
 //=======================================================================//
@@ -323,5 +400,38 @@ impl HardcodedThings
 
     /// Returns an iterator to the contained [`Thing`]s.
     #[inline]
-    fn iter(&self) -> std::slice::Iter<Thing> { self.0.iter() }
+    pub fn iter(&self) -> std::slice::Iter<Thing> { self.0.iter() }
+
+    /// Returns the contained [`Thing`] with [`ThingId`] `id`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, id: ThingId) -> Option<&Thing> { self.0.iter().find(|thing| thing.id == id) }
+
+    /// Returns the catalog of the [`Thing`]s available in the editor, that is the contained ones
+    /// combined with the ones defined in the .ini files of the `things_dir` folder and its
+    /// subfolders, usually `assets/things/`. A [`Thing`] overwrites the previous one with the same
+    /// [`ThingId`], so the ones defined in the .ini files overwrite the contained ones. Useful to
+    /// resolve the [`ThingId`]s of the exported things into their definitions. Nothing is written
+    /// to disk.
+    /// # Errors
+    /// Returns an error if the content of `things_dir` could not be read.
+    #[inline]
+    pub fn catalog(
+        &self,
+        things_dir: impl AsRef<Path>
+    ) -> Result<HvHashMap<ThingId, Thing>, &'static str>
+    {
+        let configs = match ThingsCatalog::ini_configs(things_dir.as_ref())
+        {
+            Ok(configs) => configs,
+            Err(_) => return Err("Error reading the things folder")
+        };
+
+        Ok(hv_hash_map![
+            collect;
+            ThingsCatalog::merged_things(self.iter().cloned(), configs)
+                .into_iter()
+                .map(|thing| (thing.id, thing))
+        ])
+    }
 }
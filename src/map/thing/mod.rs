@@ -20,7 +20,7 @@ use super::{
         manager::{Animators, Brushes}
     },
     path::{common_edit_path, EditPath, MovementSimulator, Moving, NodesDeletionPayload, Path},
-    properties::{Properties, PropertiesRefactor, Value},
+    properties::{Properties, PropertiesRefactor, Value, SPAWN_ORDER_PROPERTY},
     OutOfBounds
 };
 use crate::utils::{
@@ -103,7 +103,9 @@ pub struct Thing
     /// The height of the bounding box.
     height:  f32,
     /// The name of the texture used to draw a preview.
-    preview: String
+    preview: String,
+    /// The rgb color the preview is tinted with in the editor, if any.
+    tint:    Option<[f32; 3]>
 }
 
 impl Thing
@@ -120,11 +122,26 @@ impl Thing
                 id: ThingId(id),
                 width,
                 height,
-                preview: preview.to_string()
+                preview: preview.to_string(),
+                tint: None
             }
         })
     }
 
+    /// Returns `self` with the preview tinted with the `rgb` color in the editor. The color values
+    /// are clamped between 0 and 1. The tint is not exported.
+    #[inline]
+    pub fn with_tint(mut self, rgb: [f32; 3]) -> Self
+    {
+        self.tint = rgb.map(|c| c.clamp(0f32, 1f32)).into();
+        self
+    }
+
+    /// Returns the name.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str { &self.name }
+
     /// Returns the [`ThingId`].
     #[inline]
     #[must_use]
@@ -139,6 +156,16 @@ impl Thing
     #[inline]
     #[must_use]
     pub const fn height(&self) -> f32 { self.height }
+
+    /// Returns the name of the texture used to draw a preview.
+    #[inline]
+    #[must_use]
+    pub fn preview(&self) -> &str { &self.preview }
+
+    /// Returns the rgb color the preview is tinted with in the editor, if any.
+    #[inline]
+    #[must_use]
+    pub const fn tint(&self) -> Option<[f32; 3]> { self.tint }
 }
 
 //=======================================================================//
@@ -532,10 +559,10 @@ impl ThingInstance
     /// box.
     #[inline]
     #[must_use]
-    pub fn check_thing_change(&self, thing: &Thing) -> bool
+    pub fn check_thing_change(&self, thing: &Thing, bound: f32) -> bool
     {
         let hull = ThingInstanceData::create_hull(self.data.pos, thing);
-        !hull.out_of_bounds() && !self.path_hull_out_of_bounds(hull.center())
+        !hull.out_of_bounds(bound) && !self.path_hull_out_of_bounds(hull.center(), bound)
     }
 
     /// Sets `self` to represent an instance of another [`Thing`].
@@ -546,10 +573,10 @@ impl ThingInstance
     /// Check whether `self` can be moved without being out of bounds.
     #[inline]
     #[must_use]
-    pub fn check_move(&self, delta: Vec2) -> bool
+    pub fn check_move(&self, delta: Vec2, bound: f32) -> bool
     {
-        !(self.data.hull + delta).out_of_bounds() &&
-            !self.path_hull_out_of_bounds(self.data.pos + delta)
+        !(self.data.hull + delta).out_of_bounds(bound) &&
+            !self.path_hull_out_of_bounds(self.data.pos + delta, bound)
     }
 
     /// Moves `self` by the vector `delta`.
@@ -592,10 +619,18 @@ impl ThingInstance
 
     /// Snaps `self` to the grid. Returns how much `self` was moved, if it was.
     #[inline]
-    pub fn snap(&mut self, grid: Grid) -> Option<Vec2>
+    pub fn snap(&mut self, grid: Grid, bound: f32) -> Option<Vec2>
     {
-        let delta = grid.snap_point(self.center())?;
-        self.check_move(delta).then_some(delta)
+        let center = self.center();
+        let delta = grid.snap_point(center)? - center;
+
+        if !self.check_move(delta, bound)
+        {
+            return None;
+        }
+
+        self.move_by_delta(delta);
+        delta.into()
     }
 
     /// Sets the property `key` to `value`. Returns the previous value if different.
@@ -670,6 +705,9 @@ pub struct ThingViewer
     pub angle:       f32,
     /// The draw height.
     pub draw_height: f32,
+    /// The [`Hull`] describing the size of the [`Thing`] as defined in the catalog, centered
+    /// at `pos`.
+    pub hull:        Hull,
     /// The optional associated [`Path`].
     pub path:        Option<Path>,
     pub properties:  HvHashMap<String, Value>
@@ -687,6 +725,7 @@ impl ThingViewer
             thing,
             pos,
             angle,
+            hull,
             path,
             properties,
             ..
@@ -698,8 +737,35 @@ impl ThingViewer
             pos,
             angle,
             draw_height,
+            hull,
             path,
             properties: properties.take()
         }
     }
+
+    /// Returns the order in which the thing should be spawned stored in the built-in spawn order
+    /// property, which is 0 if it was not assigned.
+    #[inline]
+    #[must_use]
+    pub fn spawn_order(&self) -> u32
+    {
+        match self.properties.get(SPAWN_ORDER_PROPERTY)
+        {
+            Some(Value::U32(order)) => *order,
+            _ => 0
+        }
+    }
+
+    /// Multiplies the position, size, and path of `self` by `factor`.
+    #[inline]
+    pub(in crate::map) fn export_scale(&mut self, factor: f32)
+    {
+        self.pos *= factor;
+        self.hull *= factor;
+
+        if let Some(path) = &mut self.path
+        {
+            path.export_scale(factor);
+        }
+    }
 }
@@ -0,0 +1,254 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::io::Write;
+
+use bevy::prelude::{UVec2, Vec2};
+
+use crate::{
+    map::{
+        brush::BrushViewer,
+        containers::HvHashMap,
+        drawer::texture::TextureInterface,
+        properties::Value,
+        thing::ThingViewer
+    },
+    utils::hull::Hull
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The version of the TMX format the files are written in.
+const TMX_VERSION: &str = "1.10";
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns `string` with the XML special characters replaced by their escape sequences.
+#[inline]
+#[must_use]
+fn escape(string: &str) -> String
+{
+    let mut escaped = String::with_capacity(string.len());
+
+    for c in string.chars()
+    {
+        match c
+        {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c)
+        };
+    }
+
+    escaped
+}
+
+//=======================================================================//
+
+/// Returns the name of the TMX property type associated with `value`.
+#[inline]
+#[must_use]
+const fn property_type(value: &Value) -> &'static str
+{
+    match value
+    {
+        Value::Bool(_) => "bool",
+        Value::U8(_) |
+        Value::U16(_) |
+        Value::U32(_) |
+        Value::U64(_) |
+        Value::U128(_) |
+        Value::I8(_) |
+        Value::I16(_) |
+        Value::I32(_) |
+        Value::I64(_) |
+        Value::I128(_) => "int",
+        Value::F32(_) | Value::F64(_) => "float",
+        Value::String(_) => "string"
+    }
+}
+
+//=======================================================================//
+
+/// Writes the properties of an object, the built-in ones in `builtin` followed by `properties`
+/// sorted by name.
+#[inline]
+fn write_properties<W: Write>(
+    writer: &mut W,
+    builtin: &[(&str, &str, String)],
+    properties: &HvHashMap<String, Value>
+) -> std::io::Result<()>
+{
+    let mut properties = properties.iter().collect::<Vec<_>>();
+    properties.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+    writeln!(writer, "   <properties>")?;
+
+    for (name, ty, value) in builtin
+    {
+        writeln!(
+            writer,
+            "    <property name=\"{name}\" type=\"{ty}\" value=\"{}\"/>",
+            escape(value)
+        )?;
+    }
+
+    for (name, value) in properties
+    {
+        writeln!(
+            writer,
+            "    <property name=\"{}\" type=\"{}\" value=\"{}\"/>",
+            escape(name),
+            property_type(value),
+            escape(&value.to_string())
+        )?;
+    }
+
+    writeln!(writer, "   </properties>")
+}
+
+//=======================================================================//
+
+/// Writes `brushes` and `things` to `writer` as a Tiled TMX map with square tiles of side
+/// `tile_size`.
+/// The brushes are stored as polygon objects in the "brushes" object group, the things as point
+/// objects in the "things" object group, both with their properties.
+/// The coordinates are converted to the Tiled ones, with the Y axis pointing down and the origin
+/// placed at the top left corner of the tiles encompassing `bounds`. The position of each polygon
+/// object is the center of its vertexes, which are written in clockwise order as seen in Tiled.
+/// # Errors
+/// Returns an error if writing to `writer` fails.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+#[inline]
+pub(in crate::map) fn write_tmx<'a, B, T, W>(
+    brushes: B,
+    things: T,
+    bounds: Option<Hull>,
+    tile_size: UVec2,
+    writer: &mut W
+) -> std::io::Result<()>
+where
+    B: Iterator<Item = &'a BrushViewer>,
+    T: Iterator<Item = &'a ThingViewer>,
+    W: Write
+{
+    assert!(tile_size.x != 0 && tile_size.y != 0, "Invalid tile size {tile_size}.");
+
+    let tile = tile_size.as_vec2();
+    let (origin, width, height) = match bounds
+    {
+        Some(hull) =>
+        {
+            let origin = Vec2::new(
+                (hull.left() / tile.x).floor() * tile.x,
+                (hull.top() / tile.y).ceil() * tile.y
+            );
+
+            (
+                origin,
+                ((hull.right() - origin.x) / tile.x).ceil() as u32,
+                ((origin.y - hull.bottom()) / tile.y).ceil() as u32
+            )
+        },
+        None => (Vec2::ZERO, 0, 0)
+    };
+    let to_tmx = |pos: Vec2| Vec2::new(pos.x - origin.x, origin.y - pos.y);
+
+    let mut brushes = brushes.collect::<Vec<_>>();
+    brushes.sort_by_key(|brush| brush.id.value());
+    let mut things = things.collect::<Vec<_>>();
+    things.sort_by_key(|thing| thing.id.value());
+
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        writer,
+        "<map version=\"{TMX_VERSION}\" orientation=\"orthogonal\" renderorder=\"right-down\" \
+         width=\"{width}\" height=\"{height}\" tilewidth=\"{}\" tileheight=\"{}\" \
+         infinite=\"0\" nextlayerid=\"3\" nextobjectid=\"{}\">",
+        tile_size.x,
+        tile_size.y,
+        brushes.len() + things.len() + 1
+    )?;
+
+    let mut object_id = 1;
+
+    writeln!(writer, " <objectgroup id=\"1\" name=\"brushes\">")?;
+
+    for brush in brushes
+    {
+        let center = brush.vertexes.iter().copied().sum::<Vec2>() / brush.vertexes.len() as f32;
+        let pos = to_tmx(center);
+
+        writeln!(writer, "  <object id=\"{object_id}\" x=\"{}\" y=\"{}\">", pos.x, pos.y)?;
+        write_properties(
+            writer,
+            &[
+                ("id", "int", brush.id.value().to_string()),
+                (
+                    "texture",
+                    "string",
+                    brush
+                        .texture
+                        .as_ref()
+                        .map_or(String::new(), |texture| texture.name().to_owned())
+                ),
+                ("collision", "bool", brush.collision.to_string())
+            ],
+            &brush.properties
+        )?;
+
+        let points = brush
+            .vertexes
+            .iter()
+            .rev()
+            .map(|vx| {
+                let vx = to_tmx(*vx) - pos;
+                format!("{},{}", vx.x, vx.y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        writeln!(writer, "   <polygon points=\"{points}\"/>")?;
+        writeln!(writer, "  </object>")?;
+
+        object_id += 1;
+    }
+
+    writeln!(writer, " </objectgroup>")?;
+    writeln!(writer, " <objectgroup id=\"2\" name=\"things\">")?;
+
+    for thing in things
+    {
+        let pos = to_tmx(thing.pos);
+
+        writeln!(writer, "  <object id=\"{object_id}\" x=\"{}\" y=\"{}\">", pos.x, pos.y)?;
+        write_properties(
+            writer,
+            &[
+                ("id", "int", thing.id.value().to_string()),
+                ("thing", "int", thing.thing_id.value().to_string()),
+                ("angle", "float", thing.angle.to_string()),
+                ("draw_height", "float", thing.draw_height.to_string())
+            ],
+            &thing.properties
+        )?;
+        writeln!(writer, "   <point/>")?;
+        writeln!(writer, "  </object>")?;
+
+        object_id += 1;
+    }
+
+    writeln!(writer, " </objectgroup>")?;
+    writeln!(writer, "</map>")
+}
@@ -6,7 +6,7 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
-    ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign}
+    ops::{Add, AddAssign, MulAssign, RangeInclusive, Sub, SubAssign}
 };
 
 use arrayvec::ArrayVec;
@@ -310,6 +310,19 @@ impl SubAssign<Vec2> for Hull
     }
 }
 
+impl MulAssign<f32> for Hull
+{
+    /// Scales the [`Hull`] by `rhs` with respect to the origin.
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32)
+    {
+        self.top *= rhs;
+        self.bottom *= rhs;
+        self.left *= rhs;
+        self.right *= rhs;
+    }
+}
+
 impl AroundEqual for Hull
 {
     #[inline]
@@ -56,6 +56,11 @@ impl Id
     /// [`Id`] with wrapped value equal to zero.
     pub(crate) const ZERO: Self = Self(0);
 
+    /// Returns the [`Id`] wrapping `value`.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(value: usize) -> Self { Self(value) }
+
     /// Returns the [`Id`] with the highest value.
     #[inline]
     #[must_use]
@@ -44,6 +44,27 @@ pub fn rotate_point(p: Vec2, o: Vec2, angle: f32) -> Vec2
 
 //=======================================================================//
 
+/// Rotates a point around origin `o` by 90 degrees, clockwise if `clockwise` is true.
+/// The coordinates are swapped rather than computed through trigonometry, so integer inputs
+/// return integer results.
+#[inline]
+#[must_use]
+pub fn rotate_point_quarter(p: Vec2, o: Vec2, clockwise: bool) -> Vec2
+{
+    let t = p - o;
+
+    if clockwise
+    {
+        Vec2::new(o.x + t.y, o.y - t.x)
+    }
+    else
+    {
+        Vec2::new(o.x - t.y, o.y + t.x)
+    }
+}
+
+//=======================================================================//
+
 /// Rotates a point around the origin.
 #[inline]
 #[must_use]
@@ -123,6 +123,14 @@ pub trait Camera
     /// Zooms in/out by `units`.
     fn zoom(&mut self, units: f32);
 
+    /// Returns the zoom as a percentage, where 100 means one world unit per pixel.
+    #[inline]
+    #[must_use]
+    fn zoom_percentage(&self) -> f32 { 100f32 / self.scale() }
+
+    /// Sets the zoom to exactly `percentage`, within the allowed scale range.
+    fn set_zoom_percentage(&mut self, percentage: f32);
+
     /// Zooms in.
     #[inline]
     fn zoom_in(&mut self) { self.zoom(1f32); }